@@ -0,0 +1,7 @@
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum AuthCommand {
+    /// Check AWS, Slack, Jira, GitHub, New Relic and PagerDuty credentials
+    Status,
+}
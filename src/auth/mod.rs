@@ -0,0 +1,216 @@
+//! `hu auth` — check every integration's credentials in one go
+
+mod cli;
+
+pub use cli::AuthCommand;
+
+use anstream::println;
+use anyhow::Result;
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+use std::future::Future;
+
+use crate::doctor::check_aws_session;
+use crate::util::shell::RealShell;
+
+/// What we learned about one integration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthState {
+    /// Credentials work; holds who they belong to
+    Authenticated(String),
+    /// No credentials stored
+    NotConfigured,
+    /// Credentials exist but the check failed
+    Failed(String),
+}
+
+/// One line of `hu auth status`
+#[derive(Debug, Clone)]
+pub struct AuthRow {
+    pub name: &'static str,
+    pub state: AuthState,
+    /// How to fix a row that isn't authenticated
+    pub hint: &'static str,
+}
+
+/// Run an auth subcommand
+#[cfg(not(tarpaulin_include))]
+pub async fn run_command(cmd: AuthCommand) -> Result<()> {
+    match cmd {
+        AuthCommand::Status => run_status().await,
+    }
+}
+
+/// Check every integration concurrently and print one table
+///
+/// A failing integration shows up red; it never fails the command.
+#[cfg(not(tarpaulin_include))]
+async fn run_status() -> Result<()> {
+    let credentials = crate::util::load_credentials();
+    let (github, jira) = match &credentials {
+        Ok(creds) => (Ok(creds.github.is_some()), Ok(creds.jira.is_some())),
+        Err(e) => (Err(anyhow::anyhow!("{}", e)), Err(anyhow::anyhow!("{}", e))),
+    };
+
+    let (aws, slack, jira, github, newrelic, pagerduty) = tokio::join!(
+        aws_state(),
+        probe(
+            crate::slack::get_config().map(|c| c.is_configured),
+            slack_identity
+        ),
+        probe(jira, jira_identity),
+        probe(github, crate::gh::get_current_login),
+        probe(
+            crate::newrelic::get_config().map(|c| c.is_configured()),
+            crate::newrelic::get_account_name
+        ),
+        probe(
+            crate::pagerduty::get_config().map(|c| c.is_configured()),
+            pagerduty_identity
+        ),
+    );
+
+    let rows = vec![
+        row("AWS", aws, "Run: aws sso login (or set AWS_PROFILE)"),
+        row("Slack", slack, "Run: hu slack auth"),
+        row("Jira", jira, "Run: hu jira auth"),
+        row("GitHub", github, "Run: hu gh login"),
+        row("New Relic", newrelic, "Run: hu newrelic auth <key>"),
+        row("PagerDuty", pagerduty, "Run: hu pagerduty auth <token>"),
+    ];
+    println!("{}", render(&rows));
+    Ok(())
+}
+
+fn row(name: &'static str, state: AuthState, hint: &'static str) -> AuthRow {
+    AuthRow { name, state, hint }
+}
+
+/// Ask the integration who we are, unless it isn't set up
+async fn probe<F>(configured: Result<bool>, whoami: impl FnOnce() -> F) -> AuthState
+where
+    F: Future<Output = Result<String>>,
+{
+    match configured {
+        Err(e) => AuthState::Failed(e.to_string()),
+        Ok(false) => AuthState::NotConfigured,
+        Ok(true) => match whoami().await {
+            Ok(identity) => AuthState::Authenticated(identity),
+            Err(e) => AuthState::Failed(e.to_string()),
+        },
+    }
+}
+
+/// AWS has no stored config of ours; the session is either valid or not
+#[cfg(not(tarpaulin_include))]
+async fn aws_state() -> AuthState {
+    let check = check_aws_session(&RealShell).await;
+    if check.ok {
+        AuthState::Authenticated(check.detail)
+    } else {
+        AuthState::Failed(check.detail)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn slack_identity() -> Result<String> {
+    let info = crate::slack::whoami().await?;
+    Ok(format!("{} ({})", info.user, info.team))
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn jira_identity() -> Result<String> {
+    let user = crate::jira::get_current_user().await?;
+    Ok(with_email(
+        &user.display_name,
+        user.email_address.as_deref(),
+    ))
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn pagerduty_identity() -> Result<String> {
+    let user = crate::pagerduty::get_current_user().await?;
+    Ok(with_email(user.display_name(), Some(&user.email)))
+}
+
+/// `Name <email>`, or just the name when there's no email
+fn with_email(name: &str, email: Option<&str>) -> String {
+    match email {
+        Some(email) if !email.is_empty() => format!("{} <{}>", name, email),
+        _ => name.to_string(),
+    }
+}
+
+/// Render rows as a ✓/✗ table
+fn render(rows: &[AuthRow]) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["", "Integration", "Identity"]);
+
+    for row in rows {
+        let (icon, color, detail) = match &row.state {
+            AuthState::Authenticated(identity) => ("✓", Color::Green, identity.clone()),
+            AuthState::NotConfigured => (
+                "✗",
+                Color::DarkGrey,
+                format!("not configured\n{}", row.hint),
+            ),
+            AuthState::Failed(error) => ("✗", Color::Red, format!("{}\n{}", error, row.hint)),
+        };
+        table.add_row(vec![
+            Cell::new(icon).fg(color),
+            Cell::new(row.name),
+            Cell::new(detail),
+        ]);
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn probe_skips_unconfigured() {
+        let state = probe(Ok(false), || async { panic!("should not be called") }).await;
+        assert_eq!(state, AuthState::NotConfigured);
+    }
+
+    #[tokio::test]
+    async fn probe_reports_identity_or_error() {
+        let ok = probe(Ok(true), || async { Ok("octocat".to_string()) }).await;
+        assert_eq!(ok, AuthState::Authenticated("octocat".to_string()));
+
+        let failed = probe(Ok(true), || async { anyhow::bail!("401 Unauthorized") }).await;
+        assert_eq!(failed, AuthState::Failed("401 Unauthorized".to_string()));
+
+        let unreadable = probe(Err(anyhow::anyhow!("bad settings")), || async {
+            Ok(String::new())
+        })
+        .await;
+        assert_eq!(unreadable, AuthState::Failed("bad settings".to_string()));
+    }
+
+    #[test]
+    fn with_email_skips_missing() {
+        assert_eq!(with_email("Ann", Some("ann@x.io")), "Ann <ann@x.io>");
+        assert_eq!(with_email("Ann", Some("")), "Ann");
+        assert_eq!(with_email("Ann", None), "Ann");
+    }
+
+    #[test]
+    fn render_shows_hints_only_for_problems() {
+        let rows = vec![
+            row("GitHub", AuthState::Authenticated("octocat".into()), "x"),
+            row("Slack", AuthState::NotConfigured, "Run: hu slack auth"),
+            row("Jira", AuthState::Failed("401".into()), "Run: hu jira auth"),
+        ];
+        let out = render(&rows);
+        assert!(out.contains("octocat"));
+        assert!(out.contains("not configured"));
+        assert!(out.contains("Run: hu slack auth"));
+        assert!(out.contains("401"));
+        assert!(out.contains("Run: hu jira auth"));
+        assert!(!out.contains(" x "));
+    }
+}
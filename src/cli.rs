@@ -1,5 +1,9 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use std::ffi::OsString;
+use std::path::PathBuf;
 
+use crate::auth::AuthCommand;
+use crate::config::ConfigCommand;
 use crate::context::ContextCommand;
 use crate::cron::CronCommand;
 use crate::data::DataCommand;
@@ -17,6 +21,8 @@ use crate::sentry::SentryCommand;
 use crate::setup::SetupCommand;
 use crate::shell::ShellCommand;
 use crate::slack::SlackCommands;
+use crate::status::StatusArgs;
+use crate::util::OutputFormat;
 use crate::utils::UtilsCommand;
 
 #[derive(Parser)]
@@ -24,6 +30,45 @@ use crate::utils::UtilsCommand;
 #[command(about = "Dev workflow CLI", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Output format (table, json, csv); also accepted after the subcommand
+    #[arg(short, long, value_enum)]
+    pub output: Option<OutputFormat>,
+
+    /// Keep only these keys in JSON/CSV output (comma-separated)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "FIELDS")]
+    pub fields: Option<Vec<String>>,
+
+    /// Show debug output (-v) or also trace output (-vv) on stderr
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Only print results, warnings and errors; no progress notes or spinners
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Disable colored output (also honors NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Settings file to use instead of ~/.config/hu/settings.toml
+    #[arg(long, global = true, env = "HU_CONFIG", value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Seconds before an HTTP request gives up [default: 30]
+    #[arg(
+        long,
+        global = true,
+        env = "HU_HTTP_TIMEOUT",
+        value_name = "SECS",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub http_timeout: Option<u64>,
+
+    /// Print the kubectl/aws/git commands that would run instead of running
+    /// them (eks exec/last/logs/describe/forward, pipeline, gh checkout)
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -136,4 +181,106 @@ pub enum Command {
         #[command(subcommand)]
         cmd: Option<SetupCommand>,
     },
+
+    /// Check external tools, AWS session and integration config
+    Doctor,
+
+    /// Check every integration's credentials (status)
+    Auth {
+        #[command(subcommand)]
+        cmd: Option<AuthCommand>,
+    },
+
+    /// Inspect and validate settings.toml (check, init, path, show)
+    Config {
+        #[command(subcommand)]
+        cmd: Option<ConfigCommand>,
+    },
+
+    /// Open PRs, PagerDuty alerts and New Relic incidents at a glance
+    Status(StatusArgs),
+
+    /// Move file-stored tokens into the OS keychain
+    MigrateSecrets,
+
+    /// Print a shell completion script (e.g. `hu completions bash > /etc/bash_completion.d/hu`)
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
+impl Command {
+    /// Whether every write this command makes is an external command that
+    /// `--dry-run` can print instead
+    pub fn supports_dry_run(&self) -> bool {
+        match self {
+            Command::Eks { cmd: Some(cmd) } => !matches!(cmd, EksCommand::List { .. }),
+            Command::Pipeline { cmd: Some(_) } => true,
+            Command::Gh {
+                cmd: Some(GhCommand::Checkout(_)),
+            } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Move `-o`/`--output` given after a subcommand to the front, so
+/// `hu pd oncall -o json` means `hu -o json pd oncall`
+///
+/// Works like a global flag, which clap can't declare here because `read`,
+/// `data export` and `utils` already use `-o`/`--output` for their own
+/// options; where the subcommand has such a flag it keeps it.
+pub fn hoist_output(args: Vec<OsString>) -> Vec<OsString> {
+    let root = Cli::command();
+    let mut cmd = &root;
+    let mut depth = 0;
+    let mut hoisted = Vec::new();
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.into_iter();
+    rest.extend(iter.next());
+
+    while let Some(arg) = iter.next() {
+        let Some(text) = arg.to_str().map(str::to_string) else {
+            rest.push(arg);
+            continue;
+        };
+        if text == "--" {
+            rest.push(arg);
+            rest.extend(iter.by_ref());
+            break;
+        }
+        if depth > 0 {
+            let (short, long) = (
+                text == "-o" || (text.starts_with("-o") && !text.starts_with("--")),
+                text == "--output" || text.starts_with("--output="),
+            );
+            let owned = cmd.get_arguments().any(|a| {
+                (short && a.get_short() == Some('o')) || (long && a.get_long() == Some("output"))
+            });
+            if (short || long) && !owned {
+                let takes_value = text == "-o" || text == "--output";
+                hoisted.push(arg);
+                if takes_value {
+                    hoisted.extend(iter.next());
+                }
+                continue;
+            }
+        }
+        if !text.starts_with('-') {
+            if let Some(sub) = cmd.find_subcommand(&text) {
+                cmd = sub;
+                depth += 1;
+            }
+        }
+        rest.push(arg);
+    }
+
+    let mut out = Vec::with_capacity(rest.len() + hoisted.len());
+    let mut rest = rest.into_iter();
+    out.extend(rest.next());
+    out.extend(hoisted);
+    out.extend(rest);
+    out
 }
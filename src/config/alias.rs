@@ -0,0 +1,353 @@
+//! `[aliases]` in `settings.toml`, expanded before clap sees the arguments
+//!
+//! Works like git aliases: `hu <alias> args...` is replaced by the alias's
+//! arguments. `$1`..`$9` take positional arguments and `$@` takes all of
+//! them; a template without placeholders gets the arguments appended.
+//! Built-in commands always win over an alias of the same name.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::util::{bail_kind, resolve_settings_path};
+
+/// Global flags that take a value, so the command word comes after it
+const VALUE_FLAGS: &[&str] = &["-o", "--output", "--config", "--http-timeout"];
+
+/// An alias's arguments: a whitespace-separated string, or a list for
+/// arguments that contain spaces
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum AliasArgs {
+    Line(String),
+    List(Vec<String>),
+}
+
+impl AliasArgs {
+    fn tokens(&self) -> Vec<String> {
+        let tokens: Vec<String> = match self {
+            AliasArgs::Line(line) => line.split_whitespace().map(str::to_string).collect(),
+            AliasArgs::List(list) => list.clone(),
+        };
+        // `hu` at the front is optional
+        match tokens.split_first() {
+            Some((first, rest)) if first == "hu" => rest.to_vec(),
+            _ => tokens,
+        }
+    }
+}
+
+/// Aliases by name, checked only when one is used
+type Aliases = HashMap<String, toml::Value>;
+
+/// Expand an alias in the process arguments (`args[0]` is the binary)
+///
+/// `builtins` are the real command names; they are never treated as
+/// aliases. Arguments that aren't valid UTF-8 are passed through as is.
+#[cfg(not(tarpaulin_include))]
+pub fn expand_aliases(args: Vec<OsString>, builtins: &[String]) -> Result<Vec<OsString>> {
+    let Some(strings) = args
+        .iter()
+        .map(|a| a.to_str().map(str::to_string))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(args);
+    };
+    let aliases = load_aliases(&strings)?;
+    if aliases.is_empty() {
+        return Ok(args);
+    }
+    Ok(expand(strings, &aliases, builtins)?
+        .into_iter()
+        .map(OsString::from)
+        .collect())
+}
+
+/// Read `[aliases]` from the settings file `--config` / `HU_CONFIG` points at
+#[cfg(not(tarpaulin_include))]
+fn load_aliases(args: &[String]) -> Result<Aliases> {
+    let over = config_flag(args).or_else(|| std::env::var_os("HU_CONFIG").map(PathBuf::from));
+    match resolve_settings_path(over.as_deref(), dirs::home_dir()) {
+        Some(path) if path.exists() => Ok(parse_aliases(&fs::read_to_string(&path)?)),
+        _ => Ok(HashMap::new()),
+    }
+}
+
+/// `[aliases]` from settings contents
+///
+/// A broken file yields no aliases rather than an error, so commands like
+/// `hu config check` still run and report it.
+fn parse_aliases(contents: &str) -> Aliases {
+    let Ok(mut settings) = toml::from_str::<toml::Table>(contents) else {
+        return HashMap::new();
+    };
+    match settings.remove("aliases") {
+        Some(toml::Value::Table(table)) => table.into_iter().collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// An alias's arguments, or a config error naming it
+fn alias_args(name: &str, value: &toml::Value) -> Result<AliasArgs> {
+    match value.clone().try_into() {
+        Ok(args) => Ok(args),
+        Err(_) => bail_kind!(
+            Config,
+            "Alias '{}' should be a string or a list of strings",
+            name
+        ),
+    }
+}
+
+/// Value of `--config`, which clap hasn't parsed yet
+fn config_flag(args: &[String]) -> Option<PathBuf> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            break;
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(PathBuf::from(path));
+        }
+        if arg == "--config" {
+            return iter.next().map(PathBuf::from);
+        }
+    }
+    None
+}
+
+/// Index of the command word: the first argument that isn't a global flag
+fn command_index(args: &[String]) -> Option<usize> {
+    let mut i = 1;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        if arg == "--" || !arg.starts_with('-') {
+            return (arg != "--").then_some(i);
+        }
+        i += if VALUE_FLAGS.contains(&arg) { 2 } else { 1 };
+    }
+    None
+}
+
+/// Expand aliases until the command word is a built-in (testable)
+///
+/// An alias may name another alias; revisiting one is an error.
+pub fn expand(
+    mut args: Vec<String>,
+    aliases: &Aliases,
+    builtins: &[String],
+) -> Result<Vec<String>> {
+    let mut chain: Vec<String> = Vec::new();
+    while let Some(idx) = command_index(&args) {
+        let name = args[idx].clone();
+        if builtins.contains(&name) {
+            break;
+        }
+        let Some(value) = aliases.get(&name) else {
+            break;
+        };
+        if chain.contains(&name) {
+            chain.push(name);
+            bail_kind!(Config, "Alias loop: {}", chain.join(" -> "));
+        }
+
+        let rest = args.split_off(idx + 1);
+        args.truncate(idx);
+        let alias = alias_args(&name, value)?;
+        args.extend(substitute(&name, &alias.tokens(), &rest)?);
+        chain.push(name);
+    }
+    Ok(args)
+}
+
+/// Fill `$1`..`$9` and `$@` in an alias's tokens
+fn substitute(name: &str, template: &[String], args: &[String]) -> Result<Vec<String>> {
+    let mut out = Vec::new();
+    let mut placeholders = false;
+
+    for token in template {
+        if token == "$@" {
+            out.extend(args.iter().cloned());
+            placeholders = true;
+            continue;
+        }
+        let mut filled = String::new();
+        let mut chars = token.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek().and_then(|d| d.to_digit(10))) {
+                ('$', Some(n)) if n > 0 => {
+                    chars.next();
+                    placeholders = true;
+                    let Some(arg) = args.get(n as usize - 1) else {
+                        bail_kind!(Config, "Alias '{}' needs at least {} argument(s)", name, n);
+                    };
+                    filled.push_str(arg);
+                }
+                _ => filled.push(c),
+            }
+        }
+        out.push(filled);
+    }
+
+    if !placeholders {
+        out.extend(args.iter().cloned());
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn aliases(toml: &str) -> Aliases {
+        parse_aliases(toml)
+    }
+
+    fn tokens(table: &Aliases, name: &str) -> Vec<String> {
+        alias_args(name, &table[name]).unwrap().tokens()
+    }
+
+    fn builtins() -> Vec<String> {
+        vec!["eks".to_string(), "gh".to_string(), "slack".to_string()]
+    }
+
+    #[test]
+    fn parse_line_and_list() {
+        let parsed = aliases(
+            r#"
+[aliases]
+mine = "gh prs"
+hello = ["slack", "send", "general", "hello there"]
+"#,
+        );
+        assert_eq!(tokens(&parsed, "mine"), args("gh prs"));
+        assert_eq!(tokens(&parsed, "hello").last().unwrap(), "hello there");
+        assert!(aliases("").is_empty());
+        assert!(aliases("[aliases\n").is_empty());
+    }
+
+    #[test]
+    fn bad_value_fails_only_when_used() {
+        let table = aliases("[aliases]\nx = 3\nok = \"gh prs\"\n");
+        assert!(expand(args("hu ok"), &table, &builtins()).is_ok());
+        let err = expand(args("hu x"), &table, &builtins()).unwrap_err();
+        assert!(err.to_string().contains("Alias 'x' should be"));
+    }
+
+    #[test]
+    fn leading_hu_is_optional() {
+        let parsed = aliases("[aliases]\nmine = \"hu gh prs\"\n");
+        assert_eq!(tokens(&parsed, "mine"), args("gh prs"));
+    }
+
+    #[test]
+    fn expand_appends_args_without_placeholders() {
+        let table = aliases("[aliases]\nci = \"gh runs\"\n");
+        let out = expand(args("hu ci --limit 5"), &table, &builtins()).unwrap();
+        assert_eq!(out, args("hu gh runs --limit 5"));
+    }
+
+    #[test]
+    fn expand_positional_and_all() {
+        let table = aliases(
+            r#"
+[aliases]
+logs = "eks logs --context $1 $2"
+say = "slack send $1 $@"
+"#,
+        );
+        let out = expand(args("hu logs prod web extra"), &table, &builtins()).unwrap();
+        assert_eq!(out, args("hu eks logs --context prod web"));
+
+        let out = expand(args("hu say general hi"), &table, &builtins()).unwrap();
+        assert_eq!(out, args("hu slack send general general hi"));
+    }
+
+    #[test]
+    fn expand_placeholder_inside_token() {
+        let table = aliases("[aliases]\ntail = \"eks logs --file=/app/log/$1.log\"\n");
+        let out = expand(args("hu tail deploy"), &table, &builtins()).unwrap();
+        assert_eq!(out, args("hu eks logs --file=/app/log/deploy.log"));
+    }
+
+    #[test]
+    fn expand_missing_argument_fails() {
+        let table = aliases("[aliases]\nlogs = \"eks logs $2\"\n");
+        let err = expand(args("hu logs prod"), &table, &builtins()).unwrap_err();
+        assert!(err.to_string().contains("needs at least 2"));
+    }
+
+    #[test]
+    fn expand_skips_global_flags() {
+        let table = aliases("[aliases]\nmine = \"gh prs\"\n");
+        let out = expand(
+            args("hu -o json --config x.toml -v mine"),
+            &table,
+            &builtins(),
+        )
+        .unwrap();
+        assert_eq!(out, args("hu -o json --config x.toml -v gh prs"));
+    }
+
+    #[test]
+    fn builtins_win_and_unknown_words_pass_through() {
+        let table = aliases("[aliases]\ngh = \"slack\"\n");
+        assert_eq!(
+            expand(args("hu gh prs"), &table, &builtins()).unwrap(),
+            args("hu gh prs")
+        );
+        assert_eq!(
+            expand(args("hu nope"), &table, &builtins()).unwrap(),
+            args("hu nope")
+        );
+        assert_eq!(
+            expand(args("hu --help"), &table, &builtins()).unwrap(),
+            args("hu --help")
+        );
+    }
+
+    #[test]
+    fn expand_chains_aliases() {
+        let table = aliases("[aliases]\na = \"b --x\"\nb = \"gh prs\"\n");
+        let out = expand(args("hu a"), &table, &builtins()).unwrap();
+        assert_eq!(out, args("hu gh prs --x"));
+    }
+
+    #[test]
+    fn expand_detects_loops() {
+        let table = aliases("[aliases]\na = \"b\"\nb = \"a\"\nself = \"self\"\n");
+        let err = expand(args("hu a"), &table, &builtins()).unwrap_err();
+        assert_eq!(err.to_string(), "Alias loop: a -> b -> a");
+        assert!(expand(args("hu self"), &table, &builtins()).is_err());
+    }
+
+    #[test]
+    fn config_flag_forms() {
+        assert_eq!(
+            config_flag(&args("hu --config a.toml gh")),
+            Some(PathBuf::from("a.toml"))
+        );
+        assert_eq!(
+            config_flag(&args("hu --config=b.toml")),
+            Some(PathBuf::from("b.toml"))
+        );
+        assert_eq!(config_flag(&args("hu -- --config c.toml")), None);
+        assert_eq!(config_flag(&args("hu gh")), None);
+    }
+
+    #[test]
+    fn command_index_finds_first_word() {
+        assert_eq!(command_index(&args("hu gh")), Some(1));
+        assert_eq!(command_index(&args("hu -o json gh")), Some(3));
+        assert_eq!(command_index(&args("hu --no-color")), None);
+        assert_eq!(command_index(&args("hu -- gh")), None);
+    }
+}
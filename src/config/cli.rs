@@ -0,0 +1,25 @@
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Validate settings.toml: unknown keys, wrong types, empty values
+    Check,
+    /// Write a commented example settings.toml
+    Init {
+        /// Overwrite an existing settings file
+        #[arg(long)]
+        force: bool,
+    },
+    /// List every config and credential file and whether it exists
+    Path {
+        /// Output as JSON
+        #[arg(short, long)]
+        json: bool,
+    },
+    /// Print effective settings with secrets redacted (TOML, or JSON)
+    Show {
+        /// Output as JSON
+        #[arg(short, long)]
+        json: bool,
+    },
+}
@@ -0,0 +1,213 @@
+//! `hu config` — inspect and validate `settings.toml`
+
+mod alias;
+mod cli;
+mod schema;
+mod show;
+mod template;
+
+pub use alias::expand_aliases;
+pub use cli::ConfigCommand;
+
+use anstream::{print, println};
+use anyhow::{bail, Context, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+use std::fs;
+use std::path::Path;
+
+use crate::util::{print_structured, OutputFormat};
+use schema::Report;
+use show::ConfigFile;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Run a config subcommand
+#[cfg(not(tarpaulin_include))]
+pub fn run_command(cmd: ConfigCommand) -> Result<()> {
+    match cmd {
+        ConfigCommand::Check => run_check(),
+        ConfigCommand::Init { force } => run_init(force),
+        ConfigCommand::Path { json } => run_path(json),
+        ConfigCommand::Show { json } => run_show(json),
+    }
+}
+
+/// List the files hu reads
+#[cfg(not(tarpaulin_include))]
+fn run_path(json: bool) -> Result<()> {
+    let files = show::config_files()?;
+    match OutputFormat::resolve(json) {
+        OutputFormat::Table => println!("{}", format_files(&files)),
+        format => print_structured(&files, format)?,
+    }
+    Ok(())
+}
+
+/// Print the settings every module ends up using
+#[cfg(not(tarpaulin_include))]
+fn run_show(json: bool) -> Result<()> {
+    let mut settings = show::effective_settings()?;
+    show::redact(&mut settings);
+    match OutputFormat::resolve(json) {
+        OutputFormat::Table => print!("{}", show::to_toml(&settings)?),
+        format => print_structured(&settings, format)?,
+    }
+    Ok(())
+}
+
+/// Write the example settings file
+#[cfg(not(tarpaulin_include))]
+fn run_init(force: bool) -> Result<()> {
+    let path = crate::util::settings_path().context("Cannot determine config directory")?;
+    write_template(&path, force)?;
+    println!("{GREEN}✓{RESET} Wrote {}", path.display());
+    Ok(())
+}
+
+/// Write [`template::SETTINGS_TEMPLATE`] to `path`, refusing to clobber a file
+fn write_template(path: &Path, force: bool) -> Result<()> {
+    if path.exists() && !force {
+        bail!(
+            "{} already exists. Pass --force to overwrite it",
+            path.display()
+        );
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    fs::write(path, template::SETTINGS_TEMPLATE)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Check the settings file, failing if anything is wrong
+#[cfg(not(tarpaulin_include))]
+fn run_check() -> Result<()> {
+    let path = crate::util::settings_path().context("Cannot determine config directory")?;
+    if !path.exists() {
+        println!(
+            "{GRAY}No settings file at {}; every section uses defaults{RESET}",
+            path.display()
+        );
+        return Ok(());
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let report =
+        schema::check_settings(&contents).with_context(|| format!("In {}", path.display()))?;
+
+    println!("{}", format_report(&report));
+    if !report.is_ok() {
+        bail!("{} problem(s) in {}", report.problems.len(), path.display());
+    }
+    Ok(())
+}
+
+/// One line per problem, then the sections left on defaults
+fn format_report(report: &Report) -> String {
+    let mut lines: Vec<String> = report
+        .problems
+        .iter()
+        .map(|p| format!("{RED}✗{RESET} {}: {}", p.path, p.message))
+        .collect();
+    if report.is_ok() {
+        lines.push(format!("{GREEN}✓{RESET} No problems found"));
+    }
+    if !report.defaulted.is_empty() {
+        lines.push(format!(
+            "{GRAY}Using defaults: {}{RESET}",
+            report.defaulted.join(", ")
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Render files as a ✓/✗ table
+fn format_files(files: &[ConfigFile]) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["", "File", "Path"]);
+    for file in files {
+        let (icon, color) = if file.exists {
+            ("✓", Color::Green)
+        } else {
+            ("✗", Color::DarkGrey)
+        };
+        table.add_row(vec![
+            Cell::new(icon).fg(color),
+            Cell::new(file.name),
+            Cell::new(file.path.display()),
+        ]);
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_report_lists_problems_and_defaults() {
+        let report = schema::check_settings("[jira]\ncache_tll = 5\n").unwrap();
+        let out = format_report(&report);
+        assert!(out.contains("jira.cache_tll: unknown key (did you mean `cache_ttl`?)"));
+        assert!(out.contains("Using defaults: general, sync"));
+        assert!(!out.contains("No problems"));
+    }
+
+    #[test]
+    fn template_passes_check() {
+        let report = schema::check_settings(template::SETTINGS_TEMPLATE).unwrap();
+        assert!(report.is_ok(), "{:?}", report.problems);
+        assert!(report.defaulted.is_empty(), "{:?}", report.defaulted);
+    }
+
+    #[test]
+    fn write_template_refuses_to_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hu").join("settings.toml");
+
+        write_template(&path, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            template::SETTINGS_TEMPLATE
+        );
+
+        fs::write(&path, "secure_credentials = true\n").unwrap();
+        let err = write_template(&path, false).unwrap_err();
+        assert!(err.to_string().contains("--force"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "secure_credentials = true\n"
+        );
+
+        write_template(&path, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            template::SETTINGS_TEMPLATE
+        );
+    }
+
+    #[test]
+    fn format_files_marks_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![
+            ConfigFile::new("settings", dir.path().join("settings.toml")),
+            ConfigFile::new("config dir", dir.path().to_path_buf()),
+        ];
+        let out = format_files(&files);
+        assert!(out.contains("settings.toml"));
+        assert!(out.contains("✗"));
+        assert!(out.contains("✓"));
+    }
+
+    #[test]
+    fn format_report_clean() {
+        let report = schema::check_settings("").unwrap();
+        assert!(format_report(&report).contains("No problems found"));
+    }
+}
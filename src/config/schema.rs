@@ -0,0 +1,371 @@
+//! Known shape of `settings.toml`
+//!
+//! Every module reads its own `[section]` and ignores what it doesn't know,
+//! so a typo silently falls back to the default. This table lists what the
+//! loaders actually read, and [`check_settings`] diffs a file against it.
+
+use anyhow::{Context, Result};
+use toml::Value;
+
+/// Expected type of a setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Bool,
+    Int,
+    Str,
+    StrList,
+    /// A string or a list of strings (alias arguments)
+    Args,
+    Table(&'static [(&'static str, Kind)]),
+    /// A table of user-chosen keys, every value of the given kind
+    Map(&'static Kind),
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::Bool => "a boolean",
+            Kind::Int => "an integer",
+            Kind::Str => "a string",
+            Kind::StrList => "a list of strings",
+            Kind::Args => "a string or a list of strings",
+            Kind::Table(_) | Kind::Map(_) => "a table",
+        }
+    }
+}
+
+const GENERAL: &[(&str, Kind)] = &[("claude_dir", Kind::Str), ("database", Kind::Str)];
+
+const SYNC: &[(&str, Kind)] = &[
+    ("auto_sync_interval", Kind::Int),
+    ("sync_on_start", Kind::Bool),
+];
+
+const ERRORS: &[(&str, Kind)] = &[("patterns", Kind::StrList), ("limit", Kind::Int)];
+
+const JIRA: &[(&str, Kind)] = &[("cache_ttl", Kind::Int)];
+
+const EKS: &[(&str, Kind)] = &[
+    ("default_port", Kind::Int),
+    ("confirm_prod", Kind::Bool),
+    ("prod_contexts", Kind::StrList),
+    ("namespaces", Kind::Map(&Kind::Str)),
+];
+
+const SLACK_OAUTH: &[(&str, Kind)] = &[
+    ("client_id", Kind::Str),
+    ("client_secret", Kind::Str),
+    ("bot_token", Kind::Str),
+    ("user_token", Kind::Str),
+    ("team_id", Kind::Str),
+    ("team_name", Kind::Str),
+];
+
+const SLACK: &[(&str, Kind)] = &[
+    ("default_channel", Kind::Str),
+    ("tidy_exclude", Kind::StrList),
+    ("oauth", Kind::Table(SLACK_OAUTH)),
+];
+
+const PAGERDUTY: &[(&str, Kind)] = &[
+    ("api_token", Kind::Str),
+    ("escalation_policy_ids", Kind::StrList),
+    ("schedule_ids", Kind::StrList),
+];
+
+const NEWRELIC: &[(&str, Kind)] = &[("api_key", Kind::Str), ("account_id", Kind::Int)];
+
+const SENTRY: &[(&str, Kind)] = &[
+    ("auth_token", Kind::Str),
+    ("organization", Kind::Str),
+    ("project", Kind::Str),
+];
+
+/// Top level of `settings.toml`
+pub const ROOT: &[(&str, Kind)] = &[
+    ("secure_credentials", Kind::Bool),
+    ("general", Kind::Table(GENERAL)),
+    ("sync", Kind::Table(SYNC)),
+    ("errors", Kind::Table(ERRORS)),
+    ("jira", Kind::Table(JIRA)),
+    ("eks", Kind::Table(EKS)),
+    ("slack", Kind::Table(SLACK)),
+    ("pagerduty", Kind::Table(PAGERDUTY)),
+    ("newrelic", Kind::Table(NEWRELIC)),
+    ("sentry", Kind::Table(SENTRY)),
+    ("aliases", Kind::Map(&Kind::Args)),
+];
+
+/// Something wrong with one setting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Dotted path, e.g. `slack.oauth.bot_tokn`
+    pub path: String,
+    pub message: String,
+}
+
+/// Outcome of checking a settings file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub problems: Vec<Problem>,
+    /// Sections absent from the file, so every value is the default
+    pub defaulted: Vec<&'static str>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Check settings contents against [`ROOT`]
+///
+/// Fails only if the file isn't valid TOML; everything else is a problem
+/// in the report.
+pub fn check_settings(contents: &str) -> Result<Report> {
+    let value: toml::Table = toml::from_str(contents).context("Settings are not valid TOML")?;
+    let mut report = Report::default();
+    check_table(&value, ROOT, "", &mut report.problems);
+    report.defaulted = ROOT
+        .iter()
+        .filter(|(name, kind)| matches!(kind, Kind::Table(_)) && !value.contains_key(*name))
+        .map(|(name, _)| *name)
+        .collect();
+    Ok(report)
+}
+
+fn check_table(
+    table: &toml::Table,
+    known: &[(&str, Kind)],
+    prefix: &str,
+    problems: &mut Vec<Problem>,
+) {
+    for (key, value) in table {
+        let path = format!("{}{}", prefix, key);
+        match known.iter().find(|(name, _)| name == key) {
+            Some((_, kind)) => check_value(value, *kind, &path, problems),
+            None => problems.push(Problem {
+                message: unknown_message(key, known),
+                path,
+            }),
+        }
+    }
+}
+
+fn check_value(value: &Value, kind: Kind, path: &str, problems: &mut Vec<Problem>) {
+    let mut problem = |message: String| {
+        problems.push(Problem {
+            path: path.to_string(),
+            message,
+        })
+    };
+    match (kind, value) {
+        (Kind::Bool, Value::Boolean(_)) | (Kind::Int, Value::Integer(_)) => {}
+        (Kind::Str, Value::String(s)) => {
+            if s.trim().is_empty() {
+                problem("is empty".to_string());
+            }
+        }
+        (Kind::Args, Value::String(s)) => {
+            if s.trim().is_empty() {
+                problem("is empty".to_string());
+            }
+        }
+        (Kind::StrList | Kind::Args, Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate() {
+                match item.as_str() {
+                    Some(s) if s.trim().is_empty() => problem(format!("entry {} is empty", i + 1)),
+                    Some(_) => {}
+                    None => problem(format!("entry {} should be a string", i + 1)),
+                }
+            }
+        }
+        (Kind::Table(known), Value::Table(table)) => {
+            check_table(table, known, &format!("{}.", path), problems)
+        }
+        (Kind::Map(kind), Value::Table(table)) => {
+            for (key, value) in table {
+                check_value(value, *kind, &format!("{}.{}", path, key), problems);
+            }
+        }
+        (kind, value) => problem(format!(
+            "should be {}, found {}",
+            kind.describe(),
+            value.type_str()
+        )),
+    }
+}
+
+/// "unknown key", with a suggestion when a known key is one typo away
+fn unknown_message(key: &str, known: &[(&str, Kind)]) -> String {
+    match known
+        .iter()
+        .map(|(name, _)| *name)
+        .find(|name| edit_distance(key, name) <= 2)
+    {
+        Some(name) => format!("unknown key (did you mean `{}`?)", name),
+        None => "unknown key".to_string(),
+    }
+}
+
+/// Levenshtein distance between two short strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(cur).min(row[j])
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(report: &Report) -> Vec<&str> {
+        report.problems.iter().map(|p| p.path.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_file_uses_defaults_everywhere() {
+        let report = check_settings("").unwrap();
+        assert!(report.is_ok());
+        assert!(report.defaulted.contains(&"general"));
+        assert!(report.defaulted.contains(&"sentry"));
+        assert!(!report.defaulted.contains(&"secure_credentials"));
+    }
+
+    #[test]
+    fn valid_file_has_no_problems() {
+        let report = check_settings(
+            r#"
+secure_credentials = true
+
+[eks]
+default_port = 8080
+prod_contexts = ["prod", "live"]
+
+[slack.oauth]
+bot_token = "xoxb-1"
+"#,
+        )
+        .unwrap();
+        assert!(report.is_ok(), "{:?}", report.problems);
+        assert!(!report.defaulted.contains(&"eks"));
+        assert!(!report.defaulted.contains(&"slack"));
+    }
+
+    #[test]
+    fn unknown_keys_report_dotted_path() {
+        let report = check_settings(
+            r#"
+[enviroments]
+x = 1
+
+[slack.oauth]
+bot_tokn = "xoxb-1"
+"#,
+        )
+        .unwrap();
+        assert_eq!(paths(&report), vec!["enviroments", "slack.oauth.bot_tokn"]);
+        assert_eq!(
+            report.problems[1].message,
+            "unknown key (did you mean `bot_token`?)"
+        );
+        assert_eq!(report.problems[0].message, "unknown key");
+    }
+
+    #[test]
+    fn wrong_types_are_reported() {
+        let report = check_settings(
+            r#"
+secure_credentials = "yes"
+
+[jira]
+cache_ttl = "60"
+
+[errors]
+patterns = "panicked"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            paths(&report),
+            vec!["errors.patterns", "jira.cache_ttl", "secure_credentials"]
+        );
+        assert_eq!(
+            report.problems[1].message,
+            "should be an integer, found string"
+        );
+    }
+
+    #[test]
+    fn empty_values_are_reported() {
+        let report = check_settings(
+            r#"
+[eks]
+prod_contexts = ["prod", "", 3]
+
+[sentry]
+organization = "  "
+"#,
+        )
+        .unwrap();
+        let messages: Vec<&str> = report.problems.iter().map(|p| p.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["entry 2 is empty", "entry 3 should be a string", "is empty"]
+        );
+    }
+
+    #[test]
+    fn section_given_as_value_is_a_type_error() {
+        let report = check_settings("sync = true\n").unwrap();
+        assert_eq!(
+            report.problems[0].message,
+            "should be a table, found boolean"
+        );
+        assert!(!report.defaulted.contains(&"sync"));
+    }
+
+    #[test]
+    fn aliases_accept_any_name() {
+        let report = check_settings(
+            r#"
+[aliases]
+mine = "gh prs"
+hello = ["slack", "send", "general", "hello there"]
+empty = ""
+bad = 3
+"#,
+        )
+        .unwrap();
+        assert_eq!(paths(&report), vec!["aliases.bad", "aliases.empty"]);
+        assert_eq!(
+            report.problems[0].message,
+            "should be a string or a list of strings, found integer"
+        );
+    }
+
+    #[test]
+    fn invalid_toml_fails() {
+        assert!(check_settings("[eks\n").is_err());
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("enviroments", "environments"), 1);
+        assert_eq!(edit_distance("limit", "limit"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
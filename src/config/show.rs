@@ -0,0 +1,182 @@
+//! Where `hu` keeps its files, and the settings it ends up using
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Placeholder for redacted values
+const REDACTED: &str = "<redacted>";
+
+/// A file `hu` reads
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigFile {
+    pub name: &'static str,
+    pub path: PathBuf,
+    pub exists: bool,
+}
+
+impl ConfigFile {
+    pub fn new(name: &'static str, path: PathBuf) -> Self {
+        let exists = path.exists();
+        Self { name, path, exists }
+    }
+}
+
+/// Every config, credential and state file, in the order they matter
+#[cfg(not(tarpaulin_include))]
+pub fn config_files() -> Result<Vec<ConfigFile>> {
+    let dir = crate::util::config_dir()?;
+    let settings = crate::util::settings_path().context("Cannot determine config directory")?;
+    Ok(vec![
+        ConfigFile::new("settings", settings),
+        ConfigFile::new("credentials", crate::util::credentials_path()?),
+        ConfigFile::new("jira oauth", dir.join("jira-oauth.toml")),
+        ConfigFile::new("jira cache", dir.join("jira-cache.json")),
+        ConfigFile::new("eks last exec", dir.join("eks-last.toml")),
+        ConfigFile::new("newrelic queries", crate::newrelic::queries_path()?),
+        ConfigFile::new("data database", crate::data::load_data_config()?.database),
+    ])
+}
+
+/// Settings after defaults, environment variables and the keychain
+///
+/// Laid out like `settings.toml`, from each module's own loader.
+#[cfg(not(tarpaulin_include))]
+pub fn effective_settings() -> Result<Value> {
+    let data = crate::data::load_data_config()?;
+    let eks = crate::eks::load_config()?;
+    let slack = crate::slack::get_config()?;
+
+    Ok(json!({
+        "secure_credentials": crate::util::secrets::enabled(),
+        "general": {
+            "claude_dir": data.claude_dir.display().to_string(),
+            "database": data.database.display().to_string(),
+        },
+        "sync": {
+            "auto_sync_interval": data.auto_sync_interval,
+            "sync_on_start": data.sync_on_start,
+        },
+        "errors": {
+            "patterns": data.error_patterns,
+            "limit": data.error_limit,
+        },
+        "jira": { "cache_ttl": crate::jira::load_config()?.cache_ttl },
+        "eks": {
+            "default_port": eks.default_port,
+            "confirm_prod": eks.confirm_prod,
+            "prod_contexts": eks.prod_contexts,
+            "namespaces": eks.namespaces,
+        },
+        "slack": {
+            "default_channel": slack.default_channel,
+            "tidy_exclude": slack.tidy_exclude,
+            "oauth": slack.oauth,
+        },
+        "pagerduty": crate::pagerduty::get_config()?,
+        "newrelic": crate::newrelic::get_config()?,
+        "sentry": crate::sentry::get_config()?,
+    }))
+}
+
+/// Replace every string under a token-like key with a placeholder
+pub fn redact(value: &mut Value) {
+    let pattern = Regex::new(r"(?i)(token|secret|password|api_?key)").expect("valid regex");
+    redact_with(value, &pattern);
+}
+
+fn redact_with(value: &mut Value, pattern: &Regex) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if pattern.is_match(key) && value.as_str().is_some_and(|s| !s.is_empty()) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_with(value, pattern);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact_with(item, pattern)),
+        _ => {}
+    }
+}
+
+/// Render settings as TOML; unset values (null) are left out
+pub fn to_toml(value: &Value) -> Result<String> {
+    let mut value = value.clone();
+    drop_nulls(&mut value);
+    toml::to_string(&value).context("Failed to render settings as TOML")
+}
+
+fn drop_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, v| !v.is_null());
+            map.values_mut().for_each(drop_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(drop_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Value {
+        json!({
+            "secure_credentials": false,
+            "eks": { "default_port": null, "prod_contexts": ["prod"] },
+            "slack": {
+                "default_channel": "#dev",
+                "oauth": { "bot_token": "xoxb-123", "client_secret": null, "team_id": "T1" },
+            },
+            "newrelic": { "api_key": "NRAK-1", "account_id": 42 },
+            "pagerduty": { "api_token": "" },
+        })
+    }
+
+    #[test]
+    fn redact_hides_token_like_keys() {
+        let mut value = sample();
+        redact(&mut value);
+        assert_eq!(value["slack"]["oauth"]["bot_token"], REDACTED);
+        assert_eq!(value["newrelic"]["api_key"], REDACTED);
+        assert_eq!(value["slack"]["oauth"]["team_id"], "T1");
+        assert_eq!(value["newrelic"]["account_id"], 42);
+        assert!(value["slack"]["oauth"]["client_secret"].is_null());
+        assert_eq!(value["pagerduty"]["api_token"], "");
+    }
+
+    #[test]
+    fn to_toml_drops_nulls() {
+        let out = to_toml(&sample()).unwrap();
+        assert!(out.contains("secure_credentials = false"));
+        assert!(out.contains("[eks]"));
+        assert!(out.contains("prod_contexts = [\"prod\"]"));
+        assert!(!out.contains("default_port"));
+        assert!(!out.contains("client_secret"));
+        assert!(out.contains("[slack.oauth]"));
+    }
+
+    #[test]
+    fn to_toml_output_passes_check() {
+        let mut value = sample();
+        value["pagerduty"]["api_token"] = json!("pd-1");
+        redact(&mut value);
+        let out = to_toml(&value).unwrap();
+        let report = super::super::schema::check_settings(&out).unwrap();
+        assert!(report.is_ok(), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn config_file_checks_existence() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = ConfigFile::new("settings", dir.path().join("settings.toml"));
+        assert!(!file.exists);
+        let file = ConfigFile::new("dir", dir.path().to_path_buf());
+        assert!(file.exists);
+    }
+}
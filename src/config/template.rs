@@ -0,0 +1,79 @@
+//! Example `settings.toml` written by `hu config init`
+
+/// Every section with its defaults; keys without a default are commented out
+pub const SETTINGS_TEMPLATE: &str = r##"# hu settings
+#
+# Every value below is the default; delete or change what you need.
+# Commented-out keys have no default. Run `hu config check` after editing.
+
+# Keep tokens in the OS keychain instead of this file (see `hu migrate-secrets`)
+secure_credentials = false
+
+# Claude Code session data (`hu data`)
+[general]
+claude_dir = "~/.claude"
+# Relative paths are resolved against ~/.config/hu
+database = "hu.db"
+
+[sync]
+# Seconds between automatic syncs
+auto_sync_interval = 300
+sync_on_start = true
+
+# `hu data errors`
+[errors]
+limit = 50
+# Regexes a debug log line must match to count as an error
+# patterns = ["(?i)(error|failed|exception|warning|ENOENT|EACCES|EPERM)"]
+
+[jira]
+# Seconds a fetched issue is reused; 0 disables the cache
+cache_ttl = 60
+
+[eks]
+# Ask before `hu eks exec` into a production context
+confirm_prod = true
+# Context name fragments that mark a context as production
+prod_contexts = ["prod"]
+# Pod port `hu eks forward` uses when none is given
+# default_port = 8080
+
+# Namespace per context name fragment when -n isn't given
+# [eks.namespaces]
+# prod = "web-prod"
+# dev = "web"
+
+[slack]
+# default_channel = "#general"
+# Channels `hu slack tidy` never marks as read
+tidy_exclude = []
+
+# Filled in by `hu slack auth`
+# [slack.oauth]
+# client_id = ""
+# client_secret = ""
+# bot_token = ""       # or SLACK_BOT_TOKEN
+# user_token = ""      # or SLACK_USER_TOKEN
+# team_id = ""
+# team_name = ""
+
+[pagerduty]
+# api_token = ""       # or PAGERDUTY_API_TOKEN; set by `hu pagerduty auth`
+# Only show on-call for these escalation policies / schedules
+escalation_policy_ids = []
+schedule_ids = []
+
+[newrelic]
+# api_key = ""         # or NEW_RELIC_API_KEY; set by `hu newrelic auth`
+# account_id = 0       # or NEW_RELIC_ACCOUNT_ID
+
+[sentry]
+# auth_token = ""      # or SENTRY_AUTH_TOKEN
+# organization = ""    # or SENTRY_ORG
+# project = ""         # or SENTRY_PROJECT
+
+# [aliases]
+# Run with `hu <name> args...`; $1..$9 take positional args, $@ takes all
+# prs = "gh prs --json"
+# tail = "eks logs $1 -n $2 -f"
+"##;
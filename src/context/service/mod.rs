@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::{Context, Result};
 use std::fs;
 use std::io::BufRead;
@@ -77,7 +78,7 @@ pub fn summary_with_store(store: &impl ContextStore) -> Result<()> {
     println!();
 
     let mut entries: Vec<_> = state.all_entries().into_iter().collect();
-    entries.sort_by(|a, b| b.tracked_at.cmp(&a.tracked_at));
+    entries.sort_by_key(|e| std::cmp::Reverse(e.tracked_at));
 
     for entry in &entries {
         let age = format_age(now.saturating_sub(entry.tracked_at));
@@ -17,7 +17,7 @@ pub struct AddArgs {
     /// Command to run
     pub command: String,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
 
@@ -27,7 +27,7 @@ pub struct ListArgs {
     #[arg(long)]
     pub hu_only: bool,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
 
@@ -39,7 +39,7 @@ pub struct RemoveArgs {
     #[arg(long, short)]
     pub force: bool,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
 
@@ -6,11 +6,7 @@ use super::types::CronJob;
 mod tests;
 
 /// Format job list as a pretty table
-pub fn format_jobs(jobs: &[CronJob], json: bool) -> String {
-    if json {
-        return serde_json::to_string_pretty(jobs).unwrap_or_else(|_| "[]".to_string());
-    }
-
+pub fn format_jobs(jobs: &[CronJob]) -> String {
     if jobs.is_empty() {
         return "No cron jobs found".to_string();
     }
@@ -53,11 +49,7 @@ pub fn format_jobs(jobs: &[CronJob], json: bool) -> String {
 }
 
 /// Format a single added job
-pub fn format_added(job: &CronJob, json: bool) -> String {
-    if json {
-        return serde_json::to_string_pretty(job).unwrap_or_else(|_| "{}".to_string());
-    }
-
+pub fn format_added(job: &CronJob) -> String {
     format!(
         "\x1b[32m\u{2713}\x1b[0m Added {} job: {} {}",
         job.schedule_name.as_deref().unwrap_or("cron"),
@@ -67,11 +59,7 @@ pub fn format_added(job: &CronJob, json: bool) -> String {
 }
 
 /// Format removed jobs
-pub fn format_removed(jobs: &[CronJob], json: bool) -> String {
-    if json {
-        return serde_json::to_string_pretty(jobs).unwrap_or_else(|_| "[]".to_string());
-    }
-
+pub fn format_removed(jobs: &[CronJob]) -> String {
     if jobs.is_empty() {
         return "No matching jobs found".to_string();
     }
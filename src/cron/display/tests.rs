@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn format_jobs_empty() {
     let jobs: Vec<CronJob> = vec![];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("No cron jobs"));
 }
 
@@ -15,7 +15,7 @@ fn format_jobs_single() {
         schedule_name: Some("daily".to_string()),
         is_hu_job: true,
     }];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("daily"));
     assert!(output.contains("echo hello"));
     assert!(output.contains("hu"));
@@ -37,30 +37,23 @@ fn format_jobs_multiple() {
             is_hu_job: false,
         },
     ];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("hourly"));
     assert!(output.contains("job1"));
     assert!(output.contains("job2"));
 }
 
 #[test]
-fn format_jobs_json() {
+fn cron_job_serializes_for_structured_output() {
     let jobs = vec![CronJob {
         expression: "35 18 * * *".to_string(),
         command: "test".to_string(),
         schedule_name: Some("daily".to_string()),
         is_hu_job: true,
     }];
-    let output = format_jobs(&jobs, true);
-    assert!(output.contains("\"expression\""));
-    assert!(output.contains("\"is_hu_job\": true"));
-}
-
-#[test]
-fn format_jobs_json_empty() {
-    let jobs: Vec<CronJob> = vec![];
-    let output = format_jobs(&jobs, true);
-    assert_eq!(output, "[]");
+    let value = serde_json::to_value(&jobs).unwrap();
+    assert_eq!(value[0]["expression"], "35 18 * * *");
+    assert_eq!(value[0]["is_hu_job"], true);
 }
 
 #[test]
@@ -71,29 +64,16 @@ fn format_added_basic() {
         schedule_name: Some("daily".to_string()),
         is_hu_job: true,
     };
-    let output = format_added(&job, false);
+    let output = format_added(&job);
     assert!(output.contains("\u{2713}")); // checkmark
     assert!(output.contains("daily"));
     assert!(output.contains("35 18 * * *"));
 }
 
-#[test]
-fn format_added_json() {
-    let job = CronJob {
-        expression: "35 18 * * *".to_string(),
-        command: "test".to_string(),
-        schedule_name: Some("daily".to_string()),
-        is_hu_job: true,
-    };
-    let output = format_added(&job, true);
-    assert!(output.contains("\"expression\""));
-    assert!(output.contains("35 18 * * *"));
-}
-
 #[test]
 fn format_removed_empty() {
     let jobs: Vec<CronJob> = vec![];
-    let output = format_removed(&jobs, false);
+    let output = format_removed(&jobs);
     assert!(output.contains("No matching jobs"));
 }
 
@@ -105,7 +85,7 @@ fn format_removed_single() {
         schedule_name: None,
         is_hu_job: false,
     }];
-    let output = format_removed(&jobs, false);
+    let output = format_removed(&jobs);
     assert!(output.contains("Removed 1 job"));
     assert!(!output.contains("jobs:")); // singular
 }
@@ -126,24 +106,12 @@ fn format_removed_multiple() {
             is_hu_job: false,
         },
     ];
-    let output = format_removed(&jobs, false);
+    let output = format_removed(&jobs);
     assert!(output.contains("Removed 2 jobs"));
     assert!(output.contains("job1"));
     assert!(output.contains("job2"));
 }
 
-#[test]
-fn format_removed_json() {
-    let jobs = vec![CronJob {
-        expression: "35 18 * * *".to_string(),
-        command: "test".to_string(),
-        schedule_name: None,
-        is_hu_job: false,
-    }];
-    let output = format_removed(&jobs, true);
-    assert!(output.contains("\"expression\""));
-}
-
 #[test]
 fn truncate_command_short() {
     let cmd = "echo hello";
@@ -172,7 +140,7 @@ fn format_jobs_no_schedule_name() {
         schedule_name: None,
         is_hu_job: false,
     }];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("-")); // dash for no schedule name
     assert!(output.contains("midnight job"));
 }
@@ -185,7 +153,7 @@ fn format_jobs_table_has_headers() {
         schedule_name: Some("daily".to_string()),
         is_hu_job: true,
     }];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("Schedule"));
     assert!(output.contains("Time"));
     assert!(output.contains("Command"));
@@ -199,7 +167,7 @@ fn format_added_no_schedule_name() {
         schedule_name: None,
         is_hu_job: false,
     };
-    let output = format_added(&job, false);
+    let output = format_added(&job);
     assert!(output.contains("cron job")); // fallback
 }
 
@@ -211,6 +179,6 @@ fn format_jobs_long_command_truncated() {
         schedule_name: None,
         is_hu_job: false,
     }];
-    let output = format_jobs(&jobs, false);
+    let output = format_jobs(&jobs);
     assert!(output.contains("..."));
 }
@@ -5,8 +5,11 @@ mod types;
 
 pub use cli::CronCommand;
 
+use anstream::println;
 use anyhow::Result;
 
+use crate::util::{print_structured, OutputFormat};
+
 use cli::{AddArgs, ListArgs, RemoveArgs};
 use types::Schedule;
 
@@ -28,26 +31,32 @@ fn run_add(args: AddArgs) -> Result<()> {
     })?;
 
     let job = service::add_job(schedule, &args.command)?;
-    println!("{}", display::format_added(&job, args.json));
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => println!("{}", display::format_added(&job)),
+        format => print_structured(&job, format)?,
+    }
     Ok(())
 }
 
 fn run_list(args: ListArgs) -> Result<()> {
     let jobs = service::list_jobs(args.hu_only)?;
-    println!("{}", display::format_jobs(&jobs, args.json));
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => println!("{}", display::format_jobs(&jobs)),
+        format => print_structured(&jobs, format)?,
+    }
     Ok(())
 }
 
 fn run_remove(args: RemoveArgs) -> Result<()> {
+    let format = OutputFormat::resolve(args.json);
     let jobs = service::list_jobs(false)?;
     let matching: Vec<_> = jobs.iter().filter(|j| j.matches(&args.pattern)).collect();
 
     if matching.is_empty() {
-        println!("{}", display::format_removed(&[], args.json));
-        return Ok(());
+        return print_removed(&[], format);
     }
 
-    if !args.force && !args.json {
+    if !args.force && format == OutputFormat::Table {
         println!("Will remove {} job(s):", matching.len());
         for job in &matching {
             println!("  - {} {}", job.expression, job.command);
@@ -57,7 +66,14 @@ fn run_remove(args: RemoveArgs) -> Result<()> {
     }
 
     let removed = service::remove_jobs(&args.pattern)?;
-    println!("{}", display::format_removed(&removed, args.json));
+    print_removed(&removed, format)
+}
+
+fn print_removed(jobs: &[types::CronJob], format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Table => println!("{}", display::format_removed(jobs)),
+        OutputFormat::Json | OutputFormat::Csv => print_structured(jobs, format)?,
+    }
     Ok(())
 }
 
@@ -1,5 +1,7 @@
 use clap::Subcommand;
 
+use super::types::TimeBucket;
+
 #[derive(Subcommand, Debug)]
 pub enum DataCommand {
     /// Sync Claude Code data to local database
@@ -7,16 +9,29 @@ pub enum DataCommand {
         /// Force full resync
         #[arg(short, long)]
         force: bool,
-
-        /// Quiet output
-        #[arg(short, long)]
-        quiet: bool,
     },
 
     /// Show data configuration
     Config {
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
+        json: bool,
+    },
+
+    /// Show the database location
+    Db {
+        /// Print only the database path
+        #[arg(long)]
+        path: bool,
+    },
+
+    /// Run a read-only SELECT against the database
+    Query {
+        /// SQL query (SELECT or WITH ... SELECT)
+        sql: String,
+
+        /// Output as JSON
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
@@ -29,12 +44,16 @@ pub enum DataCommand {
     /// Usage statistics
     Stats {
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
 
         /// Today only
         #[arg(short, long)]
         today: bool,
+
+        /// Print Prometheus text exposition format
+        #[arg(long, conflicts_with = "json")]
+        prometheus: bool,
     },
 
     /// Todo operations
@@ -53,29 +72,53 @@ pub enum DataCommand {
         limit: i64,
 
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
     /// Tool usage statistics
     Tools {
         /// Show detail for specific tool
-        #[arg(short, long)]
+        #[arg(value_name = "NAME")]
+        name: Option<String>,
+
+        /// Show detail for specific tool (same as NAME)
+        #[arg(short, long, conflicts_with = "name")]
         tool: Option<String>,
 
+        /// Count uses of the tool over time instead of listing them
+        #[arg(long)]
+        timeline: bool,
+
+        /// Bucket width for --timeline
+        #[arg(long, value_enum, default_value_t = TimeBucket::Day)]
+        by: TimeBucket,
+
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
     /// Extract errors from debug logs
     Errors {
-        /// Days to look back
+        /// Days to look back (fractions allowed, e.g. 0.5)
         #[arg(short, long, default_value = "7")]
-        recent: u32,
+        recent: f64,
 
-        /// Output as JSON
+        /// Only logs modified since this time (Unix seconds, RFC 3339 or YYYY-MM-DD)
+        #[arg(long, conflicts_with = "recent")]
+        since: Option<String>,
+
+        /// Also read files with this extension (repeatable, e.g. `--ext log`)
+        #[arg(long = "ext", value_name = "EXT")]
+        extensions: Vec<String>,
+
+        /// Tally occurrences per unique message instead of listing them
         #[arg(short, long)]
+        count: bool,
+
+        /// Output as JSON
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
@@ -90,7 +133,7 @@ pub enum DataCommand {
         limit: i64,
 
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 }
@@ -108,7 +151,7 @@ pub enum SessionCommand {
         limit: i64,
 
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
@@ -118,14 +161,24 @@ pub enum SessionCommand {
         id: String,
 
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
+    /// Export a session as a Markdown transcript
+    Export {
+        /// Session ID (or prefix)
+        id: String,
+
+        /// Output file (`-` for stdout)
+        #[arg(short, long, default_value = "-")]
+        out: String,
+    },
+
     /// Show current session
     Current {
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 }
@@ -139,7 +192,7 @@ pub enum TodosCommand {
         status: Option<String>,
 
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 
@@ -149,8 +202,12 @@ pub enum TodosCommand {
         #[arg(short, long)]
         project: Option<String>,
 
+        /// Only todos from sessions started more than this many days ago
+        #[arg(long, value_name = "DAYS")]
+        stale: Option<u32>,
+
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
 }
@@ -168,13 +225,7 @@ mod tests {
     #[test]
     fn parse_sync() {
         let cli = TestCli::try_parse_from(["test", "sync"]).unwrap();
-        assert!(matches!(
-            cli.cmd,
-            super::DataCommand::Sync {
-                force: false,
-                quiet: false
-            }
-        ));
+        assert!(matches!(cli.cmd, super::DataCommand::Sync { force: false }));
     }
 
     #[test]
@@ -201,6 +252,23 @@ mod tests {
         assert!(matches!(cli.cmd, super::DataCommand::Config { json: true }));
     }
 
+    #[test]
+    fn parse_db_path() {
+        let cli = TestCli::try_parse_from(["test", "db", "--path"]).unwrap();
+        assert!(matches!(cli.cmd, super::DataCommand::Db { path: true }));
+    }
+
+    #[test]
+    fn parse_query() {
+        let cli = TestCli::try_parse_from(["test", "query", "SELECT 1", "-j"]).unwrap();
+        if let super::DataCommand::Query { sql, json } = cli.cmd {
+            assert_eq!(sql, "SELECT 1");
+            assert!(json);
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
     #[test]
     fn parse_session_list() {
         let cli = TestCli::try_parse_from(["test", "session", "list"]).unwrap();
@@ -238,6 +306,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_session_export() {
+        let cli =
+            TestCli::try_parse_from(["test", "session", "export", "abc", "-o", "t.md"]).unwrap();
+        if let super::DataCommand::Session {
+            cmd: super::SessionCommand::Export { id, out },
+        } = cli.cmd
+        {
+            assert_eq!(id, "abc");
+            assert_eq!(out, "t.md");
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
+    #[test]
+    fn parse_session_export_defaults_to_stdout() {
+        let cli = TestCli::try_parse_from(["test", "session", "export", "abc"]).unwrap();
+        assert!(matches!(
+            cli.cmd,
+            super::DataCommand::Session {
+                cmd: super::SessionCommand::Export { ref out, .. }
+            } if out == "-"
+        ));
+    }
+
     #[test]
     fn parse_session_current() {
         let cli = TestCli::try_parse_from(["test", "session", "current"]).unwrap();
@@ -255,6 +349,17 @@ mod tests {
         assert!(matches!(cli.cmd, super::DataCommand::Stats { .. }));
     }
 
+    #[test]
+    fn parse_stats_prometheus() {
+        let cli = TestCli::try_parse_from(["test", "stats", "--prometheus"]).unwrap();
+        if let super::DataCommand::Stats { prometheus, .. } = cli.cmd {
+            assert!(prometheus);
+        } else {
+            panic!("wrong variant");
+        }
+        assert!(TestCli::try_parse_from(["test", "stats", "--prometheus", "--json"]).is_err());
+    }
+
     #[test]
     fn parse_stats_today() {
         let cli = TestCli::try_parse_from(["test", "stats", "-t"]).unwrap();
@@ -287,6 +392,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_todos_pending_stale() {
+        let cli = TestCli::try_parse_from(["test", "todos", "pending", "--stale", "14"]).unwrap();
+        assert!(matches!(
+            cli.cmd,
+            super::DataCommand::Todos {
+                cmd: super::TodosCommand::Pending {
+                    stale: Some(14),
+                    ..
+                }
+            }
+        ));
+    }
+
     #[test]
     fn parse_search() {
         let cli = TestCli::try_parse_from(["test", "search", "hello"]).unwrap();
@@ -327,16 +446,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_tools_positional_timeline() {
+        let cli = TestCli::try_parse_from(["test", "tools", "Bash", "--timeline", "--by", "week"])
+            .unwrap();
+        if let super::DataCommand::Tools {
+            name, timeline, by, ..
+        } = cli.cmd
+        {
+            assert_eq!(name, Some("Bash".to_string()));
+            assert!(timeline);
+            assert_eq!(by, super::TimeBucket::Week);
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
+    #[test]
+    fn parse_tools_name_and_flag_conflict() {
+        assert!(TestCli::try_parse_from(["test", "tools", "Bash", "-t", "Read"]).is_err());
+    }
+
     #[test]
     fn parse_errors() {
         let cli = TestCli::try_parse_from(["test", "errors"]).unwrap();
         if let super::DataCommand::Errors { recent, .. } = cli.cmd {
-            assert_eq!(recent, 7);
+            assert_eq!(recent, 7.0);
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
+    #[test]
+    fn parse_errors_options() {
+        let cli = TestCli::try_parse_from([
+            "test", "errors", "-r", "0.5", "--ext", "log", "--ext", ".out", "--count",
+        ])
+        .unwrap();
+        if let super::DataCommand::Errors {
+            recent,
+            since,
+            extensions,
+            count,
+            ..
+        } = cli.cmd
+        {
+            assert_eq!(recent, 0.5);
+            assert_eq!(since, None);
+            assert_eq!(extensions, vec!["log", ".out"]);
+            assert!(count);
         } else {
             panic!("wrong variant");
         }
     }
 
+    #[test]
+    fn parse_errors_since_conflicts_with_recent() {
+        assert!(TestCli::try_parse_from(["test", "errors", "--since", "2026-01-01"]).is_ok());
+        assert!(
+            TestCli::try_parse_from(["test", "errors", "-r", "2", "--since", "2026-01-01"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn parse_branches() {
         let cli = TestCli::try_parse_from(["test", "branches"]).unwrap();
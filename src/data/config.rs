@@ -1,5 +1,5 @@
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 pub struct DataConfig {
@@ -7,8 +7,15 @@ pub struct DataConfig {
     pub database: PathBuf,
     pub auto_sync_interval: u64,
     pub sync_on_start: bool,
+    /// Regexes a debug log line must match to count as an error
+    pub error_patterns: Vec<String>,
+    /// Most errors `hu data errors` lists
+    pub error_limit: usize,
 }
 
+/// Default pattern for `hu data errors`
+pub const DEFAULT_ERROR_PATTERN: &str = r"(?i)(error|failed|exception|warning|ENOENT|EACCES|EPERM)";
+
 impl Default for DataConfig {
     fn default() -> Self {
         Self {
@@ -16,6 +23,8 @@ impl Default for DataConfig {
             database: resolve_db_path("hu.db"),
             auto_sync_interval: 300,
             sync_on_start: true,
+            error_patterns: vec![DEFAULT_ERROR_PATTERN.to_string()],
+            error_limit: 50,
         }
     }
 }
@@ -58,12 +67,19 @@ fn config_dir_with_home(home: Option<PathBuf>) -> PathBuf {
 
 #[cfg(not(tarpaulin_include))]
 pub fn load_data_config() -> Result<DataConfig> {
-    let config_path = config_dir().join("settings.toml");
-    if !config_path.exists() {
+    match crate::util::settings_path() {
+        Some(path) => load_data_config_from(&path),
+        None => Ok(DataConfig::default()),
+    }
+}
+
+/// Load data config from a specific settings file (testable)
+pub fn load_data_config_from(path: &Path) -> Result<DataConfig> {
+    if !path.exists() {
         return Ok(DataConfig::default());
     }
 
-    let content = std::fs::read_to_string(&config_path)?;
+    let content = std::fs::read_to_string(path)?;
     load_from_toml(&content)
 }
 
@@ -89,6 +105,18 @@ pub fn load_from_toml(content: &str) -> Result<DataConfig> {
         }
     }
 
+    if let Some(errors) = table.get("errors") {
+        if let Some(patterns) = errors.get("patterns").and_then(|v| v.as_array()) {
+            config.error_patterns = patterns
+                .iter()
+                .filter_map(|p| p.as_str().map(String::from))
+                .collect();
+        }
+        if let Some(limit) = errors.get("limit").and_then(|v| v.as_integer()) {
+            config.error_limit = limit.max(0) as usize;
+        }
+    }
+
     Ok(config)
 }
 
@@ -140,6 +168,20 @@ mod tests {
         assert!(config.database.ends_with("hu.db"));
         assert_eq!(config.auto_sync_interval, 300);
         assert!(config.sync_on_start);
+        assert_eq!(config.error_patterns, vec![DEFAULT_ERROR_PATTERN]);
+        assert_eq!(config.error_limit, 50);
+    }
+
+    #[test]
+    fn load_errors_section() {
+        let toml = r#"
+[errors]
+patterns = ["panicked", "(?i)timeout"]
+limit = 10
+"#;
+        let config = load_from_toml(toml).unwrap();
+        assert_eq!(config.error_patterns, vec!["panicked", "(?i)timeout"]);
+        assert_eq!(config.error_limit, 10);
     }
 
     #[test]
@@ -1,5 +1,5 @@
-use anyhow::Result;
-use rusqlite::Connection;
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OpenFlags};
 use std::path::Path;
 
 use super::schema::initialize_schema;
@@ -18,6 +18,16 @@ impl SqliteStore {
         Ok(Self { conn })
     }
 
+    /// Open an existing database without write access
+    pub fn open_read_only(path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .with_context(|| format!("Failed to open {} read-only", path.display()))?;
+        Ok(Self { conn })
+    }
+
     #[allow(dead_code)]
     pub fn open_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
@@ -113,4 +123,25 @@ mod tests {
             .unwrap();
         assert_eq!(fk, 1);
     }
+
+    #[test]
+    fn open_read_only_rejects_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ro.db");
+        SqliteStore::open_initialized(&path).unwrap();
+
+        let store = SqliteStore::open_read_only(&path).unwrap();
+        let count: i64 = store
+            .conn
+            .query_row("SELECT COUNT(*) FROM sessions", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+        assert!(store.conn.execute("DELETE FROM sessions", []).is_err());
+    }
+
+    #[test]
+    fn open_read_only_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(SqliteStore::open_read_only(&dir.path().join("nope.db")).is_err());
+    }
 }
@@ -1,10 +1,15 @@
+use anstream::println;
 use anyhow::Result;
 use comfy_table::presets::UTF8_FULL_CONDENSED;
 use comfy_table::{Cell, Color, Table};
 
+use crate::util::fmt::truncate;
+use crate::util::print_structured;
+
 use super::types::{
-    BranchWithPr, DebugError, Message, ModelUsage, OutputFormat, SearchResult, Session, SyncResult,
-    Todo, TodoWithProject, ToolUsageDetail, ToolUsageStats, UsageStats,
+    BranchWithPr, DebugError, ErrorCount, Message, ModelUsage, OutputFormat, QueryResult,
+    SearchResult, Session, SyncResult, TimeBucket, Todo, TodoWithProject, ToolUsageDetail,
+    ToolUsageStats, UsageStats,
 };
 
 // Re-export types needed by display tests for constructing composite test data
@@ -32,16 +37,6 @@ pub fn time_ago_ms(ms: i64) -> String {
     }
 }
 
-pub fn truncate(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.to_string()
-    } else if max <= 3 {
-        s[..max].to_string()
-    } else {
-        format!("{}...", &s[..max - 3])
-    }
-}
-
 pub fn role_color(role: &str) -> Color {
     match role {
         "user" => Color::Cyan,
@@ -83,9 +78,7 @@ fn format_tokens(tokens: i64) -> String {
 
 pub fn output_sync(result: &SyncResult, format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(result)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(result, *format)?,
         OutputFormat::Table => {
             println!("✓ Sync complete:");
             println!("  Sessions: {} new", result.history);
@@ -98,30 +91,67 @@ pub fn output_sync(result: &SyncResult, format: &OutputFormat) -> Result<()> {
 
 pub fn output_config(config: &super::config::DataConfig, format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Csv => {
             let json = serde_json::json!({
                 "claude_dir": config.claude_dir.display().to_string(),
                 "database": config.database.display().to_string(),
                 "auto_sync_interval": config.auto_sync_interval,
                 "sync_on_start": config.sync_on_start,
+                "error_patterns": config.error_patterns,
+                "error_limit": config.error_limit,
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            print_structured(&json, *format)?;
         }
         OutputFormat::Table => {
             println!("Claude dir: {}", config.claude_dir.display());
             println!("Database:   {}", config.database.display());
             println!("Sync interval: {}s", config.auto_sync_interval);
             println!("Sync on start: {}", config.sync_on_start);
+            println!("Error limit:   {}", config.error_limit);
+            for pattern in &config.error_patterns {
+                println!("Error pattern: {pattern}");
+            }
         }
     }
     Ok(())
 }
 
-pub fn output_sessions(sessions: &[Session], format: &OutputFormat) -> Result<()> {
+pub fn output_db_info(path: &std::path::Path, size: Option<u64>) {
+    println!("Database: {}", path.display());
+    match size {
+        Some(bytes) => println!("Size:     {:.1} MB", bytes as f64 / 1_048_576.0),
+        None => println!("Size:     (not created yet, run `hu data sync`)"),
+    }
+}
+
+pub fn output_query_result(result: &QueryResult, format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(sessions)?);
+        OutputFormat::Json | OutputFormat::Csv => print_structured(&result.records(), *format)?,
+        OutputFormat::Table => {
+            if result.rows.is_empty() {
+                println!("No rows.");
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL_CONDENSED);
+            table.set_header(result.columns.clone());
+            for row in &result.rows {
+                table.add_row(row.iter().map(|v| match v {
+                    serde_json::Value::String(s) => truncate(s, 60),
+                    serde_json::Value::Null => String::new(),
+                    other => other.to_string(),
+                }));
+            }
+            println!("{table}");
+            println!("{} row(s)", result.rows.len());
         }
+    }
+    Ok(())
+}
+
+pub fn output_sessions(sessions: &[Session], format: &OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Csv => print_structured(sessions, *format)?,
         OutputFormat::Table => {
             if sessions.is_empty() {
                 println!("No sessions found.");
@@ -149,9 +179,7 @@ pub fn output_sessions(sessions: &[Session], format: &OutputFormat) -> Result<()
 
 pub fn output_session_messages(messages: &[Message], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(messages)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(messages, *format)?,
         OutputFormat::Table => {
             if messages.is_empty() {
                 println!("No messages found.");
@@ -178,11 +206,55 @@ pub fn output_session_messages(messages: &[Message], format: &OutputFormat) -> R
     Ok(())
 }
 
+/// Render a session as a Markdown transcript
+///
+/// Message content is copied verbatim, so code fences survive.
+pub fn format_transcript(session: &Session, messages: &[Message]) -> String {
+    let mut models: Vec<&str> = messages.iter().filter_map(|m| m.model.as_deref()).collect();
+    models.sort_unstable();
+    models.dedup();
+    let input: i64 = messages.iter().filter_map(|m| m.input_tokens).sum();
+    let output: i64 = messages.iter().filter_map(|m| m.output_tokens).sum();
+    let started = chrono::DateTime::from_timestamp_millis(session.started_at)
+        .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_default();
+
+    let mut out = format!(
+        "# {}\n\n",
+        session.display.as_deref().unwrap_or(&session.id)
+    );
+    out.push_str(&format!("- **Session:** `{}`\n", session.id));
+    out.push_str(&format!("- **Project:** `{}`\n", session.project));
+    if let Some(branch) = &session.git_branch {
+        out.push_str(&format!("- **Branch:** `{branch}`\n"));
+    }
+    out.push_str(&format!("- **Started:** {started}\n"));
+    if !models.is_empty() {
+        out.push_str(&format!("- **Model:** {}\n", models.join(", ")));
+    }
+    out.push_str(&format!(
+        "- **Cost:** {}\n",
+        format_cost(session.total_cost_usd)
+    ));
+    out.push_str(&format!(
+        "- **Tokens:** {} in / {} out\n",
+        format_tokens(input),
+        format_tokens(output)
+    ));
+
+    for msg in messages {
+        let content = msg.content.as_deref().unwrap_or("").trim_end();
+        if content.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n---\n\n**{}:**\n\n{}\n", msg.role, content));
+    }
+    out
+}
+
 pub fn output_search_results(results: &[SearchResult], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(results)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(results, *format)?,
         OutputFormat::Table => {
             if results.is_empty() {
                 println!("No results found.");
@@ -213,12 +285,12 @@ pub fn output_stats(
     format: &OutputFormat,
 ) -> Result<()> {
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Csv => {
             let json = serde_json::json!({
                 "stats": stats,
                 "model_usage": model_usage,
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            print_structured(&json, *format)?;
         }
         OutputFormat::Table => {
             println!("Usage Statistics:");
@@ -255,11 +327,100 @@ pub fn output_stats(
     Ok(())
 }
 
+/// Stats in Prometheus text exposition format, for a textfile collector
+///
+/// Values are the unrounded numbers behind the table.
+pub fn format_stats_prometheus(stats: &UsageStats, model_usage: &[ModelUsage]) -> String {
+    let mut out = String::new();
+    let mut metric = |name: &str, kind: &str, help: &str, samples: Vec<(String, String)>| {
+        out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n"));
+        for (labels, value) in samples {
+            out.push_str(&format!("{name}{labels} {value}\n"));
+        }
+    };
+    let total = |value: String| vec![(String::new(), value)];
+    let by_model = |value: fn(&ModelUsage) -> String| {
+        model_usage
+            .iter()
+            .map(|m| {
+                (
+                    format!("{{model=\"{}\"}}", escape_label(&m.model)),
+                    value(m),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    metric(
+        "hu_sessions_total",
+        "counter",
+        "Claude Code sessions",
+        total(stats.total_sessions.to_string()),
+    );
+    metric(
+        "hu_messages_total",
+        "counter",
+        "Messages across all sessions",
+        total(stats.total_messages.to_string()),
+    );
+    metric(
+        "hu_cost_usd",
+        "gauge",
+        "Total cost in US dollars",
+        total(stats.total_cost.to_string()),
+    );
+    metric(
+        "hu_input_tokens_total",
+        "counter",
+        "Input tokens",
+        total(stats.total_input_tokens.to_string()),
+    );
+    metric(
+        "hu_output_tokens_total",
+        "counter",
+        "Output tokens",
+        total(stats.total_output_tokens.to_string()),
+    );
+    if !model_usage.is_empty() {
+        metric(
+            "hu_model_messages_total",
+            "counter",
+            "Messages per model",
+            by_model(|m| m.count.to_string()),
+        );
+        metric(
+            "hu_model_cost_usd",
+            "gauge",
+            "Cost per model in US dollars",
+            by_model(|m| m.cost.to_string()),
+        );
+        metric(
+            "hu_model_input_tokens_total",
+            "counter",
+            "Input tokens per model",
+            by_model(|m| m.input_tokens.to_string()),
+        );
+        metric(
+            "hu_model_output_tokens_total",
+            "counter",
+            "Output tokens per model",
+            by_model(|m| m.output_tokens.to_string()),
+        );
+    }
+    out
+}
+
+/// Escape a Prometheus label value: backslash, double quote and newline
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 pub fn output_todos(todos: &[Todo], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(todos)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(todos, *format)?,
         OutputFormat::Table => {
             if todos.is_empty() {
                 println!("No todos found.");
@@ -291,9 +452,7 @@ pub fn output_todos(todos: &[Todo], format: &OutputFormat) -> Result<()> {
 
 pub fn output_pending_todos(todos: &[TodoWithProject], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(todos)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(todos, *format)?,
         OutputFormat::Table => {
             if todos.is_empty() {
                 println!("No pending todos found.");
@@ -301,7 +460,7 @@ pub fn output_pending_todos(todos: &[TodoWithProject], format: &OutputFormat) ->
             }
             let mut table = Table::new();
             table.load_preset(UTF8_FULL_CONDENSED);
-            table.set_header(vec!["Status", "Content", "Project"]);
+            table.set_header(vec!["Status", "Content", "Project", "Age"]);
 
             for t in todos {
                 let icon = match t.status.as_str() {
@@ -313,6 +472,7 @@ pub fn output_pending_todos(todos: &[TodoWithProject], format: &OutputFormat) ->
                     Cell::new(format!("{icon} {}", t.status)).fg(status_color(&t.status)),
                     Cell::new(truncate(&t.content, 50)),
                     Cell::new(truncate(&t.project, 30)),
+                    Cell::new(time_ago_ms(t.started_at)),
                 ]);
             }
             println!("{table}");
@@ -323,9 +483,7 @@ pub fn output_pending_todos(todos: &[TodoWithProject], format: &OutputFormat) ->
 
 pub fn output_tool_stats(stats: &[ToolUsageStats], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(stats)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(stats, *format)?,
         OutputFormat::Table => {
             if stats.is_empty() {
                 println!("No tool usage data.");
@@ -350,9 +508,7 @@ pub fn output_tool_stats(stats: &[ToolUsageStats], format: &OutputFormat) -> Res
 
 pub fn output_tool_detail(detail: &[ToolUsageDetail], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(detail)?);
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(detail, *format)?,
         OutputFormat::Table => {
             if detail.is_empty() {
                 println!("No usage found for this tool.");
@@ -376,11 +532,105 @@ pub fn output_tool_detail(detail: &[ToolUsageDetail], format: &OutputFormat) ->
     Ok(())
 }
 
-pub fn output_errors(errors: &[DebugError], format: &OutputFormat) -> Result<()> {
+/// Most recent buckets shown in the timeline sparkline
+const SPARKLINE_WIDTH: usize = 60;
+
+/// One block character per count, scaled to the largest; zero is blank
+pub fn sparkline(counts: &[i64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = counts.iter().copied().max().unwrap_or(0).max(1);
+    counts
+        .iter()
+        .map(|&c| {
+            if c <= 0 {
+                ' '
+            } else {
+                let level = (c * 8 + max - 1) / max;
+                BLOCKS[(level.clamp(1, 8) - 1) as usize]
+            }
+        })
+        .collect()
+}
+
+/// Counts for every bucket from the first to the last, zeros included
+fn fill_gaps(buckets: &[(i64, i64)], bucket: TimeBucket) -> Vec<i64> {
+    let (Some(&(first, _)), Some(&(last, _))) = (buckets.first(), buckets.last()) else {
+        return vec![];
+    };
+    let step = bucket.millis();
+    let mut counts = vec![0; ((last - first) / step + 1) as usize];
+    for &(start, count) in buckets {
+        counts[((start - first) / step) as usize] = count;
+    }
+    counts
+}
+
+fn format_bucket(start_ms: i64, bucket: TimeBucket) -> String {
+    let fmt = match bucket {
+        TimeBucket::Hour => "%Y-%m-%d %H:00",
+        TimeBucket::Day | TimeBucket::Week => "%Y-%m-%d",
+    };
+    chrono::DateTime::from_timestamp_millis(start_ms)
+        .map(|t| t.format(fmt).to_string())
+        .unwrap_or_default()
+}
+
+#[derive(serde::Serialize)]
+struct TimelineRow {
+    bucket_start_ms: i64,
+    count: i64,
+}
+
+pub fn output_tool_timeline(
+    tool: &str,
+    buckets: &[(i64, i64)],
+    bucket: TimeBucket,
+    format: &OutputFormat,
+) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(errors)?);
+        OutputFormat::Json | OutputFormat::Csv => {
+            let rows: Vec<TimelineRow> = buckets
+                .iter()
+                .map(|&(bucket_start_ms, count)| TimelineRow {
+                    bucket_start_ms,
+                    count,
+                })
+                .collect();
+            print_structured(&rows, *format)?
         }
+        OutputFormat::Table => {
+            if buckets.is_empty() {
+                println!("No usage found for tool: {tool}");
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL_CONDENSED);
+            let label = match bucket {
+                TimeBucket::Hour => "Hour",
+                TimeBucket::Day => "Day",
+                TimeBucket::Week => "Week of",
+            };
+            table.set_header(vec![label, "Uses"]);
+
+            for &(start, count) in buckets {
+                table.add_row(vec![
+                    Cell::new(format_bucket(start, bucket)),
+                    Cell::new(count.to_string()),
+                ]);
+            }
+            println!("{table}");
+
+            let counts = fill_gaps(buckets, bucket);
+            let recent = &counts[counts.len().saturating_sub(SPARKLINE_WIDTH)..];
+            println!("{tool} {}", sparkline(recent));
+        }
+    }
+    Ok(())
+}
+
+pub fn output_errors(errors: &[DebugError], format: &OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Csv => print_structured(errors, *format)?,
         OutputFormat::Table => {
             if errors.is_empty() {
                 println!("No errors found.");
@@ -403,11 +653,34 @@ pub fn output_errors(errors: &[DebugError], format: &OutputFormat) -> Result<()>
     Ok(())
 }
 
-pub fn output_branches(branches: &[BranchWithPr], format: &OutputFormat) -> Result<()> {
+pub fn output_error_counts(counts: &[ErrorCount], format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(branches)?);
+        OutputFormat::Json | OutputFormat::Csv => print_structured(counts, *format)?,
+        OutputFormat::Table => {
+            if counts.is_empty() {
+                println!("No errors found.");
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL_CONDENSED);
+            table.set_header(vec!["Count", "Content", "First seen in"]);
+
+            for c in counts {
+                table.add_row(vec![
+                    Cell::new(c.count.to_string()),
+                    Cell::new(truncate(&c.content, 60)).fg(Color::Red),
+                    Cell::new(truncate(&c.file, 25)),
+                ]);
+            }
+            println!("{table}");
         }
+    }
+    Ok(())
+}
+
+pub fn output_branches(branches: &[BranchWithPr], format: &OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Csv => print_structured(branches, *format)?,
         OutputFormat::Table => {
             if branches.is_empty() {
                 println!("No branches found.");
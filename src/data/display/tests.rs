@@ -27,26 +27,6 @@ fn time_ago_ms_days() {
     assert_eq!(time_ago_ms(three_days_ago), "3d ago");
 }
 
-#[test]
-fn truncate_short() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn truncate_exact() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn truncate_long() {
-    assert_eq!(truncate("hello world", 8), "hello...");
-}
-
-#[test]
-fn truncate_tiny_max() {
-    assert_eq!(truncate("hello", 2), "he");
-}
-
 #[test]
 fn role_color_values() {
     assert_eq!(role_color("user"), Color::Cyan);
@@ -186,6 +166,43 @@ fn output_session_messages_no_tokens() {
     assert!(output_session_messages(&[msg], &OutputFormat::Table).is_ok());
 }
 
+#[test]
+fn format_transcript_header_and_blocks() {
+    let mut assistant = make_message("assistant");
+    assistant.content = Some("Here:\n\n```rust\nfn main() {}\n```\n".to_string());
+    let msgs = vec![make_message("user"), assistant];
+    let md = format_transcript(&make_session(), &msgs);
+
+    assert!(md.starts_with("# Test session\n"));
+    assert!(md.contains("- **Project:** `/home/user/project`"));
+    assert!(md.contains("- **Model:** claude-sonnet-4-5-20251101"));
+    assert!(md.contains("- **Cost:** $0.050"));
+    assert!(md.contains("- **Tokens:** 200 in / 400 out"));
+    assert!(md.contains("**user:**\n\nHello world\n"));
+    assert!(md.contains("**assistant:**\n\nHere:\n\n```rust\nfn main() {}\n```\n"));
+}
+
+#[test]
+fn format_transcript_skips_empty_messages() {
+    let mut empty = make_message("assistant");
+    empty.content = None;
+    let md = format_transcript(&make_session(), &[empty]);
+    assert!(!md.contains("**assistant:**"));
+    assert!(md.contains("- **Tokens:** 100 in / 200 out"));
+}
+
+#[test]
+fn format_transcript_falls_back_to_id() {
+    let session = Session {
+        display: None,
+        git_branch: None,
+        ..make_session()
+    };
+    let md = format_transcript(&session, &[]);
+    assert!(md.starts_with("# abc-123-def\n"));
+    assert!(!md.contains("**Branch:**"));
+}
+
 #[test]
 fn output_search_results_empty() {
     assert!(output_search_results(&[], &OutputFormat::Table).is_ok());
@@ -241,6 +258,44 @@ fn output_stats_empty_models() {
     assert!(output_stats(&stats, &[], &OutputFormat::Table).is_ok());
 }
 
+#[test]
+fn format_stats_prometheus_totals_and_models() {
+    let stats = UsageStats {
+        total_sessions: 10,
+        total_messages: 100,
+        total_cost: 5.25,
+        total_input_tokens: 1_000_000,
+        total_output_tokens: 500_000,
+    };
+    let model_usage = vec![ModelUsage {
+        model: "claude-sonnet-4-5".to_string(),
+        count: 50,
+        cost: 3.125,
+        input_tokens: 800_000,
+        output_tokens: 400_000,
+    }];
+    let out = format_stats_prometheus(&stats, &model_usage);
+    assert!(out.contains("# HELP hu_messages_total "));
+    assert!(out.contains("# TYPE hu_messages_total counter\nhu_messages_total 100\n"));
+    assert!(out.contains("hu_cost_usd 5.25\n"));
+    assert!(out.contains("hu_input_tokens_total 1000000\n"));
+    assert!(out.contains("hu_model_input_tokens_total{model=\"claude-sonnet-4-5\"} 800000\n"));
+    assert!(out.contains("hu_model_cost_usd{model=\"claude-sonnet-4-5\"} 3.125\n"));
+}
+
+#[test]
+fn format_stats_prometheus_no_models() {
+    let out = format_stats_prometheus(&UsageStats::default(), &[]);
+    assert!(out.contains("hu_sessions_total 0\n"));
+    assert!(!out.contains("hu_model_"));
+}
+
+#[test]
+fn escape_label_special_chars() {
+    assert_eq!(escape_label(r#"a\b"c"#), r#"a\\b\"c"#);
+    assert_eq!(escape_label("x\ny"), r"x\ny");
+}
+
 fn make_todo(status: &str) -> Todo {
     Todo {
         id: 1,
@@ -285,6 +340,7 @@ fn output_pending_todos_table() {
         status: "pending".to_string(),
         active_form: None,
         project: "/home/user/proj".to_string(),
+        started_at: chrono::Utc::now().timestamp_millis(),
     }];
     assert!(output_pending_todos(&todos, &OutputFormat::Table).is_ok());
 }
@@ -423,6 +479,7 @@ fn output_pending_todos_unknown_status() {
         status: "weird_status".to_string(),
         active_form: None,
         project: "/proj".to_string(),
+        started_at: 0,
     };
     assert!(output_pending_todos(&[todo], &OutputFormat::Table).is_ok());
 }
@@ -436,6 +493,78 @@ fn output_pending_in_progress() {
         status: "in_progress".to_string(),
         active_form: None,
         project: "/proj".to_string(),
+        started_at: 0,
     };
     assert!(output_pending_todos(&[todo], &OutputFormat::Table).is_ok());
 }
+
+#[test]
+fn sparkline_scales_to_max() {
+    assert_eq!(sparkline(&[0, 1, 4, 8]), " ▁▄█");
+    assert_eq!(sparkline(&[3, 3]), "██");
+    assert_eq!(sparkline(&[]), "");
+}
+
+#[test]
+fn fill_gaps_inserts_zeros() {
+    let day = TimeBucket::Day.millis();
+    let buckets = vec![(0, 2), (2 * day, 5), (3 * day, 1)];
+    assert_eq!(fill_gaps(&buckets, TimeBucket::Day), vec![2, 0, 5, 1]);
+    assert!(fill_gaps(&[], TimeBucket::Day).is_empty());
+}
+
+#[test]
+fn format_bucket_labels() {
+    assert_eq!(
+        format_bucket(1_699_999_200_000, TimeBucket::Hour),
+        "2023-11-14 22:00"
+    );
+    assert_eq!(
+        format_bucket(1_699_920_000_000, TimeBucket::Day),
+        "2023-11-14"
+    );
+}
+
+#[test]
+fn output_tool_timeline_formats() {
+    let buckets = vec![(1_699_920_000_000, 2), (1_700_179_200_000, 1)];
+    assert!(output_tool_timeline("Read", &buckets, TimeBucket::Day, &OutputFormat::Table).is_ok());
+    assert!(output_tool_timeline("Read", &buckets, TimeBucket::Day, &OutputFormat::Json).is_ok());
+    assert!(output_tool_timeline("Read", &[], TimeBucket::Week, &OutputFormat::Table).is_ok());
+}
+
+#[test]
+fn output_error_counts_formats() {
+    let counts = vec![ErrorCount {
+        content: "Error: boom".to_string(),
+        count: 3,
+        file: "a.txt".to_string(),
+        last_seen: 0,
+    }];
+    assert!(output_error_counts(&counts, &OutputFormat::Table).is_ok());
+    assert!(output_error_counts(&counts, &OutputFormat::Json).is_ok());
+    assert!(output_error_counts(&[], &OutputFormat::Table).is_ok());
+}
+
+#[test]
+fn output_query_result_formats() {
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "cost".to_string(), "branch".to_string()],
+        rows: vec![vec!["s1".into(), 0.05.into(), serde_json::Value::Null]],
+    };
+    assert!(output_query_result(&result, &OutputFormat::Table).is_ok());
+    assert!(output_query_result(&result, &OutputFormat::Json).is_ok());
+    assert!(output_query_result(&QueryResult::default(), &OutputFormat::Table).is_ok());
+}
+
+#[test]
+fn query_result_records() {
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "n".to_string()],
+        rows: vec![vec!["s1".into(), 3.into()]],
+    };
+    assert_eq!(
+        serde_json::to_value(result.records()).unwrap(),
+        serde_json::json!([{"id": "s1", "n": 3}])
+    );
+}
@@ -10,21 +10,54 @@ mod sync;
 mod types;
 
 pub use cli::DataCommand;
+pub use config::load_data_config;
+#[allow(unused_imports)]
+pub use config::load_data_config_from;
 
-use anyhow::Result;
+use anstream::{print, println};
+use anyhow::{Context, Result};
 use types::OutputFormat;
 
 #[cfg(not(tarpaulin_include))]
 pub async fn run_command(cmd: DataCommand) -> Result<()> {
     match cmd {
-        DataCommand::Sync { force, quiet } => cmd_sync(force, quiet),
+        DataCommand::Sync { force } => cmd_sync(force),
         DataCommand::Config { json } => cmd_config(json),
+        DataCommand::Db { path } => cmd_db(path),
+        DataCommand::Query { sql, json } => cmd_query(&sql, json),
         DataCommand::Session { cmd } => cmd_session(cmd),
-        DataCommand::Stats { json, today } => cmd_stats(json, today),
+        DataCommand::Stats {
+            json,
+            today,
+            prometheus,
+        } => cmd_stats(json, today, prometheus),
         DataCommand::Todos { cmd } => cmd_todos(cmd),
         DataCommand::Search { query, limit, json } => cmd_search(&query, limit, json),
-        DataCommand::Tools { tool, json } => cmd_tools(tool.as_deref(), json),
-        DataCommand::Errors { recent, json } => cmd_errors(recent, json),
+        DataCommand::Tools {
+            name,
+            tool,
+            timeline,
+            by,
+            json,
+        } => {
+            let tool = name.or(tool);
+            if timeline {
+                cmd_tool_timeline(tool.as_deref(), by, json)
+            } else {
+                cmd_tools(tool.as_deref(), json)
+            }
+        }
+        DataCommand::Errors {
+            recent,
+            since,
+            extensions,
+            count,
+            json,
+        } => {
+            let now = chrono::Utc::now().timestamp();
+            let since = service::resolve_since(recent, since.as_deref(), now)?;
+            cmd_errors(since, &extensions, count, json)
+        }
         DataCommand::Branches {
             branch,
             limit,
@@ -33,16 +66,9 @@ pub async fn run_command(cmd: DataCommand) -> Result<()> {
     }
 }
 
-fn get_format(json: bool) -> OutputFormat {
-    if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    }
-}
-
 #[cfg(not(tarpaulin_include))]
-fn cmd_sync(force: bool, quiet: bool) -> Result<()> {
+fn cmd_sync(force: bool) -> Result<()> {
+    let quiet = crate::util::logging::is_quiet();
     let store = service::open_db()?;
     match service::sync_data(&store, force)? {
         Some(result) => {
@@ -62,7 +88,30 @@ fn cmd_sync(force: bool, quiet: bool) -> Result<()> {
 #[cfg(not(tarpaulin_include))]
 fn cmd_config(json: bool) -> Result<()> {
     let cfg = service::get_config()?;
-    display::output_config(&cfg, &get_format(json))
+    display::output_config(&cfg, &OutputFormat::resolve(json))
+}
+
+#[cfg(not(tarpaulin_include))]
+fn cmd_db(path_only: bool) -> Result<()> {
+    let cfg = service::get_config()?;
+    if path_only {
+        println!("{}", cfg.database.display());
+        return Ok(());
+    }
+    let size = std::fs::metadata(&cfg.database).map(|m| m.len()).ok();
+    display::output_db_info(&cfg.database, size);
+    Ok(())
+}
+
+#[cfg(not(tarpaulin_include))]
+fn cmd_query(sql: &str, json: bool) -> Result<()> {
+    {
+        // Make sure the file exists and is current before opening it read-only
+        let store = service::open_db()?;
+        service::ensure_synced(&store)?;
+    }
+    let result = service::run_query(sql)?;
+    display::output_query_result(&result, &OutputFormat::resolve(json))
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -77,25 +126,41 @@ fn cmd_session(cmd: cli::SessionCommand) -> Result<()> {
             json,
         } => {
             let sessions = service::get_sessions(&store, project.as_deref(), limit)?;
-            display::output_sessions(&sessions, &get_format(json))
+            display::output_sessions(&sessions, &OutputFormat::resolve(json))
         }
         cli::SessionCommand::Read { id, json } => {
             let (_session, messages) = service::get_session_messages(&store, &id)?;
-            display::output_session_messages(&messages, &get_format(json))
+            display::output_session_messages(&messages, &OutputFormat::resolve(json))
+        }
+        cli::SessionCommand::Export { id, out } => {
+            let (session, messages) = service::get_session_messages(&store, &id)?;
+            let transcript = display::format_transcript(&session, &messages);
+            if out == "-" {
+                print!("{transcript}");
+            } else {
+                std::fs::write(&out, transcript)
+                    .with_context(|| format!("Failed to write {out}"))?;
+                println!("Wrote {} messages to {out}", messages.len());
+            }
+            Ok(())
         }
         cli::SessionCommand::Current { json } => {
             let (_session, messages) = service::get_current_session_messages(&store)?;
-            display::output_session_messages(&messages, &get_format(json))
+            display::output_session_messages(&messages, &OutputFormat::resolve(json))
         }
     }
 }
 
 #[cfg(not(tarpaulin_include))]
-fn cmd_stats(json: bool, today: bool) -> Result<()> {
+fn cmd_stats(json: bool, today: bool, prometheus: bool) -> Result<()> {
     let store = service::open_db()?;
     service::ensure_synced(&store)?;
     let (stats, model_usage) = service::get_stats(&store, today)?;
-    display::output_stats(&stats, &model_usage, &get_format(json))
+    if prometheus {
+        print!("{}", display::format_stats_prometheus(&stats, &model_usage));
+        return Ok(());
+    }
+    display::output_stats(&stats, &model_usage, &OutputFormat::resolve(json))
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -106,11 +171,15 @@ fn cmd_todos(cmd: cli::TodosCommand) -> Result<()> {
     match cmd {
         cli::TodosCommand::List { status, json } => {
             let todos = service::get_todos(&store, status.as_deref())?;
-            display::output_todos(&todos, &get_format(json))
+            display::output_todos(&todos, &OutputFormat::resolve(json))
         }
-        cli::TodosCommand::Pending { project, json } => {
-            let todos = service::get_pending_todos(&store, project.as_deref())?;
-            display::output_pending_todos(&todos, &get_format(json))
+        cli::TodosCommand::Pending {
+            project,
+            stale,
+            json,
+        } => {
+            let todos = service::get_pending_todos(&store, project.as_deref(), stale)?;
+            display::output_pending_todos(&todos, &OutputFormat::resolve(json))
         }
     }
 }
@@ -120,14 +189,14 @@ fn cmd_search(query: &str, limit: i64, json: bool) -> Result<()> {
     let store = service::open_db()?;
     service::ensure_synced(&store)?;
     let results = service::search_messages(&store, query, limit)?;
-    display::output_search_results(&results, &get_format(json))
+    display::output_search_results(&results, &OutputFormat::resolve(json))
 }
 
 #[cfg(not(tarpaulin_include))]
 fn cmd_tools(tool: Option<&str>, json: bool) -> Result<()> {
     let store = service::open_db()?;
     service::ensure_synced(&store)?;
-    let format = get_format(json);
+    let format = OutputFormat::resolve(json);
 
     match tool {
         Some(name) => {
@@ -142,10 +211,28 @@ fn cmd_tools(tool: Option<&str>, json: bool) -> Result<()> {
 }
 
 #[cfg(not(tarpaulin_include))]
-fn cmd_errors(recent_days: u32, json: bool) -> Result<()> {
+fn cmd_tool_timeline(tool: Option<&str>, bucket: types::TimeBucket, json: bool) -> Result<()> {
+    let Some(name) = tool else {
+        anyhow::bail!("--timeline needs a tool name, e.g. `hu data tools Bash --timeline`");
+    };
+    let store = service::open_db()?;
+    service::ensure_synced(&store)?;
+    let timeline = service::get_tool_timeline(&store, name, bucket)?;
+    display::output_tool_timeline(name, &timeline, bucket, &OutputFormat::resolve(json))
+}
+
+#[cfg(not(tarpaulin_include))]
+fn cmd_errors(since: i64, extensions: &[String], count: bool, json: bool) -> Result<()> {
     let cfg = service::get_config()?;
-    let errors = service::scan_debug_errors(&cfg.claude_dir, recent_days)?;
-    display::output_errors(&errors, &get_format(json))
+    let scan = service::ErrorScan::new(&cfg, since, extensions);
+    let format = OutputFormat::resolve(json);
+    if count {
+        let counts = service::count_debug_errors(&cfg.claude_dir, &scan)?;
+        display::output_error_counts(&counts, &format)
+    } else {
+        let errors = service::scan_debug_errors_with(&cfg.claude_dir, &scan)?;
+        display::output_errors(&errors, &format)
+    }
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -161,7 +248,7 @@ async fn cmd_branches(branch: Option<&str>, limit: i64, json: bool) -> Result<()
         branches.push(types::BranchWithPr { branch: b, pr });
     }
 
-    display::output_branches(&branches, &get_format(json))
+    display::output_branches(&branches, &OutputFormat::resolve(json))
 }
 
 #[cfg(test)]
@@ -169,12 +256,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn get_format_json() {
-        assert!(matches!(get_format(true), OutputFormat::Json));
+    fn resolve_json() {
+        assert!(matches!(OutputFormat::resolve(true), OutputFormat::Json));
     }
 
     #[test]
-    fn get_format_table() {
-        assert!(matches!(get_format(false), OutputFormat::Table));
+    fn resolve_table() {
+        assert!(matches!(OutputFormat::resolve(false), OutputFormat::Table));
     }
 }
@@ -139,20 +139,31 @@ pub fn get_todos(conn: &Connection, status: Option<&str>) -> Result<Vec<Todo>> {
     Ok(rows.filter_map(|r| r.ok()).collect())
 }
 
-pub fn get_pending_todos(conn: &Connection, project: Option<&str>) -> Result<Vec<TodoWithProject>> {
-    let (sql, params) = match project {
-        Some(p) => {
-            let pattern = format!("%{p}%");
-            (
-                "SELECT t.id, t.session_id, t.content, t.status, t.active_form, s.project FROM todos t JOIN sessions s ON t.session_id = s.id WHERE t.status != 'completed' AND s.project LIKE ?1 ORDER BY t.id DESC".to_string(),
-                vec![rusqlite::types::Value::Text(pattern)],
-            )
+/// Todos not yet completed, newest first
+///
+/// With `started_before` (ms), only todos from sessions started before then,
+/// oldest first.
+pub fn get_pending_todos(
+    conn: &Connection,
+    project: Option<&str>,
+    started_before: Option<i64>,
+) -> Result<Vec<TodoWithProject>> {
+    let mut sql = "SELECT t.id, t.session_id, t.content, t.status, t.active_form, s.project, s.started_at FROM todos t JOIN sessions s ON t.session_id = s.id WHERE t.status != 'completed'".to_string();
+    let mut params: Vec<rusqlite::types::Value> = vec![];
+    if let Some(p) = project {
+        params.push(rusqlite::types::Value::Text(format!("%{p}%")));
+        sql.push_str(&format!(" AND s.project LIKE ?{}", params.len()));
+    }
+    match started_before {
+        Some(cutoff) => {
+            params.push(rusqlite::types::Value::Integer(cutoff));
+            sql.push_str(&format!(
+                " AND s.started_at < ?{} ORDER BY s.started_at ASC, t.id ASC",
+                params.len()
+            ));
         }
-        None => (
-            "SELECT t.id, t.session_id, t.content, t.status, t.active_form, s.project FROM todos t JOIN sessions s ON t.session_id = s.id WHERE t.status != 'completed' ORDER BY t.id DESC".to_string(),
-            vec![],
-        ),
-    };
+        None => sql.push_str(" ORDER BY t.id DESC"),
+    }
 
     let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(rusqlite::params_from_iter(params), |row| {
@@ -163,6 +174,7 @@ pub fn get_pending_todos(conn: &Connection, project: Option<&str>) -> Result<Vec
             status: row.get(3)?,
             active_form: row.get(4)?,
             project: row.get(5)?,
+            started_at: row.get(6)?,
         })
     })?;
     Ok(rows.filter_map(|r| r.ok()).collect())
@@ -278,6 +290,22 @@ pub fn get_tool_detail(conn: &Connection, tool_name: &str) -> Result<Vec<ToolUsa
     Ok(rows.filter_map(|r| r.ok()).collect())
 }
 
+/// Uses of a tool per time bucket, oldest first; empty buckets are omitted
+pub fn get_tool_timeline(
+    conn: &Connection,
+    tool_name: &str,
+    bucket: TimeBucket,
+) -> Result<Vec<(i64, i64)>> {
+    let mut stmt = conn.prepare(
+        "SELECT ((created_at - ?3) / ?2) * ?2 + ?3 AS bucket, COUNT(*) FROM tool_usage WHERE tool_name = ?1 AND created_at >= ?3 GROUP BY bucket ORDER BY bucket",
+    )?;
+    let rows = stmt.query_map(
+        rusqlite::params![tool_name, bucket.millis(), bucket.offset()],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    Ok(rows.filter_map(|r| r.ok()).collect())
+}
+
 pub fn get_branch_stats(
     conn: &Connection,
     branch_filter: Option<&str>,
@@ -312,6 +340,56 @@ pub fn get_branch_stats(
     Ok(rows.filter_map(|r| r.ok()).collect())
 }
 
+/// Refuse anything but a single SELECT (or WITH ... SELECT) statement
+pub fn check_select(sql: &str) -> Result<()> {
+    let body = sql.trim().trim_end_matches(';').trim_end();
+    let first = body
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_ascii_uppercase();
+    if first != "SELECT" && first != "WITH" {
+        anyhow::bail!("Only SELECT queries are allowed");
+    }
+    if body.contains(';') {
+        anyhow::bail!("Only a single statement is allowed");
+    }
+    Ok(())
+}
+
+/// Run a read-only SELECT and collect every row
+pub fn run_select(conn: &Connection, sql: &str) -> Result<QueryResult> {
+    check_select(sql)?;
+    let mut stmt = conn.prepare(sql)?;
+    if !stmt.readonly() {
+        anyhow::bail!("Only read-only queries are allowed");
+    }
+
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+    let width = columns.len();
+    let rows = stmt.query_map([], |row| {
+        (0..width)
+            .map(|i| row.get_ref(i).map(sql_to_json))
+            .collect::<rusqlite::Result<Vec<_>>>()
+    })?;
+
+    Ok(QueryResult {
+        columns,
+        rows: rows.collect::<rusqlite::Result<_>>()?,
+    })
+}
+
+fn sql_to_json(value: rusqlite::types::ValueRef<'_>) -> serde_json::Value {
+    use rusqlite::types::ValueRef;
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => f.into(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned().into(),
+        ValueRef::Blob(b) => format!("<{} bytes>", b.len()).into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -478,7 +556,7 @@ mod tests {
     fn get_pending_todos_all() {
         let store = open_test_db();
         seed_data(&store.conn);
-        let todos = get_pending_todos(&store.conn, None).unwrap();
+        let todos = get_pending_todos(&store.conn, None, None).unwrap();
         assert_eq!(todos.len(), 2); // pending + in_progress
     }
 
@@ -486,15 +564,32 @@ mod tests {
     fn get_pending_todos_filtered() {
         let store = open_test_db();
         seed_data(&store.conn);
-        let todos = get_pending_todos(&store.conn, Some("proj2")).unwrap();
+        let todos = get_pending_todos(&store.conn, Some("proj2"), None).unwrap();
         assert_eq!(todos.len(), 1);
         assert_eq!(todos[0].content, "Review PR");
     }
 
+    #[test]
+    fn get_pending_todos_stale_oldest_first() {
+        let store = open_test_db();
+        seed_data(&store.conn);
+        let todos = get_pending_todos(&store.conn, None, Some(1700002000000)).unwrap();
+        let ids: Vec<&str> = todos.iter().map(|t| t.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["s1", "s2"]);
+        assert_eq!(todos[0].started_at, 1700000000000);
+
+        let todos = get_pending_todos(&store.conn, None, Some(1700000500000)).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].content, "Fix bug");
+
+        let todos = get_pending_todos(&store.conn, Some("proj2"), Some(1700000500000)).unwrap();
+        assert!(todos.is_empty());
+    }
+
     #[test]
     fn get_pending_todos_empty() {
         let store = open_test_db();
-        let todos = get_pending_todos(&store.conn, None).unwrap();
+        let todos = get_pending_todos(&store.conn, None, None).unwrap();
         assert!(todos.is_empty());
     }
 
@@ -586,6 +681,29 @@ mod tests {
         assert!(detail.is_empty());
     }
 
+    #[test]
+    fn get_tool_timeline_buckets() {
+        let store = open_test_db();
+        seed_data(&store.conn);
+        store
+            .conn
+            .execute(
+                "INSERT INTO tool_usage (session_id, message_id, tool_name, created_at) VALUES ('s1', 'm2', 'Read', 1700200000000)",
+                [],
+            )
+            .unwrap();
+
+        let days = get_tool_timeline(&store.conn, "Read", TimeBucket::Day).unwrap();
+        assert_eq!(days, vec![(1_699_920_000_000, 2), (1_700_179_200_000, 1)]);
+
+        let weeks = get_tool_timeline(&store.conn, "Read", TimeBucket::Week).unwrap();
+        assert_eq!(weeks, vec![(1_699_833_600_000, 3)]);
+
+        assert!(get_tool_timeline(&store.conn, "Nope", TimeBucket::Hour)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn get_branch_stats_all() {
         let store = open_test_db();
@@ -617,4 +735,48 @@ mod tests {
         let stats = get_branch_stats(&store.conn, None, 20).unwrap();
         assert!(stats.is_empty());
     }
+
+    #[test]
+    fn check_select_accepts_reads() {
+        assert!(check_select("SELECT 1").is_ok());
+        assert!(check_select("  select * from sessions;  ").is_ok());
+        assert!(check_select("WITH t AS (SELECT 1) SELECT * FROM t").is_ok());
+    }
+
+    #[test]
+    fn check_select_rejects_writes() {
+        assert!(check_select("DELETE FROM sessions").is_err());
+        assert!(check_select("PRAGMA journal_mode=DELETE").is_err());
+        assert!(check_select("SELECT 1; DROP TABLE sessions").is_err());
+        assert!(check_select("").is_err());
+    }
+
+    #[test]
+    fn run_select_rows() {
+        let store = open_test_db();
+        seed_data(&store.conn);
+        let result = run_select(
+            &store.conn,
+            "SELECT id, total_cost_usd, git_branch, NULL AS empty FROM sessions ORDER BY id",
+        )
+        .unwrap();
+        assert_eq!(
+            result.columns,
+            vec!["id", "total_cost_usd", "git_branch", "empty"]
+        );
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0][0], "s1");
+        assert_eq!(result.rows[0][1], 0.05);
+        assert_eq!(result.rows[0][3], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn run_select_rejects_writing_cte() {
+        let store = open_test_db();
+        assert!(run_select(
+            &store.conn,
+            "WITH t AS (SELECT 1) DELETE FROM sessions WHERE id IN (SELECT * FROM t)"
+        )
+        .is_err());
+    }
 }
@@ -8,8 +8,9 @@ use super::paths;
 use super::queries;
 use super::sync;
 use super::types::{
-    start_of_today_ms, BranchStats, DebugError, Message, SearchResult, Session, SyncResult, Todo,
-    TodoWithProject, ToolUsageDetail, ToolUsageStats, UsageStats,
+    start_of_today_ms, BranchStats, DebugError, ErrorCount, Message, QueryResult, SearchResult,
+    Session, SyncResult, TimeBucket, Todo, TodoWithProject, ToolUsageDetail, ToolUsageStats,
+    UsageStats,
 };
 
 // --- DB lifecycle ---
@@ -32,6 +33,15 @@ pub fn ensure_synced(store: &SqliteStore) -> Result<()> {
     Ok(())
 }
 
+/// Run a read-only SELECT on a read-only connection to the database
+#[cfg(not(tarpaulin_include))]
+pub fn run_query(sql: &str) -> Result<QueryResult> {
+    queries::check_select(sql)?;
+    let cfg = get_config()?;
+    let store = SqliteStore::open_read_only(&cfg.database)?;
+    queries::run_select(&store.conn, sql)
+}
+
 // --- Sync ---
 
 #[cfg(not(tarpaulin_include))]
@@ -113,11 +123,16 @@ pub fn get_todos(store: &SqliteStore, status: Option<&str>) -> Result<Vec<Todo>>
     queries::get_todos(&store.conn, status)
 }
 
+/// Pending todos; with `stale_days`, only those from sessions started
+/// more than that many days ago, oldest first
 pub fn get_pending_todos(
     store: &SqliteStore,
     project: Option<&str>,
+    stale_days: Option<u32>,
 ) -> Result<Vec<TodoWithProject>> {
-    queries::get_pending_todos(&store.conn, project)
+    let cutoff =
+        stale_days.map(|days| chrono::Utc::now().timestamp_millis() - i64::from(days) * 86_400_000);
+    queries::get_pending_todos(&store.conn, project, cutoff)
 }
 
 // --- Search ---
@@ -136,25 +151,148 @@ pub fn get_tool_detail(store: &SqliteStore, name: &str) -> Result<Vec<ToolUsageD
     queries::get_tool_detail(&store.conn, name)
 }
 
+pub fn get_tool_timeline(
+    store: &SqliteStore,
+    name: &str,
+    bucket: TimeBucket,
+) -> Result<Vec<(i64, i64)>> {
+    queries::get_tool_timeline(&store.conn, name, bucket)
+}
+
 // --- Errors ---
 
+/// What `hu data errors` looks for in the debug logs
+#[derive(Debug, Clone)]
+pub struct ErrorScan {
+    /// Only files modified at or after this (Unix seconds)
+    pub since: i64,
+    /// A line is an error if any pattern matches
+    pub patterns: Vec<String>,
+    /// Most errors returned by [`scan_debug_errors_with`]
+    pub limit: usize,
+    /// File extensions to read, without the dot
+    pub extensions: Vec<String>,
+}
+
+impl ErrorScan {
+    /// Patterns and limit from `config`; `.txt` files plus `extra_extensions`
+    pub fn new(config: &DataConfig, since: i64, extra_extensions: &[String]) -> Self {
+        let mut extensions = vec!["txt".to_string()];
+        extensions.extend(
+            extra_extensions
+                .iter()
+                .map(|e| e.trim_start_matches('.').to_string()),
+        );
+        Self {
+            since,
+            patterns: config.error_patterns.clone(),
+            limit: config.error_limit,
+            extensions,
+        }
+    }
+}
+
+/// Start of the lookback window: an explicit `since` (Unix seconds,
+/// RFC 3339 or `YYYY-MM-DD`), else `recent_days` (may be fractional) ago
+pub fn resolve_since(recent_days: f64, since: Option<&str>, now: i64) -> Result<i64> {
+    if let Some(since) = since {
+        if let Ok(secs) = since.parse::<i64>() {
+            return Ok(secs);
+        }
+        if let Ok(t) = chrono::DateTime::parse_from_rfc3339(since) {
+            return Ok(t.timestamp());
+        }
+        if let Ok(d) = chrono::NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+            return Ok(d.and_time(chrono::NaiveTime::MIN).and_utc().timestamp());
+        }
+        bail!("Invalid --since: {since} (use Unix seconds, RFC 3339 or YYYY-MM-DD)");
+    }
+    if !recent_days.is_finite() || recent_days < 0.0 {
+        bail!("--recent must be a non-negative number of days");
+    }
+    Ok(now - (recent_days * 86400.0) as i64)
+}
+
 pub fn scan_debug_errors(claude_dir: &Path, recent_days: u32) -> Result<Vec<DebugError>> {
+    let since = chrono::Utc::now().timestamp() - (recent_days as i64 * 86400);
+    scan_debug_errors_with(
+        claude_dir,
+        &ErrorScan::new(&DataConfig::default(), since, &[]),
+    )
+}
+
+/// Unique error lines, first occurrence each, capped at `scan.limit`
+pub fn scan_debug_errors_with(claude_dir: &Path, scan: &ErrorScan) -> Result<Vec<DebugError>> {
+    let mut errors = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    visit_debug_errors(claude_dir, scan, |file, line, content, modified| {
+        if seen.insert(content.to_string()) {
+            errors.push(DebugError {
+                file: file.to_string(),
+                line,
+                content: content.to_string(),
+                timestamp: modified,
+            });
+        }
+    })?;
+
+    errors.truncate(scan.limit);
+    Ok(errors)
+}
+
+/// Occurrences of each unique error line, most frequent first (no cap)
+pub fn count_debug_errors(claude_dir: &Path, scan: &ErrorScan) -> Result<Vec<ErrorCount>> {
+    let mut counts: Vec<ErrorCount> = Vec::new();
+    let mut index = std::collections::HashMap::new();
+
+    visit_debug_errors(
+        claude_dir,
+        scan,
+        |file, _line, content, modified| match index.get(content) {
+            Some(&i) => {
+                let entry: &mut ErrorCount = &mut counts[i];
+                entry.count += 1;
+                entry.last_seen = entry.last_seen.max(modified);
+            }
+            None => {
+                index.insert(content.to_string(), counts.len());
+                counts.push(ErrorCount {
+                    content: content.to_string(),
+                    count: 1,
+                    file: file.to_string(),
+                    last_seen: modified,
+                });
+            }
+        },
+    )?;
+
+    // Stable, so ties keep first-seen order
+    counts.sort_by_key(|c| std::cmp::Reverse(c.count));
+    Ok(counts)
+}
+
+/// Call `visit(file, line_number, line, modified)` for every matching line
+fn visit_debug_errors(
+    claude_dir: &Path,
+    scan: &ErrorScan,
+    mut visit: impl FnMut(&str, usize, &str, i64),
+) -> Result<()> {
     let dir = paths::debug_dir(claude_dir);
     if !dir.exists() {
-        return Ok(vec![]);
+        return Ok(());
     }
 
-    let cutoff = chrono::Utc::now().timestamp() - (recent_days as i64 * 86400);
-    let error_patterns =
-        regex::Regex::new(r"(?i)(error|failed|exception|warning|ENOENT|EACCES|EPERM)")?;
+    let error_patterns = regex::RegexSet::new(&scan.patterns)
+        .map_err(|e| anyhow::anyhow!("Invalid error pattern: {e}"))?;
 
-    let mut errors = Vec::new();
-    let mut seen = std::collections::HashSet::new();
+    let mut entries: Vec<_> = std::fs::read_dir(&dir)?.collect::<std::io::Result<_>>()?;
+    entries.sort_by_key(|e| e.file_name());
 
-    for entry in std::fs::read_dir(&dir)? {
-        let entry = entry?;
+    for entry in entries {
         let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) != Some("txt") {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if !scan.extensions.iter().any(|e| e == ext) {
             continue;
         }
 
@@ -164,7 +302,7 @@ pub fn scan_debug_errors(claude_dir: &Path, recent_days: u32) -> Result<Vec<Debu
             .duration_since(std::time::UNIX_EPOCH)?
             .as_secs() as i64;
 
-        if modified < cutoff {
+        if modified < scan.since {
             continue;
         }
 
@@ -176,19 +314,13 @@ pub fn scan_debug_errors(claude_dir: &Path, recent_days: u32) -> Result<Vec<Debu
             .to_string();
 
         for (i, line) in content.lines().enumerate() {
-            if error_patterns.is_match(line) && seen.insert(line.to_string()) {
-                errors.push(DebugError {
-                    file: filename.clone(),
-                    line: i + 1,
-                    content: line.to_string(),
-                    timestamp: modified,
-                });
+            if error_patterns.is_match(line) {
+                visit(&filename, i + 1, line, modified);
             }
         }
     }
 
-    errors.truncate(50);
-    Ok(errors)
+    Ok(())
 }
 
 // --- Branches ---
@@ -377,7 +509,7 @@ mod tests {
     fn get_pending_todos_all() {
         let store = open_test_db();
         seed_data(&store);
-        let todos = get_pending_todos(&store, None).unwrap();
+        let todos = get_pending_todos(&store, None, None).unwrap();
         assert_eq!(todos.len(), 2);
     }
 
@@ -385,10 +517,21 @@ mod tests {
     fn get_pending_todos_filtered() {
         let store = open_test_db();
         seed_data(&store);
-        let todos = get_pending_todos(&store, Some("proj2")).unwrap();
+        let todos = get_pending_todos(&store, Some("proj2"), None).unwrap();
         assert_eq!(todos.len(), 1);
     }
 
+    #[test]
+    fn get_pending_todos_stale() {
+        let store = open_test_db();
+        seed_data(&store);
+        // Seeded sessions are from 2023
+        assert_eq!(get_pending_todos(&store, None, Some(30)).unwrap().len(), 2);
+        assert!(get_pending_todos(&store, None, Some(100_000))
+            .unwrap()
+            .is_empty());
+    }
+
     // --- Search ---
 
     #[test]
@@ -536,6 +679,78 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    fn scan(extensions: &[&str]) -> ErrorScan {
+        let extensions: Vec<String> = extensions.iter().map(|e| e.to_string()).collect();
+        ErrorScan::new(&DataConfig::default(), 0, &extensions)
+    }
+
+    #[test]
+    fn scan_debug_errors_extra_extension_and_patterns() {
+        let tmp = std::env::temp_dir().join("hu-svc-test-debug-ext");
+        let _ = std::fs::remove_dir_all(&tmp);
+        let debug = tmp.join("debug");
+        std::fs::create_dir_all(&debug).unwrap();
+
+        std::fs::write(debug.join("a.txt"), "thread panicked\nError: x\n").unwrap();
+        std::fs::write(debug.join("b.log"), "Error: in log file\n").unwrap();
+
+        let errors = scan_debug_errors_with(&tmp, &scan(&[".log"])).unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[1].file, "b.log");
+
+        let mut custom = scan(&[]);
+        custom.patterns = vec!["panicked".to_string()];
+        custom.limit = 5;
+        let errors = scan_debug_errors_with(&tmp, &custom).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].content, "thread panicked");
+
+        custom.patterns = vec!["(unclosed".to_string()];
+        assert!(scan_debug_errors_with(&tmp, &custom).is_err());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn count_debug_errors_tallies_without_cap() {
+        let tmp = std::env::temp_dir().join("hu-svc-test-debug-count");
+        let _ = std::fs::remove_dir_all(&tmp);
+        let debug = tmp.join("debug");
+        std::fs::create_dir_all(&debug).unwrap();
+
+        std::fs::write(debug.join("a.txt"), "Error: once\nError: twice\n").unwrap();
+        std::fs::write(debug.join("b.txt"), "Error: twice\n").unwrap();
+
+        let mut tally = scan(&[]);
+        tally.limit = 1;
+        let counts = count_debug_errors(&tmp, &tally).unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].content, "Error: twice");
+        assert_eq!(counts[0].count, 2);
+        assert_eq!(counts[0].file, "a.txt");
+        assert_eq!(counts[1].count, 1);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn resolve_since_forms() {
+        let now = 1_000_000;
+        assert_eq!(resolve_since(7.0, None, now).unwrap(), now - 7 * 86400);
+        assert_eq!(resolve_since(0.5, None, now).unwrap(), now - 43200);
+        assert_eq!(resolve_since(7.0, Some("12345"), now).unwrap(), 12345);
+        assert_eq!(
+            resolve_since(7.0, Some("2026-01-01"), now).unwrap(),
+            1_767_225_600
+        );
+        assert_eq!(
+            resolve_since(7.0, Some("2026-01-01T01:00:00+01:00"), now).unwrap(),
+            1_767_225_600
+        );
+        assert!(resolve_since(7.0, Some("last week"), now).is_err());
+        assert!(resolve_since(-1.0, None, now).is_err());
+    }
+
     // --- Branches ---
 
     #[test]
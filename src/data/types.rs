@@ -117,6 +117,8 @@ pub struct TodoWithProject {
     pub status: String,
     pub active_form: Option<String>,
     pub project: String,
+    /// When the todo's session started (ms)
+    pub started_at: i64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -144,6 +146,38 @@ pub struct ToolUsageStats {
     pub last_used: i64,
 }
 
+/// Bucket width for time series
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum TimeBucket {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl TimeBucket {
+    const HOUR_MS: i64 = 3_600_000;
+    const DAY_MS: i64 = 24 * Self::HOUR_MS;
+
+    /// Bucket width in milliseconds
+    pub fn millis(self) -> i64 {
+        match self {
+            Self::Hour => Self::HOUR_MS,
+            Self::Day => Self::DAY_MS,
+            Self::Week => 7 * Self::DAY_MS,
+        }
+    }
+
+    /// Offset of bucket boundaries from the Unix epoch (weeks start on
+    /// Monday; 1970-01-01 was a Thursday)
+    pub fn offset(self) -> i64 {
+        match self {
+            Self::Week => 4 * Self::DAY_MS,
+            _ => 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ToolUsageDetail {
     pub tool_name: String,
@@ -182,6 +216,40 @@ pub struct DebugError {
     pub timestamp: i64,
 }
 
+/// Columns and rows of an ad-hoc query
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+impl QueryResult {
+    /// Rows as objects keyed by column name
+    pub fn records(&self) -> Vec<serde_json::Map<String, serde_json::Value>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .cloned()
+                    .zip(row.iter().cloned())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// How often one error line shows up across the debug logs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ErrorCount {
+    pub content: String,
+    pub count: usize,
+    /// File it was first seen in
+    pub file: String,
+    /// Latest modification time of a file containing it (Unix seconds)
+    pub last_seen: i64,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SyncResult {
     pub history: usize,
@@ -500,4 +568,13 @@ mod tests {
         assert_eq!(pr.number, 1);
         assert_eq!(pr.state, "MERGED");
     }
+
+    #[test]
+    fn time_bucket_widths() {
+        assert_eq!(TimeBucket::Hour.millis(), 3_600_000);
+        assert_eq!(TimeBucket::Week.millis(), 7 * TimeBucket::Day.millis());
+        // Monday 1970-01-05
+        assert_eq!(TimeBucket::Week.offset(), 345_600_000);
+        assert_eq!(TimeBucket::Day.offset(), 0);
+    }
 }
@@ -1,6 +1,8 @@
 use comfy_table::presets::UTF8_FULL_CONDENSED;
 use comfy_table::{Cell, Table};
 
+use crate::util::fmt::truncate;
+
 use super::types::DocEntry;
 
 #[cfg(test)]
@@ -56,15 +58,6 @@ fn format_table(docs: &[DocEntry]) -> String {
     table.to_string()
 }
 
-/// Truncate string with ellipsis
-fn truncate(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max - 3])
-    }
-}
-
 /// Truncate URL, keeping domain visible
 fn truncate_url(url: &str, max: usize) -> String {
     if url.len() <= max {
@@ -61,21 +61,6 @@ fn format_docs_json() {
     assert!(output.contains("\"source\""));
 }
 
-#[test]
-fn truncate_short() {
-    assert_eq!(truncate("short", 10), "short");
-}
-
-#[test]
-fn truncate_exact() {
-    assert_eq!(truncate("exactly10!", 10), "exactly10!");
-}
-
-#[test]
-fn truncate_long() {
-    assert_eq!(truncate("this is too long", 10), "this is...");
-}
-
 #[test]
 fn truncate_url_short() {
     assert_eq!(
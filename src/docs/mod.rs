@@ -5,6 +5,7 @@ mod types;
 
 pub use cli::DocsCommand;
 
+use anstream::println;
 use anyhow::Result;
 
 /// Run a docs subcommand
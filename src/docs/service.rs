@@ -291,9 +291,7 @@ fn slug_from_url(url: &str) -> String {
 
 /// Fetch URL content (async)
 async fn fetch_url(url: &str) -> Result<String> {
-    let client = reqwest::Client::builder()
-        .user_agent("hu-cli/0.1")
-        .build()?;
+    let client = crate::util::http::client()?;
 
     let response = client
         .get(url)
@@ -0,0 +1,226 @@
+//! Individual doctor checks
+//!
+//! Tool and AWS checks go through [`Shell`] so they can be scripted in tests;
+//! integration checks take the result of each module's config loader.
+
+use anyhow::Result;
+
+use crate::util::shell::Shell;
+
+/// What a check covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Tool,
+    Aws,
+    Integration,
+}
+
+impl Category {
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::Tool => "tool",
+            Category::Aws => "aws",
+            Category::Integration => "integration",
+        }
+    }
+}
+
+/// Outcome of one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub category: Category,
+    pub name: String,
+    pub ok: bool,
+    /// Version, identity or error
+    pub detail: String,
+    /// How to fix a failed check
+    pub hint: Option<String>,
+    /// Whether a failure should make `hu doctor` exit non-zero
+    pub required: bool,
+}
+
+/// An external binary `hu` shells out to
+pub struct Tool {
+    pub name: &'static str,
+    pub version_args: &'static [&'static str],
+    pub hint: &'static str,
+}
+
+/// Binaries required by `hu` subcommands
+pub const TOOLS: &[Tool] = &[
+    Tool {
+        name: "git",
+        version_args: &["--version"],
+        hint: "Install git: https://git-scm.com/downloads",
+    },
+    Tool {
+        name: "gh",
+        version_args: &["--version"],
+        hint: "Install the GitHub CLI: https://cli.github.com",
+    },
+    Tool {
+        name: "kubectl",
+        version_args: &["version", "--client"],
+        hint: "Install kubectl: https://kubernetes.io/docs/tasks/tools/",
+    },
+    Tool {
+        name: "aws",
+        version_args: &["--version"],
+        hint: "Install the AWS CLI v2: https://aws.amazon.com/cli/",
+    },
+];
+
+/// Check a binary is on PATH and report its version
+pub async fn check_tool(shell: &dyn Shell, tool: &Tool) -> Check {
+    let mut check = Check {
+        category: Category::Tool,
+        name: tool.name.to_string(),
+        ok: false,
+        detail: "not found on PATH".to_string(),
+        hint: Some(tool.hint.to_string()),
+        required: true,
+    };
+    if !shell.which(tool.name).await {
+        return check;
+    }
+
+    check.ok = true;
+    check.hint = None;
+    check.detail = match shell.run(tool.name, tool.version_args).await {
+        // Some tools (aws v1) print their version on stderr
+        Ok(out) => first_line(&out.stdout)
+            .or_else(|| first_line(&out.stderr))
+            .unwrap_or("installed")
+            .to_string(),
+        Err(_) => "installed".to_string(),
+    };
+    check
+}
+
+/// Verify the AWS CLI has a valid session (`aws sts get-caller-identity`)
+pub async fn check_aws_session(shell: &dyn Shell) -> Check {
+    let mut check = Check {
+        category: Category::Aws,
+        name: "session".to_string(),
+        ok: false,
+        detail: String::new(),
+        hint: Some("Run: aws sso login (or set AWS_PROFILE)".to_string()),
+        required: false,
+    };
+
+    match shell
+        .run("aws", &["sts", "get-caller-identity", "--output", "json"])
+        .await
+    {
+        Ok(out) if out.is_success() => {
+            check.ok = true;
+            check.hint = None;
+            check.detail = caller_arn(&out.stdout).unwrap_or_else(|| "valid".to_string());
+        }
+        Ok(out) => {
+            check.detail = first_line(&out.stderr)
+                .unwrap_or("no valid credentials")
+                .to_string();
+        }
+        Err(e) => check.detail = e.to_string(),
+    }
+    check
+}
+
+/// Build an integration check from a config loader's answer
+pub fn check_integration(name: &str, configured: Result<bool>, hint: &str) -> Check {
+    let (ok, detail) = match configured {
+        Ok(true) => (true, "configured".to_string()),
+        Ok(false) => (false, "not configured".to_string()),
+        Err(e) => (false, format!("config error: {}", e)),
+    };
+    Check {
+        category: Category::Integration,
+        name: name.to_string(),
+        ok,
+        detail,
+        hint: (!ok).then(|| hint.to_string()),
+        required: false,
+    }
+}
+
+fn first_line(text: &str) -> Option<&str> {
+    text.lines().map(str::trim).find(|l| !l.is_empty())
+}
+
+fn caller_arn(json: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    value.get("Arn")?.as_str().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::shell::FakeShell;
+
+    #[tokio::test]
+    async fn tool_found_reports_version() {
+        let shell = FakeShell::new();
+        shell.expect("which", &["git"], "/usr/bin/git\n", 0);
+        shell.expect("git", &["--version"], "git version 2.43.0\n", 0);
+
+        let check = check_tool(&shell, &TOOLS[0]).await;
+        assert!(check.ok);
+        assert_eq!(check.detail, "git version 2.43.0");
+        assert!(check.hint.is_none());
+    }
+
+    #[tokio::test]
+    async fn tool_missing_has_hint() {
+        let shell = FakeShell::new();
+        let kubectl = TOOLS.iter().find(|t| t.name == "kubectl").unwrap();
+
+        let check = check_tool(&shell, kubectl).await;
+        assert!(!check.ok);
+        assert!(check.required);
+        assert!(check.hint.unwrap().contains("kubectl"));
+    }
+
+    #[tokio::test]
+    async fn aws_session_valid() {
+        let shell = FakeShell::new();
+        shell.expect(
+            "aws",
+            &["sts", "get-caller-identity", "--output", "json"],
+            r#"{"UserId": "X", "Account": "123", "Arn": "arn:aws:sts::123:assumed-role/dev/me"}"#,
+            0,
+        );
+
+        let check = check_aws_session(&shell).await;
+        assert!(check.ok);
+        assert_eq!(check.detail, "arn:aws:sts::123:assumed-role/dev/me");
+    }
+
+    #[tokio::test]
+    async fn aws_session_expired_is_not_required() {
+        let shell = FakeShell::new();
+        shell.expect(
+            "aws",
+            &["sts", "get-caller-identity", "--output", "json"],
+            "",
+            255,
+        );
+
+        let check = check_aws_session(&shell).await;
+        assert!(!check.ok);
+        assert!(!check.required);
+        assert!(check.hint.is_some());
+    }
+
+    #[test]
+    fn integration_states() {
+        assert!(check_integration("Slack", Ok(true), "hint").ok);
+
+        let missing = check_integration("Slack", Ok(false), "Run: hu slack auth");
+        assert!(!missing.ok);
+        assert_eq!(missing.hint.as_deref(), Some("Run: hu slack auth"));
+
+        let broken = check_integration("Jira", Err(anyhow::anyhow!("bad toml")), "hint");
+        assert!(broken.detail.contains("bad toml"));
+    }
+}
@@ -0,0 +1,131 @@
+//! `hu doctor` — check external tools, AWS session and integration config
+
+mod checks;
+
+use anstream::println;
+use anyhow::{bail, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+
+pub use checks::check_aws_session;
+use checks::{check_integration, check_tool, Check, TOOLS};
+
+use crate::util::shell::{RealShell, Shell};
+
+/// Run all checks, print the table, and fail if a required tool is missing
+#[cfg(not(tarpaulin_include))]
+pub async fn run() -> Result<()> {
+    let shell = RealShell;
+    let mut results = tool_checks(&shell).await;
+    if results.iter().any(|c| c.name == "aws" && c.ok) {
+        results.push(check_aws_session(&shell).await);
+    }
+    results.extend(integration_checks());
+
+    println!("{}", render(&results));
+
+    let missing: Vec<&str> = results
+        .iter()
+        .filter(|c| c.required && !c.ok)
+        .map(|c| c.name.as_str())
+        .collect();
+    if !missing.is_empty() {
+        bail!("Missing required tools: {}", missing.join(", "));
+    }
+    Ok(())
+}
+
+async fn tool_checks(shell: &dyn Shell) -> Vec<Check> {
+    let mut results = Vec::new();
+    for tool in TOOLS {
+        results.push(check_tool(shell, tool).await);
+    }
+    results
+}
+
+#[cfg(not(tarpaulin_include))]
+fn integration_checks() -> Vec<Check> {
+    let credentials = crate::util::load_credentials();
+    let (github, jira) = match &credentials {
+        Ok(creds) => (Ok(creds.github.is_some()), Ok(creds.jira.is_some())),
+        Err(e) => (Err(anyhow::anyhow!("{}", e)), Err(anyhow::anyhow!("{}", e))),
+    };
+
+    vec![
+        check_integration(
+            "Slack",
+            crate::slack::get_config().map(|c| c.is_configured),
+            "Run: hu slack auth",
+        ),
+        check_integration("Jira", jira, "Run: hu jira auth"),
+        check_integration("GitHub", github, "Run: hu gh login"),
+        check_integration(
+            "New Relic",
+            crate::newrelic::get_config().map(|c| c.is_configured()),
+            "Run: hu newrelic auth <key> --account <id>",
+        ),
+        check_integration(
+            "PagerDuty",
+            crate::pagerduty::get_config().map(|c| c.is_configured()),
+            "Run: hu pagerduty auth <token>",
+        ),
+    ]
+}
+
+/// Render checks as a ✓/✗ table
+fn render(results: &[Check]) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["", "Category", "Check", "Details"]);
+
+    for check in results {
+        let (icon, color) = match (check.ok, check.required) {
+            (true, _) => ("✓", Color::Green),
+            (false, true) => ("✗", Color::Red),
+            (false, false) => ("✗", Color::Yellow),
+        };
+        let details = match &check.hint {
+            Some(hint) => format!("{}\n{}", check.detail, hint),
+            None => check.detail.clone(),
+        };
+        table.add_row(vec![
+            Cell::new(icon).fg(color),
+            Cell::new(check.category.label()),
+            Cell::new(&check.name),
+            Cell::new(details),
+        ]);
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::shell::FakeShell;
+
+    #[tokio::test]
+    async fn tool_checks_cover_every_tool() {
+        let shell = FakeShell::new();
+        shell.expect("which", &["git"], "/usr/bin/git\n", 0);
+        shell.expect("git", &["--version"], "git version 2.43.0\n", 0);
+
+        let results = tool_checks(&shell).await;
+        assert_eq!(results.len(), TOOLS.len());
+        assert!(results[0].ok);
+        assert!(results[1..].iter().all(|c| !c.ok && c.required));
+    }
+
+    #[test]
+    fn render_shows_hints_for_failures() {
+        let results = vec![
+            check_integration("Slack", Ok(true), "unused"),
+            check_integration("PagerDuty", Ok(false), "Run: hu pagerduty auth <token>"),
+        ];
+        let out = render(&results);
+        assert!(out.contains("Slack"));
+        assert!(out.contains("✓"));
+        assert!(out.contains("✗"));
+        assert!(out.contains("Run: hu pagerduty auth <token>"));
+        assert!(!out.contains("unused"));
+    }
+}
@@ -0,0 +1,170 @@
+//! EKS CLI commands
+
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum EksCommand {
+    /// List pods in the cluster
+    List {
+        /// Namespace to list pods from
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// List pods from all namespaces
+        #[arg(short = 'A', long)]
+        all_namespaces: bool,
+
+        /// Kubeconfig context to use
+        #[arg(short, long)]
+        context: Option<String>,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Execute a command in a pod (interactive shell by default)
+    Exec {
+        /// Pod name (pick from a list when omitted)
+        pod: Option<String>,
+
+        /// Treat <POD> as part of a name and use the one pod that matches
+        #[arg(short = 'm', long = "match")]
+        match_name: bool,
+
+        /// If nothing matches, look for the pod in every namespace
+        #[arg(long, requires = "match_name")]
+        search_all_namespaces: bool,
+
+        /// Namespace
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Container name (if pod has multiple containers)
+        #[arg(short, long)]
+        container: Option<String>,
+
+        /// Kubeconfig context to use
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Don't allocate a TTY (the default when stdin isn't a terminal)
+        #[arg(short = 'T', long)]
+        no_tty: bool,
+
+        /// Skip the production confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Command to run (default: /bin/sh)
+        #[arg(last = true)]
+        command: Vec<String>,
+    },
+
+    /// Tail logs from a pod
+    Logs {
+        /// Pod name
+        pod: String,
+
+        /// Treat <POD> as part of a name and use the one pod that matches
+        #[arg(short = 'm', long = "match")]
+        match_name: bool,
+
+        /// If nothing matches, look for the pod in every namespace
+        #[arg(long, requires = "match_name")]
+        search_all_namespaces: bool,
+
+        /// Namespace
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Container name (if pod has multiple containers)
+        #[arg(short, long)]
+        container: Option<String>,
+
+        /// Follow log output
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Show logs from previous container instance
+        #[arg(long)]
+        previous: bool,
+
+        /// Number of lines to show from the end
+        #[arg(long)]
+        tail: Option<usize>,
+
+        /// Kubeconfig context to use
+        #[arg(long)]
+        context: Option<String>,
+    },
+
+    /// Show pod details: container states, restarts and events
+    Describe {
+        /// Pod name
+        pod: String,
+
+        /// Treat <POD> as part of a name and use the one pod that matches
+        #[arg(short = 'm', long = "match")]
+        match_name: bool,
+
+        /// If nothing matches, look for the pod in every namespace
+        #[arg(long, requires = "match_name")]
+        search_all_namespaces: bool,
+
+        /// Namespace
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Only list the pod's events
+        #[arg(short, long)]
+        events: bool,
+
+        /// Kubeconfig context to use
+        #[arg(long)]
+        context: Option<String>,
+    },
+
+    /// Forward a local port to a pod until Ctrl-C
+    Forward {
+        /// Pod name
+        pod: String,
+
+        /// Treat <POD> as part of a name and use the one pod that matches
+        #[arg(short = 'm', long = "match")]
+        match_name: bool,
+
+        /// If nothing matches, look for the pod in every namespace
+        #[arg(long, requires = "match_name")]
+        search_all_namespaces: bool,
+
+        /// Pod port (default: `default_port` under `[eks]` in settings)
+        remote_port: Option<u16>,
+
+        /// Local port (default: a free port)
+        #[arg(short, long)]
+        local_port: Option<u16>,
+
+        /// Namespace
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Kubeconfig context to use
+        #[arg(long)]
+        context: Option<String>,
+    },
+
+    /// Re-run the last `exec` (same context, namespace, container)
+    Last {
+        /// Don't allocate a TTY (the default when stdin isn't a terminal)
+        #[arg(short = 'T', long)]
+        no_tty: bool,
+
+        /// Skip the production confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[cfg(test)]
+mod tests;
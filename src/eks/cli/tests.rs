@@ -0,0 +1,329 @@
+use super::*;
+use clap::{CommandFactory, Parser};
+
+#[derive(Parser)]
+struct TestCli {
+    #[command(subcommand)]
+    cmd: EksCommand,
+}
+
+#[test]
+fn search_all_namespaces_needs_match() {
+    assert!(
+        TestCli::try_parse_from(["test", "logs", "worker", "--search-all-namespaces"]).is_err()
+    );
+    let cli = TestCli::try_parse_from(["test", "logs", "-m", "worker", "--search-all-namespaces"])
+        .unwrap();
+    match cli.cmd {
+        EksCommand::Logs {
+            search_all_namespaces,
+            ..
+        } => assert!(search_all_namespaces),
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_last() {
+    let cli = TestCli::try_parse_from(["test", "last", "-y"]).unwrap();
+    match cli.cmd {
+        EksCommand::Last { no_tty, yes } => {
+            assert!(!no_tty);
+            assert!(yes);
+        }
+        _ => panic!("Expected Last command"),
+    }
+}
+
+#[test]
+fn parses_list_basic() {
+    let cli = TestCli::try_parse_from(["test", "list"]).unwrap();
+    match cli.cmd {
+        EksCommand::List {
+            namespace,
+            all_namespaces,
+            context,
+            json,
+        } => {
+            assert!(namespace.is_none());
+            assert!(!all_namespaces);
+            assert!(context.is_none());
+            assert!(!json);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn parses_list_with_namespace() {
+    let cli = TestCli::try_parse_from(["test", "list", "-n", "kube-system"]).unwrap();
+    match cli.cmd {
+        EksCommand::List { namespace, .. } => {
+            assert_eq!(namespace, Some("kube-system".to_string()));
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn parses_list_all_namespaces() {
+    let cli = TestCli::try_parse_from(["test", "list", "-A"]).unwrap();
+    match cli.cmd {
+        EksCommand::List { all_namespaces, .. } => {
+            assert!(all_namespaces);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn parses_list_with_context() {
+    let cli = TestCli::try_parse_from(["test", "list", "-c", "prod"]).unwrap();
+    match cli.cmd {
+        EksCommand::List { context, .. } => {
+            assert_eq!(context, Some("prod".to_string()));
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn parses_list_json() {
+    let cli = TestCli::try_parse_from(["test", "list", "--json"]).unwrap();
+    match cli.cmd {
+        EksCommand::List { json, .. } => {
+            assert!(json);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn parses_exec_basic() {
+    let cli = TestCli::try_parse_from(["test", "exec", "my-pod"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec {
+            pod,
+            namespace,
+            container,
+            command,
+            ..
+        } => {
+            assert_eq!(pod.as_deref(), Some("my-pod"));
+            assert!(namespace.is_none());
+            assert!(container.is_none());
+            assert!(command.is_empty());
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_exec_with_namespace() {
+    let cli = TestCli::try_parse_from(["test", "exec", "my-pod", "-n", "prod"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec { namespace, .. } => {
+            assert_eq!(namespace, Some("prod".to_string()));
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_exec_with_container() {
+    let cli = TestCli::try_parse_from(["test", "exec", "my-pod", "-c", "app"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec { container, .. } => {
+            assert_eq!(container, Some("app".to_string()));
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_exec_with_command() {
+    let cli =
+        TestCli::try_parse_from(["test", "exec", "my-pod", "--", "bash", "-c", "ls"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec { command, .. } => {
+            assert_eq!(command, vec!["bash", "-c", "ls"]);
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_exec_without_pod() {
+    let cli = TestCli::try_parse_from(["test", "exec", "--", "ls"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec { pod, command, .. } => {
+            assert!(pod.is_none());
+            assert_eq!(command, vec!["ls"]);
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_exec_no_tty() {
+    let cli = TestCli::try_parse_from(["test", "exec", "my-pod", "-T", "--", "true"]).unwrap();
+    match cli.cmd {
+        EksCommand::Exec { no_tty, yes, .. } => {
+            assert!(no_tty);
+            assert!(!yes);
+        }
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn parses_logs_basic() {
+    let cli = TestCli::try_parse_from(["test", "logs", "my-pod"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs {
+            pod,
+            follow,
+            previous,
+            tail,
+            ..
+        } => {
+            assert_eq!(pod, "my-pod");
+            assert!(!follow);
+            assert!(!previous);
+            assert!(tail.is_none());
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_logs_follow() {
+    let cli = TestCli::try_parse_from(["test", "logs", "my-pod", "-f"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs { follow, .. } => {
+            assert!(follow);
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_logs_previous() {
+    let cli = TestCli::try_parse_from(["test", "logs", "my-pod", "--previous"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs { previous, .. } => {
+            assert!(previous);
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_logs_tail() {
+    let cli = TestCli::try_parse_from(["test", "logs", "my-pod", "--tail", "100"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs { tail, .. } => {
+            assert_eq!(tail, Some(100));
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_logs_with_container() {
+    let cli = TestCli::try_parse_from(["test", "logs", "my-pod", "-c", "sidecar"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs { container, .. } => {
+            assert_eq!(container, Some("sidecar".to_string()));
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn parses_describe() {
+    let cli =
+        TestCli::try_parse_from(["test", "describe", "my-pod", "-n", "prod", "--events"]).unwrap();
+    match cli.cmd {
+        EksCommand::Describe {
+            pod,
+            namespace,
+            events,
+            context,
+            ..
+        } => {
+            assert_eq!(pod, "my-pod");
+            assert_eq!(namespace, Some("prod".to_string()));
+            assert!(events);
+            assert!(context.is_none());
+        }
+        _ => panic!("Expected Describe command"),
+    }
+}
+
+#[test]
+fn parses_forward() {
+    let cli = TestCli::try_parse_from(["test", "forward", "my-pod", "8080", "-l", "9000"]).unwrap();
+    match cli.cmd {
+        EksCommand::Forward {
+            pod,
+            remote_port,
+            local_port,
+            ..
+        } => {
+            assert_eq!(pod, "my-pod");
+            assert_eq!(remote_port, Some(8080));
+            assert_eq!(local_port, Some(9000));
+        }
+        _ => panic!("Expected Forward command"),
+    }
+}
+
+#[test]
+fn parses_forward_defaults() {
+    let cli = TestCli::try_parse_from(["test", "forward", "my-pod"]).unwrap();
+    match cli.cmd {
+        EksCommand::Forward {
+            remote_port,
+            local_port,
+            ..
+        } => {
+            assert!(remote_port.is_none());
+            assert!(local_port.is_none());
+        }
+        _ => panic!("Expected Forward command"),
+    }
+}
+
+#[test]
+fn parses_match_flag() {
+    let cli = TestCli::try_parse_from(["test", "logs", "web", "-m"]).unwrap();
+    match cli.cmd {
+        EksCommand::Logs {
+            pod, match_name, ..
+        } => {
+            assert_eq!(pod, "web");
+            assert!(match_name);
+        }
+        _ => panic!("Expected Logs command"),
+    }
+}
+
+#[test]
+fn command_debug() {
+    let cmd = EksCommand::List {
+        namespace: None,
+        all_namespaces: false,
+        context: None,
+        json: false,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("List"));
+}
+
+#[test]
+fn command_has_help() {
+    let mut cmd = TestCli::command();
+    let help = cmd.render_help();
+    assert!(!help.to_string().is_empty());
+}
@@ -0,0 +1,144 @@
+//! EKS configuration
+//!
+//! Loads the `[eks]` section of `~/.config/hu/settings.toml`
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// EKS configuration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct EksConfig {
+    /// Pod port `hu eks forward` uses when none is given
+    pub default_port: Option<u16>,
+    /// Ask before `hu eks exec` into a production context
+    pub confirm_prod: bool,
+    /// Context name fragments that mark a context as production
+    pub prod_contexts: Vec<String>,
+    /// Default namespace per context name fragment, used when `-n` isn't given
+    pub namespaces: BTreeMap<String, String>,
+}
+
+impl Default for EksConfig {
+    fn default() -> Self {
+        Self {
+            default_port: None,
+            confirm_prod: true,
+            prod_contexts: vec!["prod".to_string()],
+            namespaces: BTreeMap::new(),
+        }
+    }
+}
+
+impl EksConfig {
+    /// Whether a kubeconfig context name looks like production
+    #[must_use]
+    pub fn is_prod_context(&self, context: &str) -> bool {
+        let context = context.to_lowercase();
+        self.prod_contexts
+            .iter()
+            .any(|fragment| context.contains(&fragment.to_lowercase()))
+    }
+
+    /// Configured namespace for a context; the longest matching fragment wins
+    #[must_use]
+    pub fn namespace_for(&self, context: &str) -> Option<&str> {
+        let context = context.to_lowercase();
+        self.namespaces
+            .iter()
+            .filter(|(fragment, _)| context.contains(&fragment.to_lowercase()))
+            .max_by_key(|(fragment, _)| fragment.len())
+            .map(|(_, namespace)| namespace.as_str())
+    }
+}
+
+/// Settings file structure
+#[derive(Debug, Default, Deserialize)]
+struct SettingsFile {
+    eks: Option<EksConfig>,
+}
+
+/// Load EKS config from the settings file
+#[cfg(not(tarpaulin_include))]
+pub fn load_config() -> Result<EksConfig> {
+    match crate::util::settings_path() {
+        Some(path) if path.exists() => parse_config(&fs::read_to_string(&path)?),
+        _ => Ok(EksConfig::default()),
+    }
+}
+
+/// Parse config from TOML string
+fn parse_config(contents: &str) -> Result<EksConfig> {
+    let settings: SettingsFile = toml::from_str(contents)?;
+    Ok(settings.eks.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config_empty() {
+        let config = parse_config("").unwrap();
+        assert!(config.default_port.is_none());
+    }
+
+    #[test]
+    fn parse_config_with_default_port() {
+        let config = parse_config("[eks]\ndefault_port = 3000\n").unwrap();
+        assert_eq!(config.default_port, Some(3000));
+    }
+
+    #[test]
+    fn parse_config_other_sections_ignored() {
+        let config = parse_config("[pagerduty]\napi_token = \"x\"\n").unwrap();
+        assert!(config.default_port.is_none());
+    }
+
+    #[test]
+    fn confirm_prod_defaults_on() {
+        let config = parse_config("[eks]\ndefault_port = 3000\n").unwrap();
+        assert!(config.confirm_prod);
+        assert_eq!(config.prod_contexts, vec!["prod"]);
+    }
+
+    #[test]
+    fn parse_config_prod_settings() {
+        let config =
+            parse_config("[eks]\nconfirm_prod = false\nprod_contexts = [\"live\"]\n").unwrap();
+        assert!(!config.confirm_prod);
+        assert!(config.is_prod_context("eu-live-cluster"));
+        assert!(!config.is_prod_context("eu-prod-cluster"));
+    }
+
+    #[test]
+    fn is_prod_context_ignores_case() {
+        let config = EksConfig::default();
+        assert!(config.is_prod_context("arn:aws:eks:us-east-1:1:cluster/API-Prod"));
+        assert!(!config.is_prod_context("dev"));
+    }
+
+    #[test]
+    fn namespace_for_matches_context_fragment() {
+        let config =
+            parse_config("[eks.namespaces]\nprod = \"web-prod\"\ndev = \"web\"\n").unwrap();
+        assert_eq!(config.namespace_for("eks-Prod-cluster"), Some("web-prod"));
+        assert_eq!(config.namespace_for("eks-dev"), Some("web"));
+        assert_eq!(config.namespace_for("staging"), None);
+    }
+
+    #[test]
+    fn namespace_for_prefers_longest_fragment() {
+        let config =
+            parse_config("[eks.namespaces]\nprod = \"web-prod\"\neu-prod = \"web-eu\"\n").unwrap();
+        assert_eq!(config.namespace_for("eu-prod"), Some("web-eu"));
+        assert_eq!(config.namespace_for("us-prod"), Some("web-prod"));
+    }
+
+    #[test]
+    fn parse_config_invalid_port() {
+        assert!(parse_config("[eks]\ndefault_port = 70000\n").is_err());
+    }
+}
@@ -1,7 +1,11 @@
 //! EKS output formatting
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+use owo_colors::OwoColorize;
+
+use crate::util::print_structured;
 
 use super::types::{OutputFormat, Pod};
 
@@ -9,9 +13,15 @@ use super::types::{OutputFormat, Pod};
 fn status_color(status: &str) -> Color {
     match status {
         "Running" => Color::Green,
-        "Pending" => Color::Yellow,
-        "Succeeded" => Color::Cyan,
-        "Failed" => Color::Red,
+        "Pending" | "ContainerCreating" | "PodInitializing" => Color::Yellow,
+        "Succeeded" | "Completed" => Color::Cyan,
+        "Failed"
+        | "Error"
+        | "CrashLoopBackOff"
+        | "OOMKilled"
+        | "ImagePullBackOff"
+        | "ErrImagePull"
+        | "CreateContainerConfigError" => Color::Red,
         "Unknown" => Color::DarkGrey,
         _ => Color::White,
     }
@@ -67,18 +77,108 @@ pub fn output_pods(pods: &[Pod], format: OutputFormat, show_namespace: bool) ->
             println!("{table}");
             println!("\n{} pods", pods.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(pods).context("Failed to serialize pods")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(pods, format)?,
     }
     Ok(())
 }
 
+/// Highlight the lines of `kubectl describe` / events output worth reading
+///
+/// Non-zero restart counts and warnings are red, previous container states
+/// and their termination reasons yellow.
+pub fn highlight_describe(output: &str) -> String {
+    let mut in_last_state = false;
+    output
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            in_last_state = if trimmed.starts_with("Last State:") {
+                !trimmed.ends_with("<none>")
+            } else {
+                in_last_state && is_state_detail(trimmed)
+            };
+
+            let restarted = trimmed
+                .strip_prefix("Restart Count:")
+                .is_some_and(|count| count.trim() != "0");
+            let warning = trimmed.starts_with("Warning ") || line.contains(" Warning ");
+
+            if in_last_state {
+                line.yellow().to_string()
+            } else if restarted || warning {
+                line.red().to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Fields kubectl prints under a container's `Last State`
+fn is_state_detail(line: &str) -> bool {
+    [
+        "Reason:",
+        "Exit Code:",
+        "Started:",
+        "Finished:",
+        "Message:",
+        "Signal:",
+    ]
+    .iter()
+    .any(|field| line.starts_with(field))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const DESCRIBE: &str = "\
+Name:         web-1
+Containers:
+  app:
+    State:          Running
+    Last State:     Terminated
+      Reason:       OOMKilled
+      Exit Code:    137
+    Ready:          True
+    Restart Count:  3
+  sidecar:
+    Restart Count:  0
+Events:
+  Type     Reason   Age  From     Message
+  ----     ------   ---  ----     -------
+  Normal   Pulled   2m   kubelet  Container image pulled
+  Warning  BackOff  1m   kubelet  Back-off restarting failed container";
+
+    #[test]
+    fn highlight_describe_marks_restarts_and_last_state() {
+        let out = highlight_describe(DESCRIBE);
+        let lines: Vec<&str> = out.lines().collect();
+        let colored = |i: usize| lines[i].contains('\x1b');
+
+        assert!(!colored(0)); // Name
+        assert!(!colored(3)); // State: Running
+        assert!(colored(4)); // Last State: Terminated
+        assert!(colored(5)); // Reason: OOMKilled
+        assert!(colored(6)); // Exit Code
+        assert!(!colored(7)); // Ready
+        assert!(colored(8)); // Restart Count: 3
+        assert!(!colored(10)); // Restart Count: 0
+        assert!(!colored(14)); // Normal event
+        assert!(colored(15)); // Warning event
+    }
+
+    #[test]
+    fn highlight_describe_keeps_text() {
+        let out = highlight_describe(DESCRIBE);
+        let plain = out
+            .replace("\x1b[31m", "")
+            .replace("\x1b[33m", "")
+            .replace("\x1b[39m", "");
+        assert_eq!(plain, DESCRIBE);
+    }
+
     #[test]
     fn status_color_running() {
         assert_eq!(status_color("Running"), Color::Green);
@@ -106,7 +206,15 @@ mod tests {
 
     #[test]
     fn status_color_other() {
-        assert_eq!(status_color("CrashLoopBackOff"), Color::White);
+        assert_eq!(status_color("Terminating"), Color::White);
+    }
+
+    #[test]
+    fn status_color_container_reasons() {
+        assert_eq!(status_color("CrashLoopBackOff"), Color::Red);
+        assert_eq!(status_color("OOMKilled"), Color::Red);
+        assert_eq!(status_color("ContainerCreating"), Color::Yellow);
+        assert_eq!(status_color("Completed"), Color::Cyan);
     }
 
     #[test]
@@ -0,0 +1,177 @@
+//! `hu eks exec` and `hu eks last`: check the pod, confirm production
+//! contexts, then hand the terminal to `kubectl exec`
+
+use anyhow::{bail, Result};
+use std::io::IsTerminal;
+
+use crate::util::ChildExit;
+
+use super::last::{self, LastExec};
+use super::types::{KubectlConfig, Pod};
+use super::{config, kubectl};
+
+/// Exec into a pod; a failing remote command ends with [`ChildExit`] so hu
+/// exits with its code
+///
+/// The target is saved for `hu eks last` whatever the remote command returns.
+#[cfg(not(tarpaulin_include))]
+pub fn cmd_exec(target: LastExec, no_tty: bool, yes: bool) -> Result<()> {
+    let config = KubectlConfig {
+        context: target.context.clone(),
+        namespace: target.namespace.clone(),
+    };
+    let interactive = std::io::stdin().is_terminal();
+    let dry_run = crate::util::dry_run::enabled();
+    if !dry_run {
+        check_running(&config, &target.pod, interactive && !yes)?;
+    }
+    if !yes && interactive && !dry_run {
+        confirm_prod(&config, &target.pod)?;
+    }
+
+    let status = kubectl::exec_pod(
+        &config,
+        &target.pod,
+        target.container.as_deref(),
+        &target.command,
+        interactive && !no_tty,
+    )?;
+    // kubectl got as far as the running pod, so the target is worth
+    // replaying even when the remote command itself failed
+    if let Err(e) = last::save(&target) {
+        log::warn!("{:#}", e);
+    }
+    if !status.success() {
+        return Err(ChildExit::from_code(status.code()).into());
+    }
+    Ok(())
+}
+
+/// Re-run the last saved exec, re-resolving the pod
+///
+/// Pods get replaced on every deploy, so if the saved pod is gone the first
+/// pod of the same workload is used instead.
+#[cfg(not(tarpaulin_include))]
+pub fn cmd_last(no_tty: bool, yes: bool) -> Result<()> {
+    let Some(mut target) = last::load()? else {
+        bail!("No previous exec to repeat. Run `hu eks exec` first.");
+    };
+    let config = KubectlConfig {
+        context: target.context.clone(),
+        namespace: target.namespace.clone(),
+    };
+
+    let pods = kubectl::list_pods(&config, false)?;
+    match last::repick(&pods, &target.pod) {
+        Some((name, false)) => target.pod = name.to_string(),
+        Some((name, true)) => {
+            log::info!("Pod {} is gone; using {}", target.pod, name);
+            target.pod = name.to_string();
+        }
+        None => bail!(
+            "Pod {} is gone and no {} pod is running",
+            target.pod,
+            last::workload_name(&target.pod)
+        ),
+    }
+    cmd_exec(target, no_tty, yes)
+}
+
+/// Make sure the pod is running before exec-ing into it
+///
+/// Otherwise kubectl fails with a cryptic error. With `ask` the user may go
+/// ahead anyway; without (`--yes` or no terminal) it's an error.
+#[cfg(not(tarpaulin_include))]
+fn check_running(config: &KubectlConfig, pod: &str, ask: bool) -> Result<()> {
+    let pod = kubectl::get_pod(config, pod)?;
+    if pod.status == "Running" {
+        return Ok(());
+    }
+
+    let message = not_running_message(&pod);
+    if !ask {
+        bail!(message);
+    }
+    let confirmed = dialoguer::Confirm::new()
+        .with_prompt(format!("{}. Exec anyway?", message))
+        .default(false)
+        .interact()?;
+    if !confirmed {
+        bail!("Aborted.");
+    }
+    Ok(())
+}
+
+/// Why exec into `pod` will likely fail
+fn not_running_message(pod: &Pod) -> String {
+    format!(
+        "Pod {} is {} ({} ready, {} restarts)",
+        pod.name, pod.status, pod.ready, pod.restarts
+    )
+}
+
+/// Ask before connecting to a pod in a production context
+///
+/// Controlled by `confirm_prod` and `prod_contexts` under `[eks]`.
+#[cfg(not(tarpaulin_include))]
+fn confirm_prod(config: &KubectlConfig, pod: &str) -> Result<()> {
+    let settings = config::load_config()?;
+    if !settings.confirm_prod {
+        return Ok(());
+    }
+    let context = kubectl::effective_context(config)?;
+    if !settings.is_prod_context(&context) {
+        return Ok(());
+    }
+
+    let prompt = prod_prompt(&context, config.namespace.as_deref(), pod);
+    let confirmed = dialoguer::Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?;
+    if !confirmed {
+        bail!("Aborted.");
+    }
+    Ok(())
+}
+
+/// Confirmation prompt naming the cluster, namespace and pod
+fn prod_prompt(context: &str, namespace: Option<&str>, pod: &str) -> String {
+    format!(
+        "PRODUCTION context {}: connect to pod {} in namespace {}?",
+        context,
+        pod,
+        namespace.unwrap_or("(default)")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prod_prompt_names_target() {
+        let prompt = prod_prompt("eks-prod", Some("web"), "web-1");
+        assert!(prompt.contains("eks-prod"));
+        assert!(prompt.contains("web-1"));
+        assert!(prompt.contains("namespace web"));
+        assert!(prod_prompt("eks-prod", None, "web-1").contains("(default)"));
+    }
+
+    #[test]
+    fn not_running_message_shows_status() {
+        let pod = Pod {
+            name: "web-1".to_string(),
+            namespace: "web".to_string(),
+            status: "CrashLoopBackOff".to_string(),
+            ready: "0/1".to_string(),
+            restarts: 12,
+            age: "5m".to_string(),
+            node: None,
+        };
+        assert_eq!(
+            not_running_message(&pod),
+            "Pod web-1 is CrashLoopBackOff (0/1 ready, 12 restarts)"
+        );
+    }
+}
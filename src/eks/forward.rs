@@ -0,0 +1,57 @@
+//! `hu eks forward`: port-forward a local port to a pod
+
+use anstream::println;
+use anyhow::Result;
+
+use super::types::KubectlConfig;
+use super::{config, kubectl};
+
+/// Port-forward to a pod
+#[cfg(not(tarpaulin_include))]
+pub async fn cmd_forward(
+    config: &KubectlConfig,
+    pod: &str,
+    remote_port: Option<u16>,
+    local_port: Option<u16>,
+) -> Result<()> {
+    let remote = resolve_remote_port(remote_port, config::load_config()?.default_port)?;
+    let local = match local_port {
+        Some(port) => port,
+        None => kubectl::free_local_port()?,
+    };
+
+    if !crate::util::dry_run::enabled() {
+        println!(
+            "Forwarding 127.0.0.1:{} -> {}:{} (Ctrl-C to stop)",
+            local, pod, remote
+        );
+    }
+    kubectl::port_forward(config, pod, local, remote).await
+}
+
+/// Pod port from the command line, else the configured default
+fn resolve_remote_port(arg: Option<u16>, default: Option<u16>) -> Result<u16> {
+    arg.or(default).ok_or_else(|| {
+        anyhow::anyhow!(
+            "No pod port given. Pass one (hu eks forward <pod> <port>) \
+             or set default_port under [eks] in settings.toml"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_remote_port_prefers_argument() {
+        assert_eq!(resolve_remote_port(Some(8080), Some(3000)).unwrap(), 8080);
+        assert_eq!(resolve_remote_port(None, Some(3000)).unwrap(), 3000);
+    }
+
+    #[test]
+    fn resolve_remote_port_requires_one() {
+        let err = resolve_remote_port(None, None).unwrap_err().to_string();
+        assert!(err.contains("default_port"));
+    }
+}
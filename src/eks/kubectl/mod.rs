@@ -1,9 +1,10 @@
 //! kubectl wrapper functions
 
 use anyhow::{Context, Result};
-use std::process::{Command, Stdio};
+use std::process::{Command, ExitStatus, Stdio};
 
-use super::types::{KubectlConfig, Pod, PodList};
+use super::types::{KubectlConfig, Pod, PodItem, PodList};
+use crate::util::dry_run;
 
 #[cfg(test)]
 mod tests;
@@ -52,42 +53,73 @@ pub fn parse_pod_list(json: &str) -> Result<Vec<Pod>> {
     Ok(pod_list.items.iter().map(|item| item.to_pod()).collect())
 }
 
-/// Execute into a pod (interactive)
+/// Fetch one pod by name
+pub fn get_pod(config: &KubectlConfig, pod: &str) -> Result<Pod> {
+    let mut cmd = build_kubectl_cmd(config);
+    cmd.args(["get", "pod", pod, "-o", "json"]);
+
+    let output = cmd
+        .output()
+        .context("Failed to execute kubectl. Is kubectl installed and configured?")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("kubectl failed: {}", stderr.trim());
+    }
+
+    parse_pod(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `kubectl get pod <name> -o json` output
+pub fn parse_pod(json: &str) -> Result<Pod> {
+    let item: PodItem = serde_json::from_str(json).context("Failed to parse kubectl output")?;
+    Ok(item.to_pod())
+}
+
+/// Execute a command in a pod, returning its exit status
+///
+/// With `tty`, kubectl allocates a terminal (`-it`) for interactive use;
+/// without, output is streamed as-is for scripts.
 pub fn exec_pod(
     config: &KubectlConfig,
     pod: &str,
     container: Option<&str>,
     command: &[String],
-) -> Result<()> {
+    tty: bool,
+) -> Result<ExitStatus> {
     let mut cmd = build_kubectl_cmd(config);
-    cmd.arg("exec").arg("-it").arg(pod);
+    cmd.args(exec_args(pod, container, command, tty));
 
-    if let Some(c) = container {
-        cmd.arg("-c").arg(c);
-    }
+    cmd.stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
 
-    cmd.arg("--");
+    dry_run::intercept(&cmd)?;
+    cmd.status().context("Failed to execute kubectl exec")
+}
 
-    if command.is_empty() {
-        cmd.arg("/bin/sh");
-    } else {
-        for arg in command {
-            cmd.arg(arg);
-        }
+/// kubectl args (after context/namespace) for `exec_pod`
+pub fn exec_args(pod: &str, container: Option<&str>, command: &[String], tty: bool) -> Vec<String> {
+    let mut args = vec!["exec".to_string()];
+    if tty {
+        args.push("-it".to_string());
     }
+    args.push(pod.to_string());
 
-    // Run interactively
-    cmd.stdin(Stdio::inherit())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit());
+    if let Some(c) = container {
+        args.push("-c".to_string());
+        args.push(c.to_string());
+    }
 
-    let status = cmd.status().context("Failed to execute kubectl exec")?;
+    args.push("--".to_string());
 
-    if !status.success() {
-        anyhow::bail!("kubectl exec exited with status: {}", status);
+    if command.is_empty() {
+        args.push("/bin/sh".to_string());
+    } else {
+        args.extend(command.iter().cloned());
     }
 
-    Ok(())
+    args
 }
 
 /// Tail logs from a pod
@@ -124,6 +156,7 @@ pub fn tail_logs(
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit());
 
+    dry_run::intercept(&cmd)?;
     let status = cmd.status().context("Failed to execute kubectl logs")?;
 
     if !status.success() {
@@ -133,6 +166,109 @@ pub fn tail_logs(
     Ok(())
 }
 
+/// Describe a pod, or list just its events
+pub fn describe_pod(config: &KubectlConfig, pod: &str, events: bool) -> Result<String> {
+    let mut cmd = build_kubectl_cmd(config);
+    cmd.args(describe_args(pod, events));
+    dry_run::intercept(&cmd)?;
+
+    let output = cmd
+        .output()
+        .context("Failed to execute kubectl. Is kubectl installed and configured?")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("kubectl failed: {}", stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// kubectl args (after context/namespace) for `describe_pod`
+pub fn describe_args(pod: &str, events: bool) -> Vec<String> {
+    if events {
+        vec![
+            "get".to_string(),
+            "events".to_string(),
+            "--field-selector".to_string(),
+            format!("involvedObject.name={}", pod),
+            "--sort-by".to_string(),
+            ".lastTimestamp".to_string(),
+        ]
+    } else {
+        vec!["describe".to_string(), "pod".to_string(), pod.to_string()]
+    }
+}
+
+/// Forward `local` to the pod's `remote` port until kubectl exits or Ctrl-C
+#[cfg(not(tarpaulin_include))]
+pub async fn port_forward(
+    config: &KubectlConfig,
+    pod: &str,
+    local: u16,
+    remote: u16,
+) -> Result<()> {
+    let mut cmd = build_kubectl_cmd(config);
+    cmd.args(forward_args(pod, local, remote));
+    dry_run::intercept(&cmd)?;
+
+    let mut cmd = tokio::process::Command::from(cmd);
+    cmd.kill_on_drop(true);
+
+    let mut child = cmd
+        .spawn()
+        .context("Failed to execute kubectl port-forward")?;
+
+    tokio::select! {
+        status = child.wait() => {
+            let status = status.context("Failed to wait for kubectl port-forward")?;
+            if !status.success() {
+                anyhow::bail!("kubectl port-forward exited with status: {}", status);
+            }
+        }
+        _ = tokio::signal::ctrl_c() => {
+            child.kill().await.ok();
+        }
+    }
+
+    Ok(())
+}
+
+/// kubectl args (after context/namespace) for `port_forward`
+pub fn forward_args(pod: &str, local: u16, remote: u16) -> Vec<String> {
+    vec![
+        "port-forward".to_string(),
+        format!("pod/{}", pod),
+        format!("{}:{}", local, remote),
+    ]
+}
+
+/// Ask the OS for a free local port
+pub fn free_local_port() -> Result<u16> {
+    let listener =
+        std::net::TcpListener::bind("127.0.0.1:0").context("Failed to find a free local port")?;
+    Ok(listener.local_addr()?.port())
+}
+
+/// Context kubectl will use: `--context` if given, else the current one
+pub fn effective_context(config: &KubectlConfig) -> Result<String> {
+    if let Some(ctx) = &config.context {
+        return Ok(ctx.clone());
+    }
+
+    let output = Command::new("kubectl")
+        .args(["config", "current-context"])
+        .output()
+        .context("Failed to execute kubectl. Is kubectl installed and configured?")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("kubectl failed: {}", stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Get list of containers in a pod
 #[allow(dead_code)]
 pub fn get_containers(config: &KubectlConfig, pod: &str) -> Result<Vec<String>> {
@@ -201,23 +337,7 @@ pub fn build_exec_args(
         args.push(ns.clone());
     }
 
-    args.push("exec".to_string());
-    args.push("-it".to_string());
-    args.push(pod.to_string());
-
-    if let Some(c) = container {
-        args.push("-c".to_string());
-        args.push(c.to_string());
-    }
-
-    args.push("--".to_string());
-
-    if command.is_empty() {
-        args.push("/bin/sh".to_string());
-    } else {
-        args.extend(command.iter().cloned());
-    }
-
+    args.extend(exec_args(pod, container, command, true));
     args
 }
 
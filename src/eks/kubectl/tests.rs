@@ -429,3 +429,85 @@ fn parse_pod_list_unknown_status() {
     let pods = parse_pod_list(json).unwrap();
     assert_eq!(pods[0].status, "Unknown");
 }
+
+#[test]
+fn describe_args_pod() {
+    assert_eq!(
+        describe_args("web-1", false),
+        vec!["describe", "pod", "web-1"]
+    );
+}
+
+#[test]
+fn describe_args_events() {
+    assert_eq!(
+        describe_args("web-1", true),
+        vec![
+            "get",
+            "events",
+            "--field-selector",
+            "involvedObject.name=web-1",
+            "--sort-by",
+            ".lastTimestamp"
+        ]
+    );
+}
+
+#[test]
+fn forward_args_maps_ports() {
+    assert_eq!(
+        forward_args("web-1", 9000, 8080),
+        vec!["port-forward", "pod/web-1", "9000:8080"]
+    );
+}
+
+#[test]
+fn free_local_port_is_bindable() {
+    let port = free_local_port().unwrap();
+    assert_ne!(port, 0);
+    assert!(std::net::TcpListener::bind(("127.0.0.1", port)).is_ok());
+}
+
+#[test]
+fn exec_args_without_tty() {
+    let command = vec![
+        "rails".to_string(),
+        "runner".to_string(),
+        "puts 1".to_string(),
+    ];
+    assert_eq!(
+        exec_args("web-1", None, &command, false),
+        vec!["exec", "web-1", "--", "rails", "runner", "puts 1"]
+    );
+}
+
+#[test]
+fn exec_args_with_tty() {
+    assert_eq!(
+        exec_args("web-1", Some("app"), &[], true),
+        vec!["exec", "-it", "web-1", "-c", "app", "--", "/bin/sh"]
+    );
+}
+
+#[test]
+fn parse_pod_single_object() {
+    let json = r#"{
+        "metadata": {"name": "web-1", "namespace": "web"},
+        "status": {
+            "phase": "Pending",
+            "containerStatuses": [
+                {"name": "app", "ready": false, "restartCount": 0,
+                 "state": {"waiting": {"reason": "ContainerCreating"}}}
+            ]
+        }
+    }"#;
+    let pod = parse_pod(json).unwrap();
+    assert_eq!(pod.name, "web-1");
+    assert_eq!(pod.status, "ContainerCreating");
+    assert_eq!(pod.ready, "0/1");
+}
+
+#[test]
+fn parse_pod_invalid_json() {
+    assert!(parse_pod("not json").is_err());
+}
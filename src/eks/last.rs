@@ -0,0 +1,154 @@
+//! Last `hu eks exec` target, replayed by `hu eks last`
+//!
+//! Stored in `~/.config/hu/eks-last.toml`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use super::types::Pod;
+
+const LAST_FILE: &str = "eks-last.toml";
+
+/// A saved exec target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastExec {
+    pub pod: String,
+    pub context: Option<String>,
+    pub namespace: Option<String>,
+    pub container: Option<String>,
+    #[serde(default)]
+    pub command: Vec<String>,
+}
+
+fn last_path() -> Result<PathBuf> {
+    Ok(crate::util::config_dir()?.join(LAST_FILE))
+}
+
+/// Load the saved target; `None` if nothing has been saved yet
+#[cfg(not(tarpaulin_include))]
+pub fn load() -> Result<Option<LastExec>> {
+    let path = last_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let last =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(last))
+}
+
+/// Save `last` as the target to replay
+#[cfg(not(tarpaulin_include))]
+pub fn save(last: &LastExec) -> Result<()> {
+    let path = last_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    let contents = toml::to_string_pretty(last).context("Failed to serialize last exec")?;
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Workload a pod belongs to, from its generated name
+///
+/// `web-7d9f8c6b5-x2k4p` (Deployment) → `web`, `db-0` (StatefulSet) → `db`.
+pub fn workload_name(pod: &str) -> &str {
+    let Some((rest, last)) = pod.rsplit_once('-') else {
+        return pod;
+    };
+    if last.chars().all(|c| c.is_ascii_digit()) {
+        return rest;
+    }
+    match rest.rsplit_once('-') {
+        Some((workload, _hash)) => workload,
+        None => rest,
+    }
+}
+
+/// Pod to reconnect to: the saved one if it still runs, else the first pod
+/// of the same workload
+///
+/// The flag is `true` when a replacement pod was chosen.
+pub fn repick<'a>(pods: &'a [Pod], saved: &str) -> Option<(&'a str, bool)> {
+    if let Some(pod) = pods.iter().find(|p| p.name == saved) {
+        return Some((&pod.name, false));
+    }
+    let workload = workload_name(saved);
+    pods.iter()
+        .find(|p| workload_name(&p.name) == workload)
+        .map(|p| (p.name.as_str(), true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pods(names: &[&str]) -> Vec<Pod> {
+        names
+            .iter()
+            .map(|name| Pod {
+                name: name.to_string(),
+                namespace: "default".to_string(),
+                status: "Running".to_string(),
+                ready: "1/1".to_string(),
+                restarts: 0,
+                age: "1d".to_string(),
+                node: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn workload_name_deployment() {
+        assert_eq!(workload_name("web-7d9f8c6b5-x2k4p"), "web");
+        assert_eq!(workload_name("api-server-7d9f8c6b5-x2k4p"), "api-server");
+    }
+
+    #[test]
+    fn workload_name_statefulset() {
+        assert_eq!(workload_name("db-0"), "db");
+        assert_eq!(workload_name("redis-cache-12"), "redis-cache");
+    }
+
+    #[test]
+    fn workload_name_plain() {
+        assert_eq!(workload_name("toolbox"), "toolbox");
+    }
+
+    #[test]
+    fn repick_keeps_running_pod() {
+        let list = pods(&["web-aaa-1", "web-aaa-2"]);
+        assert_eq!(repick(&list, "web-aaa-2"), Some(("web-aaa-2", false)));
+    }
+
+    #[test]
+    fn repick_falls_back_to_same_workload() {
+        let list = pods(&["worker-bbb-xyz12", "web-ccc-abc34"]);
+        assert_eq!(
+            repick(&list, "web-aaa-old99"),
+            Some(("web-ccc-abc34", true))
+        );
+    }
+
+    #[test]
+    fn repick_none_when_workload_gone() {
+        let list = pods(&["worker-bbb-xyz12"]);
+        assert_eq!(repick(&list, "web-aaa-old99"), None);
+    }
+
+    #[test]
+    fn last_exec_round_trips() {
+        let last = LastExec {
+            pod: "web-aaa-1".to_string(),
+            context: Some("eks-dev".to_string()),
+            namespace: None,
+            container: Some("app".to_string()),
+            command: vec!["bash".to_string()],
+        };
+        let toml = toml::to_string_pretty(&last).unwrap();
+        assert_eq!(toml::from_str::<LastExec>(&toml).unwrap(), last);
+    }
+}
@@ -0,0 +1,265 @@
+//! Pod selection by name substring or from an interactive list
+
+use anyhow::{bail, Result};
+use dialoguer::{theme::ColorfulTheme, FuzzySelect};
+use std::io::IsTerminal;
+
+use super::kubectl;
+use super::types::{KubectlConfig, Pod};
+
+/// Pod to act on: `pod` as given, or with `match_name` the one pod whose
+/// name contains it
+///
+/// With `search_all`, a failed match checks every namespace and names the
+/// ones where the pod does exist.
+#[cfg(not(tarpaulin_include))]
+pub fn resolve_pod(
+    config: &KubectlConfig,
+    pod: &str,
+    match_name: bool,
+    search_all: bool,
+) -> Result<String> {
+    if !match_name {
+        return Ok(pod.to_string());
+    }
+
+    let pods = kubectl::list_pods(config, false)?;
+    let found = match_pod(&pods, pod);
+    if found == PodMatch::NotFound && search_all {
+        let everywhere = kubectl::list_pods(config, true)?;
+        bail!(not_found_elsewhere(pod, &namespaces_with(&everywhere, pod)));
+    }
+    let name = matched_pod(found, pod)?;
+    if name != pod {
+        log::info!("Using pod {}", name);
+    }
+    Ok(name)
+}
+
+/// Result of matching a query against the pod list
+#[derive(Debug, PartialEq, Eq)]
+pub enum PodMatch {
+    /// Exactly one pod matched (or one is named exactly the query)
+    Found(String),
+    /// Several pods matched; carries their names
+    Ambiguous(Vec<String>),
+    NotFound,
+}
+
+/// Find the pod whose name contains `query`
+///
+/// An exact name wins even if it is also a substring of other pods.
+pub fn match_pod(pods: &[Pod], query: &str) -> PodMatch {
+    if let Some(pod) = pods.iter().find(|p| p.name == query) {
+        return PodMatch::Found(pod.name.clone());
+    }
+
+    let mut names: Vec<String> = pods
+        .iter()
+        .filter(|p| p.name.contains(query))
+        .map(|p| p.name.clone())
+        .collect();
+    match names.len() {
+        0 => PodMatch::NotFound,
+        1 => PodMatch::Found(names.remove(0)),
+        _ => PodMatch::Ambiguous(names),
+    }
+}
+
+/// Namespaces holding a pod whose name contains `query`, first-seen order
+pub fn namespaces_with(pods: &[Pod], query: &str) -> Vec<String> {
+    let mut namespaces: Vec<String> = Vec::new();
+    for pod in pods.iter().filter(|p| p.name.contains(query)) {
+        if !namespaces.contains(&pod.namespace) {
+            namespaces.push(pod.namespace.clone());
+        }
+    }
+    namespaces
+}
+
+/// Turn a match result into the pod name or an error listing candidates
+fn matched_pod(found: PodMatch, query: &str) -> Result<String> {
+    match found {
+        PodMatch::Found(name) => Ok(name),
+        PodMatch::Ambiguous(names) => bail!(
+            "'{}' matches {} pods:\n  {}\nUse more of the name to pick one.",
+            query,
+            names.len(),
+            names.join("\n  ")
+        ),
+        PodMatch::NotFound => bail!(
+            "No pod name contains '{}' (--search-all-namespaces checks other namespaces)",
+            query
+        ),
+    }
+}
+
+/// Error for a failed match, pointing at namespaces that have the pod
+fn not_found_elsewhere(query: &str, namespaces: &[String]) -> String {
+    if namespaces.is_empty() {
+        return format!("No pod name contains '{}' in any namespace", query);
+    }
+    format!(
+        "No pod name contains '{}' here, but it is in namespace {}. Pass -n {}",
+        query,
+        namespaces.join(", "),
+        namespaces[0]
+    )
+}
+
+/// Choose a pod from a fuzzy-search list; needs a terminal
+#[cfg(not(tarpaulin_include))]
+pub fn choose_pod(config: &KubectlConfig) -> Result<String> {
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        bail!("No pod given. Pass a pod name, or run `hu eks list` to see pods.");
+    }
+
+    let pods = kubectl::list_pods(config, false)?;
+    if pods.is_empty() {
+        bail!("No pods found.");
+    }
+    match pick_pod(&pods)? {
+        Some(name) => Ok(name),
+        None => bail!("No pod selected."),
+    }
+}
+
+/// Let the user fuzzy-search the pods; `None` if they cancel with Esc
+#[cfg(not(tarpaulin_include))]
+fn pick_pod(pods: &[Pod]) -> Result<Option<String>> {
+    let choice = FuzzySelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Pod")
+        .items(&pick_labels(pods))
+        .default(0)
+        .interact_opt()?;
+    Ok(choice.map(|i| pods[i].name.clone()))
+}
+
+/// One aligned line per pod: name, status, ready count and age
+pub fn pick_labels(pods: &[Pod]) -> Vec<String> {
+    let width = pods
+        .iter()
+        .map(|p| p.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    pods.iter()
+        .map(|p| {
+            format!(
+                "{:<width$}  {:<10} {:<5} {}",
+                p.name,
+                p.status,
+                p.ready,
+                p.age,
+                width = width
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pods(names: &[&str]) -> Vec<Pod> {
+        names
+            .iter()
+            .map(|name| Pod {
+                name: name.to_string(),
+                namespace: "default".to_string(),
+                status: "Running".to_string(),
+                ready: "1/1".to_string(),
+                restarts: 0,
+                age: "1d".to_string(),
+                node: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_substring_match() {
+        let list = pods(&["web-7d9f-abcde", "worker-5c6b-fghij"]);
+        assert_eq!(
+            match_pod(&list, "worker"),
+            PodMatch::Found("worker-5c6b-fghij".to_string())
+        );
+    }
+
+    #[test]
+    fn ambiguous_lists_candidates() {
+        let list = pods(&["web-1", "web-2", "worker-1"]);
+        assert_eq!(
+            match_pod(&list, "web"),
+            PodMatch::Ambiguous(vec!["web-1".to_string(), "web-2".to_string()])
+        );
+    }
+
+    #[test]
+    fn exact_name_beats_substring() {
+        let list = pods(&["web", "web-canary"]);
+        assert_eq!(match_pod(&list, "web"), PodMatch::Found("web".to_string()));
+    }
+
+    #[test]
+    fn pick_labels_align_names() {
+        let labels = pick_labels(&pods(&["web-1", "worker-12"]));
+        assert_eq!(labels[0], "web-1      Running    1/1   1d");
+        assert_eq!(labels[1], "worker-12  Running    1/1   1d");
+    }
+
+    #[test]
+    fn namespaces_with_dedupes() {
+        let mut list = pods(&["worker-1", "worker-2", "web-1", "worker-3"]);
+        list[1].namespace = "jobs".to_string();
+        list[3].namespace = "jobs".to_string();
+        assert_eq!(namespaces_with(&list, "worker"), vec!["default", "jobs"]);
+        assert!(namespaces_with(&list, "db").is_empty());
+    }
+
+    #[test]
+    fn matched_pod_found() {
+        let name = matched_pod(PodMatch::Found("web-1".to_string()), "web").unwrap();
+        assert_eq!(name, "web-1");
+    }
+
+    #[test]
+    fn matched_pod_ambiguous_lists_candidates() {
+        let err = matched_pod(
+            PodMatch::Ambiguous(vec!["web-1".to_string(), "web-2".to_string()]),
+            "web",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("matches 2 pods"));
+        assert!(err.contains("web-1") && err.contains("web-2"));
+    }
+
+    #[test]
+    fn matched_pod_not_found() {
+        let err = matched_pod(PodMatch::NotFound, "db")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'db'"));
+    }
+
+    #[test]
+    fn not_found_elsewhere_suggests_namespace() {
+        let msg = not_found_elsewhere("worker", &["jobs".to_string(), "batch".to_string()]);
+        assert!(msg.contains("namespace jobs, batch"));
+        assert!(msg.ends_with("Pass -n jobs"));
+    }
+
+    #[test]
+    fn not_found_elsewhere_nowhere() {
+        assert_eq!(
+            not_found_elsewhere("worker", &[]),
+            "No pod name contains 'worker' in any namespace"
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let list = pods(&["web-1"]);
+        assert_eq!(match_pod(&list, "db"), PodMatch::NotFound);
+        assert_eq!(match_pod(&[], "db"), PodMatch::NotFound);
+    }
+}
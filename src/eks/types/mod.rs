@@ -108,6 +108,28 @@ pub struct ContainerStatus {
     /// Restart count
     #[serde(rename = "restartCount")]
     pub restart_count: u32,
+    /// Current state
+    #[serde(default)]
+    pub state: ContainerState,
+}
+
+/// Container state; kubectl fills in the one that applies
+#[derive(Debug, Deserialize, Default)]
+pub struct ContainerState {
+    /// Set while the container isn't running yet (or is backing off)
+    #[serde(default)]
+    pub waiting: Option<StateReason>,
+    /// Set once the container has exited
+    #[serde(default)]
+    pub terminated: Option<StateReason>,
+}
+
+/// Why a container is waiting or terminated
+#[derive(Debug, Deserialize)]
+pub struct StateReason {
+    /// e.g. CrashLoopBackOff, ContainerCreating, OOMKilled
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 impl PodItem {
@@ -121,7 +143,7 @@ impl PodItem {
         Pod {
             name: self.metadata.name.clone(),
             namespace: self.metadata.namespace.clone(),
-            status: self.status.phase.clone(),
+            status: self.status_string(),
             ready,
             restarts,
             age,
@@ -129,6 +151,21 @@ impl PodItem {
         }
     }
 
+    /// Status as kubectl shows it: a container's waiting or terminated
+    /// reason (CrashLoopBackOff, OOMKilled, ...) over the pod phase
+    fn status_string(&self) -> String {
+        let containers = &self.status.container_statuses;
+        containers
+            .iter()
+            .find_map(|c| c.state.waiting.as_ref()?.reason.clone())
+            .or_else(|| {
+                containers
+                    .iter()
+                    .find_map(|c| c.state.terminated.as_ref()?.reason.clone())
+            })
+            .unwrap_or_else(|| self.status.phase.clone())
+    }
+
     /// Get ready string (e.g., "1/2")
     fn ready_string(&self) -> String {
         let total = self.status.container_statuses.len();
@@ -323,6 +323,7 @@ fn container_status_debug() {
         name: "app".to_string(),
         ready: true,
         restart_count: 3,
+        state: ContainerState::default(),
     };
     let debug = format!("{:?}", status);
     assert!(debug.contains("app"));
@@ -345,11 +346,13 @@ fn ready_string_all_ready() {
                     name: "a".to_string(),
                     ready: true,
                     restart_count: 0,
+                    state: ContainerState::default(),
                 },
                 ContainerStatus {
                     name: "b".to_string(),
                     ready: true,
                     restart_count: 0,
+                    state: ContainerState::default(),
                 },
             ],
         },
@@ -374,11 +377,13 @@ fn ready_string_none_ready() {
                     name: "a".to_string(),
                     ready: false,
                     restart_count: 0,
+                    state: ContainerState::default(),
                 },
                 ContainerStatus {
                     name: "b".to_string(),
                     ready: false,
                     restart_count: 0,
+                    state: ContainerState::default(),
                 },
             ],
         },
@@ -403,16 +408,19 @@ fn total_restarts_sum() {
                     name: "a".to_string(),
                     ready: true,
                     restart_count: 5,
+                    state: ContainerState::default(),
                 },
                 ContainerStatus {
                     name: "b".to_string(),
                     ready: true,
                     restart_count: 3,
+                    state: ContainerState::default(),
                 },
                 ContainerStatus {
                     name: "c".to_string(),
                     ready: true,
                     restart_count: 2,
+                    state: ContainerState::default(),
                 },
             ],
         },
@@ -551,3 +559,59 @@ fn pod_with_spec_no_node() {
     let pod = item.to_pod();
     assert!(pod.node.is_none());
 }
+
+#[test]
+fn status_prefers_waiting_reason() {
+    let json = r#"{
+            "items": [
+                {
+                    "metadata": {"name": "web-1", "namespace": "default"},
+                    "status": {
+                        "phase": "Running",
+                        "containerStatuses": [
+                            {"name": "sidecar", "ready": true, "restartCount": 0,
+                             "state": {"running": {}}},
+                            {"name": "app", "ready": false, "restartCount": 7,
+                             "state": {"waiting": {"reason": "CrashLoopBackOff"}}}
+                        ]
+                    }
+                }
+            ]
+        }"#;
+    let pod_list: PodList = serde_json::from_str(json).unwrap();
+    assert_eq!(pod_list.items[0].to_pod().status, "CrashLoopBackOff");
+}
+
+#[test]
+fn status_uses_terminated_reason() {
+    let json = r#"{
+            "items": [
+                {
+                    "metadata": {"name": "job-1", "namespace": "default"},
+                    "status": {
+                        "phase": "Failed",
+                        "containerStatuses": [
+                            {"name": "app", "ready": false, "restartCount": 0,
+                             "state": {"terminated": {"reason": "OOMKilled"}}}
+                        ]
+                    }
+                }
+            ]
+        }"#;
+    let pod_list: PodList = serde_json::from_str(json).unwrap();
+    assert_eq!(pod_list.items[0].to_pod().status, "OOMKilled");
+}
+
+#[test]
+fn status_falls_back_to_phase() {
+    let json = r#"{
+            "items": [
+                {
+                    "metadata": {"name": "web-1", "namespace": "default"},
+                    "status": {"phase": "Pending"}
+                }
+            ]
+        }"#;
+    let pod_list: PodList = serde_json::from_str(json).unwrap();
+    assert_eq!(pod_list.items[0].to_pod().status, "Pending");
+}
@@ -1,6 +1,8 @@
+use anstream::println;
 use anyhow::{bail, Context, Result};
+use std::io::IsTerminal;
 
-use crate::util::{load_credentials, save_credentials, GithubCredentials};
+use crate::util::{bail_kind, load_credentials, save_credentials, GithubCredentials};
 
 #[cfg(test)]
 use crate::util::{load_credentials_from, save_credentials_to};
@@ -8,22 +10,65 @@ use crate::util::{load_credentials_from, save_credentials_to};
 #[cfg(test)]
 use std::path::PathBuf;
 
-/// Save token and fetch username
-pub async fn login(token: &str) -> Result<String> {
-    let username = fetch_username_from_github(token).await?;
-    save_login(&username, token)?;
-    Ok(username)
+/// Token scopes hu relies on, with what stops working without them
+const REQUIRED_SCOPES: &[(&str, &str)] = &[
+    ("repo", "PRs, CI status and logs on private repos"),
+    ("workflow", "`hu gh rerun`"),
+];
+
+/// Who a token belongs to and what it may do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub login: String,
+    /// Classic token scopes; `None` for fine-grained tokens, which GitHub
+    /// doesn't report scopes for
+    pub scopes: Option<Vec<String>>,
 }
 
-/// Start device flow authentication (uses `gh auth token` if available)
-pub async fn device_flow_login() -> Result<String> {
+impl TokenInfo {
+    /// Required scopes the token lacks, with what they are needed for
+    pub fn missing_scopes(&self) -> Vec<(&'static str, &'static str)> {
+        let Some(scopes) = &self.scopes else {
+            return vec![];
+        };
+        REQUIRED_SCOPES
+            .iter()
+            .filter(|(scope, _)| !scopes.iter().any(|s| s == scope))
+            .copied()
+            .collect()
+    }
+}
+
+/// Parse the `X-OAuth-Scopes` header (`repo, workflow, read:org`)
+pub fn parse_scopes(header: Option<&str>) -> Option<Vec<String>> {
+    header.map(|h| {
+        h.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+}
+
+/// Token to log in with: the given one, `gh auth token`, or a prompt
+pub async fn find_token(token: Option<String>) -> Result<String> {
+    if let Some(token) = token {
+        return Ok(token);
+    }
+
     // Try to get token from gh CLI first
     if let Some(token) = get_gh_cli_token().await {
         println!("Using token from gh CLI...");
-        return login(&token).await;
+        return Ok(token);
+    }
+
+    if std::io::stdin().is_terminal() {
+        let token = dialoguer::Password::new()
+            .with_prompt("GitHub personal access token")
+            .interact()?;
+        return Ok(token.trim().to_string());
     }
 
-    // Fall back to prompting for PAT
     bail!(
         "No token found. Please either:\n  \
          1. Run 'gh auth login' first, or\n  \
@@ -77,20 +122,42 @@ fn load_login_from(path: &PathBuf) -> Option<(String, String)> {
         .and_then(|c| c.github.map(|g| (g.username, g.token)))
 }
 
-/// Fetch username from GitHub API (the actual network call)
-async fn fetch_username_from_github(token: &str) -> Result<String> {
-    let octocrab = octocrab::OctocrabBuilder::new()
-        .personal_token(token.to_string())
-        .build()
-        .context("Failed to create GitHub client")?;
-
-    let user = octocrab
-        .current()
-        .user()
+/// Check a token against `/user` and read its scopes (the actual network call)
+#[cfg(not(tarpaulin_include))]
+pub async fn validate_token(token: &str) -> Result<TokenInfo> {
+    let client = crate::util::http::client()?;
+    let response = client
+        .get("https://api.github.com/user")
+        .bearer_auth(token)
+        .header("Accept", "application/vnd.github+json")
+        .send()
         .await
-        .context("Failed to get current user - check your token")?;
+        .context("Failed to reach GitHub")?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        bail_kind!(
+            Auth,
+            "GitHub rejected the token (401). Check it hasn't expired or been revoked."
+        );
+    }
+    if !status.is_success() {
+        bail!("Failed to get current user (HTTP {})", status.as_u16());
+    }
 
-    Ok(user.login)
+    let scopes = parse_scopes(
+        response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|v| v.to_str().ok()),
+    );
+    let user: serde_json::Value = response.json().await.context("Invalid /user response")?;
+    let login = user["login"]
+        .as_str()
+        .context("No login in /user response")?
+        .to_string();
+
+    Ok(TokenInfo { login, scopes })
 }
 
 /// Get stored token if available
@@ -119,6 +186,39 @@ mod tests {
         assert_eq!(result1.is_some(), result2.is_some());
     }
 
+    #[test]
+    fn parse_scopes_header() {
+        assert_eq!(
+            parse_scopes(Some("repo, workflow,read:org")),
+            Some(vec![
+                "repo".to_string(),
+                "workflow".to_string(),
+                "read:org".to_string()
+            ])
+        );
+        assert_eq!(parse_scopes(Some("")), Some(vec![]));
+        assert_eq!(parse_scopes(None), None);
+    }
+
+    #[test]
+    fn missing_scopes_lists_gaps() {
+        let info = TokenInfo {
+            login: "octocat".to_string(),
+            scopes: Some(vec!["repo".to_string()]),
+        };
+        let missing: Vec<&str> = info.missing_scopes().iter().map(|(s, _)| *s).collect();
+        assert_eq!(missing, vec!["workflow"]);
+    }
+
+    #[test]
+    fn missing_scopes_unknown_for_fine_grained() {
+        let info = TokenInfo {
+            login: "octocat".to_string(),
+            scopes: None,
+        };
+        assert!(info.missing_scopes().is_empty());
+    }
+
     #[test]
     fn credentials_struct_usage() {
         // Verify we can create and use the credential structs
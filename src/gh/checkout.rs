@@ -0,0 +1,229 @@
+use anstream::println;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use super::cli::CheckoutArgs;
+use super::client::{GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+use crate::git;
+use crate::util::dry_run;
+
+// ANSI color codes
+const GREEN: &str = "\x1b[32m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// How to fetch a PR's branch and what to call it locally
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckoutPlan {
+    /// Remote to fetch from
+    pub remote: String,
+    /// URL to add `remote` with if it doesn't exist yet (forks)
+    pub remote_url: Option<String>,
+    /// Refspec passed to `git fetch`
+    pub refspec: String,
+    /// Ref the local branch starts from
+    pub start: String,
+    /// Whether the local branch should track `start`
+    pub track: bool,
+    /// Local branch name
+    pub local_branch: String,
+}
+
+/// Handle the `hu gh checkout` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: CheckoutArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+    let path = Path::new(".");
+
+    let status = git::get_status(path)?;
+    if !status.is_clean() && !args.stash {
+        bail!(
+            "Working tree has {} changed file(s). Commit them or pass --stash",
+            status.file_count()
+        );
+    }
+
+    let plan = resolve_checkout(&client, &owner, &repo, args.pr).await?;
+
+    let dry_run = dry_run::enabled();
+    if !status.is_clean() {
+        git::stash(path, &format!("hu gh checkout #{}", args.pr))?;
+        if !dry_run {
+            println!("{GRAY}Stashed local changes (git stash pop to restore){RESET}");
+        }
+    }
+    let existed = apply_plan(path, &plan)?;
+    if dry_run {
+        return Ok(());
+    }
+
+    if existed {
+        println!(
+            "{GREEN}✓{RESET} Switched to existing branch {} (fetched {})",
+            plan.local_branch, plan.start
+        );
+    } else {
+        println!(
+            "{GREEN}✓{RESET} Checked out PR #{} as {}",
+            args.pr, plan.local_branch
+        );
+    }
+    Ok(())
+}
+
+/// Look up a PR's branch and where it lives (testable)
+pub async fn resolve_checkout(
+    client: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<CheckoutPlan> {
+    let branch = client.get_pr_branch(owner, repo, pr_number).await?;
+    let head_repo = client.get_pr_head_repo(owner, repo, pr_number).await?;
+    Ok(plan_checkout(
+        &format!("{}/{}", owner, repo),
+        head_repo.as_deref(),
+        &branch,
+        pr_number,
+    ))
+}
+
+/// Decide remote, refspec and local name for a PR branch
+///
+/// Same-repo branches come from `origin` and keep their name. Fork
+/// branches come from a remote named after the fork owner and are
+/// prefixed with it, so a fork's `main` doesn't clash with ours. If the
+/// fork is gone, GitHub's `pull/N/head` ref still has the commits.
+pub fn plan_checkout(
+    base: &str,
+    head_repo: Option<&str>,
+    branch: &str,
+    pr_number: u64,
+) -> CheckoutPlan {
+    match head_repo {
+        Some(head) if head.eq_ignore_ascii_case(base) => CheckoutPlan {
+            remote: "origin".to_string(),
+            remote_url: None,
+            refspec: format!("+refs/heads/{0}:refs/remotes/origin/{0}", branch),
+            start: format!("origin/{}", branch),
+            track: true,
+            local_branch: branch.to_string(),
+        },
+        Some(head) => {
+            let fork_owner = head.split('/').next().unwrap_or(head);
+            CheckoutPlan {
+                remote: fork_owner.to_string(),
+                remote_url: Some(format!("https://github.com/{}.git", head)),
+                refspec: format!(
+                    "+refs/heads/{}:refs/remotes/{}/{}",
+                    branch, fork_owner, branch
+                ),
+                start: format!("{}/{}", fork_owner, branch),
+                track: true,
+                local_branch: format!("{}-{}", fork_owner, branch),
+            }
+        }
+        None => CheckoutPlan {
+            remote: "origin".to_string(),
+            remote_url: None,
+            refspec: format!("+refs/pull/{0}/head:refs/remotes/origin/pr/{0}", pr_number),
+            start: format!("origin/pr/{}", pr_number),
+            track: false,
+            local_branch: format!("pr-{}", pr_number),
+        },
+    }
+}
+
+/// Fetch and switch; returns whether the local branch already existed
+#[cfg(not(tarpaulin_include))]
+fn apply_plan(path: &Path, plan: &CheckoutPlan) -> Result<bool> {
+    if let Some(url) = &plan.remote_url {
+        if git::remote_url(path, &plan.remote).is_none() {
+            git::add_remote(path, &plan.remote, url)?;
+            println!("{GRAY}Added remote {} ({}){RESET}", plan.remote, url);
+        }
+    }
+    git::fetch_ref(path, &plan.remote, &plan.refspec)?;
+
+    if git::branch_exists(path, &plan.local_branch) {
+        git::checkout(path, &plan.local_branch)?;
+        return Ok(true);
+    }
+    git::checkout_new(path, &plan.local_branch, &plan.start, plan.track)?;
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+
+    #[test]
+    fn plan_same_repo() {
+        let plan = plan_checkout("acme/api", Some("acme/api"), "feature/login", 12);
+        assert_eq!(plan.remote, "origin");
+        assert_eq!(plan.remote_url, None);
+        assert_eq!(
+            plan.refspec,
+            "+refs/heads/feature/login:refs/remotes/origin/feature/login"
+        );
+        assert_eq!(plan.start, "origin/feature/login");
+        assert!(plan.track);
+        assert_eq!(plan.local_branch, "feature/login");
+    }
+
+    #[test]
+    fn plan_same_repo_ignores_case() {
+        let plan = plan_checkout("Acme/API", Some("acme/api"), "fix", 1);
+        assert_eq!(plan.remote, "origin");
+    }
+
+    #[test]
+    fn plan_fork_adds_remote() {
+        let plan = plan_checkout("acme/api", Some("octocat/api"), "main", 12);
+        assert_eq!(plan.remote, "octocat");
+        assert_eq!(
+            plan.remote_url.as_deref(),
+            Some("https://github.com/octocat/api.git")
+        );
+        assert_eq!(plan.refspec, "+refs/heads/main:refs/remotes/octocat/main");
+        assert_eq!(plan.start, "octocat/main");
+        assert_eq!(plan.local_branch, "octocat-main");
+    }
+
+    #[test]
+    fn plan_deleted_fork_uses_pull_ref() {
+        let plan = plan_checkout("acme/api", None, "main", 12);
+        assert_eq!(plan.remote, "origin");
+        assert_eq!(plan.refspec, "+refs/pull/12/head:refs/remotes/origin/pr/12");
+        assert_eq!(plan.start, "origin/pr/12");
+        assert!(!plan.track);
+        assert_eq!(plan.local_branch, "pr-12");
+    }
+
+    #[tokio::test]
+    async fn resolve_checkout_same_repo() {
+        let mock = MockGithubApi {
+            head_repo: Some("acme/api".to_string()),
+            ..Default::default()
+        };
+        let plan = resolve_checkout(&mock, "acme", "api", 5).await.unwrap();
+        assert_eq!(plan.local_branch, "feature");
+        assert_eq!(plan.start, "origin/feature");
+    }
+
+    #[tokio::test]
+    async fn resolve_checkout_fork() {
+        let mock = MockGithubApi {
+            head_repo: Some("octocat/api".to_string()),
+            ..Default::default()
+        };
+        let plan = resolve_checkout(&mock, "acme", "api", 5).await.unwrap();
+        assert_eq!(plan.local_branch, "octocat-feature");
+    }
+}
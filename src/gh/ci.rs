@@ -0,0 +1,205 @@
+use anstream::{print, println};
+use anyhow::Result;
+
+use super::cli::CiArgs;
+use super::client::{parse_test_failures, GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+use super::service;
+use super::types::TestFailure;
+
+// ANSI color codes
+const RED: &str = "\x1b[31m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Maximum failures printed before collapsing into a "(+N more)" footer
+const MAX_FAILURES: usize = 15;
+
+/// Lines of each failure message kept in the summary
+const MAX_ERROR_LINES: usize = 2;
+
+/// Parsed failures for one failed CI job
+#[derive(Debug, Clone)]
+pub struct JobFailures {
+    pub name: String,
+    pub failures: Vec<TestFailure>,
+}
+
+/// Handle the `hu gh ci` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: CiArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+
+    let jobs = collect_ci_failures(&client, &owner, &repo, args.pr).await?;
+    if jobs.is_empty() {
+        println!("No failed jobs found for PR #{}.", args.pr);
+        return Ok(());
+    }
+
+    print!("{}", format_summary(&jobs, MAX_FAILURES));
+    Ok(())
+}
+
+/// Fetch failed jobs for a PR and parse test failures from their logs (testable)
+pub async fn collect_ci_failures(
+    client: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Vec<JobFailures>> {
+    let failed_jobs = service::failed_jobs_for_pr(client, owner, repo, pr_number).await?;
+
+    let mut jobs = Vec::with_capacity(failed_jobs.len());
+    for (job_id, name) in failed_jobs {
+        let failures = match client.get_job_logs(owner, repo, job_id).await {
+            Ok(logs) => parse_test_failures(&logs),
+            Err(e) => {
+                log::warn!("Failed to fetch logs for {}: {}", name, e);
+                vec![]
+            }
+        };
+        jobs.push(JobFailures { name, failures });
+    }
+    Ok(jobs)
+}
+
+/// Render the per-job failure summary, showing at most `max` failures in total
+pub fn format_summary(jobs: &[JobFailures], max: usize) -> String {
+    let mut out = String::new();
+    let mut shown = 0;
+    let total: usize = jobs.iter().map(|j| j.failures.len()).sum();
+
+    for job in jobs {
+        if shown >= max {
+            break;
+        }
+        out.push_str(&format!("{RED}✗{RESET} {}\n", job.name));
+        if job.failures.is_empty() {
+            out.push_str(&format!("  {GRAY}(no test failures parsed){RESET}\n"));
+        }
+        for failure in job.failures.iter().take(max - shown) {
+            out.push_str(&format!("  {}\n", failure.spec_file));
+            for line in trim_error(&failure.failure_text) {
+                out.push_str(&format!("    {GRAY}{}{RESET}\n", line));
+            }
+            shown += 1;
+        }
+    }
+
+    if total > shown {
+        out.push_str(&format!("(+{} more)\n", total - shown));
+    }
+    out
+}
+
+/// Keep the first few non-empty lines of a failure message
+fn trim_error(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .take(MAX_ERROR_LINES)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    const RSPEC_LOG: &str = "Failures:\n\n  1) A fails\n     Failure/Error: expect(1).to eq(2)\n       expected: 2\n\nFailed examples:\n\nrspec ./spec/a_spec.rb:3 # A fails\n";
+
+    fn failure(spec: &str, text: &str) -> TestFailure {
+        TestFailure {
+            spec_file: spec.to_string(),
+            failure_text: text.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_ci_failures_parses_each_job() {
+        let mock = MockGithubApi {
+            failed_run: Some(1),
+            failed_jobs: vec![
+                (1, "rspec".to_string()),
+                (2, "build".to_string()),
+                (3, "lint".to_string()),
+            ],
+            job_logs: Mutex::new(HashMap::from([
+                (1, vec![Some(RSPEC_LOG.to_string())]),
+                (2, vec![Some("build exploded".to_string())]),
+            ])),
+            ..Default::default()
+        };
+        let jobs = collect_ci_failures(&mock, "o", "r", 1).await.unwrap();
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs[0].failures.len(), 1);
+        assert_eq!(jobs[0].failures[0].spec_file, "./spec/a_spec.rb:3");
+        assert!(jobs[1].failures.is_empty());
+        // Log fetch errors degrade to an empty failure list
+        assert!(jobs[2].failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn collect_ci_failures_no_failed_jobs() {
+        let mock = MockGithubApi {
+            failed_run: Some(1),
+            ..Default::default()
+        };
+        let jobs = collect_ci_failures(&mock, "o", "r", 1).await.unwrap();
+        assert!(jobs.is_empty());
+    }
+
+    #[test]
+    fn format_summary_lists_jobs_and_failures() {
+        let jobs = vec![
+            JobFailures {
+                name: "rspec (1)".to_string(),
+                failures: vec![failure("./spec/a_spec.rb:3", "expect(1)\nexpected: 2")],
+            },
+            JobFailures {
+                name: "build".to_string(),
+                failures: vec![],
+            },
+        ];
+        let out = format_summary(&jobs, MAX_FAILURES);
+        assert!(out.contains("rspec (1)"));
+        assert!(out.contains("./spec/a_spec.rb:3"));
+        assert!(out.contains("expected: 2"));
+        assert!(out.contains("no test failures parsed"));
+        assert!(!out.contains("more)"));
+    }
+
+    #[test]
+    fn format_summary_caps_output() {
+        let failures = (0..20)
+            .map(|i| failure(&format!("./spec/f{}_spec.rb:1", i), "boom"))
+            .collect();
+        let jobs = vec![
+            JobFailures {
+                name: "rspec".to_string(),
+                failures,
+            },
+            JobFailures {
+                name: "jest".to_string(),
+                failures: vec![failure("a.test.js", "boom")],
+            },
+        ];
+        let out = format_summary(&jobs, MAX_FAILURES);
+        assert!(out.contains("./spec/f14_spec.rb:1"));
+        assert!(!out.contains("./spec/f15_spec.rb:1"));
+        assert!(!out.contains("jest"));
+        assert!(out.ends_with("(+6 more)\n"));
+    }
+
+    #[test]
+    fn trim_error_keeps_first_lines() {
+        let lines = trim_error("  first  \n\n second\nthird\n");
+        assert_eq!(lines, vec!["first", "second"]);
+    }
+}
@@ -5,14 +5,34 @@ use std::path::PathBuf;
 pub enum GhCommand {
     /// Authenticate with GitHub (uses gh CLI token or PAT)
     Login(LoginArgs),
+    /// Show the GitHub login hu is authenticated as
+    Whoami,
     /// List open pull requests authored by you
-    Prs,
+    Prs(PrsArgs),
+    /// List open pull requests waiting for your review
+    Reviews(ReviewsArgs),
     /// Extract test failures from CI
     Failures(FailuresArgs),
     /// Analyze CI failures and output investigation context
     Fix(FixArgs),
     /// List workflow runs
     Runs(RunsArgs),
+    /// Re-run failed jobs of a PR's latest failed workflow run
+    Rerun(RerunArgs),
+    /// Poll a PR's CI status until it passes or fails
+    Watch(WatchArgs),
+    /// Follow a PR's CI job log as it runs
+    Logs(LogsArgs),
+    /// Summarize a PR's CI failures with log excerpts
+    Ci(CiArgs),
+    /// Approve a pull request
+    Approve(ReviewArgs),
+    /// Request changes on a pull request
+    RequestChanges(ReviewArgs),
+    /// Add a comment to a pull request's conversation
+    Comment(CommentArgs),
+    /// Check out a PR's branch locally
+    Checkout(CheckoutArgs),
     /// Commit and push all changes (quick sync)
     Sync(SyncArgs),
 }
@@ -43,13 +63,13 @@ pub struct SyncArgs {
     #[arg(long)]
     pub log_file: Option<PathBuf>,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
 
 #[derive(Debug, Args)]
 pub struct LoginArgs {
-    /// Personal Access Token (if not provided, uses device flow)
+    /// Personal Access Token (default: `gh auth token`, else prompt)
     #[arg(long, short)]
     pub token: Option<String>,
 }
@@ -64,6 +84,109 @@ pub struct FailuresArgs {
     pub repo: Option<String>,
 }
 
+#[derive(Debug, Args)]
+pub struct RerunArgs {
+    /// PR number
+    pub pr: u64,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ReviewArgs {
+    /// PR number
+    pub pr: u64,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+    /// Review comment (required to request changes)
+    #[arg(long, short)]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckoutArgs {
+    /// PR number
+    pub pr: u64,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+    /// Stash uncommitted changes instead of refusing to switch
+    #[arg(long)]
+    pub stash: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CommentArgs {
+    /// PR number
+    pub pr: u64,
+    /// Comment text (Markdown)
+    pub text: String,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct PrsArgs {
+    /// Max CI status lookups in flight at once
+    #[arg(long, short, default_value = "4", value_parser = clap::value_parser!(u64).range(1..))]
+    pub concurrency: u64,
+    /// List PRs you authored (the default unless --reviews is given)
+    #[arg(long)]
+    pub mine: bool,
+    /// List PRs waiting for your review; with --mine, tag each row
+    #[arg(long)]
+    pub reviews: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ReviewsArgs {
+    /// Output as JSON
+    #[arg(long, short, hide = true)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// PR number
+    pub pr: u64,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+    /// Seconds between status checks
+    #[arg(long, short, default_value = "15", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+    /// Give up after this many seconds
+    #[arg(long, short, default_value = "1800")]
+    pub timeout: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct LogsArgs {
+    /// PR number
+    pub pr: u64,
+    /// Job to follow (name or part of it; default: the first still running)
+    #[arg(long)]
+    pub job: Option<String>,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+    /// Seconds between log polls
+    #[arg(long, short, default_value = "5")]
+    pub interval: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct CiArgs {
+    /// PR number
+    pub pr: u64,
+    /// Repository in owner/repo format (defaults to current directory's repo)
+    #[arg(long, short)]
+    pub repo: Option<String>,
+}
+
 #[derive(Debug, Args)]
 pub struct FixArgs {
     /// PR number
@@ -79,7 +202,7 @@ pub struct FixArgs {
     #[arg(long, short)]
     pub repo: Option<String>,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
 
@@ -100,6 +223,6 @@ pub struct RunsArgs {
     #[arg(long, short = 'n', default_value = "20")]
     pub limit: usize,
     /// Output as JSON
-    #[arg(long, short)]
+    #[arg(long, short, hide = true)]
     pub json: bool,
 }
@@ -0,0 +1,330 @@
+//! CI GitHub API operations
+//!
+//! Endpoints:
+//! - `GET /repos/{owner}/{repo}/commits/{sha}/status` and `/check-runs` —
+//!   a PR's combined CI status
+//! - `GET /repos/{owner}/{repo}/actions/runs` — latest failed run, run listing
+//! - `GET /repos/{owner}/{repo}/actions/runs/{id}/jobs` — jobs of a run
+//! - `GET /repos/{owner}/{repo}/actions/jobs/{id}/logs` — a job's log
+//! - `POST /repos/{owner}/{repo}/actions/runs/{id}/rerun-failed-jobs`
+
+use anyhow::{Context, Result};
+
+use crate::gh::auth::get_token;
+use crate::gh::types::{CiStatus, RunsQuery, WorkflowJob, WorkflowRun};
+use crate::util::{bail_kind, ErrorKind, HuError};
+
+use super::retry::{send_with_retry, with_retry};
+use super::GithubClient;
+
+#[cfg(test)]
+mod tests;
+
+/// Combined status and check runs of a PR's head commit
+pub(super) async fn get_ci_status(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<CiStatus> {
+    // Get the PR to find the head SHA
+    let pr = with_retry(|| async move { client.client.pulls(owner, repo).get(pr_number).await })
+        .await
+        .context("Failed to get PR")?;
+
+    let sha = &pr.head.sha;
+
+    // Get combined status
+    let status: serde_json::Value = client
+        .get_json(&format!("/repos/{}/{}/commits/{}/status", owner, repo, sha))
+        .await
+        .context("Failed to get commit status")?;
+
+    let state = status["state"].as_str().unwrap_or("unknown");
+
+    // Also check for check runs (GitHub Actions uses this)
+    let checks: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/commits/{}/check-runs",
+            owner, repo, sha
+        ))
+        .await
+        .unwrap_or_default();
+
+    let check_runs = checks["check_runs"].as_array();
+
+    Ok(parse_ci_status(state, check_runs))
+}
+
+/// Latest failed workflow run on a branch
+pub(super) async fn get_latest_failed_run_for_branch(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<u64>> {
+    let runs: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/actions/runs?branch={}&status=failure&per_page=1",
+            owner, repo, branch
+        ))
+        .await
+        .context("Failed to get workflow runs")?;
+
+    Ok(extract_run_id(&runs))
+}
+
+/// Latest failed workflow run in the repository
+pub(super) async fn get_latest_failed_run(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+) -> Result<Option<u64>> {
+    let runs: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/actions/runs?status=failure&per_page=1",
+            owner, repo
+        ))
+        .await
+        .context("Failed to get workflow runs")?;
+
+    Ok(extract_run_id(&runs))
+}
+
+/// Failed jobs of a workflow run
+pub(super) async fn get_failed_jobs(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<Vec<(u64, String)>> {
+    let jobs: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/actions/runs/{}/jobs",
+            owner, repo, run_id
+        ))
+        .await
+        .context("Failed to get jobs")?;
+
+    Ok(extract_failed_jobs(&jobs))
+}
+
+/// Every job of a workflow run, whatever its state
+pub(super) async fn list_run_jobs(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<Vec<WorkflowJob>> {
+    let jobs: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/actions/runs/{}/jobs?per_page=100",
+            owner, repo, run_id
+        ))
+        .await
+        .context("Failed to get jobs")?;
+
+    Ok(extract_jobs(&jobs))
+}
+
+/// Download a job's log
+pub(super) async fn get_job_logs(owner: &str, repo: &str, job_id: u64) -> Result<String> {
+    // The logs endpoint returns a redirect to a download URL
+    // We need to use reqwest directly for this
+    let token = get_token().context("Not authenticated")?;
+
+    let client = crate::util::http::client()?;
+    let url = format!(
+        "https://api.github.com/repos/{}/{}/actions/jobs/{}/logs",
+        owner, repo, job_id
+    );
+
+    let response = send_with_retry(|| {
+        client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+    })
+    .await
+    .context("Failed to request job logs")?;
+
+    let status = response.status().as_u16();
+    if !response.status().is_success() {
+        return Err(HuError::new(
+            ErrorKind::from_status(status),
+            format!("Job logs not available (HTTP {})", status),
+        )
+        .into());
+    }
+    let logs = response.text().await.context("Failed to read job logs")?;
+
+    Ok(logs)
+}
+
+/// Re-run the failed jobs of a workflow run
+pub(super) async fn rerun_failed_jobs(owner: &str, repo: &str, run_id: u64) -> Result<()> {
+    // Use reqwest directly so the HTTP status is available for error mapping
+    let token = get_token().context("Not authenticated")?;
+
+    let client = crate::util::http::client()?;
+    let url = format!(
+        "https://api.github.com/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
+        owner, repo, run_id
+    );
+
+    let response = send_with_retry(|| {
+        client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+    })
+    .await
+    .context("Failed to request re-run of failed jobs")?;
+
+    check_rerun_status(response.status().as_u16())
+}
+
+/// Workflow runs, optionally filtered by branch and status
+pub(super) async fn list_workflow_runs(
+    client: &GithubClient,
+    query: &RunsQuery<'_>,
+) -> Result<Vec<WorkflowRun>> {
+    let mut url = format!(
+        "/repos/{}/{}/actions/runs?per_page={}",
+        query.owner, query.repo, query.limit
+    );
+    if let Some(b) = query.branch {
+        url.push_str(&format!("&branch={}", b));
+    }
+    if let Some(s) = query.status {
+        url.push_str(&format!("&status={}", s));
+    }
+
+    let response: serde_json::Value = client
+        .get_json(&url)
+        .await
+        .context("Failed to list workflow runs")?;
+
+    Ok(extract_workflow_runs(&response))
+}
+
+/// Parse CI status from GitHub API responses (pure function, testable)
+fn parse_ci_status(state: &str, check_runs: Option<&Vec<serde_json::Value>>) -> CiStatus {
+    if let Some(runs) = check_runs {
+        if runs.is_empty() && state == "pending" {
+            return CiStatus::Pending;
+        }
+
+        let any_failed = runs
+            .iter()
+            .any(|r| r["conclusion"].as_str() == Some("failure"));
+        let any_pending = runs.iter().any(|r| {
+            r["status"].as_str() != Some("completed") || r["conclusion"].as_str().is_none()
+        });
+        let all_success = runs
+            .iter()
+            .all(|r| r["conclusion"].as_str() == Some("success"));
+
+        if any_failed {
+            CiStatus::Failed
+        } else if any_pending {
+            CiStatus::Pending
+        } else if all_success && !runs.is_empty() {
+            CiStatus::Success
+        } else {
+            parse_state_string(state)
+        }
+    } else {
+        parse_state_string(state)
+    }
+}
+
+/// Parse state string to CiStatus
+fn parse_state_string(state: &str) -> CiStatus {
+    match state {
+        "success" => CiStatus::Success,
+        "pending" => CiStatus::Pending,
+        "failure" | "error" => CiStatus::Failed,
+        _ => CiStatus::Unknown,
+    }
+}
+
+/// Extract failed jobs from GitHub jobs API response (pure function, testable)
+fn extract_failed_jobs(jobs: &serde_json::Value) -> Vec<(u64, String)> {
+    jobs["jobs"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter(|j| j["conclusion"].as_str() == Some("failure"))
+        .filter_map(|j| {
+            let id = j["id"].as_u64()?;
+            let name = j["name"].as_str()?.to_string();
+            Some((id, name))
+        })
+        .collect()
+}
+
+/// Extract jobs from a run's jobs response (pure function, testable)
+fn extract_jobs(jobs: &serde_json::Value) -> Vec<WorkflowJob> {
+    jobs["jobs"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter_map(|j| {
+            Some(WorkflowJob {
+                id: j["id"].as_u64()?,
+                name: j["name"].as_str()?.to_string(),
+                status: j["status"].as_str().unwrap_or("unknown").to_string(),
+                conclusion: j["conclusion"].as_str().map(|s| s.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Map the rerun endpoint's HTTP status to a result (pure function, testable)
+fn check_rerun_status(status: u16) -> Result<()> {
+    match status {
+        200..=299 => Ok(()),
+        403 => bail_kind!(
+            Auth,
+            "Permission denied re-running jobs. Your token needs the `actions:write` \
+             permission (classic PATs: `repo` + `workflow` scopes). Run `hu gh login` \
+             with a token that has it."
+        ),
+        404 => bail_kind!(
+            NotFound,
+            "Workflow run not found (or not visible to your token)"
+        ),
+        _ => anyhow::bail!("Failed to re-run failed jobs (HTTP {})", status),
+    }
+}
+
+/// Extract run ID from workflow runs response (pure function, testable)
+fn extract_run_id(runs: &serde_json::Value) -> Option<u64> {
+    runs["workflow_runs"]
+        .as_array()
+        .and_then(|arr| arr.first())
+        .and_then(|r| r["id"].as_u64())
+}
+
+/// Extract workflow runs from GitHub API response (pure function, testable)
+fn extract_workflow_runs(response: &serde_json::Value) -> Vec<WorkflowRun> {
+    response["workflow_runs"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter_map(|r| {
+            Some(WorkflowRun {
+                id: r["id"].as_u64()?,
+                name: r["name"].as_str()?.to_string(),
+                status: r["status"].as_str().unwrap_or("unknown").to_string(),
+                conclusion: r["conclusion"].as_str().map(|s| s.to_string()),
+                branch: r["head_branch"].as_str().unwrap_or("").to_string(),
+                html_url: r["html_url"].as_str().unwrap_or("").to_string(),
+                created_at: r["created_at"].as_str().unwrap_or("").to_string(),
+                updated_at: r["updated_at"].as_str().unwrap_or("").to_string(),
+                run_number: r["run_number"].as_u64().unwrap_or(0),
+            })
+        })
+        .collect()
+}
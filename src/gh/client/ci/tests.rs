@@ -0,0 +1,241 @@
+use super::*;
+use serde_json::json;
+
+// parse_ci_status tests
+#[test]
+fn parse_ci_status_success_from_runs() {
+    let runs = vec![json!({"status": "completed", "conclusion": "success"})];
+    assert_eq!(parse_ci_status("pending", Some(&runs)), CiStatus::Success);
+}
+
+#[test]
+fn parse_ci_status_failed_from_runs() {
+    let runs = vec![
+        json!({"status": "completed", "conclusion": "success"}),
+        json!({"status": "completed", "conclusion": "failure"}),
+    ];
+    assert_eq!(parse_ci_status("pending", Some(&runs)), CiStatus::Failed);
+}
+
+#[test]
+fn parse_ci_status_pending_from_runs() {
+    let runs = vec![
+        json!({"status": "completed", "conclusion": "success"}),
+        json!({"status": "in_progress", "conclusion": null}),
+    ];
+    assert_eq!(parse_ci_status("pending", Some(&runs)), CiStatus::Pending);
+}
+
+#[test]
+fn parse_ci_status_empty_runs_pending() {
+    let runs: Vec<serde_json::Value> = vec![];
+    assert_eq!(parse_ci_status("pending", Some(&runs)), CiStatus::Pending);
+}
+
+#[test]
+fn parse_ci_status_no_runs_uses_state() {
+    assert_eq!(parse_ci_status("success", None), CiStatus::Success);
+    assert_eq!(parse_ci_status("failure", None), CiStatus::Failed);
+    assert_eq!(parse_ci_status("error", None), CiStatus::Failed);
+    assert_eq!(parse_ci_status("pending", None), CiStatus::Pending);
+    assert_eq!(parse_ci_status("unknown", None), CiStatus::Unknown);
+}
+
+#[test]
+fn parse_state_string_all_cases() {
+    assert_eq!(parse_state_string("success"), CiStatus::Success);
+    assert_eq!(parse_state_string("pending"), CiStatus::Pending);
+    assert_eq!(parse_state_string("failure"), CiStatus::Failed);
+    assert_eq!(parse_state_string("error"), CiStatus::Failed);
+    assert_eq!(parse_state_string("other"), CiStatus::Unknown);
+}
+
+// extract_failed_jobs tests
+#[test]
+fn extract_failed_jobs_filters_failures() {
+    let jobs = json!({
+        "jobs": [
+            {"id": 1, "name": "build", "conclusion": "success"},
+            {"id": 2, "name": "test", "conclusion": "failure"},
+            {"id": 3, "name": "lint", "conclusion": "failure"},
+        ]
+    });
+    let failed = extract_failed_jobs(&jobs);
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed[0], (2, "test".to_string()));
+    assert_eq!(failed[1], (3, "lint".to_string()));
+}
+
+#[test]
+fn extract_failed_jobs_empty_when_all_success() {
+    let jobs = json!({
+        "jobs": [
+            {"id": 1, "name": "build", "conclusion": "success"},
+        ]
+    });
+    assert!(extract_failed_jobs(&jobs).is_empty());
+}
+
+#[test]
+fn extract_failed_jobs_handles_missing_jobs() {
+    let jobs = json!({});
+    assert!(extract_failed_jobs(&jobs).is_empty());
+}
+
+#[test]
+fn extract_failed_jobs_handles_null_jobs() {
+    let jobs = json!({"jobs": null});
+    assert!(extract_failed_jobs(&jobs).is_empty());
+}
+
+// extract_jobs tests
+#[test]
+fn extract_jobs_keeps_every_state() {
+    let jobs = json!({
+        "jobs": [
+            {"id": 1, "name": "lint", "status": "completed", "conclusion": "success"},
+            {"id": 2, "name": "test", "status": "in_progress", "conclusion": null},
+            {"name": "no id"}
+        ]
+    });
+    let jobs = extract_jobs(&jobs);
+    assert_eq!(jobs.len(), 2);
+    assert!(jobs[0].is_completed());
+    assert_eq!(jobs[0].conclusion.as_deref(), Some("success"));
+    assert!(!jobs[1].is_completed());
+    assert_eq!(jobs[1].conclusion, None);
+}
+
+#[test]
+fn extract_jobs_handles_missing_jobs() {
+    assert!(extract_jobs(&json!({})).is_empty());
+}
+
+// extract_run_id tests
+#[test]
+fn extract_run_id_finds_first() {
+    let runs = json!({
+        "workflow_runs": [
+            {"id": 123},
+            {"id": 456},
+        ]
+    });
+    assert_eq!(extract_run_id(&runs), Some(123));
+}
+
+#[test]
+fn extract_run_id_empty_array() {
+    let runs = json!({"workflow_runs": []});
+    assert_eq!(extract_run_id(&runs), None);
+}
+
+#[test]
+fn extract_run_id_missing_key() {
+    let runs = json!({});
+    assert_eq!(extract_run_id(&runs), None);
+}
+
+// extract_workflow_runs tests
+#[test]
+fn extract_workflow_runs_valid_response() {
+    let response = json!({
+        "workflow_runs": [
+            {
+                "id": 100,
+                "name": "CI",
+                "status": "completed",
+                "conclusion": "success",
+                "head_branch": "main",
+                "html_url": "https://github.com/o/r/actions/runs/100",
+                "created_at": "2024-01-15T10:00:00Z",
+                "updated_at": "2024-01-15T10:05:00Z",
+                "run_number": 42
+            },
+            {
+                "id": 101,
+                "name": "Lint",
+                "status": "in_progress",
+                "conclusion": null,
+                "head_branch": "feature",
+                "html_url": "https://github.com/o/r/actions/runs/101",
+                "created_at": "2024-01-15T11:00:00Z",
+                "updated_at": "2024-01-15T11:01:00Z",
+                "run_number": 43
+            }
+        ]
+    });
+    let runs = extract_workflow_runs(&response);
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].id, 100);
+    assert_eq!(runs[0].name, "CI");
+    assert_eq!(runs[0].conclusion, Some("success".to_string()));
+    assert_eq!(runs[0].branch, "main");
+    assert_eq!(runs[1].id, 101);
+    assert!(runs[1].conclusion.is_none());
+}
+
+#[test]
+fn extract_workflow_runs_empty() {
+    let response = json!({"workflow_runs": []});
+    assert!(extract_workflow_runs(&response).is_empty());
+}
+
+#[test]
+fn extract_workflow_runs_missing_key() {
+    let response = json!({});
+    assert!(extract_workflow_runs(&response).is_empty());
+}
+
+#[test]
+fn extract_workflow_runs_skips_invalid() {
+    let response = json!({
+        "workflow_runs": [
+            {"name": "no id"},
+            {
+                "id": 100,
+                "name": "Valid",
+                "status": "completed",
+                "conclusion": "success",
+                "head_branch": "main",
+                "html_url": "url",
+                "created_at": "c",
+                "updated_at": "u",
+                "run_number": 1
+            }
+        ]
+    });
+    let runs = extract_workflow_runs(&response);
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].id, 100);
+}
+
+#[test]
+fn extract_workflow_runs_null_runs() {
+    let response = json!({"workflow_runs": null});
+    assert!(extract_workflow_runs(&response).is_empty());
+}
+
+// check_rerun_status tests
+#[test]
+fn check_rerun_status_success() {
+    assert!(check_rerun_status(201).is_ok());
+    assert!(check_rerun_status(200).is_ok());
+}
+
+#[test]
+fn check_rerun_status_forbidden_mentions_scope() {
+    let err = check_rerun_status(403).unwrap_err().to_string();
+    assert!(err.contains("actions:write"));
+}
+
+#[test]
+fn check_rerun_status_not_found() {
+    let err = check_rerun_status(404).unwrap_err().to_string();
+    assert!(err.contains("not found"));
+}
+
+#[test]
+fn check_rerun_status_other_error() {
+    let err = check_rerun_status(500).unwrap_err().to_string();
+    assert!(err.contains("HTTP 500"));
+}
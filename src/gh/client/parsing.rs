@@ -1,7 +1,12 @@
+use regex::Regex;
+use std::sync::OnceLock;
+
 use super::super::types::TestFailure;
 
 /// Extract test failures from logs (RSpec format)
 pub fn parse_test_failures(logs: &str) -> Vec<TestFailure> {
+    let logs = strip_ansi(logs);
+    let logs = logs.as_str();
     let mut failures = Vec::new();
 
     // Collect failure error messages in order
@@ -13,7 +18,7 @@ pub fn parse_test_failures(logs: &str) -> Vec<TestFailure> {
         let failures_section = &logs[failures_start..failures_end];
 
         // Split by numbered failure pattern "N) description"
-        let block_starts: Vec<usize> = regex::Regex::new(r"\d+\)\s+\S")
+        let block_starts: Vec<usize> = Regex::new(r"\d+\)\s+\S")
             .ok()
             .map(|re| re.find_iter(failures_section).map(|m| m.start()).collect())
             .unwrap_or_default();
@@ -55,7 +60,7 @@ pub fn parse_test_failures(logs: &str) -> Vec<TestFailure> {
 
     // Extract failed examples from the "Failed examples:" section
     // Format: rspec ./spec/helpers/prices_api_helper_spec.rb:289 # description
-    let failed_examples_re = regex::Regex::new(r"rspec\s+(\./spec/[^\s]+:\d+)").ok();
+    let failed_examples_re = Regex::new(r"rspec\s+(\./spec/[^\s]+:\d+)").ok();
 
     if let Some(re) = &failed_examples_re {
         for (i, cap) in re.captures_iter(logs).enumerate() {
@@ -84,12 +89,240 @@ pub fn parse_test_failures(logs: &str) -> Vec<TestFailure> {
 }
 
 /// Clean up CI log line by removing timestamp prefix
-pub(super) fn clean_ci_line(line: &str) -> String {
+fn clean_ci_line(line: &str) -> String {
     // Remove timestamp prefix like "2026-01-27T18:51:46.1029380Z"
-    let re = regex::Regex::new(r"^\d{4}-\d{2}-\d{2}T[\d:.]+Z\s*").ok();
+    let re = Regex::new(r"^\d{4}-\d{2}-\d{2}T[\d:.]+Z\s*").ok();
     if let Some(re) = re {
         re.replace(line, "").trim().to_string()
     } else {
         line.trim().to_string()
     }
 }
+
+/// Remove ANSI escape sequences (colors, cursor movement) embedded in CI logs
+pub fn strip_ansi(text: &str) -> String {
+    ansi_re().replace_all(text, "").into_owned()
+}
+
+fn ansi_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\x1b\[[0-9;?]*[A-Za-z]").expect("valid regex"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_ci_line_removes_timestamp() {
+        let line = "2026-01-27T18:51:46.1029380Z      Failure/Error: some code";
+        assert_eq!(clean_ci_line(line), "Failure/Error: some code");
+    }
+
+    #[test]
+    fn clean_ci_line_preserves_line_without_timestamp() {
+        let line = "  some regular line  ";
+        assert_eq!(clean_ci_line(line), "some regular line");
+    }
+
+    #[test]
+    fn clean_ci_line_handles_empty() {
+        assert_eq!(clean_ci_line(""), "");
+        assert_eq!(clean_ci_line("   "), "");
+    }
+
+    #[test]
+    fn parse_test_failures_extracts_rspec_failures() {
+        let logs = r#"
+    2026-01-27T18:51:46.1025638Z Failures:
+    2026-01-27T18:51:46.1026049Z
+    2026-01-27T18:51:46.1027821Z   1) MyClass does something
+    2026-01-27T18:51:46.1029380Z      Failure/Error: expect(result).to eq(expected)
+    2026-01-27T18:51:46.1167230Z        expected: 42
+    2026-01-27T18:51:46.1168761Z      # ./spec/my_class_spec.rb:10:in `block'
+    2026-01-27T18:51:46.1174151Z
+    2026-01-27T18:51:46.1253383Z Failed examples:
+    2026-01-27T18:51:46.1255271Z rspec ./spec/my_class_spec.rb:8 # MyClass does something
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].spec_file, "./spec/my_class_spec.rb:8");
+        assert!(failures[0]
+            .failure_text
+            .contains("expect(result).to eq(expected)"));
+        assert!(failures[0].failure_text.contains("expected: 42"));
+    }
+
+    #[test]
+    fn parse_test_failures_handles_multiple_failures() {
+        let logs = r#"
+    Failures:
+
+      1) First test fails
+         Failure/Error: assert false
+           error one
+         # ./spec/first_spec.rb:5
+
+      2) Second test fails
+         Failure/Error: raise "boom"
+           error two
+         # ./spec/second_spec.rb:10
+
+    Failed examples:
+
+    rspec ./spec/first_spec.rb:3 # First test fails
+    rspec ./spec/second_spec.rb:8 # Second test fails
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].spec_file, "./spec/first_spec.rb:3");
+        assert_eq!(failures[1].spec_file, "./spec/second_spec.rb:8");
+        assert!(failures[0].failure_text.contains("assert false"));
+        assert!(failures[1].failure_text.contains("raise \"boom\""));
+    }
+
+    #[test]
+    fn parse_test_failures_handles_no_failures() {
+        let logs = "All tests passed!\n0 failures";
+        let failures = parse_test_failures(logs);
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn parse_test_failures_handles_empty_logs() {
+        let failures = parse_test_failures("");
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn parse_test_failures_deduplicates() {
+        let logs = r#"
+    Failures:
+
+      1) Test fails
+         Failure/Error: fail
+         # ./spec/test_spec.rb:5
+
+    Failed examples:
+
+    rspec ./spec/test_spec.rb:3 # Test fails
+    rspec ./spec/test_spec.rb:3 # Test fails duplicate
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+    }
+
+    #[test]
+    fn parse_test_failures_mock_error_format() {
+        // Test the actual format from the CI logs
+        let logs = r#"
+    2026-01-27T18:51:46.1025638Z Failures:
+    2026-01-27T18:51:46.1027821Z   1) PricesApiHelper pax value includes pax
+    2026-01-27T18:51:46.1029380Z      Failure/Error: found_lowest_prices += service.method
+    2026-01-27T18:51:46.1167230Z        #<InstanceDouble(Packages::Items)> received unexpected message :method
+    2026-01-27T18:51:46.1168761Z      # ./app/helpers/prices_api_helper.rb:62
+    2026-01-27T18:51:46.1253383Z Failed examples:
+    2026-01-27T18:51:46.1255271Z rspec ./spec/helpers/prices_api_helper_spec.rb:289 # PricesApiHelper pax value includes pax
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].spec_file,
+            "./spec/helpers/prices_api_helper_spec.rb:289"
+        );
+        assert!(failures[0]
+            .failure_text
+            .contains("received unexpected message"));
+    }
+
+    #[test]
+    fn parse_test_failures_code_only_when_error_is_stacktrace() {
+        let logs = r#"
+    Failures:
+
+      1) Test with stack trace only
+         Failure/Error: some_method_call
+         # ./spec/test_spec.rb:5
+
+    Failed examples:
+
+    rspec ./spec/test_spec.rb:3 # Test with stack trace only
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+        // Should only have the code line since next line starts with #
+        assert_eq!(failures[0].failure_text, "some_method_call");
+    }
+
+    #[test]
+    fn parse_test_failures_handles_failures_section_only() {
+        // Missing "Failed examples:" section
+        let logs = r#"
+    Failures:
+
+      1) Test fails
+         Failure/Error: expect(1).to eq(2)
+           expected: 2
+         # ./spec/test_spec.rb:5
+    "#;
+        let failures = parse_test_failures(logs);
+        // No failed examples section means we can't extract spec files
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn parse_test_failures_handles_nested_spec_paths() {
+        let logs = r#"
+    Failures:
+
+      1) Deep path test
+         Failure/Error: fail "deep"
+           error msg
+
+    Failed examples:
+
+    rspec ./spec/features/admin/users/permissions_spec.rb:42 # Deep path test
+    "#;
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].spec_file,
+            "./spec/features/admin/users/permissions_spec.rb:42"
+        );
+    }
+
+    #[test]
+    fn clean_ci_line_various_timestamps() {
+        // Different timestamp formats from CI
+        assert_eq!(
+            clean_ci_line("2026-01-27T10:00:00.000Z some text"),
+            "some text"
+        );
+        assert_eq!(clean_ci_line("2026-01-27T10:00:00.1234567Z text"), "text");
+        assert_eq!(
+            clean_ci_line("2020-12-31T23:59:59.9Z end of year"),
+            "end of year"
+        );
+    }
+
+    // strip_ansi tests
+    #[test]
+    fn strip_ansi_removes_color_codes() {
+        assert_eq!(strip_ansi("\x1b[31mFailure\x1b[0m ok"), "Failure ok");
+        assert_eq!(strip_ansi("\x1b[1;32mpass\x1b[m"), "pass");
+    }
+
+    #[test]
+    fn strip_ansi_preserves_plain_text() {
+        assert_eq!(strip_ansi("plain text"), "plain text");
+    }
+
+    #[test]
+    fn parse_test_failures_with_ansi_colors() {
+        let logs = "\x1b[31mFailures:\x1b[0m\n\n  1) Thing works\n     \x1b[31mFailure/Error:\x1b[0m expect(a).to eq(b)\n       expected: b\n\nFailed examples:\n\n\x1b[31mrspec ./spec/thing_spec.rb:5\x1b[0m \x1b[36m# Thing works\x1b[0m\n";
+        let failures = parse_test_failures(logs);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].spec_file, "./spec/thing_spec.rb:5");
+        assert_eq!(failures[0].failure_text, "expect(a).to eq(b)\nexpected: b");
+    }
+}
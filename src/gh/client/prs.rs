@@ -0,0 +1,368 @@
+//! Pull request GitHub API operations
+//!
+//! Endpoints:
+//! - `GET /search/issues` — open PRs by author or requested reviewer
+//! - `GET /repos/{owner}/{repo}/pulls/{number}` — branch, head repo, author
+//! - `GET /repos/{owner}/{repo}/pulls` — PR for a branch, PRs matching a query
+//! - `POST /repos/{owner}/{repo}/pulls/{number}/reviews` — submit a review
+//! - `POST /repos/{owner}/{repo}/issues/{number}/comments` — comment
+
+use anyhow::{Context, Result};
+
+use crate::gh::types::{PrReview, PullRequest};
+
+use super::retry::with_retry;
+use super::GithubClient;
+
+/// Run an issue search and keep the results as PRs
+pub(super) async fn search_open_prs(
+    client: &GithubClient,
+    query: &str,
+) -> Result<Vec<PullRequest>> {
+    let result = with_retry(|| async move {
+        client
+            .client
+            .search()
+            .issues_and_pull_requests(query)
+            .send()
+            .await
+    })
+    .await
+    .context("Failed to search for PRs")?;
+
+    let prs: Vec<PullRequest> = result
+        .items
+        .into_iter()
+        .filter_map(|issue| {
+            // Extract repo from URL: https://api.github.com/repos/owner/repo/issues/123
+            let repo_full_name = issue
+                .repository_url
+                .path_segments()?
+                .skip(1) // skip "repos"
+                .take(2) // take "owner" and "repo"
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let state = match issue.state {
+                octocrab::models::IssueState::Open => "open",
+                octocrab::models::IssueState::Closed => "closed",
+                _ => "unknown",
+            };
+
+            Some(PullRequest {
+                number: issue.number,
+                title: issue.title,
+                html_url: issue.html_url.to_string(),
+                state: state.to_string(),
+                repo_full_name,
+                author: issue.user.login,
+                created_at: issue.created_at.to_rfc3339(),
+                updated_at: issue.updated_at.to_rfc3339(),
+                ci_status: None,
+            })
+        })
+        .collect();
+
+    Ok(prs)
+}
+
+/// Head branch of a PR
+pub(super) async fn get_pr_branch(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<String> {
+    let pr = with_retry(|| async move { client.client.pulls(owner, repo).get(pr_number).await })
+        .await
+        .context("Failed to get PR")?;
+
+    Ok(pr.head.ref_field)
+}
+
+/// Repository the PR's head branch lives in (differs for forks)
+pub(super) async fn get_pr_head_repo(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Option<String>> {
+    let pr = with_retry(|| async move { client.client.pulls(owner, repo).get(pr_number).await })
+        .await
+        .context("Failed to get PR")?;
+
+    Ok(pr.head.repo.and_then(|r| r.full_name))
+}
+
+/// Login of the PR's author
+pub(super) async fn get_pr_author(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<String> {
+    let pr = with_retry(|| async move { client.client.pulls(owner, repo).get(pr_number).await })
+        .await
+        .context("Failed to get PR")?;
+
+    Ok(pr.user.map(|u| u.login).unwrap_or_default())
+}
+
+/// Submit a review on a PR
+pub(super) async fn review_pr(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    review: &PrReview<'_>,
+) -> Result<String> {
+    let mut body = serde_json::json!({ "event": review.event.as_api_str() });
+    if let Some(text) = review.body {
+        body["body"] = serde_json::Value::from(text);
+    }
+
+    // Not retried: a repeated POST would submit the review twice
+    let response: serde_json::Value = client
+        .client
+        .post(
+            format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pr_number),
+            Some(&body),
+        )
+        .await
+        .context("Failed to submit review")?;
+
+    Ok(response["html_url"].as_str().unwrap_or("").to_string())
+}
+
+/// Add a comment to a PR's conversation
+pub(super) async fn comment_on_pr(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    body: &str,
+) -> Result<String> {
+    let response: serde_json::Value = client
+        .client
+        .post(
+            format!("/repos/{}/{}/issues/{}/comments", owner, repo, pr_number),
+            Some(&serde_json::json!({ "body": body })),
+        )
+        .await
+        .context("Failed to add comment")?;
+
+    Ok(response["html_url"].as_str().unwrap_or("").to_string())
+}
+
+/// Open PR whose head is `branch`
+pub(super) async fn find_pr_for_branch(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<u64>> {
+    let prs: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/pulls?head={}:{}&state=open&per_page=1",
+            owner, repo, owner, branch
+        ))
+        .await
+        .context("Failed to search for PR by branch")?;
+
+    Ok(extract_pr_number_from_list(&prs))
+}
+
+/// PRs whose title or branch contains `query`
+pub(super) async fn search_prs_by_title(
+    client: &GithubClient,
+    owner: &str,
+    repo: &str,
+    query: &str,
+) -> Result<Vec<PullRequest>> {
+    let response: serde_json::Value = client
+        .get_json(&format!(
+            "/repos/{}/{}/pulls?state=all&per_page=100",
+            owner, repo
+        ))
+        .await
+        .context("Failed to list PRs for search")?;
+
+    Ok(extract_matching_prs(&response, query))
+}
+
+/// Extract PR number from pull request list response (pure function, testable)
+fn extract_pr_number_from_list(prs: &serde_json::Value) -> Option<u64> {
+    prs.as_array()
+        .and_then(|arr| arr.first())
+        .and_then(|pr| pr["number"].as_u64())
+}
+
+/// Extract PRs matching a query from GitHub PR list response (pure function, testable)
+fn extract_matching_prs(response: &serde_json::Value, query: &str) -> Vec<PullRequest> {
+    let query_lower = query.to_lowercase();
+    response
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter(|pr| {
+            let title = pr["title"].as_str().unwrap_or("").to_lowercase();
+            let branch = pr["head"]["ref"].as_str().unwrap_or("").to_lowercase();
+            title.contains(&query_lower) || branch.contains(&query_lower)
+        })
+        .filter_map(|pr| {
+            let repo_full_name = pr["base"]["repo"]["full_name"]
+                .as_str()
+                .unwrap_or("")
+                .to_string();
+            Some(PullRequest {
+                number: pr["number"].as_u64()?,
+                title: pr["title"].as_str()?.to_string(),
+                html_url: pr["html_url"].as_str().unwrap_or("").to_string(),
+                state: pr["state"].as_str().unwrap_or("").to_string(),
+                repo_full_name,
+                author: pr["user"]["login"].as_str().unwrap_or("").to_string(),
+                created_at: pr["created_at"].as_str().unwrap_or("").to_string(),
+                updated_at: pr["updated_at"].as_str().unwrap_or("").to_string(),
+                ci_status: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // extract_pr_number_from_list tests
+    #[test]
+    fn extract_pr_number_from_list_finds_first() {
+        let prs = json!([{"number": 42}, {"number": 99}]);
+        assert_eq!(extract_pr_number_from_list(&prs), Some(42));
+    }
+
+    #[test]
+    fn extract_pr_number_from_list_single() {
+        let prs = json!([{"number": 7}]);
+        assert_eq!(extract_pr_number_from_list(&prs), Some(7));
+    }
+
+    #[test]
+    fn extract_pr_number_from_list_empty() {
+        let prs = json!([]);
+        assert_eq!(extract_pr_number_from_list(&prs), None);
+    }
+
+    #[test]
+    fn extract_pr_number_from_list_missing_number() {
+        let prs = json!([{"title": "no number"}]);
+        assert_eq!(extract_pr_number_from_list(&prs), None);
+    }
+
+    #[test]
+    fn extract_pr_number_from_list_not_array() {
+        let prs = json!({"number": 42});
+        assert_eq!(extract_pr_number_from_list(&prs), None);
+    }
+
+    #[test]
+    fn extract_pr_number_from_list_null() {
+        let prs = json!(null);
+        assert_eq!(extract_pr_number_from_list(&prs), None);
+    }
+
+    // extract_matching_prs tests
+    #[test]
+    fn extract_matching_prs_by_title() {
+        let response = json!([
+            {
+                "number": 1,
+                "title": "BFR-1234 Fix login",
+                "html_url": "https://github.com/o/r/pull/1",
+                "state": "open",
+                "head": {"ref": "some-branch"},
+                "base": {"repo": {"full_name": "o/r"}},
+                "created_at": "c",
+                "updated_at": "u"
+            },
+            {
+                "number": 2,
+                "title": "Unrelated change",
+                "html_url": "https://github.com/o/r/pull/2",
+                "state": "open",
+                "head": {"ref": "other"},
+                "base": {"repo": {"full_name": "o/r"}},
+                "created_at": "c",
+                "updated_at": "u"
+            }
+        ]);
+        let prs = extract_matching_prs(&response, "BFR-1234");
+        assert_eq!(prs.len(), 1);
+        assert_eq!(prs[0].number, 1);
+    }
+
+    #[test]
+    fn extract_matching_prs_by_branch() {
+        let response = json!([
+            {
+                "number": 1,
+                "title": "Some PR",
+                "html_url": "url",
+                "state": "open",
+                "head": {"ref": "bfr-1234-fix"},
+                "base": {"repo": {"full_name": "o/r"}},
+                "created_at": "c",
+                "updated_at": "u"
+            }
+        ]);
+        let prs = extract_matching_prs(&response, "BFR-1234");
+        assert_eq!(prs.len(), 1);
+    }
+
+    #[test]
+    fn extract_matching_prs_empty() {
+        let response = json!([]);
+        assert!(extract_matching_prs(&response, "BFR-1234").is_empty());
+    }
+
+    #[test]
+    fn extract_matching_prs_no_match() {
+        let response = json!([
+            {
+                "number": 1,
+                "title": "Unrelated",
+                "html_url": "url",
+                "state": "open",
+                "head": {"ref": "other"},
+                "base": {"repo": {"full_name": "o/r"}},
+                "created_at": "c",
+                "updated_at": "u"
+            }
+        ]);
+        assert!(extract_matching_prs(&response, "BFR-999").is_empty());
+    }
+
+    #[test]
+    fn extract_matching_prs_not_array() {
+        let response = json!({"not": "array"});
+        assert!(extract_matching_prs(&response, "query").is_empty());
+    }
+
+    #[test]
+    fn extract_matching_prs_case_insensitive() {
+        let response = json!([
+            {
+                "number": 1,
+                "title": "bfr-1234 lowercase",
+                "html_url": "url",
+                "state": "open",
+                "head": {"ref": "main"},
+                "base": {"repo": {"full_name": "o/r"}},
+                "created_at": "c",
+                "updated_at": "u"
+            }
+        ]);
+        let prs = extract_matching_prs(&response, "BFR-1234");
+        assert_eq!(prs.len(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+//! Retry with backoff for GitHub rate limits and transient server errors
+//!
+//! GitHub signals primary rate limits with `X-RateLimit-Remaining: 0` and an
+//! `X-RateLimit-Reset` epoch, and secondary limits with `Retry-After`; both
+//! may come back as 403 or 429. 5xx responses are retried with exponential
+//! backoff.
+
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response};
+use tokio::time::sleep;
+
+/// Max retries before giving up
+pub const MAX_RETRIES: u32 = 3;
+
+/// Longest we'll wait between attempts
+const MAX_WAIT_SECS: u64 = 60;
+
+/// Whether a response is worth retrying
+pub fn should_retry(status: u16, headers: &HeaderMap) -> bool {
+    match status {
+        429 | 500 | 502 | 503 | 504 => true,
+        403 => {
+            headers.contains_key("retry-after")
+                || header(headers, "x-ratelimit-remaining") == Some(0)
+        }
+        _ => false,
+    }
+}
+
+/// How long to wait before retry number `attempt` (0-based)
+///
+/// Prefers `Retry-After`, then `X-RateLimit-Reset`, then `2^attempt` seconds.
+pub fn retry_delay(headers: &HeaderMap, attempt: u32, now: u64) -> Duration {
+    let secs = header(headers, "retry-after")
+        .or_else(|| {
+            header(headers, "x-ratelimit-reset").map(|reset| reset.saturating_sub(now).max(1))
+        })
+        .unwrap_or(1 << attempt.min(6));
+    Duration::from_secs(secs.min(MAX_WAIT_SECS))
+}
+
+/// Whether an octocrab error is a rate limit or transient server error
+///
+/// octocrab doesn't expose response headers, so these are retried with
+/// plain backoff.
+pub fn is_retryable_error(err: &octocrab::Error) -> bool {
+    match err {
+        octocrab::Error::GitHub { source, .. } => {
+            let status = source.status_code.as_u16();
+            should_retry(status, &HeaderMap::new())
+                || (status == 403 && source.message.to_lowercase().contains("rate limit"))
+        }
+        _ => false,
+    }
+}
+
+/// Send a raw request, retrying rate limits and 5xx responses
+///
+/// `build` is called once per attempt since a request can't be reused.
+pub async fn send_with_retry(build: impl Fn() -> RequestBuilder) -> Result<Response> {
+    let mut attempt = 0;
+    loop {
+        let response = build().send().await?;
+        let status = response.status().as_u16();
+        log::debug!("HTTP {} {}", status, response.url());
+        if !should_retry(status, response.headers()) {
+            return Ok(response);
+        }
+        if attempt >= MAX_RETRIES {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "GitHub API returned HTTP {} after {} retries: {}",
+                status,
+                MAX_RETRIES,
+                body.trim()
+            );
+        }
+        let delay = retry_delay(response.headers(), attempt, unix_now());
+        wait(status, delay, attempt).await;
+        attempt += 1;
+    }
+}
+
+/// Run an octocrab call, retrying rate limits and 5xx errors
+pub async fn with_retry<T, F, Fut>(op: F) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = octocrab::Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < MAX_RETRIES && is_retryable_error(&err) => {
+                let status = match &err {
+                    octocrab::Error::GitHub { source, .. } => source.status_code.as_u16(),
+                    _ => 0,
+                };
+                wait(status, retry_delay(&HeaderMap::new(), attempt, 0), attempt).await;
+                attempt += 1;
+            }
+            Err(err) if attempt > 0 => {
+                return Err(anyhow::Error::new(err).context(format!(
+                    "GitHub API still failing after {} retries",
+                    attempt
+                )))
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+async fn wait(status: u16, delay: Duration, attempt: u32) {
+    log::info!(
+        "GitHub returned HTTP {}, retrying in {}s... (retry {}/{})",
+        status,
+        delay.as_secs(),
+        attempt + 1,
+        MAX_RETRIES
+    );
+    sleep(delay).await;
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // retry tests
+    fn headers(pairs: &[(&'static str, &str)]) -> reqwest::header::HeaderMap {
+        let mut map = reqwest::header::HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, value.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn should_retry_rate_limit_and_server_errors() {
+        let none = headers(&[]);
+        for status in [429, 500, 502, 503, 504] {
+            assert!(should_retry(status, &none), "{status}");
+        }
+        assert!(!should_retry(200, &none));
+        assert!(!should_retry(404, &none));
+        assert!(!should_retry(501, &none));
+    }
+
+    #[test]
+    fn should_retry_403_only_when_rate_limited() {
+        assert!(!should_retry(403, &headers(&[])));
+        assert!(!should_retry(
+            403,
+            &headers(&[("x-ratelimit-remaining", "12")])
+        ));
+        assert!(should_retry(
+            403,
+            &headers(&[("x-ratelimit-remaining", "0")])
+        ));
+        assert!(should_retry(403, &headers(&[("retry-after", "30")])));
+    }
+
+    #[test]
+    fn retry_delay_prefers_retry_after() {
+        let h = headers(&[("retry-after", "7"), ("x-ratelimit-reset", "1100")]);
+        assert_eq!(retry_delay(&h, 0, 1000), std::time::Duration::from_secs(7));
+    }
+
+    #[test]
+    fn retry_delay_uses_reset_header() {
+        let h = headers(&[("x-ratelimit-reset", "1020")]);
+        assert_eq!(retry_delay(&h, 0, 1000), std::time::Duration::from_secs(20));
+        // Reset already passed: wait a second rather than zero
+        assert_eq!(retry_delay(&h, 0, 2000), std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially() {
+        let none = headers(&[]);
+        assert_eq!(retry_delay(&none, 0, 0), std::time::Duration::from_secs(1));
+        assert_eq!(retry_delay(&none, 2, 0), std::time::Duration::from_secs(4));
+    }
+
+    #[test]
+    fn retry_delay_is_capped() {
+        let h = headers(&[("retry-after", "3600")]);
+        assert_eq!(retry_delay(&h, 0, 0), std::time::Duration::from_secs(60));
+        assert_eq!(
+            retry_delay(&headers(&[]), 10, 0),
+            std::time::Duration::from_secs(60)
+        );
+    }
+}
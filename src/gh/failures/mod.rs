@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::Result;
 
 use super::cli::FailuresArgs;
@@ -34,9 +35,11 @@ pub async fn process_pr_failures(
     repo: &str,
     pr_number: u64,
 ) -> Result<()> {
-    eprintln!(
+    log::info!(
         "Fetching failures for PR #{} in {}/{}...",
-        pr_number, owner, repo
+        pr_number,
+        owner,
+        repo
     );
 
     // Get the PR's branch name
@@ -60,7 +63,7 @@ pub async fn process_pr_failures(
 
 /// Process failures for the latest failed run in the repo (testable)
 pub async fn process_repo_failures(client: &impl GithubApi, owner: &str, repo: &str) -> Result<()> {
-    eprintln!("Fetching latest failures in {}/{}...", owner, repo);
+    log::info!("Fetching latest failures in {}/{}...", owner, repo);
 
     // Get the latest failed workflow run for the repo
     let run_id = client.get_latest_failed_run(owner, repo).await?;
@@ -105,7 +108,7 @@ async fn process_run_failures(
     let mut all_failures = Vec::new();
 
     for (job_id, job_name) in test_jobs {
-        eprintln!("Fetching logs for job: {}", job_name);
+        log::info!("Fetching logs for job: {}", job_name);
 
         match client.get_job_logs(owner, repo, job_id).await {
             Ok(logs) => {
@@ -113,7 +116,7 @@ async fn process_run_failures(
                 all_failures.extend(failures);
             }
             Err(e) => {
-                eprintln!("Warning: Failed to fetch logs for {}: {}", job_name, e);
+                log::warn!("Failed to fetch logs for {}: {}", job_name, e);
             }
         }
     }
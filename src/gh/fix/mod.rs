@@ -1,5 +1,8 @@
+use anstream::{print, println};
 use anyhow::Result;
 
+use crate::util::{print_structured, OutputFormat};
+
 use super::cli::FixArgs;
 use super::client::{parse_test_failures, GithubApi, GithubClient};
 use super::helpers::{get_current_branch, get_current_repo, is_test_job, parse_owner_repo};
@@ -42,7 +45,7 @@ pub async fn run(args: FixArgs) -> Result<()> {
     let report = build_fix_report(&client, &query).await?;
 
     match report {
-        Some(r) => output_report(&r, args.json),
+        Some(r) => output_report(&r, OutputFormat::resolve(args.json)),
         None => {
             println!("No failures found.");
             Ok(())
@@ -110,14 +113,14 @@ pub async fn build_fix_report(
     let mut all_failures = Vec::new();
 
     for (job_id, job_name) in test_jobs {
-        eprintln!("Fetching logs for job: {}", job_name);
+        log::info!("Fetching logs for job: {}", job_name);
         match client.get_job_logs(owner, repo, job_id).await {
             Ok(logs) => {
                 let failures = parse_test_failures(&logs);
                 all_failures.extend(failures);
             }
             Err(e) => {
-                eprintln!("Warning: Failed to fetch logs for {}: {}", job_name, e);
+                log::warn!("Failed to fetch logs for {}: {}", job_name, e);
             }
         }
     }
@@ -164,12 +167,11 @@ pub fn enrich_failures(failures: &[TestFailure]) -> Vec<FixFailure> {
         .collect()
 }
 
-/// Output the fix report (markdown or JSON)
-fn output_report(report: &FixReport, json: bool) -> Result<()> {
-    if json {
-        println!("{}", serde_json::to_string_pretty(report)?);
-    } else {
-        print!("{}", format_markdown(report));
+/// Output the fix report (markdown, or JSON/CSV)
+fn output_report(report: &FixReport, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Table => print!("{}", format_markdown(report)),
+        OutputFormat::Json | OutputFormat::Csv => print_structured(report, format)?,
     }
     Ok(())
 }
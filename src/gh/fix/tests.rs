@@ -16,6 +16,42 @@ impl GithubApi for MockGithubApi {
         Ok(vec![])
     }
 
+    async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+        Ok(vec![])
+    }
+
+    async fn get_pr_head_repo(&self, owner: &str, repo: &str, _pr: u64) -> Result<Option<String>> {
+        Ok(Some(format!("{}/{}", owner, repo)))
+    }
+
+    async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok("octocat".to_string())
+    }
+
+    async fn get_current_login(&self) -> Result<String> {
+        Ok("me".to_string())
+    }
+
+    async fn review_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _review: &crate::gh::types::PrReview<'_>,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn comment_on_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _body: &str,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
     async fn get_ci_status(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<CiStatus> {
         Ok(CiStatus::Unknown)
     }
@@ -46,10 +82,23 @@ impl GithubApi for MockGithubApi {
         Ok(self.failed_jobs.clone())
     }
 
+    async fn list_run_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<crate::gh::types::WorkflowJob>> {
+        Ok(vec![])
+    }
+
     async fn get_job_logs(&self, _owner: &str, _repo: &str, _job_id: u64) -> Result<String> {
         Ok(self.logs.clone())
     }
 
+    async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, _run_id: u64) -> Result<()> {
+        Ok(())
+    }
+
     async fn find_pr_for_branch(
         &self,
         _owner: &str,
@@ -273,6 +322,42 @@ impl GithubApi for MockGithubApiWithLogError {
         Ok(vec![])
     }
 
+    async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+        Ok(vec![])
+    }
+
+    async fn get_pr_head_repo(&self, owner: &str, repo: &str, _pr: u64) -> Result<Option<String>> {
+        Ok(Some(format!("{}/{}", owner, repo)))
+    }
+
+    async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok("octocat".to_string())
+    }
+
+    async fn get_current_login(&self) -> Result<String> {
+        Ok("me".to_string())
+    }
+
+    async fn review_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _review: &crate::gh::types::PrReview<'_>,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn comment_on_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _body: &str,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
     async fn get_ci_status(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<CiStatus> {
         Ok(CiStatus::Unknown)
     }
@@ -303,10 +388,23 @@ impl GithubApi for MockGithubApiWithLogError {
         Ok(self.failed_jobs.clone())
     }
 
+    async fn list_run_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<crate::gh::types::WorkflowJob>> {
+        Ok(vec![])
+    }
+
     async fn get_job_logs(&self, _owner: &str, _repo: &str, _job_id: u64) -> Result<String> {
         Err(anyhow::anyhow!("Failed to fetch logs"))
     }
 
+    async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, _run_id: u64) -> Result<()> {
+        Ok(())
+    }
+
     async fn find_pr_for_branch(
         &self,
         _owner: &str,
@@ -637,7 +735,7 @@ fn output_report_json() {
         test_files: vec![],
         source_files: vec![],
     };
-    let result = output_report(&report, true);
+    let result = output_report(&report, OutputFormat::Json);
     assert!(result.is_ok());
 }
 
@@ -651,7 +749,7 @@ fn output_report_markdown() {
         test_files: vec![],
         source_files: vec![],
     };
-    let result = output_report(&report, false);
+    let result = output_report(&report, OutputFormat::Table);
     assert!(result.is_ok());
 }
 
@@ -1,18 +1,54 @@
-use anyhow::Result;
+use anstream::{eprintln, println};
+use anyhow::{Context, Result};
 
-use super::auth;
+use super::auth::{self, TokenInfo};
 use super::cli::LoginArgs;
 
 /// Handle the `hu gh login` command
+#[cfg(not(tarpaulin_include))]
 pub async fn run(args: LoginArgs) -> Result<()> {
-    let username = match args.token {
-        Some(token) => auth::login(&token).await?,
-        None => auth::device_flow_login().await?,
-    };
-    println!("{}", format_login_success(&username));
+    let token = auth::find_token(args.token).await?;
+    let info = auth::validate_token(&token).await?;
+    for warning in scope_warnings(&info) {
+        eprintln!("{}", warning);
+    }
+    auth::save_login(&info.login, &token)?;
+    println!("{}", format_login_success(&info.login));
+    Ok(())
+}
+
+/// Handle the `hu gh whoami` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run_whoami() -> Result<()> {
+    let token = auth::get_token().context("Not authenticated. Run `hu gh login` first.")?;
+    let info = auth::validate_token(&token).await?;
+    println!("{}", info.login);
+    for warning in scope_warnings(&info) {
+        eprintln!("{}", warning);
+    }
     Ok(())
 }
 
+/// One warning per missing scope (or a note that scopes can't be checked)
+pub fn scope_warnings(info: &TokenInfo) -> Vec<String> {
+    if info.scopes.is_none() {
+        return vec![
+            "Note: fine-grained token, scopes can't be checked. `hu gh rerun` needs \
+             Actions: read and write."
+                .to_string(),
+        ];
+    }
+    info.missing_scopes()
+        .iter()
+        .map(|(scope, needed_for)| {
+            format!(
+                "⚠ Token is missing the `{}` scope (needed for {})",
+                scope, needed_for
+            )
+        })
+        .collect()
+}
+
 /// Format the login success message (extracted for testability)
 pub fn format_login_success(username: &str) -> String {
     format!("✓ Logged in as {}", username)
@@ -36,6 +72,36 @@ mod tests {
         assert!(msg.contains("user-name_123"));
     }
 
+    #[test]
+    fn scope_warnings_for_missing_scope() {
+        let info = TokenInfo {
+            login: "octocat".to_string(),
+            scopes: Some(vec!["read:org".to_string(), "repo".to_string()]),
+        };
+        let warnings = scope_warnings(&info);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("`workflow`"));
+        assert!(warnings[0].contains("hu gh rerun"));
+    }
+
+    #[test]
+    fn scope_warnings_none_when_complete() {
+        let info = TokenInfo {
+            login: "octocat".to_string(),
+            scopes: Some(vec!["repo".to_string(), "workflow".to_string()]),
+        };
+        assert!(scope_warnings(&info).is_empty());
+    }
+
+    #[test]
+    fn scope_warnings_fine_grained_note() {
+        let info = TokenInfo {
+            login: "octocat".to_string(),
+            scopes: None,
+        };
+        assert!(scope_warnings(&info)[0].contains("fine-grained"));
+    }
+
     #[test]
     fn login_args_has_token_field() {
         let args = LoginArgs {
@@ -0,0 +1,283 @@
+//! `hu gh logs` — follow a PR's CI job log while it runs
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use anstream::println;
+use anyhow::Result;
+use regex::Regex;
+
+use super::cli::LogsArgs;
+use super::client::{strip_ansi, GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+use super::types::{RunsQuery, WorkflowJob, WorkflowRun};
+use crate::util::{ErrorKind, HuError};
+
+// ANSI color codes
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const BOLD: &str = "\x1b[1m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Runs to look through for one that's still going
+const RECENT_RUNS: usize = 10;
+
+/// The job being followed
+#[derive(Debug, Clone, Copy)]
+pub struct LogsQuery<'a> {
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub run_id: u64,
+    pub job_id: u64,
+    /// Delay between polls
+    pub interval: Duration,
+}
+
+/// How a log line should be highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineLevel {
+    Error,
+    Warning,
+    /// `##[group]` section header
+    Group,
+    Plain,
+}
+
+/// Handle the `hu gh logs` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: LogsArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+
+    let run = find_run(&client, &owner, &repo, args.pr).await?;
+    let jobs = client.list_run_jobs(&owner, &repo, run.id).await?;
+    let job = pick_job(&jobs, args.job.as_deref())?.clone();
+    println!(
+        "{GRAY}{} #{} › {} ({}){RESET}",
+        run.name, run.run_number, job.name, run.html_url
+    );
+
+    let emit = |line: &str| println!("{}", colorize_log_line(line));
+    let job = if job.is_completed() {
+        // Nothing to follow; print the finished log once
+        let logs = client.get_job_logs(&owner, &repo, job.id).await?;
+        new_lines(&logs, 0, true).into_iter().for_each(emit);
+        job
+    } else {
+        let query = LogsQuery {
+            owner: &owner,
+            repo: &repo,
+            run_id: run.id,
+            job_id: job.id,
+            interval: Duration::from_secs(args.interval),
+        };
+        follow_job(&client, &query, emit).await?
+    };
+
+    match job.conclusion.as_deref() {
+        Some("success") => println!("{GREEN}✓{RESET} {} succeeded", job.name),
+        Some(other) => println!("{RED}✗{RESET} {} finished: {}", job.name, other),
+        None => println!("{} finished", job.name),
+    }
+    Ok(())
+}
+
+/// Latest run for the PR's branch, preferring one that's still going
+pub async fn find_run(
+    client: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<WorkflowRun> {
+    let branch = client.get_pr_branch(owner, repo, pr_number).await?;
+    let query = RunsQuery {
+        owner,
+        repo,
+        branch: Some(&branch),
+        status: None,
+        limit: RECENT_RUNS,
+    };
+    let runs = client.list_workflow_runs(&query).await?;
+    pick_run(runs).ok_or_else(|| {
+        HuError::new(
+            ErrorKind::NotFound,
+            format!("No workflow runs for PR #{} ({})", pr_number, branch),
+        )
+        .into()
+    })
+}
+
+/// First unfinished run, else the newest (runs come newest first)
+fn pick_run(runs: Vec<WorkflowRun>) -> Option<WorkflowRun> {
+    let idx = runs
+        .iter()
+        .position(|r| r.status != "completed")
+        .unwrap_or(0);
+    runs.into_iter().nth(idx)
+}
+
+/// Job to show: the one matching `name`, else the first still running,
+/// else the first failed, else the first
+pub fn pick_job<'a>(jobs: &'a [WorkflowJob], name: Option<&str>) -> Result<&'a WorkflowJob> {
+    let Some(first) = jobs.first() else {
+        anyhow::bail!("The run has no jobs yet");
+    };
+    let Some(name) = name else {
+        return Ok(jobs
+            .iter()
+            .find(|j| !j.is_completed())
+            .or_else(|| {
+                jobs.iter()
+                    .find(|j| j.conclusion.as_deref() == Some("failure"))
+            })
+            .unwrap_or(first));
+    };
+
+    let needle = name.to_lowercase();
+    if let Some(exact) = jobs.iter().find(|j| j.name.to_lowercase() == needle) {
+        return Ok(exact);
+    }
+    let matches: Vec<&WorkflowJob> = jobs
+        .iter()
+        .filter(|j| j.name.to_lowercase().contains(&needle))
+        .collect();
+    let names = |jobs: &mut dyn Iterator<Item = &WorkflowJob>| {
+        jobs.map(|j| j.name.as_str()).collect::<Vec<_>>().join(", ")
+    };
+    match matches.as_slice() {
+        [job] => Ok(job),
+        [] => Err(HuError::new(
+            ErrorKind::NotFound,
+            format!(
+                "No job matching '{}'. Jobs: {}",
+                name,
+                names(&mut jobs.iter())
+            ),
+        )
+        .into()),
+        _ => anyhow::bail!(
+            "'{}' matches several jobs: {}",
+            name,
+            names(&mut matches.iter().copied())
+        ),
+    }
+}
+
+/// Poll a job's log, emitting new lines, until the job completes (testable)
+///
+/// Logs usually aren't downloadable until a job has started, so failures
+/// before completion are treated as "nothing yet". Returns the finished job.
+pub async fn follow_job(
+    client: &impl GithubApi,
+    query: &LogsQuery<'_>,
+    mut emit: impl FnMut(&str),
+) -> Result<WorkflowJob> {
+    let mut seen = 0;
+    loop {
+        let job = client
+            .list_run_jobs(query.owner, query.repo, query.run_id)
+            .await?
+            .into_iter()
+            .find(|j| j.id == query.job_id)
+            .ok_or_else(|| anyhow::anyhow!("Job {} disappeared from the run", query.job_id))?;
+        let done = job.is_completed();
+
+        let logs = match client
+            .get_job_logs(query.owner, query.repo, query.job_id)
+            .await
+        {
+            Ok(logs) => logs,
+            Err(e) if !done => {
+                log::debug!("Logs for job {} not ready: {:#}", query.job_id, e);
+                String::new()
+            }
+            Err(e) => return Err(e),
+        };
+        for line in new_lines(&logs, seen, done) {
+            emit(line);
+            seen += 1;
+        }
+
+        if done {
+            return Ok(job);
+        }
+        tokio::time::sleep(query.interval).await;
+    }
+}
+
+/// Lines after the first `seen`, holding back a trailing partial line
+/// until the log is `complete`
+pub fn new_lines(logs: &str, seen: usize, complete: bool) -> Vec<&str> {
+    let mut lines: Vec<&str> = logs.lines().collect();
+    if !complete && !logs.is_empty() && !logs.ends_with('\n') {
+        lines.pop();
+    }
+    lines.into_iter().skip(seen).collect()
+}
+
+fn timestamp_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\d{4}-\d{2}-\d{2}T[\d:.]+Z ?").expect("valid regex"))
+}
+
+fn error_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)\b(error|errors|failed|failure|fatal|panicked)\b").expect("valid regex")
+    })
+}
+
+fn warning_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\b(warn|warning|deprecated)\b").expect("valid regex"))
+}
+
+/// Strip the timestamp and ANSI codes GitHub adds, keeping indentation
+fn clean_line(line: &str) -> String {
+    strip_ansi(&timestamp_re().replace(line, ""))
+}
+
+/// Level of a cleaned log line
+///
+/// Runner annotations (`##[error]`) are trusted first; otherwise common
+/// words are matched, which is a heuristic.
+pub fn line_level(line: &str) -> LineLevel {
+    let text = line.trim_start();
+    if text.starts_with("##[error]") {
+        LineLevel::Error
+    } else if text.starts_with("##[warning]") {
+        LineLevel::Warning
+    } else if text.starts_with("##[group]") {
+        LineLevel::Group
+    } else if error_re().is_match(text) {
+        LineLevel::Error
+    } else if warning_re().is_match(text) {
+        LineLevel::Warning
+    } else {
+        LineLevel::Plain
+    }
+}
+
+/// A raw log line, cleaned and colored by level
+pub fn colorize_log_line(line: &str) -> String {
+    let clean = clean_line(line);
+    let level = line_level(&clean);
+    let text = ["##[error]", "##[warning]", "##[group]", "##[endgroup]"]
+        .iter()
+        .find_map(|marker| clean.trim_start().strip_prefix(marker))
+        .unwrap_or(&clean);
+    match level {
+        LineLevel::Error => format!("{RED}{}{RESET}", text),
+        LineLevel::Warning => format!("{YELLOW}{}{RESET}", text),
+        LineLevel::Group => format!("{BOLD}▸ {}{RESET}", text),
+        LineLevel::Plain => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
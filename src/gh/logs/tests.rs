@@ -0,0 +1,163 @@
+use super::*;
+use crate::gh::test_support::MockGithubApi;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+fn job(id: u64, name: &str, status: &str, conclusion: Option<&str>) -> WorkflowJob {
+    WorkflowJob {
+        id,
+        name: name.to_string(),
+        status: status.to_string(),
+        conclusion: conclusion.map(str::to_string),
+    }
+}
+
+fn workflow_run(id: u64, status: &str) -> WorkflowRun {
+    WorkflowRun {
+        id,
+        name: "CI".to_string(),
+        status: status.to_string(),
+        conclusion: None,
+        branch: "feature".to_string(),
+        html_url: String::new(),
+        created_at: String::new(),
+        updated_at: String::new(),
+        run_number: id,
+    }
+}
+
+#[test]
+fn new_lines_holds_back_partial_line() {
+    let logs = "one\ntwo\nthr";
+    assert_eq!(new_lines(logs, 0, false), vec!["one", "two"]);
+    assert_eq!(new_lines(logs, 1, false), vec!["two"]);
+    assert_eq!(new_lines(logs, 2, true), vec!["thr"]);
+    assert_eq!(new_lines("one\n", 0, false), vec!["one"]);
+    assert!(new_lines("", 3, false).is_empty());
+}
+
+#[test]
+fn pick_run_prefers_unfinished() {
+    let runs = vec![workflow_run(3, "completed"), workflow_run(2, "in_progress")];
+    assert_eq!(pick_run(runs).unwrap().id, 2);
+    assert_eq!(pick_run(vec![workflow_run(3, "completed")]).unwrap().id, 3);
+    assert!(pick_run(vec![]).is_none());
+}
+
+#[test]
+fn pick_job_defaults() {
+    let jobs = vec![
+        job(1, "lint", "completed", Some("success")),
+        job(2, "test (unit)", "completed", Some("failure")),
+        job(3, "test (integration)", "in_progress", None),
+    ];
+    assert_eq!(pick_job(&jobs, None).unwrap().id, 3);
+    assert_eq!(pick_job(&jobs[..2], None).unwrap().id, 2);
+    assert_eq!(pick_job(&jobs[..1], None).unwrap().id, 1);
+    assert!(pick_job(&[], None).is_err());
+}
+
+#[test]
+fn pick_job_by_name() {
+    let jobs = vec![
+        job(1, "test", "completed", Some("success")),
+        job(2, "test (integration)", "in_progress", None),
+        job(3, "lint", "queued", None),
+    ];
+    assert_eq!(pick_job(&jobs, Some("TEST")).unwrap().id, 1);
+    assert_eq!(pick_job(&jobs, Some("integ")).unwrap().id, 2);
+
+    let err = pick_job(&jobs, Some("deploy")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Jobs: test, test (integration), lint"));
+    let err = pick_job(&jobs, Some("t")).unwrap_err();
+    assert!(err.to_string().contains("several"));
+}
+
+#[test]
+fn line_levels() {
+    assert_eq!(line_level("##[error]Process completed"), LineLevel::Error);
+    assert_eq!(
+        line_level("##[warning]Node 16 is deprecated"),
+        LineLevel::Warning
+    );
+    assert_eq!(line_level("##[group]Run cargo test"), LineLevel::Group);
+    assert_eq!(
+        line_level("test result: FAILED. 1 passed"),
+        LineLevel::Error
+    );
+    assert_eq!(line_level("warning: unused import"), LineLevel::Warning);
+    assert_eq!(line_level("Compiling hu v0.1.0"), LineLevel::Plain);
+    assert_eq!(line_level("errorless"), LineLevel::Plain);
+}
+
+#[test]
+fn colorize_strips_timestamp_and_markers() {
+    let line = "2026-01-27T18:51:46.1029380Z ##[error]\x1b[31mboom\x1b[0m";
+    assert_eq!(colorize_log_line(line), format!("{RED}boom{RESET}"));
+    let line = "2026-01-27T18:51:46.1029380Z   indented";
+    assert_eq!(colorize_log_line(line), "  indented");
+    let line = "2026-01-27T18:51:46.1029380Z ##[group]Run tests";
+    assert_eq!(colorize_log_line(line), format!("{BOLD}▸ Run tests{RESET}"));
+}
+
+fn query() -> LogsQuery<'static> {
+    LogsQuery {
+        owner: "o",
+        repo: "r",
+        run_id: 8,
+        job_id: 5,
+        interval: Duration::ZERO,
+    }
+}
+
+#[tokio::test]
+async fn find_run_uses_pr_branch() {
+    let mock = MockGithubApi {
+        branch: "feature".to_string(),
+        workflow_runs: vec![workflow_run(9, "completed"), workflow_run(8, "in_progress")],
+        ..Default::default()
+    };
+    assert_eq!(find_run(&mock, "o", "r", 1).await.unwrap().id, 8);
+}
+
+#[tokio::test]
+async fn follow_job_appends_until_completed() {
+    let running = job(5, "test", "in_progress", None);
+    let done = job(5, "test", "completed", Some("failure"));
+    let mock = MockGithubApi {
+        run_jobs: Mutex::new(vec![
+            vec![running.clone()],
+            vec![running.clone()],
+            vec![running],
+            vec![done],
+        ]),
+        job_logs: Mutex::new(HashMap::from([(
+            5,
+            vec![
+                None,
+                Some("a\nb".to_string()),
+                Some("a\nb\nc\n".to_string()),
+                Some("a\nb\nc\nd".to_string()),
+            ],
+        )])),
+        ..Default::default()
+    };
+
+    let mut lines = Vec::new();
+    let job = follow_job(&mock, &query(), |l| lines.push(l.to_string()))
+        .await
+        .unwrap();
+    assert_eq!(lines, vec!["a", "b", "c", "d"]);
+    assert_eq!(job.conclusion.as_deref(), Some("failure"));
+}
+
+#[tokio::test]
+async fn follow_job_fails_if_final_log_is_missing() {
+    let mock = MockGithubApi {
+        run_jobs: Mutex::new(vec![vec![job(5, "test", "completed", Some("success"))]]),
+        ..Default::default()
+    };
+    assert!(follow_job(&mock, &query(), |_| {}).await.is_err());
+}
@@ -6,37 +6,62 @@
 //! # Programmatic Usage (MCP/HTTP)
 //! Use the reusable functions that return typed data:
 //! - [`list_user_prs`] - List open PRs by current user
+//! - [`list_review_requests`] - List open PRs awaiting the user's review
 //! - [`get_ci_status`] - Get CI status for a PR
 //! - [`list_workflow_runs`] - List workflow runs
 //! - [`search_prs`] - Search PRs by title/branch
 
 mod auth;
+mod checkout;
+mod ci;
 mod cli;
 mod client;
 mod failures;
 mod fix;
 mod helpers;
 mod login;
+mod logs;
 mod prs;
+mod rerun;
+mod review;
+mod reviews;
 mod runs;
 mod service;
 mod sync;
+#[cfg(test)]
+mod test_support;
 mod types;
+mod watch;
 
 use anyhow::Result;
 
 pub use cli::GhCommand;
+use client::GithubApi;
 pub use types::{CiStatus, PullRequest, RunsQuery, WorkflowRun};
 
+use types::ReviewEvent;
+
 /// Run a GitHub command (CLI entry point - formats and prints)
 #[cfg(not(tarpaulin_include))]
 pub async fn run_command(cmd: GhCommand) -> anyhow::Result<()> {
     match cmd {
         GhCommand::Login(args) => login::run(args).await,
-        GhCommand::Prs => prs::run().await,
+        GhCommand::Whoami => login::run_whoami().await,
+        GhCommand::Prs(args) => prs::run(args).await,
+        GhCommand::Reviews(args) => reviews::run(args).await,
         GhCommand::Failures(args) => failures::run(args).await,
         GhCommand::Fix(args) => fix::run(args).await,
         GhCommand::Runs(args) => runs::run(args).await,
+        GhCommand::Rerun(args) => rerun::run(args).await,
+        GhCommand::Watch(args) => watch::run(args).await,
+        GhCommand::Logs(args) => logs::run(args).await,
+        GhCommand::Ci(args) => ci::run(args).await,
+        GhCommand::Approve(args) => review::run_review(args, ReviewEvent::Approve).await,
+        GhCommand::RequestChanges(args) => {
+            review::run_review(args, ReviewEvent::RequestChanges).await
+        }
+        GhCommand::Comment(args) => review::run_comment(args).await,
+        GhCommand::Checkout(args) => checkout::run(args).await,
         GhCommand::Sync(args) => sync::run(args),
     }
 }
@@ -52,6 +77,27 @@ pub async fn list_user_prs() -> Result<Vec<PullRequest>> {
     service::list_user_prs(&client).await
 }
 
+/// Login of the authenticated user (for MCP/HTTP)
+#[allow(dead_code)]
+pub async fn get_current_login() -> Result<String> {
+    let client = service::create_client()?;
+    client.get_current_login().await
+}
+
+/// List open PRs where the current user's review is requested (for MCP/HTTP)
+#[allow(dead_code)]
+pub async fn list_review_requests() -> Result<Vec<PullRequest>> {
+    let client = service::create_client()?;
+    service::list_review_requests(&client).await
+}
+
+/// List open PRs authored by the current user, with CI status (for MCP/HTTP)
+#[allow(dead_code)]
+pub async fn list_user_prs_with_ci() -> Result<Vec<PullRequest>> {
+    let client = service::create_client()?;
+    service::list_user_prs_with_ci(&client, service::DEFAULT_CI_CONCURRENCY).await
+}
+
 /// Get CI status for a PR (for MCP/HTTP)
 #[allow(dead_code)]
 pub async fn get_ci_status(owner: &str, repo: &str, pr_number: u64) -> Result<CiStatus> {
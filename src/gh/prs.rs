@@ -1,7 +1,11 @@
+use anstream::println;
 use anyhow::Result;
 
+use crate::util::fmt::truncate;
+
+use super::cli::PrsArgs;
 use super::client::{GithubApi, GithubClient};
-use super::types::CiStatus;
+use super::types::{CiStatus, PrReason};
 
 // ANSI color codes
 const GREEN: &str = "\x1b[32m";
@@ -10,10 +14,15 @@ const RED: &str = "\x1b[31m";
 const GRAY: &str = "\x1b[90m";
 const RESET: &str = "\x1b[0m";
 
+/// Width of the reason column ("review" is the longest tag)
+const TAG_WIDTH: usize = 6;
+
 /// Handle the `hu gh prs` command
-pub async fn run() -> Result<()> {
+pub async fn run(args: PrsArgs) -> Result<()> {
     let client = GithubClient::new()?;
-    run_with_client(&client).await
+    // Authored PRs unless only `--reviews` was asked for
+    let mine = args.mine || !args.reviews;
+    run_with_client(&client, mine, args.reviews, args.concurrency as usize).await
 }
 
 fn get_terminal_width() -> usize {
@@ -22,7 +31,8 @@ fn get_terminal_width() -> usize {
         .unwrap_or(80)
 }
 
-fn print_prs_table(prs: &[super::types::PullRequest]) {
+/// `tags` adds a column saying why each PR is listed
+fn print_prs_table(prs: &[super::types::PullRequest], tags: Option<&[PrReason]>) {
     let term_width = get_terminal_width();
 
     // Calculate max link length
@@ -32,20 +42,28 @@ fn print_prs_table(prs: &[super::types::PullRequest]) {
     // Borders take: 1 + 1 + 3 + 3 + 1 = 9 chars (│ S │ ... │ ... │)
     let status_col = 1;
     let border_overhead = 10; // "│ " + " │ " + " │ " + "│"
+    let tag_col = if tags.is_some() { TAG_WIDTH + 3 } else { 0 };
 
-    let available = term_width.saturating_sub(border_overhead + status_col + max_link_len);
+    let available =
+        term_width.saturating_sub(border_overhead + status_col + tag_col + max_link_len);
     let title_width = available.max(20);
     let link_width = max_link_len;
+    let tag_border = if tags.is_some() {
+        format!("{}┬", "─".repeat(TAG_WIDTH + 2))
+    } else {
+        String::new()
+    };
 
     // Top border
     println!(
-        "┌───┬{}┬{}┐",
+        "┌───┬{}{}┬{}┐",
+        tag_border,
         "─".repeat(title_width + 2),
         "─".repeat(link_width + 2)
     );
 
     // Rows
-    for pr in prs {
+    for (i, pr) in prs.iter().enumerate() {
         let status_icon = match pr.ci_status.unwrap_or(CiStatus::Unknown) {
             CiStatus::Success => format!("{}{}{}", GREEN, "✓", RESET),
             CiStatus::Pending => format!("{}{}{}", YELLOW, "◐", RESET),
@@ -55,10 +73,15 @@ fn print_prs_table(prs: &[super::types::PullRequest]) {
 
         let title = truncate(&pr.title, title_width);
         let link = format!("{}{}{}", GRAY, &pr.html_url, RESET);
+        let tag = tags
+            .and_then(|t| t.get(i))
+            .map(|reason| format!("{:<width$} │ ", reason.label(), width = TAG_WIDTH))
+            .unwrap_or_default();
 
         println!(
-            "│ {} │ {:<width$} │ {} │",
+            "│ {} │ {}{:<width$} │ {} │",
             status_icon,
+            tag,
             title,
             link,
             width = title_width
@@ -67,41 +90,32 @@ fn print_prs_table(prs: &[super::types::PullRequest]) {
 
     // Bottom border
     println!(
-        "└───┴{}┴{}┘",
+        "└───┴{}{}┴{}┘",
+        tag_border.replace('┬', "┴"),
         "─".repeat(title_width + 2),
         "─".repeat(link_width + 2)
     );
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.chars().count() <= max_len {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max_len.saturating_sub(1)).collect();
-        format!("{}…", truncated)
-    }
-}
-
 /// Fetch and display PRs using the given API client
-pub async fn run_with_client(client: &impl GithubApi) -> Result<()> {
-    let mut prs = client.list_user_prs().await?;
-
-    if prs.is_empty() {
+///
+/// Rows are tagged with their reason when both sources are listed.
+pub async fn run_with_client(
+    client: &impl GithubApi,
+    mine: bool,
+    reviews: bool,
+    concurrency: usize,
+) -> Result<()> {
+    let rows = super::service::list_triage_prs(client, mine, reviews, concurrency).await?;
+
+    if rows.is_empty() {
         println!("No open pull requests found.");
         return Ok(());
     }
 
-    // Fetch CI status for each PR
-    for pr in &mut prs {
-        let parts: Vec<&str> = pr.repo_full_name.split('/').collect();
-        if parts.len() == 2 {
-            if let Ok(status) = client.get_ci_status(parts[0], parts[1], pr.number).await {
-                pr.ci_status = Some(status);
-            }
-        }
-    }
-
-    print_prs_table(&prs);
+    let (reasons, prs): (Vec<PrReason>, Vec<_>) = rows.into_iter().unzip();
+    let tags = (mine && reviews).then_some(reasons.as_slice());
+    print_prs_table(&prs, tags);
     Ok(())
 }
 
@@ -110,43 +124,6 @@ mod tests {
     use super::*;
     use crate::gh::types::PullRequest;
 
-    #[test]
-    fn truncate_short_string() {
-        assert_eq!(truncate("hello", 10), "hello");
-    }
-
-    #[test]
-    fn truncate_long_string() {
-        assert_eq!(truncate("hello world", 8), "hello w…");
-    }
-
-    #[test]
-    fn truncate_exact_length() {
-        assert_eq!(truncate("hello", 5), "hello");
-    }
-
-    #[test]
-    fn truncate_unicode() {
-        // Unicode chars are counted by char, not byte
-        assert_eq!(truncate("héllo", 5), "héllo");
-        assert_eq!(truncate("héllo world", 6), "héllo…");
-    }
-
-    #[test]
-    fn truncate_empty() {
-        assert_eq!(truncate("", 10), "");
-    }
-
-    #[test]
-    fn truncate_zero_length() {
-        // Edge case: max_len = 0 means we try to take 0 chars + ellipsis
-        // saturating_sub(1) on 0 = 0, so we get just "…" if string is not empty
-        let result = truncate("hello", 0);
-        // With max_len=0, chars.count()=5 > 0, so we truncate
-        // take(0.saturating_sub(1)) = take(0), so we get "" + "…" = "…"
-        assert_eq!(result, "…");
-    }
-
     #[test]
     fn status_icons_render() {
         let _ = format!("{}✓{}", GREEN, RESET);
@@ -196,6 +173,7 @@ mod tests {
                 html_url: "https://github.com/o/r/pull/1".to_string(),
                 state: "open".to_string(),
                 repo_full_name: "o/r".to_string(),
+                author: String::new(),
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 ci_status: Some(CiStatus::Success),
@@ -206,6 +184,7 @@ mod tests {
                 html_url: "https://github.com/owner/repo/pull/2".to_string(),
                 state: "open".to_string(),
                 repo_full_name: "owner/repo".to_string(),
+                author: String::new(),
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 ci_status: Some(CiStatus::Failed),
@@ -216,6 +195,7 @@ mod tests {
                 html_url: "https://github.com/o/r/pull/3".to_string(),
                 state: "open".to_string(),
                 repo_full_name: "o/r".to_string(),
+                author: String::new(),
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 ci_status: Some(CiStatus::Pending),
@@ -226,19 +206,27 @@ mod tests {
                 html_url: "https://github.com/o/r/pull/4".to_string(),
                 state: "open".to_string(),
                 repo_full_name: "o/r".to_string(),
+                author: String::new(),
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 ci_status: None,
             },
         ];
         // This just verifies it doesn't panic
-        print_prs_table(&prs);
+        print_prs_table(&prs, None);
+        let tags = [
+            PrReason::Author,
+            PrReason::ReviewRequested,
+            PrReason::Author,
+            PrReason::ReviewRequested,
+        ];
+        print_prs_table(&prs, Some(&tags));
     }
 
     #[test]
     fn print_prs_table_empty_list() {
         let prs: Vec<PullRequest> = vec![];
-        print_prs_table(&prs);
+        print_prs_table(&prs, None);
     }
 
     // Mock implementation for testing
@@ -252,6 +240,47 @@ mod tests {
             Ok(self.prs.clone())
         }
 
+        async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+            Ok(vec![])
+        }
+
+        async fn get_pr_head_repo(
+            &self,
+            owner: &str,
+            repo: &str,
+            _pr: u64,
+        ) -> Result<Option<String>> {
+            Ok(Some(format!("{}/{}", owner, repo)))
+        }
+
+        async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+            Ok("octocat".to_string())
+        }
+
+        async fn get_current_login(&self) -> Result<String> {
+            Ok("me".to_string())
+        }
+
+        async fn review_pr(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _pr: u64,
+            _review: &crate::gh::types::PrReview<'_>,
+        ) -> Result<String> {
+            Ok(String::new())
+        }
+
+        async fn comment_on_pr(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _pr: u64,
+            _body: &str,
+        ) -> Result<String> {
+            Ok(String::new())
+        }
+
         async fn get_ci_status(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<CiStatus> {
             Ok(self.ci_status)
         }
@@ -282,10 +311,23 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_run_jobs(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _run_id: u64,
+        ) -> Result<Vec<crate::gh::types::WorkflowJob>> {
+            Ok(vec![])
+        }
+
         async fn get_job_logs(&self, _owner: &str, _repo: &str, _job_id: u64) -> Result<String> {
             Ok(String::new())
         }
 
+        async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, _run_id: u64) -> Result<()> {
+            Ok(())
+        }
+
         async fn find_pr_for_branch(
             &self,
             _owner: &str,
@@ -318,7 +360,7 @@ mod tests {
             prs: vec![],
             ci_status: CiStatus::Unknown,
         };
-        let result = run_with_client(&mock).await;
+        let result = run_with_client(&mock, true, false, 4).await;
         assert!(result.is_ok());
     }
 
@@ -331,13 +373,16 @@ mod tests {
                 html_url: "https://github.com/o/r/pull/1".to_string(),
                 state: "open".to_string(),
                 repo_full_name: "o/r".to_string(),
+                author: String::new(),
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 ci_status: None,
             }],
             ci_status: CiStatus::Success,
         };
-        let result = run_with_client(&mock).await;
+        let result = run_with_client(&mock, true, false, 4).await;
+        assert!(result.is_ok());
+        let result = run_with_client(&mock, true, true, 4).await;
         assert!(result.is_ok());
     }
 }
@@ -0,0 +1,112 @@
+use anstream::println;
+use anyhow::Result;
+
+use super::cli::RerunArgs;
+use super::client::{GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+
+// ANSI color codes
+const GREEN: &str = "\x1b[32m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Handle the `hu gh rerun` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: RerunArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+
+    match rerun_pr(&client, &owner, &repo, args.pr).await? {
+        Some(run_id) => {
+            println!(
+                "{GREEN}✓{RESET} Re-running failed jobs for run {} (PR #{})",
+                run_id, args.pr
+            );
+            println!("  {GRAY}{}{RESET}", run_url(&owner, &repo, run_id));
+        }
+        None => println!("No failed workflow runs found for PR #{}.", args.pr),
+    }
+    Ok(())
+}
+
+/// Re-run failed jobs of the latest failed run on a PR's branch (testable)
+///
+/// Returns the run ID that was re-triggered, or `None` if the branch has no
+/// failed runs.
+pub async fn rerun_pr(
+    client: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Option<u64>> {
+    let branch = client.get_pr_branch(owner, repo, pr_number).await?;
+
+    let Some(run_id) = client
+        .get_latest_failed_run_for_branch(owner, repo, &branch)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    client.rerun_failed_jobs(owner, repo, run_id).await?;
+    Ok(Some(run_id))
+}
+
+/// Build the web URL for a workflow run
+pub fn run_url(owner: &str, repo: &str, run_id: u64) -> String {
+    format!(
+        "https://github.com/{}/{}/actions/runs/{}",
+        owner, repo, run_id
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+
+    fn mock(failed_run: Option<u64>) -> MockGithubApi {
+        MockGithubApi {
+            failed_run,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn rerun_pr_triggers_latest_failed_run() {
+        let mock = mock(Some(987));
+        let result = rerun_pr(&mock, "owner", "repo", 42).await.unwrap();
+        assert_eq!(result, Some(987));
+        assert_eq!(*mock.reruns.lock().unwrap(), vec![987]);
+    }
+
+    #[tokio::test]
+    async fn rerun_pr_no_failed_runs() {
+        let mock = mock(None);
+        let result = rerun_pr(&mock, "owner", "repo", 42).await.unwrap();
+        assert!(result.is_none());
+        assert!(mock.reruns.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rerun_pr_propagates_error() {
+        let mock = MockGithubApi {
+            failed_run: Some(1),
+            rerun_error: Some("forbidden"),
+            ..Default::default()
+        };
+        let result = rerun_pr(&mock, "owner", "repo", 42).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn run_url_format() {
+        assert_eq!(
+            run_url("owner", "repo", 123),
+            "https://github.com/owner/repo/actions/runs/123"
+        );
+    }
+}
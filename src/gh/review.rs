@@ -0,0 +1,183 @@
+use anstream::println;
+use anyhow::{bail, Result};
+
+use super::cli::{CommentArgs, ReviewArgs};
+use super::client::{GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+use super::types::{PrReview, ReviewEvent};
+
+// ANSI color codes
+const GREEN: &str = "\x1b[32m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Handle `hu gh approve` and `hu gh request-changes`
+#[cfg(not(tarpaulin_include))]
+pub async fn run_review(args: ReviewArgs, event: ReviewEvent) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+
+    let review = PrReview {
+        event,
+        body: args.body.as_deref(),
+    };
+    let url = submit_review(&client, &owner, &repo, args.pr, &review).await?;
+
+    let verb = match event {
+        ReviewEvent::Approve => "Approved",
+        ReviewEvent::RequestChanges => "Requested changes on",
+        ReviewEvent::Comment => "Reviewed",
+    };
+    println!("{GREEN}✓{RESET} {} PR #{}", verb, args.pr);
+    if !url.is_empty() {
+        println!("  {GRAY}{}{RESET}", url);
+    }
+    Ok(())
+}
+
+/// Handle `hu gh comment`
+#[cfg(not(tarpaulin_include))]
+pub async fn run_comment(args: CommentArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+
+    if args.text.trim().is_empty() {
+        bail!("Comment text is empty");
+    }
+    let url = client
+        .comment_on_pr(&owner, &repo, args.pr, &args.text)
+        .await?;
+
+    println!("{GREEN}✓{RESET} Commented on PR #{}", args.pr);
+    if !url.is_empty() {
+        println!("  {GRAY}{}{RESET}", url);
+    }
+    Ok(())
+}
+
+/// Submit a review, refusing what GitHub would reject (testable)
+///
+/// Returns the review's URL.
+pub async fn submit_review(
+    client: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    review: &PrReview<'_>,
+) -> Result<String> {
+    if review.event == ReviewEvent::RequestChanges && review.body.map_or(true, str::is_empty) {
+        bail!("Requesting changes needs a --body saying what to change");
+    }
+
+    if review.event != ReviewEvent::Comment {
+        let author = client.get_pr_author(owner, repo, pr_number).await?;
+        let me = client.get_current_login().await?;
+        check_not_own_pr(review.event, &author, &me, pr_number)?;
+    }
+
+    client.review_pr(owner, repo, pr_number, review).await
+}
+
+/// GitHub doesn't let authors approve or request changes on their own PR
+fn check_not_own_pr(event: ReviewEvent, author: &str, me: &str, pr_number: u64) -> Result<()> {
+    if !author.eq_ignore_ascii_case(me) {
+        return Ok(());
+    }
+    match event {
+        ReviewEvent::Approve => bail!(
+            "PR #{} is yours; GitHub doesn't allow approving your own pull request",
+            pr_number
+        ),
+        ReviewEvent::RequestChanges => bail!(
+            "PR #{} is yours; GitHub doesn't allow requesting changes on your own pull request. \
+             Use `hu gh comment` instead",
+            pr_number
+        ),
+        ReviewEvent::Comment => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+
+    fn mock(author: &str) -> MockGithubApi {
+        MockGithubApi {
+            author: author.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn review(event: ReviewEvent, body: Option<&str>) -> PrReview<'_> {
+        PrReview { event, body }
+    }
+
+    #[tokio::test]
+    async fn approve_someone_elses_pr() {
+        let mock = mock("octocat");
+        let review = review(ReviewEvent::Approve, None);
+        let url = submit_review(&mock, "o", "r", 7, &review).await.unwrap();
+        assert!(url.contains("pullrequestreview"));
+        assert_eq!(
+            *mock.reviews.lock().unwrap(),
+            vec![(7, ReviewEvent::Approve, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn approve_own_pr_refused() {
+        let mock = mock("Me");
+        let review = review(ReviewEvent::Approve, None);
+        let err = submit_review(&mock, "o", "r", 7, &review)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("approving your own"));
+        assert!(mock.reviews.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn request_changes_needs_body() {
+        let mock = mock("octocat");
+        let review = review(ReviewEvent::RequestChanges, Some(""));
+        let err = submit_review(&mock, "o", "r", 7, &review)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--body"));
+    }
+
+    #[tokio::test]
+    async fn request_changes_sends_body() {
+        let mock = mock("octocat");
+        let review = review(ReviewEvent::RequestChanges, Some("Add a test"));
+        submit_review(&mock, "o", "r", 7, &review).await.unwrap();
+        assert_eq!(
+            *mock.reviews.lock().unwrap(),
+            vec![(
+                7,
+                ReviewEvent::RequestChanges,
+                Some("Add a test".to_string())
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn comment_review_allowed_on_own_pr() {
+        let mock = mock("me");
+        let review = review(ReviewEvent::Comment, Some("Note to self"));
+        assert!(submit_review(&mock, "o", "r", 7, &review).await.is_ok());
+    }
+
+    #[test]
+    fn check_not_own_pr_request_changes_points_to_comment() {
+        let err = check_not_own_pr(ReviewEvent::RequestChanges, "me", "me", 3).unwrap_err();
+        assert!(err.to_string().contains("hu gh comment"));
+        assert!(check_not_own_pr(ReviewEvent::Approve, "octocat", "me", 3).is_ok());
+    }
+}
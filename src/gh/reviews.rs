@@ -0,0 +1,173 @@
+use anstream::println;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::util::fmt::truncate;
+use crate::util::{print_structured, OutputFormat};
+
+use super::cli::ReviewsArgs;
+use super::client::{GithubApi, GithubClient};
+use super::types::PullRequest;
+
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Handle the `hu gh reviews` command
+pub async fn run(args: ReviewsArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    run_with_client(&client, OutputFormat::resolve(args.json)).await
+}
+
+/// Fetch and display PRs awaiting review using the given API client
+pub async fn run_with_client(client: &impl GithubApi, format: OutputFormat) -> Result<()> {
+    let prs = super::service::list_review_requests(client).await?;
+
+    if format != OutputFormat::Table {
+        return print_structured(&prs, format);
+    }
+
+    if prs.is_empty() {
+        println!("No pull requests waiting for your review.");
+        return Ok(());
+    }
+
+    print_reviews_table(&prs, Utc::now());
+    Ok(())
+}
+
+/// Compact age like `45m`, `3h` or `2d` since an RFC 3339 timestamp
+///
+/// GitHub's search doesn't say when the review was requested, so this is
+/// the PR's age; `?` if the timestamp doesn't parse.
+pub fn format_age(timestamp: &str, now: DateTime<Utc>) -> String {
+    let Ok(then) = DateTime::parse_from_rfc3339(timestamp) else {
+        return "?".to_string();
+    };
+    let minutes = (now - then.with_timezone(&Utc)).num_minutes().max(0);
+    match minutes {
+        0..=59 => format!("{}m", minutes),
+        60..=1439 => format!("{}h", minutes / 60),
+        _ => format!("{}d", minutes / 1440),
+    }
+}
+
+fn get_terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(w, _)| w.0 as usize)
+        .unwrap_or(80)
+}
+
+fn print_reviews_table(prs: &[PullRequest], now: DateTime<Utc>) {
+    let term_width = get_terminal_width();
+
+    let refs: Vec<String> = prs
+        .iter()
+        .map(|p| format!("{}#{}", p.repo_full_name, p.number))
+        .collect();
+    let ages: Vec<String> = prs.iter().map(|p| format_age(&p.created_at, now)).collect();
+
+    let ref_width = refs.iter().map(|r| r.chars().count()).max().unwrap_or(10);
+    let author_width = prs
+        .iter()
+        .map(|p| p.author.chars().count())
+        .max()
+        .unwrap_or(6)
+        .clamp(6, 20);
+    let age_width = ages.iter().map(|a| a.len()).max().unwrap_or(3).max(3);
+    let link_width = prs.iter().map(|p| p.html_url.len()).max().unwrap_or(40);
+
+    // Layout: │ Repo#N │ Title │ Author │ Age │ Link │
+    let border_overhead = 16; // "│ " + 4 × " │ " + " │"
+    let available = term_width
+        .saturating_sub(border_overhead + ref_width + author_width + age_width + link_width);
+    let title_width = available.max(20);
+
+    let widths = [ref_width, title_width, author_width, age_width, link_width];
+    let rule = |left: &str, mid: &str, right: &str| {
+        let cells: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{}{}{}", left, cells.join(mid), right)
+    };
+
+    println!("{}", rule("┌", "┬", "┐"));
+    for ((pr, pr_ref), age) in prs.iter().zip(&refs).zip(&ages) {
+        println!(
+            "│ {:<rw$} │ {:<tw$} │ {:<aw$} │ {:>gw$} │ {}{}{} │",
+            pr_ref,
+            truncate(&pr.title, title_width),
+            truncate(&pr.author, author_width),
+            age,
+            GRAY,
+            pr.html_url,
+            RESET,
+            rw = ref_width,
+            tw = title_width,
+            aw = author_width,
+            gw = age_width,
+        );
+    }
+    println!("{}", rule("└", "┴", "┘"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn pr(number: u64, author: &str) -> PullRequest {
+        PullRequest {
+            number,
+            title: "Add retry to the importer".to_string(),
+            html_url: format!("https://github.com/o/r/pull/{}", number),
+            state: "open".to_string(),
+            repo_full_name: "o/r".to_string(),
+            author: author.to_string(),
+            created_at: "2026-03-08T09:00:00Z".to_string(),
+            updated_at: "2026-03-09T09:00:00Z".to_string(),
+            ci_status: None,
+        }
+    }
+
+    #[test]
+    fn format_age_units() {
+        assert_eq!(format_age("2026-03-10T11:15:00Z", now()), "45m");
+        assert_eq!(format_age("2026-03-10T09:00:00Z", now()), "3h");
+        assert_eq!(format_age("2026-03-08T09:00:00+00:00", now()), "2d");
+    }
+
+    #[test]
+    fn format_age_future_and_garbage() {
+        assert_eq!(format_age("2026-03-10T13:00:00Z", now()), "0m");
+        assert_eq!(format_age("", now()), "?");
+    }
+
+    #[test]
+    fn print_reviews_table_renders_without_panic() {
+        print_reviews_table(
+            &[pr(1, "octocat"), pr(22, "a-rather-long-login-name")],
+            now(),
+        );
+    }
+
+    #[tokio::test]
+    async fn run_with_client_empty() {
+        let mock = MockGithubApi::default();
+        assert!(run_with_client(&mock, OutputFormat::Table).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn run_with_client_table_json_and_csv() {
+        let mock = MockGithubApi {
+            review_requests: vec![pr(1, "octocat")],
+            ..Default::default()
+        };
+        assert!(run_with_client(&mock, OutputFormat::Table).await.is_ok());
+        assert!(run_with_client(&mock, OutputFormat::Json).await.is_ok());
+        assert!(run_with_client(&mock, OutputFormat::Csv).await.is_ok());
+    }
+}
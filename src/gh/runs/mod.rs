@@ -1,5 +1,9 @@
+use anstream::println;
 use anyhow::Result;
 
+use crate::util::fmt::truncate;
+use crate::util::{print_structured, OutputFormat};
+
 use super::cli::RunsArgs;
 use super::client::{GithubApi, GithubClient};
 use super::helpers::{get_current_repo, parse_owner_repo};
@@ -50,10 +54,9 @@ pub async fn run_with_client(
         return Ok(());
     }
 
-    if args.json {
-        print_runs_json(&runs);
-    } else {
-        print_runs_table(&runs);
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => print_runs_table(&runs),
+        format => print_structured(&runs, format)?,
     }
 
     Ok(())
@@ -127,15 +130,6 @@ fn get_terminal_width() -> usize {
         .unwrap_or(80)
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.chars().count() <= max_len {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max_len.saturating_sub(1)).collect();
-        format!("{truncated}…")
-    }
-}
-
 fn print_runs_table(runs: &[WorkflowRun]) {
     let term_width = get_terminal_width();
 
@@ -190,8 +184,3 @@ fn print_runs_table(runs: &[WorkflowRun]) {
         "─".repeat(link_width + 2),
     );
 }
-
-fn print_runs_json(runs: &[WorkflowRun]) {
-    let json = serde_json::to_string_pretty(runs).unwrap_or_default();
-    println!("{json}");
-}
@@ -40,6 +40,42 @@ impl GithubApi for MockGithubApi {
         Ok(self.prs.clone())
     }
 
+    async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+        Ok(vec![])
+    }
+
+    async fn get_pr_head_repo(&self, owner: &str, repo: &str, _pr: u64) -> Result<Option<String>> {
+        Ok(Some(format!("{}/{}", owner, repo)))
+    }
+
+    async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok("octocat".to_string())
+    }
+
+    async fn get_current_login(&self) -> Result<String> {
+        Ok("me".to_string())
+    }
+
+    async fn review_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _review: &crate::gh::types::PrReview<'_>,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn comment_on_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _body: &str,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
     async fn get_ci_status(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<CiStatus> {
         Ok(CiStatus::Unknown)
     }
@@ -74,10 +110,23 @@ impl GithubApi for MockGithubApi {
         Ok(vec![])
     }
 
+    async fn list_run_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<crate::gh::types::WorkflowJob>> {
+        Ok(vec![])
+    }
+
     async fn get_job_logs(&self, _owner: &str, _repo: &str, _job_id: u64) -> Result<String> {
         Ok(String::new())
     }
 
+    async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, _run_id: u64) -> Result<()> {
+        Ok(())
+    }
+
     async fn find_pr_for_branch(
         &self,
         _owner: &str,
@@ -146,6 +195,7 @@ fn make_pr(number: u64, title: &str) -> PullRequest {
         html_url: format!("https://github.com/o/r/pull/{number}"),
         state: "open".to_string(),
         repo_full_name: "o/r".to_string(),
+        author: String::new(),
         created_at: "2024-01-01T00:00:00Z".to_string(),
         updated_at: "2024-01-01T00:00:00Z".to_string(),
         ci_status: None,
@@ -211,27 +261,6 @@ fn status_icon_unknown_status() {
     assert!(icon.contains("○"));
 }
 
-// truncate tests
-#[test]
-fn truncate_short() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn truncate_long() {
-    assert_eq!(truncate("hello world", 8), "hello w…");
-}
-
-#[test]
-fn truncate_exact() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn truncate_empty() {
-    assert_eq!(truncate("", 10), "");
-}
-
 // print_runs_table tests
 #[test]
 fn print_runs_table_renders_without_panic() {
@@ -261,17 +290,18 @@ fn print_runs_table_long_names() {
     print_runs_table(&runs);
 }
 
-// print_runs_json tests
+// structured output tests
 #[test]
-fn print_runs_json_renders() {
+fn print_runs_structured_renders() {
     let runs = vec![make_run(1, "CI", "completed", Some("success"), "main")];
-    print_runs_json(&runs);
+    print_structured(&runs, OutputFormat::Json).unwrap();
+    print_structured(&runs, OutputFormat::Csv).unwrap();
 }
 
 #[test]
-fn print_runs_json_empty() {
+fn print_runs_structured_empty() {
     let runs: Vec<WorkflowRun> = vec![];
-    print_runs_json(&runs);
+    print_structured(&runs, OutputFormat::Json).unwrap();
 }
 
 // get_terminal_width test
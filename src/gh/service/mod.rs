@@ -0,0 +1,123 @@
+//! GitHub service layer - business logic that returns data
+//!
+//! Functions in this module accept trait objects and return typed data.
+//! They never print - that's the CLI layer's job.
+
+use anyhow::Result;
+
+use super::client::{GithubApi, GithubClient};
+use super::types::{CiStatus, PullRequest, RunsQuery, WorkflowRun};
+
+mod prs;
+
+pub use prs::{
+    list_review_requests, list_triage_prs, list_user_prs, list_user_prs_with_ci,
+    DEFAULT_CI_CONCURRENCY,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Get CI status for a PR
+pub async fn get_ci_status(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<CiStatus> {
+    api.get_ci_status(owner, repo, pr_number).await
+}
+
+/// Get the branch name for a PR
+#[allow(dead_code)]
+pub async fn get_pr_branch(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<String> {
+    api.get_pr_branch(owner, repo, pr_number).await
+}
+
+/// Get the latest failed workflow run for a branch
+#[allow(dead_code)]
+pub async fn get_latest_failed_run(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<u64>> {
+    api.get_latest_failed_run_for_branch(owner, repo, branch)
+        .await
+}
+
+/// Get failed jobs for a workflow run
+pub async fn get_failed_jobs(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<Vec<(u64, String)>> {
+    api.get_failed_jobs(owner, repo, run_id).await
+}
+
+/// Failed jobs of the latest failed run on a PR's branch
+pub async fn failed_jobs_for_pr(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Vec<(u64, String)>> {
+    let branch = api.get_pr_branch(owner, repo, pr_number).await?;
+    match api
+        .get_latest_failed_run_for_branch(owner, repo, &branch)
+        .await?
+    {
+        Some(run_id) => api.get_failed_jobs(owner, repo, run_id).await,
+        None => Ok(vec![]),
+    }
+}
+
+/// Download logs for a job
+#[allow(dead_code)]
+pub async fn get_job_logs(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    job_id: u64,
+) -> Result<String> {
+    api.get_job_logs(owner, repo, job_id).await
+}
+
+/// Find PR number for a branch
+pub async fn find_pr_for_branch(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<u64>> {
+    api.find_pr_for_branch(owner, repo, branch).await
+}
+
+/// List workflow runs for a repository
+pub async fn list_workflow_runs(
+    api: &impl GithubApi,
+    query: &RunsQuery<'_>,
+) -> Result<Vec<WorkflowRun>> {
+    api.list_workflow_runs(query).await
+}
+
+/// Search PRs by title/branch containing a query string
+pub async fn search_prs(
+    api: &impl GithubApi,
+    owner: &str,
+    repo: &str,
+    query: &str,
+) -> Result<Vec<PullRequest>> {
+    api.search_prs_by_title(owner, repo, query).await
+}
+
+/// Create a new authenticated client
+pub fn create_client() -> Result<GithubClient> {
+    GithubClient::new()
+}
@@ -0,0 +1,85 @@
+//! PR listing, with CI status looked up concurrently
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+
+use super::super::client::GithubApi;
+use super::super::types::{CiStatus, PrReason, PullRequest};
+
+/// List open PRs authored by the current user
+pub async fn list_user_prs(api: &impl GithubApi) -> Result<Vec<PullRequest>> {
+    api.list_user_prs().await
+}
+
+/// List open PRs where the current user's review is requested
+pub async fn list_review_requests(api: &impl GithubApi) -> Result<Vec<PullRequest>> {
+    api.list_review_requests().await
+}
+
+/// CI status lookups in flight at once when listing PRs
+pub const DEFAULT_CI_CONCURRENCY: usize = 4;
+
+/// List open PRs authored by the current user with their CI status filled in
+///
+/// Statuses are fetched concurrently, at most `concurrency` at a time. A PR
+/// whose status can't be fetched is marked `Unknown` rather than failing
+/// the whole list.
+pub async fn list_user_prs_with_ci(
+    api: &impl GithubApi,
+    concurrency: usize,
+) -> Result<Vec<PullRequest>> {
+    let prs = api.list_user_prs().await?;
+    Ok(with_ci_status(api, prs, concurrency).await)
+}
+
+/// PRs authored by the current user (`mine`) and/or awaiting their review
+/// (`reviews`), each tagged with why it is listed, CI status filled in
+pub async fn list_triage_prs(
+    api: &impl GithubApi,
+    mine: bool,
+    reviews: bool,
+    concurrency: usize,
+) -> Result<Vec<(PrReason, PullRequest)>> {
+    let mut tagged = Vec::new();
+    if mine {
+        let prs = api.list_user_prs().await?;
+        tagged.extend(prs.into_iter().map(|pr| (PrReason::Author, pr)));
+    }
+    if reviews {
+        let prs = api.list_review_requests().await?;
+        tagged.extend(prs.into_iter().map(|pr| (PrReason::ReviewRequested, pr)));
+    }
+
+    let (reasons, prs): (Vec<PrReason>, Vec<PullRequest>) = tagged.into_iter().unzip();
+    let prs = with_ci_status(api, prs, concurrency).await;
+    Ok(reasons.into_iter().zip(prs).collect())
+}
+
+/// Fill in each PR's CI status, at most `concurrency` lookups at a time
+async fn with_ci_status(
+    api: &impl GithubApi,
+    prs: Vec<PullRequest>,
+    concurrency: usize,
+) -> Vec<PullRequest> {
+    let statuses: Vec<CiStatus> = stream::iter(&prs)
+        .map(|pr| async move {
+            match pr.repo_full_name.split_once('/') {
+                Some((owner, repo)) => api
+                    .get_ci_status(owner, repo, pr.number)
+                    .await
+                    .unwrap_or(CiStatus::Unknown),
+                None => CiStatus::Unknown,
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    prs.into_iter()
+        .zip(statuses)
+        .map(|(pr, status)| PullRequest {
+            ci_status: Some(status),
+            ..pr
+        })
+        .collect()
+}
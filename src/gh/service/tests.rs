@@ -0,0 +1,321 @@
+use super::*;
+use crate::gh::types::PrReason;
+
+/// PR number whose CI lookup errors in the mock
+const FAILING_PR: u64 = 99;
+
+struct MockApi {
+    prs: Vec<PullRequest>,
+    reviews: Vec<PullRequest>,
+    runs: Vec<WorkflowRun>,
+}
+
+impl MockApi {
+    fn new() -> Self {
+        Self {
+            prs: vec![],
+            reviews: vec![],
+            runs: vec![],
+        }
+    }
+
+    fn with_prs(mut self, prs: Vec<PullRequest>) -> Self {
+        self.prs = prs;
+        self
+    }
+
+    fn with_reviews(mut self, reviews: Vec<PullRequest>) -> Self {
+        self.reviews = reviews;
+        self
+    }
+
+    fn with_runs(mut self, runs: Vec<WorkflowRun>) -> Self {
+        self.runs = runs;
+        self
+    }
+}
+
+impl GithubApi for MockApi {
+    async fn list_user_prs(&self) -> Result<Vec<PullRequest>> {
+        Ok(self.prs.clone())
+    }
+
+    async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+        Ok(self.reviews.clone())
+    }
+
+    async fn get_pr_head_repo(&self, owner: &str, repo: &str, _pr: u64) -> Result<Option<String>> {
+        Ok(Some(format!("{}/{}", owner, repo)))
+    }
+
+    async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok("octocat".to_string())
+    }
+
+    async fn get_current_login(&self) -> Result<String> {
+        Ok("me".to_string())
+    }
+
+    async fn review_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _review: &crate::gh::types::PrReview<'_>,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn comment_on_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _body: &str,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn get_ci_status(&self, _owner: &str, _repo: &str, pr: u64) -> Result<CiStatus> {
+        if pr == FAILING_PR {
+            anyhow::bail!("secondary rate limit");
+        }
+        Ok(CiStatus::Success)
+    }
+
+    async fn get_pr_branch(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok("main".to_string())
+    }
+
+    async fn get_latest_failed_run_for_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<Option<u64>> {
+        Ok(self.runs.first().map(|r| r.id))
+    }
+
+    async fn get_latest_failed_run(&self, _owner: &str, _repo: &str) -> Result<Option<u64>> {
+        Ok(self.runs.first().map(|r| r.id))
+    }
+
+    async fn get_failed_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<(u64, String)>> {
+        Ok(vec![(123, "test".to_string())])
+    }
+
+    async fn list_run_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<crate::gh::types::WorkflowJob>> {
+        Ok(vec![])
+    }
+
+    async fn get_job_logs(&self, _owner: &str, _repo: &str, _job: u64) -> Result<String> {
+        Ok("Test logs".to_string())
+    }
+
+    async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, _run_id: u64) -> Result<()> {
+        Ok(())
+    }
+
+    async fn find_pr_for_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<Option<u64>> {
+        Ok(self.prs.first().map(|p| p.number))
+    }
+
+    async fn list_workflow_runs(&self, _query: &RunsQuery<'_>) -> Result<Vec<WorkflowRun>> {
+        Ok(self.runs.clone())
+    }
+
+    async fn search_prs_by_title(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        query: &str,
+    ) -> Result<Vec<PullRequest>> {
+        let query_lower = query.to_lowercase();
+        Ok(self
+            .prs
+            .iter()
+            .filter(|p| p.title.to_lowercase().contains(&query_lower))
+            .cloned()
+            .collect())
+    }
+}
+
+fn make_pr(number: u64, title: &str) -> PullRequest {
+    PullRequest {
+        number,
+        title: title.to_string(),
+        html_url: format!("https://github.com/owner/repo/pull/{}", number),
+        state: "open".to_string(),
+        repo_full_name: "owner/repo".to_string(),
+        author: String::new(),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: "2024-01-01T00:00:00Z".to_string(),
+        ci_status: None,
+    }
+}
+
+fn make_run(id: u64, name: &str, status: &str) -> WorkflowRun {
+    WorkflowRun {
+        id,
+        name: name.to_string(),
+        status: status.to_string(),
+        conclusion: Some("success".to_string()),
+        branch: "main".to_string(),
+        html_url: format!("https://github.com/owner/repo/actions/runs/{}", id),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: "2024-01-01T00:00:00Z".to_string(),
+        run_number: id,
+    }
+}
+
+#[tokio::test]
+async fn list_user_prs_returns_all() {
+    let api = MockApi::new().with_prs(vec![make_pr(1, "Fix bug"), make_pr(2, "Add feature")]);
+
+    let result = list_user_prs(&api).await.unwrap();
+    assert_eq!(result.len(), 2);
+}
+
+#[tokio::test]
+async fn list_user_prs_with_ci_fills_status() {
+    let mut odd = make_pr(2, "No owner");
+    odd.repo_full_name = "repo-only".to_string();
+    let prs = vec![make_pr(1, "Fix bug"), odd, make_pr(FAILING_PR, "Flaky")];
+    let api = MockApi::new().with_prs(prs);
+
+    let result = list_user_prs_with_ci(&api, 2).await.unwrap();
+    let numbers: Vec<u64> = result.iter().map(|p| p.number).collect();
+    assert_eq!(numbers, vec![1, 2, FAILING_PR]);
+    assert_eq!(result[0].ci_status, Some(CiStatus::Success));
+    assert_eq!(result[1].ci_status, Some(CiStatus::Unknown));
+    // One PR's error doesn't abort the batch
+    assert_eq!(result[2].ci_status, Some(CiStatus::Unknown));
+}
+
+#[tokio::test]
+async fn list_user_prs_with_ci_zero_concurrency_still_runs() {
+    let api = MockApi::new().with_prs(vec![make_pr(1, "Fix bug")]);
+    let result = list_user_prs_with_ci(&api, 0).await.unwrap();
+    assert_eq!(result[0].ci_status, Some(CiStatus::Success));
+}
+
+#[tokio::test]
+async fn list_review_requests_returns_all() {
+    let api = MockApi::new().with_reviews(vec![make_pr(3, "Review me")]);
+    let result = list_review_requests(&api).await.unwrap();
+    assert_eq!(result[0].number, 3);
+}
+
+#[tokio::test]
+async fn list_triage_prs_tags_each_source() {
+    let api = MockApi::new()
+        .with_prs(vec![make_pr(1, "Mine")])
+        .with_reviews(vec![make_pr(2, "Theirs"), make_pr(FAILING_PR, "Flaky")]);
+
+    let result = list_triage_prs(&api, true, true, 4).await.unwrap();
+    let tags: Vec<(PrReason, u64)> = result.iter().map(|(r, p)| (*r, p.number)).collect();
+    assert_eq!(
+        tags,
+        vec![
+            (PrReason::Author, 1),
+            (PrReason::ReviewRequested, 2),
+            (PrReason::ReviewRequested, FAILING_PR),
+        ]
+    );
+    assert_eq!(result[1].1.ci_status, Some(CiStatus::Success));
+    assert_eq!(result[2].1.ci_status, Some(CiStatus::Unknown));
+}
+
+#[tokio::test]
+async fn list_triage_prs_reviews_only() {
+    let api = MockApi::new()
+        .with_prs(vec![make_pr(1, "Mine")])
+        .with_reviews(vec![make_pr(2, "Theirs")]);
+
+    let result = list_triage_prs(&api, false, true, 4).await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, PrReason::ReviewRequested);
+}
+
+#[tokio::test]
+async fn get_ci_status_returns_status() {
+    let api = MockApi::new();
+    let result = get_ci_status(&api, "owner", "repo", 1).await.unwrap();
+    assert_eq!(result, CiStatus::Success);
+}
+
+#[tokio::test]
+async fn list_workflow_runs_returns_all() {
+    let api = MockApi::new().with_runs(vec![
+        make_run(1, "CI", "completed"),
+        make_run(2, "Deploy", "in_progress"),
+    ]);
+
+    let query = RunsQuery {
+        owner: "owner",
+        repo: "repo",
+        branch: None,
+        status: None,
+        limit: 10,
+    };
+    let result = list_workflow_runs(&api, &query).await.unwrap();
+    assert_eq!(result.len(), 2);
+}
+
+#[tokio::test]
+async fn search_prs_filters_by_title() {
+    let api = MockApi::new().with_prs(vec![
+        make_pr(1, "Fix authentication bug"),
+        make_pr(2, "Add new feature"),
+    ]);
+
+    let result = search_prs(&api, "owner", "repo", "bug").await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].title, "Fix authentication bug");
+}
+
+#[tokio::test]
+async fn find_pr_for_branch_returns_first() {
+    let api = MockApi::new().with_prs(vec![make_pr(42, "My PR")]);
+    let result = find_pr_for_branch(&api, "owner", "repo", "feature")
+        .await
+        .unwrap();
+    assert_eq!(result, Some(42));
+}
+
+#[tokio::test]
+async fn failed_jobs_for_pr_uses_latest_failed_run() {
+    let api = MockApi::new().with_runs(vec![make_run(7, "CI", "completed")]);
+    let result = failed_jobs_for_pr(&api, "owner", "repo", 1).await.unwrap();
+    assert_eq!(result, vec![(123, "test".to_string())]);
+}
+
+#[tokio::test]
+async fn failed_jobs_for_pr_no_failed_run() {
+    let api = MockApi::new();
+    let result = failed_jobs_for_pr(&api, "owner", "repo", 1).await.unwrap();
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn get_failed_jobs_returns_list() {
+    let api = MockApi::new();
+    let result = get_failed_jobs(&api, "owner", "repo", 123).await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].1, "test");
+}
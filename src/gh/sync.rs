@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::{Context, Result};
 use chrono::Local;
 use std::fs::{self, OpenOptions};
@@ -5,6 +6,7 @@ use std::io::Write;
 use std::path::PathBuf;
 
 use crate::git::{self, SyncOptions, SyncResult};
+use crate::util::{print_structured, OutputFormat};
 
 use super::cli::SyncArgs;
 
@@ -75,9 +77,9 @@ pub fn run(args: SyncArgs) -> Result<()> {
         append_log(&log_path, &line)?;
     }
 
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&result)?);
-        return Ok(());
+    let format = OutputFormat::resolve(args.json);
+    if format != OutputFormat::Table {
+        return print_structured(&result, format);
     }
 
     // Trigger mode: empty commit
@@ -0,0 +1,206 @@
+//! Configurable [`GithubApi`] mock shared by the gh command tests
+//!
+//! Every method answers with a harmless default; tests set only the fields
+//! they exercise, e.g. `MockGithubApi { failed_run: Some(7), ..Default::default() }`.
+//! Queues replay their entries in order and then repeat the last one, which
+//! is what polling loops (`watch`, `logs --follow`) need.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::client::GithubApi;
+use super::types::{
+    CiStatus, PrReview, PullRequest, ReviewEvent, RunsQuery, WorkflowJob, WorkflowRun,
+};
+
+pub struct MockGithubApi {
+    /// Returned by `list_review_requests`
+    pub review_requests: Vec<PullRequest>,
+    /// Repo the PR's head lives in; `None` means the PR's own repo
+    pub head_repo: Option<String>,
+    pub author: String,
+    pub login: String,
+    pub branch: String,
+    /// Queue for `get_ci_status`
+    pub ci_statuses: Mutex<Vec<CiStatus>>,
+    /// Latest failed run, for a branch or the whole repo
+    pub failed_run: Option<u64>,
+    pub failed_jobs: Vec<(u64, String)>,
+    /// Queue for `list_run_jobs`
+    pub run_jobs: Mutex<Vec<Vec<WorkflowJob>>>,
+    /// Log snapshot queue per job id; `None` entries and unknown jobs fail
+    pub job_logs: Mutex<HashMap<u64, Vec<Option<String>>>>,
+    /// `list_workflow_runs` returns the ones on the queried branch
+    pub workflow_runs: Vec<WorkflowRun>,
+    /// Error message `rerun_failed_jobs` fails with
+    pub rerun_error: Option<&'static str>,
+    /// Run ids passed to `rerun_failed_jobs`
+    pub reruns: Mutex<Vec<u64>>,
+    /// PR number, event and body of each submitted review
+    pub reviews: Mutex<Vec<(u64, ReviewEvent, Option<String>)>>,
+}
+
+impl Default for MockGithubApi {
+    fn default() -> Self {
+        Self {
+            review_requests: vec![],
+            head_repo: None,
+            author: "octocat".to_string(),
+            login: "me".to_string(),
+            branch: "feature".to_string(),
+            ci_statuses: Mutex::new(vec![CiStatus::Unknown]),
+            failed_run: None,
+            failed_jobs: vec![],
+            run_jobs: Mutex::new(vec![vec![]]),
+            job_logs: Mutex::new(HashMap::new()),
+            workflow_runs: vec![],
+            rerun_error: None,
+            reruns: Mutex::new(vec![]),
+            reviews: Mutex::new(vec![]),
+        }
+    }
+}
+
+/// Next entry of a replay queue; the last one repeats
+fn next<T: Clone>(queue: &mut Vec<T>) -> T {
+    if queue.len() > 1 {
+        queue.remove(0)
+    } else {
+        queue[0].clone()
+    }
+}
+
+impl GithubApi for MockGithubApi {
+    async fn list_user_prs(&self) -> Result<Vec<PullRequest>> {
+        Ok(vec![])
+    }
+
+    async fn list_review_requests(&self) -> Result<Vec<PullRequest>> {
+        Ok(self.review_requests.clone())
+    }
+
+    async fn get_ci_status(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<CiStatus> {
+        Ok(next(&mut self.ci_statuses.lock().unwrap()))
+    }
+
+    async fn get_pr_branch(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok(self.branch.clone())
+    }
+
+    async fn get_pr_head_repo(&self, owner: &str, repo: &str, _pr: u64) -> Result<Option<String>> {
+        Ok(Some(
+            self.head_repo
+                .clone()
+                .unwrap_or_else(|| format!("{}/{}", owner, repo)),
+        ))
+    }
+
+    async fn get_pr_author(&self, _owner: &str, _repo: &str, _pr: u64) -> Result<String> {
+        Ok(self.author.clone())
+    }
+
+    async fn get_current_login(&self) -> Result<String> {
+        Ok(self.login.clone())
+    }
+
+    async fn review_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr: u64,
+        review: &PrReview<'_>,
+    ) -> Result<String> {
+        self.reviews
+            .lock()
+            .unwrap()
+            .push((pr, review.event, review.body.map(str::to_string)));
+        Ok(format!(
+            "https://github.com/o/r/pull/{}#pullrequestreview-1",
+            pr
+        ))
+    }
+
+    async fn comment_on_pr(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr: u64,
+        _body: &str,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    async fn get_latest_failed_run_for_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<Option<u64>> {
+        Ok(self.failed_run)
+    }
+
+    async fn get_latest_failed_run(&self, _owner: &str, _repo: &str) -> Result<Option<u64>> {
+        Ok(self.failed_run)
+    }
+
+    async fn get_failed_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<(u64, String)>> {
+        Ok(self.failed_jobs.clone())
+    }
+
+    async fn list_run_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> Result<Vec<WorkflowJob>> {
+        Ok(next(&mut self.run_jobs.lock().unwrap()))
+    }
+
+    async fn get_job_logs(&self, _owner: &str, _repo: &str, job_id: u64) -> Result<String> {
+        let mut logs = self.job_logs.lock().unwrap();
+        logs.get_mut(&job_id)
+            .and_then(next)
+            .ok_or_else(|| anyhow::anyhow!("HTTP 404"))
+    }
+
+    async fn rerun_failed_jobs(&self, _owner: &str, _repo: &str, run_id: u64) -> Result<()> {
+        self.reruns.lock().unwrap().push(run_id);
+        match self.rerun_error {
+            Some(message) => Err(anyhow::anyhow!(message)),
+            None => Ok(()),
+        }
+    }
+
+    async fn find_pr_for_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<Option<u64>> {
+        Ok(None)
+    }
+
+    async fn list_workflow_runs(&self, query: &RunsQuery<'_>) -> Result<Vec<WorkflowRun>> {
+        Ok(self
+            .workflow_runs
+            .iter()
+            .filter(|run| query.branch.map_or(true, |branch| run.branch == branch))
+            .cloned()
+            .collect())
+    }
+
+    async fn search_prs_by_title(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _query: &str,
+    ) -> Result<Vec<PullRequest>> {
+        Ok(vec![])
+    }
+}
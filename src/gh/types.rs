@@ -18,12 +18,60 @@ pub struct PullRequest {
     pub html_url: String,
     pub state: String,
     pub repo_full_name: String,
+    /// Login of the PR author
+    #[serde(default)]
+    pub author: String,
     pub created_at: String,
     pub updated_at: String,
     #[serde(skip)]
     pub ci_status: Option<CiStatus>,
 }
 
+/// Why a PR appears in `hu gh prs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrReason {
+    /// Authored by the current user
+    Author,
+    /// The current user's review is requested
+    ReviewRequested,
+}
+
+impl PrReason {
+    /// Short tag for the table column
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Author => "mine",
+            Self::ReviewRequested => "review",
+        }
+    }
+}
+
+/// Verdict of a PR review
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl ReviewEvent {
+    /// Value of the `event` field in the reviews API
+    pub fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Approve => "APPROVE",
+            Self::RequestChanges => "REQUEST_CHANGES",
+            Self::Comment => "COMMENT",
+        }
+    }
+}
+
+/// A review to submit on a PR
+#[derive(Debug, Clone, Copy)]
+pub struct PrReview<'a> {
+    pub event: ReviewEvent,
+    pub body: Option<&'a str>,
+}
+
 /// A GitHub Actions workflow run
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowRun {
@@ -38,6 +86,22 @@ pub struct WorkflowRun {
     pub run_number: u64,
 }
 
+/// A job within a workflow run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowJob {
+    pub id: u64,
+    pub name: String,
+    /// `queued`, `in_progress` or `completed`
+    pub status: String,
+    pub conclusion: Option<String>,
+}
+
+impl WorkflowJob {
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+}
+
 /// Parameters for listing workflow runs
 #[derive(Debug, Clone, Default)]
 pub struct RunsQuery<'a> {
@@ -89,6 +153,7 @@ mod tests {
             html_url: "https://github.com/org/repo/pull/123".to_string(),
             state: "open".to_string(),
             repo_full_name: "org/repo".to_string(),
+            author: String::new(),
             created_at: "2024-01-15T10:00:00Z".to_string(),
             updated_at: "2024-01-15T12:00:00Z".to_string(),
             ci_status: None,
@@ -117,6 +182,19 @@ mod tests {
         assert!(pr.ci_status.is_none());
     }
 
+    #[test]
+    fn pr_reason_labels() {
+        assert_eq!(PrReason::Author.label(), "mine");
+        assert_eq!(PrReason::ReviewRequested.label(), "review");
+    }
+
+    #[test]
+    fn review_event_api_values() {
+        assert_eq!(ReviewEvent::Approve.as_api_str(), "APPROVE");
+        assert_eq!(ReviewEvent::RequestChanges.as_api_str(), "REQUEST_CHANGES");
+        assert_eq!(ReviewEvent::Comment.as_api_str(), "COMMENT");
+    }
+
     #[test]
     fn ci_status_default_is_unknown() {
         let status = CiStatus::default();
@@ -175,6 +253,7 @@ mod tests {
             html_url: "https://github.com/a/b/pull/123".to_string(),
             state: "open".to_string(),
             repo_full_name: "a/b".to_string(),
+            author: String::new(),
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             ci_status: Some(CiStatus::Success),
@@ -192,6 +271,7 @@ mod tests {
             html_url: "u".to_string(),
             state: "open".to_string(),
             repo_full_name: "r".to_string(),
+            author: String::new(),
             created_at: "c".to_string(),
             updated_at: "u".to_string(),
             ci_status: None,
@@ -0,0 +1,218 @@
+use std::time::{Duration, Instant};
+
+use anstream::println;
+use anyhow::Result;
+use indicatif::ProgressBar;
+
+use super::cli::WatchArgs;
+use super::client::{GithubApi, GithubClient};
+use super::helpers::{get_current_repo, parse_owner_repo};
+use super::service;
+use super::types::CiStatus;
+
+// ANSI color codes
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Spinner redraw interval
+const SPINNER_TICK_MS: u64 = 120;
+
+/// Parameters for [`watch_pr`]
+#[derive(Debug, Clone, Copy)]
+pub struct WatchQuery<'a> {
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub pr_number: u64,
+    /// Delay between status checks
+    pub interval: Duration,
+    /// Give up once this much time has elapsed
+    pub timeout: Duration,
+}
+
+/// How a watch ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchOutcome {
+    /// CI reached a terminal status
+    Settled(CiStatus),
+    /// Timeout elapsed; carries the last observed status
+    TimedOut(CiStatus),
+}
+
+/// Handle the `hu gh watch` command
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: WatchArgs) -> Result<()> {
+    let client = GithubClient::new()?;
+    let (owner, repo) = match &args.repo {
+        Some(r) => parse_owner_repo(r)?,
+        None => get_current_repo()?,
+    };
+    let query = WatchQuery {
+        owner: &owner,
+        repo: &repo,
+        pr_number: args.pr,
+        interval: Duration::from_secs(args.interval),
+        timeout: Duration::from_secs(args.timeout),
+    };
+
+    let spinner = if crate::util::logging::is_quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new_spinner()
+    };
+    spinner.enable_steady_tick(Duration::from_millis(SPINNER_TICK_MS));
+    spinner.set_message(format!("PR #{}: waiting for CI...", args.pr));
+
+    let on_status = |status| spinner.set_message(status_message(args.pr, status));
+    let outcome = tokio::select! {
+        result = watch_pr(&client, &query, on_status) => result,
+        _ = tokio::signal::ctrl_c() => {
+            spinner.finish_and_clear();
+            anyhow::bail!("Interrupted");
+        }
+    };
+    spinner.finish_and_clear();
+
+    match outcome? {
+        WatchOutcome::Settled(CiStatus::Failed) => {
+            println!("{RED}✗{RESET} CI failed for PR #{}", args.pr);
+            for (_, name) in service::failed_jobs_for_pr(&client, &owner, &repo, args.pr).await? {
+                println!("  {RED}✗{RESET} {}", name);
+            }
+            anyhow::bail!("CI failed for PR #{}", args.pr)
+        }
+        WatchOutcome::Settled(_) => {
+            println!("{GREEN}✓{RESET} CI passed for PR #{}", args.pr);
+            Ok(())
+        }
+        WatchOutcome::TimedOut(last) => anyhow::bail!(
+            "Timed out after {}s waiting for CI on PR #{} (last status: {})",
+            args.timeout,
+            args.pr,
+            status_label(last)
+        ),
+    }
+}
+
+/// Poll CI status until it settles or the timeout elapses (testable)
+///
+/// `on_status` is called with every observed status, e.g. to update a spinner.
+pub async fn watch_pr(
+    client: &impl GithubApi,
+    query: &WatchQuery<'_>,
+    mut on_status: impl FnMut(CiStatus),
+) -> Result<WatchOutcome> {
+    let started = Instant::now();
+    loop {
+        let status = client
+            .get_ci_status(query.owner, query.repo, query.pr_number)
+            .await?;
+        on_status(status);
+
+        if is_settled(status) {
+            return Ok(WatchOutcome::Settled(status));
+        }
+        if started.elapsed() + query.interval > query.timeout {
+            return Ok(WatchOutcome::TimedOut(status));
+        }
+        tokio::time::sleep(query.interval).await;
+    }
+}
+
+/// Whether a status is terminal (no further polling needed)
+///
+/// `Unknown` is not terminal: right after a push the checks may not be registered yet.
+pub fn is_settled(status: CiStatus) -> bool {
+    matches!(status, CiStatus::Success | CiStatus::Failed)
+}
+
+fn status_label(status: CiStatus) -> &'static str {
+    match status {
+        CiStatus::Success => "success",
+        CiStatus::Pending => "pending",
+        CiStatus::Failed => "failed",
+        CiStatus::Unknown => "no checks yet",
+    }
+}
+
+fn status_message(pr_number: u64, status: CiStatus) -> String {
+    format!("PR #{}: {}", pr_number, status_label(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::test_support::MockGithubApi;
+    use std::sync::Mutex;
+
+    /// Mock whose CI status goes through `statuses`
+    fn mock(statuses: Vec<CiStatus>) -> MockGithubApi {
+        MockGithubApi {
+            ci_statuses: Mutex::new(statuses),
+            ..Default::default()
+        }
+    }
+
+    fn fast_query() -> WatchQuery<'static> {
+        WatchQuery {
+            owner: "o",
+            repo: "r",
+            pr_number: 1,
+            interval: Duration::ZERO,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn watch_pr_settles_on_success() {
+        let mock = mock(vec![
+            CiStatus::Unknown,
+            CiStatus::Pending,
+            CiStatus::Success,
+        ]);
+        let mut seen = vec![];
+        let outcome = watch_pr(&mock, &fast_query(), |s| seen.push(s))
+            .await
+            .unwrap();
+        assert_eq!(outcome, WatchOutcome::Settled(CiStatus::Success));
+        assert_eq!(
+            seen,
+            vec![CiStatus::Unknown, CiStatus::Pending, CiStatus::Success]
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_pr_settles_on_failure() {
+        let mock = mock(vec![CiStatus::Pending, CiStatus::Failed]);
+        let outcome = watch_pr(&mock, &fast_query(), |_| {}).await.unwrap();
+        assert_eq!(outcome, WatchOutcome::Settled(CiStatus::Failed));
+    }
+
+    #[tokio::test]
+    async fn watch_pr_times_out() {
+        let mock = mock(vec![CiStatus::Pending]);
+        let query = WatchQuery {
+            interval: Duration::from_secs(10),
+            ..fast_query()
+        };
+        let outcome = watch_pr(&mock, &query, |_| {}).await.unwrap();
+        assert_eq!(outcome, WatchOutcome::TimedOut(CiStatus::Pending));
+    }
+
+    #[test]
+    fn is_settled_terminal_states() {
+        assert!(is_settled(CiStatus::Success));
+        assert!(is_settled(CiStatus::Failed));
+        assert!(!is_settled(CiStatus::Pending));
+        assert!(!is_settled(CiStatus::Unknown));
+    }
+
+    #[test]
+    fn status_message_format() {
+        assert_eq!(status_message(12, CiStatus::Pending), "PR #12: pending");
+        assert_eq!(
+            status_message(12, CiStatus::Unknown),
+            "PR #12: no checks yet"
+        );
+    }
+}
@@ -1,7 +1,10 @@
 mod service;
 mod types;
 
-pub use service::sync;
+pub use service::{
+    add_remote, branch_exists, checkout, checkout_new, fetch_ref, get_status, remote_url, stash,
+    sync,
+};
 pub use types::{SyncOptions, SyncResult};
 
 #[cfg(test)]
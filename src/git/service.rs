@@ -4,12 +4,28 @@ use std::path::Path;
 use std::process::Command;
 
 use super::types::{GitStatus, SyncOptions, SyncResult};
+use crate::util::dry_run;
 
 /// Run a git command in a directory
 fn run_git(args: &[&str], cwd: &Path) -> Result<String> {
-    let output = Command::new("git")
-        .args(args)
-        .current_dir(cwd)
+    let mut cmd = Command::new("git");
+    cmd.args(args).current_dir(cwd);
+    git_output(cmd, args)
+}
+
+/// Run a git command that changes the repo; under `--dry-run` it is only
+/// printed and yields empty output
+fn run_git_write(args: &[&str], cwd: &Path) -> Result<String> {
+    let mut cmd = Command::new("git");
+    cmd.args(args).current_dir(cwd);
+    if dry_run::skip(&cmd) {
+        return Ok(String::new());
+    }
+    git_output(cmd, args)
+}
+
+fn git_output(mut cmd: Command, args: &[&str]) -> Result<String> {
+    let output = cmd
         .output()
         .with_context(|| format!("Failed to run git {:?}", args))?;
 
@@ -75,16 +91,8 @@ pub fn parse_status_output(output: &str) -> Result<GitStatus> {
 
         // Handle worktree changes (second column)
         match worktree {
-            'M' => {
-                if !status.staged.contains(&path) {
-                    status.modified.push(path);
-                }
-            }
-            'D' => {
-                if !status.deleted.contains(&path) {
-                    status.deleted.push(path);
-                }
-            }
+            'M' if !status.staged.contains(&path) => status.modified.push(path),
+            'D' if !status.deleted.contains(&path) => status.deleted.push(path),
             '?' => status.untracked.push(path),
             _ => {}
         }
@@ -102,32 +110,32 @@ pub fn generate_commit_message(file_count: usize) -> String {
 
 /// Stage all changes
 pub fn stage_all(path: &Path) -> Result<()> {
-    run_git(&["add", "-A"], path)?;
+    run_git_write(&["add", "-A"], path)?;
     Ok(())
 }
 
 /// Commit staged changes
 pub fn commit(path: &Path, message: &str) -> Result<String> {
-    run_git(&["commit", "-m", message], path)?;
+    run_git_write(&["commit", "-m", message], path)?;
     let hash = run_git(&["rev-parse", "--short", "HEAD"], path)?;
     Ok(hash.trim().to_string())
 }
 
 /// Push to remote
 pub fn push(path: &Path) -> Result<()> {
-    run_git(&["push"], path)?;
+    run_git_write(&["push"], path)?;
     Ok(())
 }
 
 /// Pull from remote
 pub fn pull(path: &Path) -> Result<()> {
-    run_git(&["pull", "--rebase"], path)?;
+    run_git_write(&["pull", "--rebase"], path)?;
     Ok(())
 }
 
 /// Fetch from remote
 pub fn fetch(path: &Path) -> Result<()> {
-    run_git(&["fetch"], path)?;
+    run_git_write(&["fetch"], path)?;
     Ok(())
 }
 
@@ -157,7 +165,7 @@ pub fn is_ahead_of_remote(path: &Path, branch: &str) -> Result<bool> {
 
 /// Create an empty commit
 pub fn empty_commit(path: &Path, message: &str) -> Result<String> {
-    run_git(&["commit", "--allow-empty", "-m", message], path)?;
+    run_git_write(&["commit", "--allow-empty", "-m", message], path)?;
     let hash = run_git(&["rev-parse", "--short", "HEAD"], path)?;
     Ok(hash.trim().to_string())
 }
@@ -169,6 +177,65 @@ pub fn has_remote(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
+/// Stash all changes, untracked files included
+pub fn stash(path: &Path, message: &str) -> Result<()> {
+    run_git_write(
+        &["stash", "push", "--include-untracked", "-m", message],
+        path,
+    )?;
+    Ok(())
+}
+
+/// URL of a remote, `None` if no remote has that name
+pub fn remote_url(path: &Path, name: &str) -> Option<String> {
+    run_git(&["remote", "get-url", name], path)
+        .ok()
+        .map(|url| url.trim().to_string())
+}
+
+/// Add a remote
+pub fn add_remote(path: &Path, name: &str, url: &str) -> Result<()> {
+    run_git_write(&["remote", "add", name, url], path)?;
+    Ok(())
+}
+
+/// Fetch a single refspec from a remote
+pub fn fetch_ref(path: &Path, remote: &str, refspec: &str) -> Result<()> {
+    run_git_write(&["fetch", remote, refspec], path)?;
+    Ok(())
+}
+
+/// Check if a local branch exists
+pub fn branch_exists(path: &Path, branch: &str) -> bool {
+    run_git(
+        &[
+            "show-ref",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ],
+        path,
+    )
+    .is_ok()
+}
+
+/// Switch to an existing branch
+pub fn checkout(path: &Path, branch: &str) -> Result<()> {
+    run_git_write(&["checkout", branch], path)?;
+    Ok(())
+}
+
+/// Create a branch at `start` and switch to it, tracking `start` if asked
+pub fn checkout_new(path: &Path, branch: &str, start: &str, track: bool) -> Result<()> {
+    let mut args = vec!["checkout", "-b", branch];
+    if track {
+        args.push("--track");
+    }
+    args.push(start);
+    run_git_write(&args, path)?;
+    Ok(())
+}
+
 /// Perform full sync: stage, commit, pull (if behind), push
 pub fn sync(options: &SyncOptions) -> Result<SyncResult> {
     let path = options
@@ -385,6 +452,55 @@ mod tests {
         assert!(has_remote(Path::new(".")));
     }
 
+    /// Repo in a temp dir with one commit on `main`
+    fn temp_repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path();
+        run_git(&["init", "-q", "-b", "main"], path).unwrap();
+        run_git(&["config", "user.email", "test@example.com"], path).unwrap();
+        run_git(&["config", "user.name", "Test"], path).unwrap();
+        run_git(&["config", "commit.gpgsign", "false"], path).unwrap();
+        run_git(&["commit", "-q", "--allow-empty", "-m", "init"], path).unwrap();
+        dir
+    }
+
+    #[test]
+    fn stash_cleans_tree() {
+        let dir = temp_repo();
+        std::fs::write(dir.path().join("new.txt"), "x").unwrap();
+        assert!(!get_status(dir.path()).unwrap().is_clean());
+        stash(dir.path(), "test stash").unwrap();
+        assert!(get_status(dir.path()).unwrap().is_clean());
+    }
+
+    #[test]
+    fn remote_url_and_add_remote() {
+        let dir = temp_repo();
+        assert_eq!(remote_url(dir.path(), "fork"), None);
+        add_remote(dir.path(), "fork", "https://github.com/fork/repo.git").unwrap();
+        assert_eq!(
+            remote_url(dir.path(), "fork").as_deref(),
+            Some("https://github.com/fork/repo.git")
+        );
+    }
+
+    #[test]
+    fn checkout_new_and_existing_branch() {
+        let dir = temp_repo();
+        assert!(branch_exists(dir.path(), "main"));
+        assert!(!branch_exists(dir.path(), "feature"));
+        checkout_new(dir.path(), "feature", "main", false).unwrap();
+        assert_eq!(get_branch(dir.path()).unwrap(), "feature");
+        checkout(dir.path(), "main").unwrap();
+        assert_eq!(get_branch(dir.path()).unwrap(), "main");
+    }
+
+    #[test]
+    fn fetch_ref_unknown_remote_fails() {
+        let dir = temp_repo();
+        assert!(fetch_ref(dir.path(), "nope", "main").is_err());
+    }
+
     #[test]
     fn has_remote_no_repo() {
         assert!(!has_remote(Path::new("/tmp")));
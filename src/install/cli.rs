@@ -1,3 +1,6 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 
 #[derive(Subcommand)]
@@ -8,6 +11,12 @@ pub enum InstallCommand {
     /// Show what would be installed without making changes
     Preview(InstallArgs),
 
+    /// Remove installed hooks and commands
+    Uninstall(UninstallArgs),
+
+    /// Show install status of each component
+    Status(StatusArgs),
+
     /// List available components
     List,
 }
@@ -15,13 +24,17 @@ pub enum InstallCommand {
 #[derive(Args)]
 pub struct InstallArgs {
     /// Install to global ~/.claude directory (default)
-    #[arg(short, long, conflicts_with = "local")]
+    #[arg(short, long, conflicts_with_all = ["local", "target"])]
     pub global: bool,
 
     /// Install to current project's .claude directory
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "target")]
     pub local: bool,
 
+    /// Install into this directory instead (relative paths are project-relative)
+    #[arg(long, value_name = "PATH")]
+    pub target: Option<PathBuf>,
+
     /// Override existing files
     #[arg(short, long)]
     pub force: bool,
@@ -37,11 +50,52 @@ pub struct InstallArgs {
     /// Specific components to install (e.g., "hooks/hu/pre-read", "commands/hu/read")
     #[arg(value_name = "COMPONENT")]
     pub components: Vec<String>,
+
+    #[command(flatten)]
+    pub env: HookEnvArgs,
+}
+
+/// Values written to the `env` section of settings.json for the hooks
+#[derive(Args, Debug, Default, Clone)]
+pub struct HookEnvArgs {
+    /// Lines before the read hook suggests an outline [HU_MAX_FILE_LINES, default: 500]
+    #[arg(long, value_name = "N")]
+    pub max_file_lines: Option<u32>,
+
+    /// Grep results before the grep hook warns [HU_MAX_GREP_RESULTS, default: 20]
+    #[arg(long, value_name = "N")]
+    pub max_grep_results: Option<u32>,
+
+    /// Days to keep session data [HU_CLEANUP_DAYS, default: 7]
+    #[arg(long, value_name = "DAYS")]
+    pub cleanup_days: Option<u32>,
+}
+
+#[derive(Args)]
+pub struct UninstallArgs {
+    #[command(flatten)]
+    pub install: InstallArgs,
+
+    /// Show what would be removed without making changes
+    #[arg(long)]
+    pub preview: bool,
+}
+
+#[derive(Args)]
+pub struct StatusArgs {
+    #[command(flatten)]
+    pub install: InstallArgs,
+
+    /// Show a diff against the bundled template for modified components
+    #[arg(long)]
+    pub diff: bool,
 }
 
 impl InstallArgs {
     pub fn target_dir(&self) -> TargetDir {
-        if self.local {
+        if let Some(path) = &self.target {
+            TargetDir::Custom(path.clone())
+        } else if self.local {
             TargetDir::Local
         } else {
             TargetDir::Global
@@ -57,30 +111,59 @@ impl InstallArgs {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TargetDir {
     Global,
     Local,
+    /// Explicit `--target` directory
+    Custom(PathBuf),
 }
 
 impl TargetDir {
-    pub fn path(&self) -> std::path::PathBuf {
+    /// Absolute directory to install into
+    pub fn path(&self) -> Result<PathBuf> {
         match self {
             TargetDir::Global => dirs::home_dir()
-                .expect("could not find home directory")
-                .join(".claude"),
-            TargetDir::Local => std::env::current_dir()
-                .expect("could not get current directory")
-                .join(".claude"),
+                .map(|home| home.join(".claude"))
+                .context("Could not find home directory"),
+            TargetDir::Local => Ok(current_dir()?.join(".claude")),
+            TargetDir::Custom(path) if path.is_absolute() => Ok(path.clone()),
+            TargetDir::Custom(path) => Ok(current_dir()?.join(path)),
         }
     }
 
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> String {
         match self {
-            TargetDir::Global => "~/.claude (global)",
-            TargetDir::Local => "./.claude (local)",
+            TargetDir::Global => "~/.claude (global)".to_string(),
+            TargetDir::Local => "./.claude (local)".to_string(),
+            TargetDir::Custom(path) => path.display().to_string(),
         }
     }
+
+    /// Directory prefix for hook commands in settings.json
+    ///
+    /// Project-local targets stay relative (hooks run from the project root),
+    /// targets under home use `~`, anything else is absolute.
+    pub fn hooks_prefix(&self, home: Option<&Path>) -> Result<String> {
+        match self {
+            TargetDir::Local => return Ok("./.claude".to_string()),
+            TargetDir::Custom(path) if path.is_relative() => {
+                let relative = path.strip_prefix(".").unwrap_or(path);
+                return Ok(format!("./{}", relative.display()));
+            }
+            _ => {}
+        }
+
+        let base = self.path()?;
+        Ok(match home.and_then(|h| base.strip_prefix(h).ok()) {
+            Some(rest) => format!("~/{}", rest.display()),
+            None => base.display().to_string(),
+        })
+    }
+}
+
+fn current_dir() -> Result<PathBuf> {
+    std::env::current_dir().context("Could not get current directory")
 }
 
 #[cfg(test)]
@@ -92,10 +175,12 @@ mod tests {
         let args = InstallArgs {
             global: false,
             local: false,
+            target: None,
             force: false,
             hooks_only: false,
             commands_only: false,
             components: vec![],
+            env: HookEnvArgs::default(),
         };
         assert_eq!(args.target_dir(), TargetDir::Global);
     }
@@ -105,23 +190,78 @@ mod tests {
         let args = InstallArgs {
             global: false,
             local: true,
+            target: None,
             force: false,
             hooks_only: false,
             commands_only: false,
             components: vec![],
+            env: HookEnvArgs::default(),
         };
         assert_eq!(args.target_dir(), TargetDir::Local);
     }
 
+    #[test]
+    fn target_dir_custom_wins() {
+        let args = InstallArgs {
+            global: false,
+            local: false,
+            target: Some(PathBuf::from("/srv/project/.claude")),
+            force: false,
+            hooks_only: false,
+            commands_only: false,
+            components: vec![],
+            env: HookEnvArgs::default(),
+        };
+        assert_eq!(
+            args.target_dir(),
+            TargetDir::Custom(PathBuf::from("/srv/project/.claude"))
+        );
+    }
+
+    #[test]
+    fn hooks_prefix_by_target() {
+        let home = Path::new("/home/me");
+        assert_eq!(
+            TargetDir::Local.hooks_prefix(Some(home)).unwrap(),
+            "./.claude"
+        );
+        assert_eq!(
+            TargetDir::Custom(PathBuf::from("./config/.claude"))
+                .hooks_prefix(Some(home))
+                .unwrap(),
+            "./config/.claude"
+        );
+        assert_eq!(
+            TargetDir::Custom(PathBuf::from("/home/me/work/.claude"))
+                .hooks_prefix(Some(home))
+                .unwrap(),
+            "~/work/.claude"
+        );
+        assert_eq!(
+            TargetDir::Custom(PathBuf::from("/opt/claude"))
+                .hooks_prefix(Some(home))
+                .unwrap(),
+            "/opt/claude"
+        );
+        assert_eq!(
+            TargetDir::Custom(PathBuf::from("/opt/claude"))
+                .hooks_prefix(None)
+                .unwrap(),
+            "/opt/claude"
+        );
+    }
+
     #[test]
     fn install_both_by_default() {
         let args = InstallArgs {
             global: false,
             local: false,
+            target: None,
             force: false,
             hooks_only: false,
             commands_only: false,
             components: vec![],
+            env: HookEnvArgs::default(),
         };
         assert!(args.install_hooks());
         assert!(args.install_commands());
@@ -132,24 +272,46 @@ mod tests {
         let args = InstallArgs {
             global: false,
             local: false,
+            target: None,
             force: false,
             hooks_only: true,
             commands_only: false,
             components: vec![],
+            env: HookEnvArgs::default(),
         };
         assert!(args.install_hooks());
         assert!(!args.install_commands());
     }
 
+    #[test]
+    fn parses_hook_env_flags() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct TestCli {
+            #[command(flatten)]
+            args: InstallArgs,
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "--max-file-lines", "800", "--cleanup-days", "3"])
+                .unwrap();
+        assert_eq!(cli.args.env.max_file_lines, Some(800));
+        assert_eq!(cli.args.env.max_grep_results, None);
+        assert_eq!(cli.args.env.cleanup_days, Some(3));
+    }
+
     #[test]
     fn commands_only_excludes_hooks() {
         let args = InstallArgs {
             global: false,
             local: false,
+            target: None,
             force: false,
             hooks_only: false,
             commands_only: true,
             components: vec![],
+            env: HookEnvArgs::default(),
         };
         assert!(!args.install_hooks());
         assert!(args.install_commands());
@@ -0,0 +1,66 @@
+//! Unified diffs between installed files and bundled templates
+
+use owo_colors::OwoColorize;
+use similar::TextDiff;
+
+/// Lines of context around each change
+const CONTEXT_LINES: usize = 3;
+
+/// Unified diff turning `installed` into `bundled` (`-` = on disk, `+` = template)
+pub fn unified_diff(path: &str, installed: &str, bundled: &str) -> String {
+    let old_header = format!("{} (installed)", path);
+    let new_header = format!("{} (hu {})", path, env!("CARGO_PKG_VERSION"));
+    TextDiff::from_lines(installed, bundled)
+        .unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(&old_header, &new_header)
+        .to_string()
+}
+
+/// Color a unified diff for the terminal
+pub fn colorize(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            if line.starts_with("+++") || line.starts_with("---") {
+                line.bold().to_string()
+            } else if line.starts_with('+') {
+                line.green().to_string()
+            } else if line.starts_with('-') {
+                line.red().to_string()
+            } else if line.starts_with("@@") {
+                line.cyan().to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_marks_removed_and_added_lines() {
+        let diff = unified_diff("hooks/x.sh", "a\nlocal\nc\n", "a\nb\nc\n");
+        assert!(diff.starts_with("--- hooks/x.sh (installed)\n+++ hooks/x.sh (hu "));
+        assert!(diff.contains("\n-local\n"));
+        assert!(diff.contains("\n+b\n"));
+        assert!(diff.contains("\n a\n"));
+    }
+
+    #[test]
+    fn identical_content_has_no_hunks() {
+        assert!(!unified_diff("x", "same\n", "same\n").contains("@@"));
+    }
+
+    #[test]
+    fn colorize_keeps_text() {
+        let colored = colorize("--- a\n+++ b\n@@ -1 +1 @@\n-old\n+new\n ctx");
+        assert!(colored.contains("old"));
+        assert!(colored.contains("new"));
+        assert!(colored.ends_with(" ctx"));
+        assert_eq!(colored.lines().count(), 6);
+    }
+}
@@ -1,4 +1,6 @@
 mod cli;
+mod diff;
+mod settings;
 mod templates;
 mod types;
 
@@ -7,10 +9,12 @@ pub use cli::InstallCommand;
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
 
+use anstream::println;
 use anyhow::{bail, Context, Result};
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, Table};
 
-use cli::{InstallArgs, TargetDir};
+use cli::{InstallArgs, StatusArgs, TargetDir, UninstallArgs};
+use settings::{remove_settings_hooks, update_settings_json};
 use templates::{get_components, COMPONENTS};
 use types::{Component, ComponentKind, ComponentStatus, InstallStatus};
 
@@ -18,6 +22,8 @@ pub async fn run_command(cmd: InstallCommand) -> Result<()> {
     match cmd {
         InstallCommand::Run(args) => run_install(args, false),
         InstallCommand::Preview(args) => run_install(args, true),
+        InstallCommand::Uninstall(args) => run_uninstall(args),
+        InstallCommand::Status(args) => show_status(args),
         InstallCommand::List => list_components(),
     }
 }
@@ -44,23 +50,8 @@ fn list_components() -> Result<()> {
 
 fn run_install(args: InstallArgs, preview: bool) -> Result<()> {
     let target = args.target_dir();
-    let base_dir = target.path();
-
-    // Filter components based on args
-    let components: Vec<&Component> = if !args.components.is_empty() {
-        // User specified specific components
-        let mut selected = Vec::new();
-        for id in &args.components {
-            match COMPONENTS.iter().find(|c| c.id == id.as_str()) {
-                Some(c) => selected.push(c),
-                None => bail!("Unknown component: {}", id),
-            }
-        }
-        selected
-    } else {
-        // Use flags to filter
-        get_components(args.install_hooks(), args.install_commands())
-    };
+    let base_dir = target.path()?;
+    let components = select_components(&args)?;
 
     if components.is_empty() {
         println!("No components selected for installation.");
@@ -131,7 +122,7 @@ fn run_install(args: InstallArgs, preview: bool) -> Result<()> {
 
     // Update settings.json if we installed hooks
     if has_hooks {
-        update_settings_json(&base_dir)?;
+        update_settings_json(&target, &args.env)?;
         println!("  ✓ Updated settings.json with hook configuration");
     }
 
@@ -149,6 +140,132 @@ fn run_install(args: InstallArgs, preview: bool) -> Result<()> {
     Ok(())
 }
 
+/// Components named on the command line, or all matching the kind filters
+fn select_components(args: &InstallArgs) -> Result<Vec<&'static Component>> {
+    if args.components.is_empty() {
+        return Ok(get_components(
+            args.install_hooks(),
+            args.install_commands(),
+        ));
+    }
+
+    let mut selected = Vec::new();
+    for id in &args.components {
+        match COMPONENTS.iter().find(|c| c.id == id.as_str()) {
+            Some(c) => selected.push(c),
+            None => bail!("Unknown component: {}", id),
+        }
+    }
+    Ok(selected)
+}
+
+fn show_status(args: StatusArgs) -> Result<()> {
+    let target = args.install.target_dir();
+    let base_dir = target.path()?;
+    let statuses: Vec<ComponentStatus> = select_components(&args.install)?
+        .iter()
+        .map(|c| check_component_status(c, &base_dir))
+        .collect();
+    print_status_table(&statuses, &target);
+
+    if !args.diff {
+        return Ok(());
+    }
+    for status in statuses
+        .iter()
+        .filter(|s| s.status == InstallStatus::Modified)
+    {
+        let installed =
+            fs::read_to_string(status.component.target_path(&base_dir)).unwrap_or_default();
+        println!();
+        println!(
+            "{}",
+            diff::colorize(&diff::unified_diff(
+                status.component.path,
+                &installed,
+                status.component.content
+            ))
+        );
+    }
+    Ok(())
+}
+
+fn run_uninstall(args: UninstallArgs) -> Result<()> {
+    let UninstallArgs {
+        install: args,
+        preview,
+    } = args;
+    let target = args.target_dir();
+    let base_dir = target.path()?;
+    let components = select_components(&args)?;
+
+    let statuses: Vec<ComponentStatus> = components
+        .iter()
+        .map(|c| check_component_status(c, &base_dir))
+        .collect();
+    print_status_table(&statuses, &target);
+
+    let to_remove: Vec<_> = statuses
+        .iter()
+        .filter(|s| {
+            matches!(s.status, InstallStatus::Current)
+                || (args.force && matches!(s.status, InstallStatus::Modified))
+        })
+        .collect();
+
+    let skipped = statuses
+        .iter()
+        .filter(|s| !args.force && matches!(s.status, InstallStatus::Modified))
+        .count();
+    if skipped > 0 {
+        println!();
+        println!(
+            "Keeping {} modified component(s). Use --force to remove them.",
+            skipped
+        );
+    }
+
+    if to_remove.is_empty() {
+        println!();
+        println!("Nothing to uninstall.");
+        return Ok(());
+    }
+
+    let has_hooks = to_remove
+        .iter()
+        .any(|s| s.component.kind == ComponentKind::Hook);
+
+    if preview {
+        println!();
+        println!(
+            "Preview mode. Would remove {} component(s):",
+            to_remove.len()
+        );
+        for status in &to_remove {
+            println!("  {} {}", status.status.symbol(), status.component.id);
+        }
+        if has_hooks {
+            println!("  and the hu hook configuration in settings.json");
+        }
+        return Ok(());
+    }
+
+    println!();
+    println!("Removing {} component(s)...", to_remove.len());
+    for status in &to_remove {
+        uninstall_component(status.component, &base_dir)?;
+        println!("  ✓ {}", status.component.id);
+    }
+
+    if has_hooks && remove_settings_hooks(&base_dir)? {
+        println!("  ✓ Removed hook configuration from settings.json");
+    }
+
+    println!();
+    println!("Uninstall complete.");
+    Ok(())
+}
+
 fn check_component_status(
     component: &'static Component,
     base_dir: &std::path::Path,
@@ -215,97 +332,16 @@ fn install_component(component: &Component, base_dir: &std::path::Path) -> Resul
     Ok(())
 }
 
-fn update_settings_json(base_dir: &std::path::Path) -> Result<()> {
-    let settings_path = base_dir.join("settings.json");
-
-    // Read existing settings or create new
-    let mut settings: serde_json::Value = if settings_path.exists() {
-        let content = fs::read_to_string(&settings_path)?;
-        serde_json::from_str(&content)?
-    } else {
-        serde_json::json!({})
-    };
+/// Delete a component's file, then its directory if that leaves it empty
+fn uninstall_component(component: &Component, base_dir: &std::path::Path) -> Result<()> {
+    let target_path = component.target_path(base_dir);
+    fs::remove_file(&target_path)
+        .with_context(|| format!("Failed to remove {}", target_path.display()))?;
 
-    // Ensure env section exists with defaults
-    if settings.get("env").is_none() {
-        settings["env"] = serde_json::json!({
-            "HU_MAX_FILE_LINES": "500",
-            "HU_MAX_GREP_RESULTS": "20",
-            "HU_CLEANUP_DAYS": "7"
-        });
+    if let Some(parent) = target_path.parent() {
+        // Fails (harmlessly) when other files remain
+        let _ = fs::remove_dir(parent);
     }
-
-    // Build hooks configuration
-    let hooks_dir = if base_dir.ends_with(".claude") {
-        base_dir.display().to_string()
-    } else {
-        format!("{}", base_dir.display())
-    };
-
-    // Use ~ for home directory in paths for portability
-    let hooks_prefix = if hooks_dir.starts_with(&dirs::home_dir().unwrap().display().to_string()) {
-        "~/.claude"
-    } else {
-        "./.claude"
-    };
-
-    let hooks_config = serde_json::json!({
-        "PreToolUse": [
-            {
-                "matcher": "Read",
-                "hooks": [{
-                    "type": "command",
-                    "command": format!("{}/hooks/hu/pre-read.sh", hooks_prefix),
-                    "timeout": 5000
-                }]
-            },
-            {
-                "matcher": "Grep",
-                "hooks": [{
-                    "type": "command",
-                    "command": format!("{}/hooks/hu/pre-grep.sh", hooks_prefix),
-                    "timeout": 5000
-                }]
-            },
-            {
-                "matcher": "WebFetch",
-                "hooks": [{
-                    "type": "command",
-                    "command": format!("{}/hooks/hu/pre-webfetch.sh", hooks_prefix),
-                    "timeout": 5000
-                }]
-            },
-            {
-                "matcher": "WebSearch",
-                "hooks": [{
-                    "type": "command",
-                    "command": format!("{}/hooks/hu/pre-websearch.sh", hooks_prefix),
-                    "timeout": 5000
-                }]
-            }
-        ],
-        "SessionStart": [{
-            "hooks": [{
-                "type": "command",
-                "command": format!("{}/hooks/hu/session-start.sh", hooks_prefix),
-                "timeout": 30000
-            }]
-        }],
-        "SessionEnd": [{
-            "hooks": [{
-                "type": "command",
-                "command": format!("{}/hooks/hu/session-end.sh", hooks_prefix),
-                "timeout": 10000
-            }]
-        }]
-    });
-
-    settings["hooks"] = hooks_config;
-
-    // Write back with pretty formatting
-    let content = serde_json::to_string_pretty(&settings)?;
-    fs::write(&settings_path, content)?;
-
     Ok(())
 }
 
@@ -382,42 +418,48 @@ mod tests {
     }
 
     #[test]
-    fn update_settings_creates_file() {
+    fn uninstall_removes_file_and_empty_dir() {
         let temp = TempDir::new().unwrap();
-        update_settings_json(temp.path()).unwrap();
+        let component = &templates::COMPONENTS[0];
+        install_component(component, temp.path()).unwrap();
 
-        let settings_path = temp.path().join("settings.json");
-        assert!(settings_path.exists());
+        uninstall_component(component, temp.path()).unwrap();
 
-        let content: serde_json::Value =
-            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
-        assert!(content.get("hooks").is_some());
-        assert!(content.get("env").is_some());
+        let target = temp.path().join(component.path);
+        assert!(!target.exists());
+        assert!(!target.parent().unwrap().exists());
+        assert_eq!(
+            check_component_status(component, temp.path()).status,
+            InstallStatus::Missing
+        );
     }
 
     #[test]
-    fn update_settings_preserves_existing() {
+    fn uninstall_keeps_dir_with_other_files() {
         let temp = TempDir::new().unwrap();
-        let settings_path = temp.path().join("settings.json");
+        let hooks = templates::get_hooks();
+        install_component(hooks[0], temp.path()).unwrap();
+        install_component(hooks[1], temp.path()).unwrap();
 
-        // Create existing settings with custom values
-        fs::write(
-            &settings_path,
-            r#"{"model": "opus", "permissions": {"allow": ["Bash"]}}"#,
-        )
-        .unwrap();
+        uninstall_component(hooks[0], temp.path()).unwrap();
 
-        update_settings_json(temp.path()).unwrap();
-
-        let content: serde_json::Value =
-            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
-
-        // Check preserved values
-        assert_eq!(content["model"], "opus");
-        assert!(content["permissions"]["allow"].as_array().is_some());
+        assert!(!temp.path().join(hooks[0].path).exists());
+        assert!(temp.path().join(hooks[1].path).exists());
+    }
 
-        // Check new values added
-        assert!(content.get("hooks").is_some());
+    #[test]
+    fn select_components_rejects_unknown() {
+        let args = InstallArgs {
+            global: false,
+            local: false,
+            target: None,
+            force: false,
+            hooks_only: false,
+            commands_only: false,
+            components: vec!["hooks/hu/nope".to_string()],
+            env: Default::default(),
+        };
+        assert!(select_components(&args).is_err());
     }
 
     #[test]
@@ -0,0 +1,333 @@
+//! Hook configuration in `settings.json`
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::cli::{HookEnvArgs, TargetDir};
+
+/// Marker in hook commands written by `hu install`
+const HU_HOOKS_DIR: &str = "/hooks/hu/";
+
+/// Write hu's hook configuration and env defaults into the target's settings.json
+pub fn update_settings_json(target: &TargetDir, env: &HookEnvArgs) -> Result<()> {
+    let base_dir = target.path()?;
+    let settings_path = base_dir.join("settings.json");
+
+    // Read existing settings or create new
+    let mut settings: serde_json::Value = if settings_path.exists() {
+        let content = fs::read_to_string(&settings_path)?;
+        serde_json::from_str(&content)?
+    } else {
+        serde_json::json!({})
+    };
+
+    apply_hook_env(&mut settings, env);
+
+    let hooks_prefix = target.hooks_prefix(dirs::home_dir().as_deref())?;
+
+    let hooks_config = serde_json::json!({
+        "PreToolUse": [
+            {
+                "matcher": "Read",
+                "hooks": [{
+                    "type": "command",
+                    "command": format!("{}/hooks/hu/pre-read.sh", hooks_prefix),
+                    "timeout": 5000
+                }]
+            },
+            {
+                "matcher": "Grep",
+                "hooks": [{
+                    "type": "command",
+                    "command": format!("{}/hooks/hu/pre-grep.sh", hooks_prefix),
+                    "timeout": 5000
+                }]
+            },
+            {
+                "matcher": "WebFetch",
+                "hooks": [{
+                    "type": "command",
+                    "command": format!("{}/hooks/hu/pre-webfetch.sh", hooks_prefix),
+                    "timeout": 5000
+                }]
+            },
+            {
+                "matcher": "WebSearch",
+                "hooks": [{
+                    "type": "command",
+                    "command": format!("{}/hooks/hu/pre-websearch.sh", hooks_prefix),
+                    "timeout": 5000
+                }]
+            }
+        ],
+        "SessionStart": [{
+            "hooks": [{
+                "type": "command",
+                "command": format!("{}/hooks/hu/session-start.sh", hooks_prefix),
+                "timeout": 30000
+            }]
+        }],
+        "SessionEnd": [{
+            "hooks": [{
+                "type": "command",
+                "command": format!("{}/hooks/hu/session-end.sh", hooks_prefix),
+                "timeout": 10000
+            }]
+        }]
+    });
+
+    settings["hooks"] = hooks_config;
+
+    // Write back with pretty formatting
+    let content = serde_json::to_string_pretty(&settings)?;
+    fs::write(&settings_path, content)?;
+
+    Ok(())
+}
+
+/// Write the `HU_*` hook variables into `env`
+///
+/// Values given on the command line always win; otherwise a default is only
+/// filled in when the key is absent. Other `env` entries are left alone.
+fn apply_hook_env(settings: &mut serde_json::Value, env: &HookEnvArgs) {
+    let vars = [
+        ("HU_MAX_FILE_LINES", env.max_file_lines, 500),
+        ("HU_MAX_GREP_RESULTS", env.max_grep_results, 20),
+        ("HU_CLEANUP_DAYS", env.cleanup_days, 7),
+    ];
+
+    if !settings.get("env").is_some_and(|e| e.is_object()) {
+        settings["env"] = serde_json::json!({});
+    }
+    let section = &mut settings["env"];
+    for (key, value, default) in vars {
+        match value {
+            Some(value) => section[key] = value.to_string().into(),
+            None if section.get(key).is_none() => section[key] = default.to_string().into(),
+            None => {}
+        }
+    }
+}
+
+/// Remove the hook entries `update_settings_json` added, keeping everything else
+///
+/// Only hooks whose command points into `hooks/hu/` are dropped; events and
+/// matcher groups left empty are removed, and so is `hooks` itself if nothing
+/// remains. Returns whether `settings.json` changed.
+pub fn remove_settings_hooks(base_dir: &Path) -> Result<bool> {
+    let settings_path = base_dir.join("settings.json");
+    if !settings_path.exists() {
+        return Ok(false);
+    }
+
+    let content = fs::read_to_string(&settings_path)?;
+    let mut settings: serde_json::Value = serde_json::from_str(&content)?;
+    let Some(hooks) = settings.get_mut("hooks").and_then(|h| h.as_object_mut()) else {
+        return Ok(false);
+    };
+
+    let before = hooks.clone();
+    for groups in hooks.values_mut() {
+        if let Some(groups) = groups.as_array_mut() {
+            for group in groups.iter_mut() {
+                if let Some(entries) = group.get_mut("hooks").and_then(|h| h.as_array_mut()) {
+                    entries.retain(|entry| !is_hu_hook(entry));
+                }
+            }
+            groups.retain(|group| {
+                group
+                    .get("hooks")
+                    .and_then(|h| h.as_array())
+                    .map_or(true, |entries| !entries.is_empty())
+            });
+        }
+    }
+    hooks.retain(|_, groups| groups.as_array().map_or(true, |g| !g.is_empty()));
+
+    if *hooks == before {
+        return Ok(false);
+    }
+    if hooks.is_empty() {
+        if let Some(obj) = settings.as_object_mut() {
+            obj.remove("hooks");
+        }
+    }
+
+    fs::write(&settings_path, serde_json::to_string_pretty(&settings)?)?;
+    Ok(true)
+}
+
+fn is_hu_hook(entry: &serde_json::Value) -> bool {
+    entry
+        .get("command")
+        .and_then(|c| c.as_str())
+        .is_some_and(|c| c.contains(HU_HOOKS_DIR))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn target(temp: &TempDir) -> TargetDir {
+        TargetDir::Custom(temp.path().to_path_buf())
+    }
+
+    #[test]
+    fn update_settings_creates_file() {
+        let temp = TempDir::new().unwrap();
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        let settings_path = temp.path().join("settings.json");
+        assert!(settings_path.exists());
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert!(content.get("hooks").is_some());
+        assert!(content.get("env").is_some());
+    }
+
+    #[test]
+    fn update_settings_preserves_existing() {
+        let temp = TempDir::new().unwrap();
+        let settings_path = temp.path().join("settings.json");
+
+        // Create existing settings with custom values
+        fs::write(
+            &settings_path,
+            r#"{"model": "opus", "permissions": {"allow": ["Bash"]}}"#,
+        )
+        .unwrap();
+
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+
+        // Check preserved values
+        assert_eq!(content["model"], "opus");
+        assert!(content["permissions"]["allow"].as_array().is_some());
+
+        // Check new values added
+        assert!(content.get("hooks").is_some());
+    }
+
+    #[test]
+    fn remove_hooks_preserves_other_settings() {
+        let temp = TempDir::new().unwrap();
+        let settings_path = temp.path().join("settings.json");
+        fs::write(
+            &settings_path,
+            r#"{"model": "opus", "permissions": {"allow": ["Bash"]}}"#,
+        )
+        .unwrap();
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        assert!(remove_settings_hooks(temp.path()).unwrap());
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!(content["model"], "opus");
+        assert_eq!(content["permissions"]["allow"][0], "Bash");
+        assert!(content.get("hooks").is_none());
+    }
+
+    #[test]
+    fn remove_hooks_keeps_user_hooks() {
+        let temp = TempDir::new().unwrap();
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        // A hook the user added alongside ours
+        let settings_path = temp.path().join("settings.json");
+        let mut content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        content["hooks"]["PreToolUse"][0]["hooks"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({"type": "command", "command": "~/bin/audit.sh"}));
+        fs::write(&settings_path, content.to_string()).unwrap();
+
+        assert!(remove_settings_hooks(temp.path()).unwrap());
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        let pre_tool_use = content["hooks"]["PreToolUse"].as_array().unwrap();
+        assert_eq!(pre_tool_use.len(), 1);
+        assert_eq!(pre_tool_use[0]["matcher"], "Read");
+        assert_eq!(pre_tool_use[0]["hooks"][0]["command"], "~/bin/audit.sh");
+        assert!(content["hooks"].get("SessionStart").is_none());
+    }
+
+    #[test]
+    fn remove_hooks_without_settings_is_noop() {
+        let temp = TempDir::new().unwrap();
+        assert!(!remove_settings_hooks(temp.path()).unwrap());
+
+        fs::write(temp.path().join("settings.json"), r#"{"model": "opus"}"#).unwrap();
+        assert!(!remove_settings_hooks(temp.path()).unwrap());
+    }
+
+    #[test]
+    fn update_settings_keeps_user_env() {
+        let temp = TempDir::new().unwrap();
+        let settings_path = temp.path().join("settings.json");
+        fs::write(
+            &settings_path,
+            r#"{"env": {"FOO": "bar", "HU_CLEANUP_DAYS": "30"}}"#,
+        )
+        .unwrap();
+
+        let env = HookEnvArgs {
+            max_file_lines: Some(800),
+            ..Default::default()
+        };
+        update_settings_json(&target(&temp), &env).unwrap();
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!(content["env"]["FOO"], "bar");
+        assert_eq!(content["env"]["HU_MAX_FILE_LINES"], "800");
+        assert_eq!(content["env"]["HU_MAX_GREP_RESULTS"], "20");
+        // Existing value kept when no flag is given
+        assert_eq!(content["env"]["HU_CLEANUP_DAYS"], "30");
+    }
+
+    #[test]
+    fn update_settings_flags_override_on_reinstall() {
+        let temp = TempDir::new().unwrap();
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        let env = HookEnvArgs {
+            cleanup_days: Some(14),
+            ..Default::default()
+        };
+        update_settings_json(&target(&temp), &env).unwrap();
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp.path().join("settings.json")).unwrap())
+                .unwrap();
+        assert_eq!(content["env"]["HU_CLEANUP_DAYS"], "14");
+        assert_eq!(content["env"]["HU_MAX_FILE_LINES"], "500");
+    }
+
+    #[test]
+    fn hook_commands_point_at_target_outside_home() {
+        let temp = TempDir::new_in("/tmp").unwrap();
+        update_settings_json(&target(&temp), &HookEnvArgs::default()).unwrap();
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp.path().join("settings.json")).unwrap())
+                .unwrap();
+        let expected = format!("{}/hooks/hu/pre-read.sh", temp.path().display());
+        assert_eq!(
+            content["hooks"]["PreToolUse"][0]["hooks"][0]["command"],
+            expected
+        );
+        let session_start = content["hooks"]["SessionStart"][0]["hooks"][0]["command"]
+            .as_str()
+            .unwrap();
+        assert!(session_start.starts_with(&temp.path().display().to_string()));
+    }
+}
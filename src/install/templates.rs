@@ -476,7 +476,7 @@ pub const CMD_SENTRY_ISSUES: &str = r#"List Sentry issues.
 ```bash
 hu sentry issues                    # List recent issues
 hu sentry issues -p my-project      # Filter by project
-hu sentry issues -q "is:unresolved" # Search query
+hu sentry issues --query "is:unresolved" # Search query
 hu sentry issues --json             # JSON output
 ```
 "#;
@@ -4,7 +4,7 @@ use rand::Rng;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use crate::util::{load_credentials, save_credentials, JiraCredentials};
+use crate::util::{bail_kind, http, load_credentials, save_credentials, JiraCredentials};
 
 use super::types::OAuthConfig;
 
@@ -44,7 +44,8 @@ pub fn load_oauth_config() -> Result<OAuthConfig> {
         return Ok(config);
     }
 
-    bail!(
+    bail_kind!(
+        Config,
         "Jira OAuth not configured. Set JIRA_CLIENT_ID and JIRA_CLIENT_SECRET environment variables, \
         or create {} with client_id and client_secret fields.",
         crate::util::config_dir()?.join("jira-oauth.toml").display()
@@ -151,7 +152,7 @@ struct TokenResponse {
 
 /// Exchange authorization code for tokens
 async fn exchange_code(config: &OAuthConfig, code: &str) -> Result<TokenResponse> {
-    let client = reqwest::Client::new();
+    let client = http::client()?;
     let redirect_uri = format!("http://localhost:{}/callback", CALLBACK_PORT);
 
     let response = client
@@ -199,7 +200,7 @@ async fn exchange_code(config: &OAuthConfig, code: &str) -> Result<TokenResponse
 async fn get_accessible_resources(
     access_token: &str,
 ) -> Result<Vec<super::types::AccessibleResource>> {
-    let client = reqwest::Client::new();
+    let client = http::client()?;
 
     let response = client
         .get(RESOURCES_URL)
@@ -232,7 +233,7 @@ async fn get_accessible_resources(
 
 /// Get current user display name
 async fn get_current_user(access_token: &str, cloud_id: &str) -> Result<String> {
-    let client = reqwest::Client::new();
+    let client = http::client()?;
     let url = format!(
         "https://api.atlassian.com/ex/jira/{}/rest/api/3/myself",
         cloud_id
@@ -288,7 +289,7 @@ pub async fn refresh_token_if_needed() -> Result<String> {
 
 /// Refresh access token
 async fn refresh_token(config: &OAuthConfig, refresh_token: &str) -> Result<TokenResponse> {
-    let client = reqwest::Client::new();
+    let client = http::client()?;
 
     let response = client
         .post(TOKEN_URL)
@@ -304,7 +305,11 @@ async fn refresh_token(config: &OAuthConfig, refresh_token: &str) -> Result<Toke
 
     if !response.status().is_success() {
         let error_text = response.text().await.unwrap_or_default();
-        bail!("Token refresh failed: {}", error_text);
+        bail_kind!(
+            Auth,
+            "Token refresh failed: {}. Run: hu jira auth",
+            error_text
+        );
     }
 
     let json: serde_json::Value = response
@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::Result;
 
 use super::auth;
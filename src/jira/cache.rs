@@ -0,0 +1,173 @@
+//! Short-lived on-disk cache of fetched issues
+//!
+//! Stored in `~/.config/hu/jira-cache.json`, keyed by cloud ID and issue
+//! key so several tenants never mix. A broken or missing file is treated
+//! as empty; the cache only ever saves a round trip.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::types::Issue;
+
+const CACHE_FILE: &str = "jira-cache.json";
+
+/// A cached issue and when it was fetched (Unix seconds)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: i64,
+    issue: Issue,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, now: i64, ttl: u64) -> bool {
+        now.saturating_sub(self.fetched_at) < i64::try_from(ttl).unwrap_or(i64::MAX)
+    }
+}
+
+/// Issues fetched recently, across tenants
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssueCache {
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// Cache key for an issue on a tenant
+fn cache_key(cloud_id: &str, key: &str) -> String {
+    format!("{}:{}", cloud_id, key.to_uppercase())
+}
+
+impl IssueCache {
+    /// The cached issue, if fetched less than `ttl` seconds ago
+    pub fn get(&self, cloud_id: &str, key: &str, now: i64, ttl: u64) -> Option<&Issue> {
+        self.entries
+            .get(&cache_key(cloud_id, key))
+            .filter(|entry| entry.is_fresh(now, ttl))
+            .map(|entry| &entry.issue)
+    }
+
+    /// Remember `issue` as fetched at `now`
+    pub fn insert(&mut self, cloud_id: &str, issue: Issue, now: i64) {
+        self.entries.insert(
+            cache_key(cloud_id, &issue.key),
+            CacheEntry {
+                fetched_at: now,
+                issue,
+            },
+        );
+    }
+
+    /// Forget an issue; returns whether it was cached
+    pub fn remove(&mut self, cloud_id: &str, key: &str) -> bool {
+        self.entries.remove(&cache_key(cloud_id, key)).is_some()
+    }
+
+    /// Drop every entry older than `ttl`
+    pub fn prune(&mut self, now: i64, ttl: u64) {
+        self.entries.retain(|_, entry| entry.is_fresh(now, ttl));
+    }
+}
+
+fn cache_path() -> Result<PathBuf> {
+    Ok(crate::util::config_dir()?.join(CACHE_FILE))
+}
+
+/// Load the cache; empty if missing or unreadable
+#[cfg(not(tarpaulin_include))]
+pub fn load() -> IssueCache {
+    cache_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Write the cache back to disk
+#[cfg(not(tarpaulin_include))]
+pub fn save(cache: &IssueCache) -> Result<()> {
+    let path = cache_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string(cache).context("Failed to serialize issue cache")?;
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str) -> Issue {
+        Issue {
+            key: key.to_string(),
+            summary: "Summary".to_string(),
+            status: "To Do".to_string(),
+            issue_type: "Task".to_string(),
+            assignee: None,
+            description: None,
+            updated: "2026-01-01T00:00:00.000+0000".to_string(),
+        }
+    }
+
+    #[test]
+    fn get_within_ttl() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        assert!(cache.get("cloud", "PROJ-1", 1059, 60).is_some());
+        assert!(cache.get("cloud", "PROJ-1", 1060, 60).is_none());
+    }
+
+    #[test]
+    fn get_ignores_key_case() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        assert!(cache.get("cloud", "proj-1", 1000, 60).is_some());
+    }
+
+    #[test]
+    fn get_keeps_tenants_apart() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud-a", issue("PROJ-1"), 1000);
+        assert!(cache.get("cloud-b", "PROJ-1", 1000, 60).is_none());
+    }
+
+    #[test]
+    fn zero_ttl_never_hits() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        assert!(cache.get("cloud", "PROJ-1", 1000, 0).is_none());
+    }
+
+    #[test]
+    fn remove_invalidates() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        assert!(cache.remove("cloud", "PROJ-1"));
+        assert!(!cache.remove("cloud", "PROJ-1"));
+        assert!(cache.get("cloud", "PROJ-1", 1000, 60).is_none());
+    }
+
+    #[test]
+    fn prune_drops_stale_entries() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        cache.insert("cloud", issue("PROJ-2"), 1100);
+        cache.prune(1100, 60);
+        assert!(cache.get("cloud", "PROJ-1", 1000, 600).is_none());
+        assert!(cache.get("cloud", "PROJ-2", 1100, 60).is_some());
+    }
+
+    #[test]
+    fn cache_round_trips() {
+        let mut cache = IssueCache::default();
+        cache.insert("cloud", issue("PROJ-1"), 1000);
+        let json = serde_json::to_string(&cache).unwrap();
+        let loaded: IssueCache = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.get("cloud", "PROJ-1", 1000, 60).unwrap().key,
+            "PROJ-1"
+        );
+    }
+}
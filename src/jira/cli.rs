@@ -30,6 +30,20 @@ pub enum JiraCommand {
     Show {
         /// Ticket key (e.g., PROJ-123)
         key: String,
+
+        /// Skip the issue cache and fetch from Jira
+        #[arg(long)]
+        fresh: bool,
+    },
+
+    /// Open a ticket in the browser
+    Open {
+        /// Ticket key (e.g., PROJ-123)
+        key: String,
+
+        /// Print the URL instead of opening it
+        #[arg(long, short = 'p')]
+        print: bool,
     },
 
     /// Create a new ticket
@@ -60,7 +74,7 @@ pub enum JiraCommand {
         assign: Option<String>,
 
         /// Emit the created issue as JSON
-        #[arg(long, short = 'j')]
+        #[arg(long, short = 'j', hide = true)]
         json: bool,
     },
 
@@ -74,7 +88,34 @@ pub enum JiraCommand {
         full: bool,
 
         /// Emit JSON instead of a table
-        #[arg(long, short = 'j')]
+        #[arg(long, short = 'j', hide = true)]
+        json: bool,
+    },
+
+    /// Log time on a ticket
+    Log {
+        /// Ticket key (e.g., PROJ-123)
+        key: String,
+
+        /// Time spent as a Jira duration (e.g., "2h 30m", 1d, 45m)
+        time: String,
+
+        /// Worklog comment (Markdown)
+        #[arg(long, short = 'm')]
+        comment: Option<String>,
+
+        /// When the work started, local "YYYY-MM-DD HH:MM" (default: now)
+        #[arg(long)]
+        started: Option<String>,
+    },
+
+    /// List time logged on a ticket
+    Worklogs {
+        /// Ticket key (e.g., PROJ-123)
+        key: String,
+
+        /// Emit JSON instead of a table
+        #[arg(long, short = 'j', hide = true)]
         json: bool,
     },
 
@@ -152,6 +193,36 @@ mod tests {
         assert!(matches.is_ok());
     }
 
+    #[test]
+    fn parses_log_with_comment() {
+        let cmd = build_cmd();
+        let matches =
+            cmd.try_get_matches_from(["test", "log", "PROJ-123", "2h 30m", "-m", "review"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn parses_log_requires_time() {
+        let cmd = build_cmd();
+        assert!(cmd
+            .try_get_matches_from(["test", "log", "PROJ-123"])
+            .is_err());
+    }
+
+    #[test]
+    fn parses_worklogs() {
+        let cmd = build_cmd();
+        let matches = cmd.try_get_matches_from(["test", "worklogs", "PROJ-123", "--json"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn parses_open_print() {
+        let cmd = build_cmd();
+        let matches = cmd.try_get_matches_from(["test", "open", "PROJ-123", "--print"]);
+        assert!(matches.is_ok());
+    }
+
     #[test]
     fn parses_show() {
         let cmd = build_cmd();
@@ -159,6 +230,13 @@ mod tests {
         assert!(matches.is_ok());
     }
 
+    #[test]
+    fn parses_show_fresh() {
+        let cmd = build_cmd();
+        let matches = cmd.try_get_matches_from(["test", "show", "PROJ-123", "--fresh"]);
+        assert!(matches.is_ok());
+    }
+
     #[test]
     fn parses_update_with_summary() {
         let cmd = build_cmd();
@@ -281,6 +359,7 @@ mod tests {
     fn show_command_debug() {
         let cmd = JiraCommand::Show {
             key: "X-1".to_string(),
+            fresh: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(debug_str.contains("Show"));
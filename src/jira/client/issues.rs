@@ -8,6 +8,7 @@ use anyhow::{bail, Context, Result};
 use super::JiraClient;
 use crate::jira::adf;
 use crate::jira::types::{Issue, IssueUpdate, User};
+use crate::util::bail_kind;
 
 /// Get current authenticated user.
 pub(super) async fn get_current_user(client: &JiraClient) -> Result<User> {
@@ -40,7 +41,11 @@ pub(super) async fn get_issue(client: &JiraClient, key: &str) -> Result<Issue> {
         .await
         .context("Failed to get issue")?;
 
-    if !response.status().is_success() {
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        bail_kind!(NotFound, "Issue {} not found", key);
+    }
+    if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
         bail!("Failed to get issue {}: {}", key, error_text);
     }
@@ -5,19 +5,26 @@
 //! - [`JiraClient`] — concrete OAuth-backed implementation
 //! - [`issues`] — `/myself`, `/issue/{key}`, `/search/jql`, PUT `/issue/{key}` + parsers
 //! - [`transitions`] — `/issue/{key}/transitions` GET/POST + parser
+//! - [`worklogs`] — `/issue/{key}/worklog` GET/POST + parser
+//!
+//! `get_issue` goes through the short-lived [`cache`](super::cache);
+//! `update_issue` and `transition_issue` drop the issue from it.
 
 use anyhow::{bail, Context, Result};
 use std::future::Future;
 
 use super::auth;
+use super::cache;
+use super::config;
 use super::types::{
-    Comment, CreatedIssue, Issue, IssueCreate, IssueType, IssueUpdate, Transition, User,
+    Comment, CreatedIssue, Issue, IssueCreate, IssueType, IssueUpdate, Transition, User, Worklog,
 };
 
 mod comments;
 mod create;
 mod issues;
 mod transitions;
+mod worklogs;
 
 #[cfg(test)]
 mod tests;
@@ -63,6 +70,19 @@ pub trait JiraApi: Send + Sync {
         &self,
         project_key: &str,
     ) -> impl Future<Output = Result<Vec<IssueType>>> + Send;
+
+    /// Log time on an issue. `time_spent` is a Jira duration (`2h 30m`),
+    /// `started` a Jira timestamp (`2026-04-30T10:00:00.000+0000`).
+    fn add_worklog(
+        &self,
+        key: &str,
+        time_spent: &str,
+        comment: Option<&str>,
+        started: &str,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// List the worklogs on an issue, oldest first.
+    fn list_worklogs(&self, key: &str) -> impl Future<Output = Result<Vec<Worklog>>> + Send;
 }
 
 /// Jira API client.
@@ -76,6 +96,10 @@ pub struct JiraClient {
     /// Browse-URL base, e.g. `https://acme.atlassian.net`. Used to build
     /// human-facing links after a successful issue creation.
     pub(super) site_url: String,
+    /// Seconds a cached issue stays valid; `0` disables the cache.
+    cache_ttl: u64,
+    /// Skip cached issues (still refreshes the cache).
+    fresh: bool,
 }
 
 impl JiraClient {
@@ -84,15 +108,63 @@ impl JiraClient {
         let access_token = auth::refresh_token_if_needed().await?;
         let creds =
             auth::get_credentials().context("Not authenticated. Run `hu jira auth` first.")?;
+        let config = config::load_config()?;
 
         Ok(Self {
-            http: reqwest::Client::new(),
+            http: crate::util::http::client()?,
             cloud_id: creds.cloud_id,
             access_token,
             site_url: creds.site_url,
+            cache_ttl: config.cache_ttl,
+            fresh: false,
         })
     }
 
+    /// Always fetch issues from the API, ignoring the cache.
+    #[must_use]
+    pub fn fresh(mut self, fresh: bool) -> Self {
+        self.fresh = fresh;
+        self
+    }
+
+    /// Issue from the cache, unless stale, disabled or bypassed.
+    fn cached_issue(&self, key: &str) -> Option<Issue> {
+        if self.fresh || self.cache_ttl == 0 {
+            return None;
+        }
+        let now = chrono::Utc::now().timestamp();
+        let hit = cache::load()
+            .get(&self.cloud_id, key, now, self.cache_ttl)
+            .cloned();
+        log::debug!(
+            "Jira cache {} for {}",
+            if hit.is_some() { "hit" } else { "miss" },
+            key
+        );
+        hit
+    }
+
+    /// Store a freshly fetched issue. Failing to write the cache only
+    /// costs a round trip next time, so errors are ignored.
+    fn cache_issue(&self, issue: &Issue) {
+        if self.cache_ttl == 0 {
+            return;
+        }
+        let now = chrono::Utc::now().timestamp();
+        let mut cache = cache::load();
+        cache.prune(now, self.cache_ttl);
+        cache.insert(&self.cloud_id, issue.clone(), now);
+        let _ = cache::save(&cache);
+    }
+
+    /// Drop an issue that was just changed so the next read sees it.
+    fn forget_issue(&self, key: &str) {
+        let mut cache = cache::load();
+        if cache.remove(&self.cloud_id, key) {
+            let _ = cache::save(&cache);
+        }
+    }
+
     /// Build API URL for Jira REST API v3.
     pub(super) fn api_url(&self, path: &str) -> String {
         format!(
@@ -153,7 +225,12 @@ impl JiraApi for JiraClient {
     }
 
     async fn get_issue(&self, key: &str) -> Result<Issue> {
-        issues::get_issue(self, key).await
+        if let Some(issue) = self.cached_issue(key) {
+            return Ok(issue);
+        }
+        let issue = issues::get_issue(self, key).await?;
+        self.cache_issue(&issue);
+        Ok(issue)
     }
 
     async fn search_issues(&self, jql: &str) -> Result<Vec<Issue>> {
@@ -161,7 +238,9 @@ impl JiraApi for JiraClient {
     }
 
     async fn update_issue(&self, key: &str, update: &IssueUpdate) -> Result<()> {
-        issues::update_issue(self, key, update).await
+        issues::update_issue(self, key, update).await?;
+        self.forget_issue(key);
+        Ok(())
     }
 
     async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>> {
@@ -169,7 +248,9 @@ impl JiraApi for JiraClient {
     }
 
     async fn transition_issue(&self, key: &str, transition_id: &str) -> Result<()> {
-        transitions::transition_issue(self, key, transition_id).await
+        transitions::transition_issue(self, key, transition_id).await?;
+        self.forget_issue(key);
+        Ok(())
     }
 
     async fn list_comments(&self, key: &str) -> Result<Vec<Comment>> {
@@ -183,4 +264,18 @@ impl JiraApi for JiraClient {
     async fn get_issue_types(&self, project_key: &str) -> Result<Vec<IssueType>> {
         create::get_issue_types(self, project_key).await
     }
+
+    async fn add_worklog(
+        &self,
+        key: &str,
+        time_spent: &str,
+        comment: Option<&str>,
+        started: &str,
+    ) -> Result<()> {
+        worklogs::add_worklog(self, key, time_spent, comment, started).await
+    }
+
+    async fn list_worklogs(&self, key: &str) -> Result<Vec<Worklog>> {
+        worklogs::list_worklogs(self, key).await
+    }
 }
@@ -0,0 +1,172 @@
+//! Worklog (time tracking) Jira API operations.
+//!
+//! Endpoints: `GET /issue/{key}/worklog`, `POST /issue/{key}/worklog`.
+
+use anyhow::{bail, Context, Result};
+
+use super::JiraClient;
+use crate::jira::adf;
+use crate::jira::types::{User, Worklog};
+
+/// Log time against an issue.
+pub(super) async fn add_worklog(
+    client: &JiraClient,
+    key: &str,
+    time_spent: &str,
+    comment: Option<&str>,
+    started: &str,
+) -> Result<()> {
+    let url = client.api_url(&format!("/issue/{}/worklog", key));
+    let body = build_worklog_body(time_spent, comment, started);
+
+    let response = client
+        .http
+        .post(&url)
+        .bearer_auth(&client.access_token)
+        .json(&body)
+        .send()
+        .await
+        .context("Failed to add worklog")?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        bail!("Failed to log time on {} ({}): {}", key, status, error_text);
+    }
+
+    Ok(())
+}
+
+/// List worklogs on an issue, oldest first.
+pub(super) async fn list_worklogs(client: &JiraClient, key: &str) -> Result<Vec<Worklog>> {
+    let url = client.api_url(&format!("/issue/{}/worklog?maxResults=1000", key));
+    let response = client
+        .http
+        .get(&url)
+        .bearer_auth(&client.access_token)
+        .send()
+        .await
+        .context("Failed to list worklogs")?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        bail!("Failed to list worklogs for {}: {}", key, error_text);
+    }
+
+    let json: serde_json::Value = response.json().await?;
+    Ok(parse_worklogs(&json))
+}
+
+/// Build the `POST /issue/{key}/worklog` request body. Pure function for
+/// testability.
+pub fn build_worklog_body(
+    time_spent: &str,
+    comment: Option<&str>,
+    started: &str,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "timeSpent": time_spent,
+        "started": started,
+    });
+    if let Some(text) = comment {
+        body["comment"] = adf::markdown_to_adf(text);
+    }
+    body
+}
+
+/// Parse the worklog-list response (pure function, testable).
+pub fn parse_worklogs(json: &serde_json::Value) -> Vec<Worklog> {
+    json["worklogs"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter_map(parse_single_worklog)
+        .collect()
+}
+
+/// Parse a single worklog object.
+fn parse_single_worklog(json: &serde_json::Value) -> Option<Worklog> {
+    let author = &json["author"];
+    Some(Worklog {
+        id: json["id"].as_str()?.to_string(),
+        author: User {
+            account_id: author["accountId"].as_str()?.to_string(),
+            display_name: author["displayName"]
+                .as_str()
+                .unwrap_or("Unknown")
+                .to_string(),
+            email_address: author["emailAddress"].as_str().map(|s| s.to_string()),
+        },
+        time_spent: json["timeSpent"].as_str().unwrap_or_default().to_string(),
+        time_spent_seconds: json["timeSpentSeconds"].as_u64().unwrap_or(0),
+        started: json["started"].as_str().unwrap_or_default().to_string(),
+        comment: adf::adf_to_plain_text(&json["comment"]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn build_worklog_body_without_comment() {
+        let body = build_worklog_body("2h 30m", None, "2026-04-30T10:00:00.000+0000");
+        assert_eq!(body["timeSpent"], "2h 30m");
+        assert_eq!(body["started"], "2026-04-30T10:00:00.000+0000");
+        assert!(body.get("comment").is_none());
+    }
+
+    #[test]
+    fn build_worklog_body_comment_is_adf() {
+        let body = build_worklog_body(
+            "1h",
+            Some("Pairing on **auth**"),
+            "2026-04-30T10:00:00.000+0000",
+        );
+        assert_eq!(body["comment"]["type"], "doc");
+        assert_eq!(adf::adf_to_plain_text(&body["comment"]), "Pairing on auth");
+    }
+
+    #[test]
+    fn parse_worklogs_extracts_list() {
+        let json = json!({
+            "startAt": 0,
+            "total": 2,
+            "worklogs": [
+                {
+                    "id": "100",
+                    "author": {"accountId": "u1", "displayName": "Alice"},
+                    "comment": {
+                        "type": "doc",
+                        "version": 1,
+                        "content": [{"type": "paragraph", "content": [{"type": "text", "text": "review"}]}]
+                    },
+                    "started": "2026-04-30T10:00:00.000+0000",
+                    "timeSpent": "2h 30m",
+                    "timeSpentSeconds": 9000
+                },
+                {
+                    "id": "101",
+                    "author": {"accountId": "u2"},
+                    "started": "2026-04-30T14:00:00.000+0000",
+                    "timeSpent": "1h",
+                    "timeSpentSeconds": 3600
+                }
+            ]
+        });
+        let worklogs = parse_worklogs(&json);
+        assert_eq!(worklogs.len(), 2);
+        assert_eq!(worklogs[0].author.display_name, "Alice");
+        assert_eq!(worklogs[0].time_spent, "2h 30m");
+        assert_eq!(worklogs[0].time_spent_seconds, 9000);
+        assert_eq!(worklogs[0].comment, "review");
+        assert_eq!(worklogs[1].author.display_name, "Unknown");
+        assert_eq!(worklogs[1].comment, "");
+    }
+
+    #[test]
+    fn parse_worklogs_handles_missing_field() {
+        assert!(parse_worklogs(&json!({})).is_empty());
+    }
+}
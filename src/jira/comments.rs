@@ -1,8 +1,11 @@
 //! `hu jira comments <KEY>` — list comments on an issue.
 
+use anstream::print;
 use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use crate::util::{print_structured, OutputFormat};
+
 use super::client::{JiraApi, JiraClient};
 use super::types::Comment;
 
@@ -12,29 +15,28 @@ pub struct CommentsArgs {
     pub key: String,
     /// Show full comment bodies; otherwise truncate to a single-line preview.
     pub full: bool,
-    /// Emit JSON instead of a table.
+    /// Emit JSON instead of a table (the hidden `--json` flag).
     pub json: bool,
 }
 
 /// Run the jira comments command (CLI entry point — formats and prints).
 pub async fn run(args: CommentsArgs) -> Result<()> {
     let client = JiraClient::new().await?;
-    let output = process_comments(&client, &args).await?;
-    print!("{}", output);
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => print!("{}", process_comments(&client, &args).await?),
+        format => print_structured(&client.list_comments(&args.key).await?, format)?,
+    }
     Ok(())
 }
 
 /// Process comments command (business logic, testable).
 pub async fn process_comments(client: &impl JiraApi, args: &CommentsArgs) -> Result<String> {
     let comments = client.list_comments(&args.key).await?;
-    Ok(format_comments(&args.key, &comments, args.full, args.json))
+    Ok(format_comments(&args.key, &comments, args.full))
 }
 
-/// Render the comments collection as either a table or JSON.
-pub fn format_comments(key: &str, comments: &[Comment], full: bool, json: bool) -> String {
-    if json {
-        return format_json(comments);
-    }
+/// Render the comments collection as a table, or in full with `full`.
+pub fn format_comments(key: &str, comments: &[Comment], full: bool) -> String {
     if comments.is_empty() {
         return format!("No comments on {}.\n", key);
     }
@@ -45,10 +47,6 @@ pub fn format_comments(key: &str, comments: &[Comment], full: bool, json: bool)
     }
 }
 
-fn format_json(comments: &[Comment]) -> String {
-    serde_json::to_string_pretty(comments).unwrap_or_else(|_| "[]".to_string()) + "\n"
-}
-
 fn format_table(key: &str, comments: &[Comment]) -> String {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
@@ -149,7 +147,7 @@ mod tests {
 
     #[test]
     fn format_comments_empty_message() {
-        let out = format_comments("HU-1", &[], false, false);
+        let out = format_comments("HU-1", &[], false);
         assert!(out.contains("No comments on HU-1"));
     }
 
@@ -159,7 +157,7 @@ mod tests {
             make_comment("1", "Alice", "first", "2026-04-30T10:00:00.000Z"),
             make_comment("2", "Bob", "second", "2026-04-30T11:30:00.000Z"),
         ];
-        let out = format_comments("HU-1", &comments, false, false);
+        let out = format_comments("HU-1", &comments, false);
         assert!(out.contains("HU-1"));
         assert!(out.contains("2 comments"));
         assert!(out.contains("Alice"));
@@ -176,7 +174,7 @@ mod tests {
             "only",
             "2026-04-30T10:00:00.000Z",
         )];
-        let out = format_comments("HU-1", &comments, false, false);
+        let out = format_comments("HU-1", &comments, false);
         assert!(out.contains("1 comment\n") || out.contains("1 comment\u{a0}"));
         assert!(!out.contains("1 comments"));
     }
@@ -185,7 +183,7 @@ mod tests {
     fn format_comments_full_mode_renders_complete_body() {
         let body = "line one\nline two\nline three";
         let comments = vec![make_comment("1", "Alice", body, "2026-04-30T10:00:00.000Z")];
-        let out = format_comments("HU-1", &comments, true, false);
+        let out = format_comments("HU-1", &comments, true);
         assert!(out.contains("line one"));
         assert!(out.contains("line two"));
         assert!(out.contains("line three"));
@@ -201,20 +199,19 @@ mod tests {
             &long,
             "2026-04-30T10:00:00.000Z",
         )];
-        let out = format_comments("HU-1", &comments, false, false);
+        let out = format_comments("HU-1", &comments, false);
         assert!(out.contains('…'));
     }
 
     #[test]
-    fn format_comments_json_emits_valid_array() {
+    fn comments_serialize_for_structured_output() {
         let comments = vec![make_comment(
             "1",
             "Alice",
             "body",
             "2026-04-30T10:00:00.000Z",
         )];
-        let out = format_comments("HU-1", &comments, false, true);
-        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        let parsed = serde_json::to_value(&comments).unwrap();
         let arr = parsed.as_array().unwrap();
         assert_eq!(arr.len(), 1);
         assert_eq!(arr[0]["id"], "1");
@@ -316,6 +313,20 @@ mod tests {
         ) -> Result<Vec<super::super::types::IssueType>> {
             unimplemented!()
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -338,26 +349,6 @@ mod tests {
         assert!(out.contains("hello"));
     }
 
-    #[tokio::test]
-    async fn process_comments_json_path() {
-        let client = MockJiraClient {
-            comments: vec![make_comment(
-                "1",
-                "Alice",
-                "hello",
-                "2026-04-30T10:00:00.000Z",
-            )],
-        };
-        let args = CommentsArgs {
-            key: "HU-1".to_string(),
-            full: false,
-            json: true,
-        };
-        let out = process_comments(&client, &args).await.unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
-        assert!(parsed.is_array());
-    }
-
     #[tokio::test]
     async fn process_comments_empty_returns_friendly_message() {
         let client = MockJiraClient { comments: vec![] };
@@ -0,0 +1,70 @@
+//! Jira configuration
+//!
+//! Loads the `[jira]` section of `~/.config/hu/settings.toml`
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::fs;
+
+/// Jira configuration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct JiraConfig {
+    /// Seconds a fetched issue is reused before hitting the API again;
+    /// `0` disables the cache
+    pub cache_ttl: u64,
+}
+
+impl Default for JiraConfig {
+    fn default() -> Self {
+        Self { cache_ttl: 60 }
+    }
+}
+
+/// Settings file structure
+#[derive(Debug, Default, Deserialize)]
+struct SettingsFile {
+    jira: Option<JiraConfig>,
+}
+
+/// Load Jira config from the settings file
+#[cfg(not(tarpaulin_include))]
+pub fn load_config() -> Result<JiraConfig> {
+    match crate::util::settings_path() {
+        Some(path) if path.exists() => parse_config(&fs::read_to_string(&path)?),
+        _ => Ok(JiraConfig::default()),
+    }
+}
+
+/// Parse config from TOML string
+fn parse_config(contents: &str) -> Result<JiraConfig> {
+    let settings: SettingsFile = toml::from_str(contents)?;
+    Ok(settings.jira.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config_empty() {
+        assert_eq!(parse_config("").unwrap().cache_ttl, 60);
+    }
+
+    #[test]
+    fn parse_config_cache_ttl() {
+        let config = parse_config("[jira]\ncache_ttl = 0\n").unwrap();
+        assert_eq!(config.cache_ttl, 0);
+    }
+
+    #[test]
+    fn parse_config_other_sections_ignored() {
+        let config = parse_config("[eks]\ndefault_port = 3000\n").unwrap();
+        assert_eq!(config.cache_ttl, 60);
+    }
+
+    #[test]
+    fn parse_config_invalid_ttl() {
+        assert!(parse_config("[jira]\ncache_ttl = -1\n").is_err());
+    }
+}
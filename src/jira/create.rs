@@ -7,10 +7,13 @@
 
 use std::path::{Path, PathBuf};
 
+use anstream::print;
 use anyhow::{bail, Result};
 
+use crate::util::{print_structured, OutputFormat};
+
 use super::client::{JiraApi, JiraClient};
-use super::types::{IssueCreate, IssueType};
+use super::types::{CreatedIssue, IssueCreate, IssueType};
 use super::update::load_adf;
 
 /// Arguments for the create command. Mirrors the CLI struct for ease
@@ -29,13 +32,16 @@ pub struct CreateArgs {
 /// Run the jira create command (CLI entry point — formats and prints).
 pub async fn run(args: CreateArgs) -> Result<()> {
     let client = JiraClient::new().await?;
-    let output = process_create(&client, &args).await?;
-    print!("{}", output);
+    let created = process_create(&client, &args).await?;
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => print!("{}", format_created(&created, &args.summary)),
+        format => print_structured(&created, format)?,
+    }
     Ok(())
 }
 
 /// Process create command (business logic, testable).
-pub async fn process_create(client: &impl JiraApi, args: &CreateArgs) -> Result<String> {
+pub async fn process_create(client: &impl JiraApi, args: &CreateArgs) -> Result<CreatedIssue> {
     if args.summary.trim().is_empty() {
         bail!("Summary is required and cannot be empty");
     }
@@ -68,16 +74,15 @@ pub async fn process_create(client: &impl JiraApi, args: &CreateArgs) -> Result<
         assignee,
     };
 
-    let created = client.create_issue(&new).await?;
+    client.create_issue(&new).await
+}
 
-    if args.json {
-        let json = serde_json::to_string_pretty(&created).unwrap_or_default();
-        return Ok(format!("{}\n", json));
-    }
-    Ok(format!(
+/// Confirmation line for a created issue
+fn format_created(created: &CreatedIssue, summary: &str) -> String {
+    format!(
         "\x1b[32m\u{2713}\x1b[0m Created \x1b[1m{}\x1b[0m: {}\n   {}\n",
-        created.key, args.summary, created.url
-    ))
+        created.key, summary, created.url
+    )
 }
 
 /// Wrapper around [`update::load_adf`] so this module can read raw ADF
@@ -221,6 +226,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             Ok(self.types.clone())
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     fn make_mock() -> MockJiraClient {
@@ -255,9 +274,10 @@ mod tests {
     #[tokio::test]
     async fn process_create_succeeds_and_renders_url() {
         let client = make_mock();
-        let out = process_create(&client, &args("HU", "Test issue", "Task"))
+        let created = process_create(&client, &args("HU", "Test issue", "Task"))
             .await
             .unwrap();
+        let out = format_created(&created, "Test issue");
         assert!(out.contains("HU-1"));
         assert!(out.contains("Test issue"));
         assert!(out.contains("https://example.atlassian.net/browse/HU-1"));
@@ -374,12 +394,12 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn process_create_json_output_serialises_created_issue() {
+    async fn process_create_returns_serialisable_created_issue() {
         let client = make_mock();
-        let mut a = args("HU", "x", "Task");
-        a.json = true;
-        let out = process_create(&client, &a).await.unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        let created = process_create(&client, &args("HU", "x", "Task"))
+            .await
+            .unwrap();
+        let parsed = serde_json::to_value(&created).unwrap();
         assert_eq!(parsed["key"], "HU-1");
         assert_eq!(parsed["url"], "https://example.atlassian.net/browse/HU-1");
     }
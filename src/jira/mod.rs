@@ -15,10 +15,13 @@
 mod adf;
 mod auth;
 mod auth_handler;
+mod cache;
 mod cli;
 mod client;
 mod comments;
+mod config;
 mod create;
+mod open;
 mod search;
 mod service;
 mod show;
@@ -27,15 +30,18 @@ mod sprints;
 mod tickets;
 mod types;
 mod update;
+mod worklog;
 
 use anyhow::Result;
 
 pub use cli::JiraCommand;
+pub use config::load_config;
 pub use types::{Issue, IssueUpdate, Transition, User};
 
 use comments::CommentsArgs;
 use create::CreateArgs;
 use update::UpdateArgs;
+use worklog::{LogArgs, WorklogsArgs};
 
 /// Run a Jira command (CLI entry point - formats and prints)
 #[cfg(not(tarpaulin_include))]
@@ -46,7 +52,8 @@ pub async fn run_command(cmd: JiraCommand) -> anyhow::Result<()> {
         JiraCommand::Sprint => sprint::run(sprint::SprintArgs::default()).await,
         JiraCommand::Sprints { state } => sprints::run(&state).await,
         JiraCommand::Search { query } => search::run(&query).await,
-        JiraCommand::Show { key } => show::run(&key).await,
+        JiraCommand::Show { key, fresh } => show::run(&key, fresh).await,
+        JiraCommand::Open { key, print } => open::run(&key, print),
         JiraCommand::Comments { key, full, json } => {
             comments::run(CommentsArgs { key, full, json }).await
         }
@@ -70,6 +77,23 @@ pub async fn run_command(cmd: JiraCommand) -> anyhow::Result<()> {
             })
             .await
         }
+        JiraCommand::Log {
+            key,
+            time,
+            comment,
+            started,
+        } => {
+            worklog::run_log(LogArgs {
+                key,
+                time,
+                comment,
+                started,
+            })
+            .await
+        }
+        JiraCommand::Worklogs { key, json } => {
+            worklog::run_worklogs(WorklogsArgs { key, json }).await
+        }
         JiraCommand::Update {
             key,
             summary,
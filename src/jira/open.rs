@@ -0,0 +1,81 @@
+//! `hu jira open <KEY>` — open an issue in the browser.
+
+use anstream::println;
+use anyhow::{bail, Context, Result};
+
+use super::auth;
+
+/// Open the issue's browse page, or with `print` just emit the URL.
+#[cfg(not(tarpaulin_include))]
+pub fn run(key: &str, print: bool) -> Result<()> {
+    let key = normalize_key(key)?;
+    let creds = auth::get_credentials().context("Not authenticated. Run `hu jira auth` first.")?;
+    let url = browse_url(&creds.site_url, &key);
+
+    if print {
+        println!("{}", url);
+        return Ok(());
+    }
+    open::that(&url).with_context(|| format!("Failed to open {}", url))?;
+    log::info!("Opened {}", url);
+    Ok(())
+}
+
+/// Upper-case a ticket key and check it looks like `ABC-123`.
+pub fn normalize_key(key: &str) -> Result<String> {
+    let key = key.trim().to_uppercase();
+    let valid = key.split_once('-').is_some_and(|(project, number)| {
+        project.starts_with(|c: char| c.is_ascii_alphabetic())
+            && project
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !number.is_empty()
+            && !number.starts_with('0')
+            && number.chars().all(|c| c.is_ascii_digit())
+    });
+    if !valid {
+        bail!(
+            "Invalid ticket key '{}'. Expected something like ABC-123",
+            key
+        );
+    }
+    Ok(key)
+}
+
+/// `{site_url}/browse/{key}`.
+pub fn browse_url(site_url: &str, key: &str) -> String {
+    format!("{}/browse/{}", site_url.trim_end_matches('/'), key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_key_accepts_and_uppercases() {
+        assert_eq!(normalize_key("HU-123").unwrap(), "HU-123");
+        assert_eq!(normalize_key(" proj-7 ").unwrap(), "PROJ-7");
+        assert_eq!(normalize_key("AB2_X-10").unwrap(), "AB2_X-10");
+    }
+
+    #[test]
+    fn normalize_key_rejects_typos() {
+        for bad in [
+            "HU123", "HU-", "-123", "HU-12a", "HU-0", "1HU-5", "HU-1-2", "",
+        ] {
+            assert!(normalize_key(bad).is_err(), "{bad} should be rejected");
+        }
+    }
+
+    #[test]
+    fn browse_url_trims_trailing_slash() {
+        assert_eq!(
+            browse_url("https://acme.atlassian.net/", "HU-1"),
+            "https://acme.atlassian.net/browse/HU-1"
+        );
+        assert_eq!(
+            browse_url("https://acme.atlassian.net", "HU-1"),
+            "https://acme.atlassian.net/browse/HU-1"
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use anstream::print;
 use anyhow::Result;
 
+use crate::util::fmt::truncate;
+
 use super::client::{JiraApi, JiraClient};
 use super::types::Issue;
 
@@ -71,15 +74,6 @@ fn format_search_results(issues: &[Issue], query: &str) -> String {
     output
 }
 
-/// Truncate string to max length with ellipsis
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::super::types::{
@@ -87,31 +81,6 @@ mod tests {
     };
     use super::*;
 
-    #[test]
-    fn truncate_short_string_unchanged() {
-        assert_eq!(truncate("hello", 10), "hello");
-    }
-
-    #[test]
-    fn truncate_exact_length_unchanged() {
-        assert_eq!(truncate("hello", 5), "hello");
-    }
-
-    #[test]
-    fn truncate_long_string_adds_ellipsis() {
-        assert_eq!(truncate("hello world", 8), "hello...");
-    }
-
-    #[test]
-    fn truncate_very_short_max() {
-        assert_eq!(truncate("hello", 3), "...");
-    }
-
-    #[test]
-    fn truncate_zero_max() {
-        assert_eq!(truncate("hello", 0), "...");
-    }
-
     #[test]
     fn format_search_results_empty() {
         let issues: Vec<Issue> = vec![];
@@ -263,6 +232,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             unimplemented!()
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
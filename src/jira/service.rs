@@ -125,6 +125,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             Ok(vec![])
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     fn make_issue(key: &str, summary: &str, status: &str) -> Issue {
@@ -1,11 +1,12 @@
+use anstream::print;
 use anyhow::Result;
 
 use super::client::{JiraApi, JiraClient};
 use super::types::Issue;
 
 /// Run the jira show command
-pub async fn run(key: &str) -> Result<()> {
-    let client = JiraClient::new().await?;
+pub async fn run(key: &str, fresh: bool) -> Result<()> {
+    let client = JiraClient::new().await?.fresh(fresh);
     let output = process_show(&client, key).await?;
     print!("{}", output);
     Ok(())
@@ -252,6 +253,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             unimplemented!()
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -1,3 +1,4 @@
+use anstream::print;
 use anyhow::Result;
 
 use super::client::{JiraApi, JiraClient};
@@ -249,6 +250,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             unimplemented!()
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
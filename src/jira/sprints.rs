@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::{bail, Result};
 use std::collections::HashMap;
 
@@ -1,5 +1,8 @@
+use anstream::print;
 use anyhow::Result;
 
+use crate::util::fmt::truncate;
+
 use super::client::{JiraApi, JiraClient};
 use super::types::Issue;
 
@@ -159,16 +162,6 @@ fn format_tickets(issues: &[Issue]) -> String {
     output
 }
 
-/// Truncate string to max length with ellipsis
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.chars().count() <= max_len {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max_len.saturating_sub(1)).collect();
-        format!("{}…", truncated)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::super::types::{
@@ -176,27 +169,6 @@ mod tests {
     };
     use super::*;
 
-    #[test]
-    fn truncate_short_unchanged() {
-        assert_eq!(truncate("hello", 10), "hello");
-    }
-
-    #[test]
-    fn truncate_long_adds_ellipsis() {
-        assert_eq!(truncate("hello world", 8), "hello w…");
-    }
-
-    #[test]
-    fn truncate_unicode() {
-        assert_eq!(truncate("héllo", 5), "héllo");
-        assert_eq!(truncate("héllo world", 6), "héllo…");
-    }
-
-    #[test]
-    fn truncate_exact_length() {
-        assert_eq!(truncate("hello", 5), "hello");
-    }
-
     #[test]
     fn get_terminal_width_returns_reasonable_value() {
         let width = get_terminal_width();
@@ -303,7 +275,7 @@ mod tests {
         }];
         let output = format_tickets(&issues);
         // Should contain truncation indicator
-        assert!(output.contains("…"));
+        assert!(output.contains("..."));
     }
 
     // Mock client for testing
@@ -347,6 +319,20 @@ mod tests {
         async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
             unimplemented!()
         }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _time_spent: &str,
+            _comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -103,6 +103,20 @@ pub struct Comment {
     pub updated: String,
 }
 
+/// A time-tracking entry on an issue.
+///
+/// `time_spent` is Jira's display form (`2h 30m`); `comment` is the
+/// comment rendered to plain text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Worklog {
+    pub id: String,
+    pub author: User,
+    pub time_spent: String,
+    pub time_spent_seconds: u64,
+    pub started: String,
+    pub comment: String,
+}
+
 /// OAuth configuration for Jira
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OAuthConfig {
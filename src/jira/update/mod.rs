@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use anstream::print;
 use anyhow::{bail, Context, Result};
 
 use super::client::{JiraApi, JiraClient};
@@ -223,6 +223,20 @@ impl JiraApi for MockJiraClient {
     ) -> Result<Vec<super::super::types::IssueType>> {
         unimplemented!()
     }
+
+    async fn add_worklog(
+        &self,
+        _key: &str,
+        _time_spent: &str,
+        _comment: Option<&str>,
+        _started: &str,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    async fn list_worklogs(&self, _key: &str) -> Result<Vec<crate::jira::types::Worklog>> {
+        unimplemented!()
+    }
 }
 
 fn make_mock(user_account_id: &str, transitions: Vec<Transition>) -> MockJiraClient {
@@ -0,0 +1,392 @@
+//! `hu jira log <KEY> <TIME>` and `hu jira worklogs <KEY>` — time tracking.
+
+use anstream::print;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+
+use crate::util::{print_structured, OutputFormat};
+
+use super::client::{JiraApi, JiraClient};
+use super::types::Worklog;
+
+/// Arguments for the log command
+#[derive(Debug, Clone)]
+pub struct LogArgs {
+    pub key: String,
+    /// Time spent, e.g. `2h 30m`.
+    pub time: String,
+    /// Worklog comment (Markdown).
+    pub comment: Option<String>,
+    /// Local start time as `YYYY-MM-DD HH:MM`; now when absent.
+    pub started: Option<String>,
+}
+
+/// Arguments for the worklogs command
+#[derive(Debug, Clone)]
+pub struct WorklogsArgs {
+    pub key: String,
+    /// Emit JSON instead of a table (the hidden `--json` flag).
+    pub json: bool,
+}
+
+/// Run the jira log command (CLI entry point — formats and prints).
+pub async fn run_log(args: LogArgs) -> Result<()> {
+    let client = JiraClient::new().await?;
+    let output = process_log(&client, &args).await?;
+    print!("{}", output);
+    Ok(())
+}
+
+/// Run the jira worklogs command (CLI entry point — formats and prints).
+pub async fn run_worklogs(args: WorklogsArgs) -> Result<()> {
+    let client = JiraClient::new().await?;
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => print!("{}", process_worklogs(&client, &args).await?),
+        format => print_structured(&client.list_worklogs(&args.key).await?, format)?,
+    }
+    Ok(())
+}
+
+/// Process log command (business logic, testable).
+///
+/// The duration and start time are validated before anything is sent.
+pub async fn process_log(client: &impl JiraApi, args: &LogArgs) -> Result<String> {
+    let time_spent = parse_time_spent(&args.time)?;
+    let started = match &args.started {
+        Some(input) => parse_started(input)?,
+        None => Local::now(),
+    };
+
+    client
+        .add_worklog(
+            &args.key,
+            &time_spent,
+            args.comment.as_deref(),
+            &jira_timestamp(&started),
+        )
+        .await?;
+
+    Ok(format!(
+        "\x1b[32m✓\x1b[0m Logged {} on {}\n",
+        time_spent, args.key
+    ))
+}
+
+/// Process worklogs command (business logic, testable).
+pub async fn process_worklogs(client: &impl JiraApi, args: &WorklogsArgs) -> Result<String> {
+    let worklogs = client.list_worklogs(&args.key).await?;
+    Ok(format_worklogs(&args.key, &worklogs))
+}
+
+/// Validate a Jira duration and normalise it to `1w 2d 3h 4m` form.
+///
+/// Units must appear at most once and largest first; spaces between them
+/// are optional (`2h30m` is fine).
+pub fn parse_time_spent(input: &str) -> Result<String> {
+    const UNITS: [char; 4] = ['w', 'd', 'h', 'm'];
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let invalid = || {
+        anyhow::anyhow!(
+            "Invalid time '{}'. Use Jira durations like 2h 30m, 1d or 45m",
+            input
+        )
+    };
+
+    let mut parts = Vec::new();
+    let mut last_unit = None;
+    let mut rest = compact.as_str();
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let amount: u32 = rest[..digits].parse().map_err(|_| invalid())?;
+        let unit = rest[digits..].chars().next().ok_or_else(invalid)?;
+        let rank = UNITS.iter().position(|&u| u == unit).ok_or_else(invalid)?;
+        if last_unit.is_some_and(|last| rank <= last) {
+            return Err(invalid());
+        }
+        last_unit = Some(rank);
+        if amount > 0 {
+            parts.push(format!("{}{}", amount, unit));
+        }
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+
+    if parts.is_empty() {
+        bail!("Time spent must be more than zero");
+    }
+    Ok(parts.join(" "))
+}
+
+/// Parse a local `YYYY-MM-DD HH:MM` start time.
+pub fn parse_started(input: &str) -> Result<DateTime<Local>> {
+    let naive = NaiveDateTime::parse_from_str(input.trim(), "%Y-%m-%d %H:%M")
+        .with_context(|| format!("Invalid start time '{}'. Use YYYY-MM-DD HH:MM", input))?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .with_context(|| format!("{} does not exist in the local timezone", input))
+}
+
+/// Format a time the way the worklog API expects (`2026-04-30T10:00:00.000+0200`).
+pub fn jira_timestamp<Tz: TimeZone>(time: &DateTime<Tz>) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    time.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string()
+}
+
+/// Render worklogs as a table with the total time logged.
+pub fn format_worklogs(key: &str, worklogs: &[Worklog]) -> String {
+    if worklogs.is_empty() {
+        return format!("No time logged on {}.\n", key);
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["STARTED", "AUTHOR", "TIME", "COMMENT"]);
+
+    for worklog in worklogs {
+        table.add_row(vec![
+            Cell::new(format_started(&worklog.started)),
+            Cell::new(&worklog.author.display_name).fg(Color::Cyan),
+            Cell::new(&worklog.time_spent),
+            Cell::new(worklog.comment.replace('\n', " ")),
+        ]);
+    }
+
+    let total: u64 = worklogs.iter().map(|w| w.time_spent_seconds).sum();
+    let mut output = format!("\x1b[1m{}\x1b[0m — {} logged\n", key, format_seconds(total));
+    output.push_str(&format!("{}\n", table));
+    output
+}
+
+/// `2026-04-30T10:00:00.000+0000` → `2026-04-30 10:00`.
+fn format_started(started: &str) -> String {
+    match started.split_once('T') {
+        Some((date, time)) => format!("{} {}", date, time.get(..5).unwrap_or(time)),
+        None => started.to_string(),
+    }
+}
+
+/// Total seconds as hours and minutes, e.g. `3h 15m`.
+fn format_seconds(seconds: u64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    match (hours, minutes) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::{
+        Comment, CreatedIssue, Issue, IssueCreate, IssueType, IssueUpdate, Transition, User,
+    };
+    use super::*;
+    use std::sync::Mutex;
+
+    fn make_worklog(author: &str, time: &str, seconds: u64) -> Worklog {
+        Worklog {
+            id: "1".to_string(),
+            author: User {
+                account_id: "a-1".to_string(),
+                display_name: author.to_string(),
+                email_address: None,
+            },
+            time_spent: time.to_string(),
+            time_spent_seconds: seconds,
+            started: "2026-04-30T10:00:00.000+0000".to_string(),
+            comment: "review".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_time_spent_normalises() {
+        assert_eq!(parse_time_spent("2h 30m").unwrap(), "2h 30m");
+        assert_eq!(parse_time_spent("2h30m").unwrap(), "2h 30m");
+        assert_eq!(parse_time_spent(" 1w 2d ").unwrap(), "1w 2d");
+        assert_eq!(parse_time_spent("45m").unwrap(), "45m");
+        assert_eq!(parse_time_spent("1h 0m").unwrap(), "1h");
+    }
+
+    #[test]
+    fn parse_time_spent_rejects_bad_input() {
+        assert!(parse_time_spent("").is_err());
+        assert!(parse_time_spent("2").is_err());
+        assert!(parse_time_spent("2x").is_err());
+        assert!(parse_time_spent("1.5h").is_err());
+        assert!(parse_time_spent("2ч").is_err());
+        assert!(parse_time_spent("30m 2h").is_err());
+        assert!(parse_time_spent("1h 1h").is_err());
+        assert!(parse_time_spent("0m")
+            .unwrap_err()
+            .to_string()
+            .contains("more than zero"));
+    }
+
+    #[test]
+    fn parse_started_accepts_local_time() {
+        let started = parse_started("2026-04-30 09:15").unwrap();
+        assert_eq!(
+            started.format("%Y-%m-%d %H:%M").to_string(),
+            "2026-04-30 09:15"
+        );
+        assert!(parse_started("30/04/2026").is_err());
+    }
+
+    #[test]
+    fn jira_timestamp_format() {
+        let time = chrono::Utc.with_ymd_and_hms(2026, 4, 30, 10, 0, 0).unwrap();
+        assert_eq!(jira_timestamp(&time), "2026-04-30T10:00:00.000+0000");
+    }
+
+    #[test]
+    fn format_worklogs_empty_message() {
+        assert!(format_worklogs("HU-1", &[]).contains("No time logged on HU-1"));
+    }
+
+    #[test]
+    fn format_worklogs_table_has_rows_and_total() {
+        let worklogs = vec![
+            make_worklog("Alice", "2h 30m", 9000),
+            make_worklog("Bob", "45m", 2700),
+        ];
+        let out = format_worklogs("HU-1", &worklogs);
+        assert!(out.contains("3h 15m logged"));
+        assert!(out.contains("Alice"));
+        assert!(out.contains("2h 30m"));
+        assert!(out.contains("2026-04-30 10:00"));
+    }
+
+    #[test]
+    fn worklogs_serialize_for_structured_output() {
+        let parsed = serde_json::to_value([make_worklog("Alice", "1h", 3600)]).unwrap();
+        assert_eq!(parsed[0]["time_spent"], "1h");
+    }
+
+    #[test]
+    fn format_seconds_units() {
+        assert_eq!(format_seconds(2700), "45m");
+        assert_eq!(format_seconds(7200), "2h");
+        assert_eq!(format_seconds(11700), "3h 15m");
+    }
+
+    // Mock client that records the worklog it is asked to add
+    struct MockJiraClient {
+        added: Mutex<Option<(String, String, Option<String>)>>,
+    }
+
+    impl JiraApi for MockJiraClient {
+        async fn get_current_user(&self) -> Result<User> {
+            unimplemented!()
+        }
+
+        async fn get_issue(&self, _key: &str) -> Result<Issue> {
+            unimplemented!()
+        }
+
+        async fn search_issues(&self, _jql: &str) -> Result<Vec<Issue>> {
+            unimplemented!()
+        }
+
+        async fn update_issue(&self, _key: &str, _update: &IssueUpdate) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_transitions(&self, _key: &str) -> Result<Vec<Transition>> {
+            unimplemented!()
+        }
+
+        async fn transition_issue(&self, _key: &str, _transition_id: &str) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn list_comments(&self, _key: &str) -> Result<Vec<Comment>> {
+            unimplemented!()
+        }
+
+        async fn create_issue(&self, _new: &IssueCreate) -> Result<CreatedIssue> {
+            unimplemented!()
+        }
+
+        async fn get_issue_types(&self, _project_key: &str) -> Result<Vec<IssueType>> {
+            unimplemented!()
+        }
+
+        async fn add_worklog(
+            &self,
+            key: &str,
+            time_spent: &str,
+            comment: Option<&str>,
+            _started: &str,
+        ) -> Result<()> {
+            *self.added.lock().unwrap() = Some((
+                key.to_string(),
+                time_spent.to_string(),
+                comment.map(str::to_string),
+            ));
+            Ok(())
+        }
+
+        async fn list_worklogs(&self, _key: &str) -> Result<Vec<Worklog>> {
+            Ok(vec![make_worklog("Alice", "1h", 3600)])
+        }
+    }
+
+    fn make_mock() -> MockJiraClient {
+        MockJiraClient {
+            added: Mutex::new(None),
+        }
+    }
+
+    #[tokio::test]
+    async fn process_log_sends_normalised_time() {
+        let client = make_mock();
+        let args = LogArgs {
+            key: "HU-1".to_string(),
+            time: "2h30m".to_string(),
+            comment: Some("pairing".to_string()),
+            started: None,
+        };
+        let out = process_log(&client, &args).await.unwrap();
+        assert!(out.contains("Logged 2h 30m on HU-1"));
+        let added = client.added.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            added,
+            (
+                "HU-1".to_string(),
+                "2h 30m".to_string(),
+                Some("pairing".to_string())
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn process_log_rejects_bad_time_before_sending() {
+        let client = make_mock();
+        let args = LogArgs {
+            key: "HU-1".to_string(),
+            time: "two hours".to_string(),
+            comment: None,
+            started: None,
+        };
+        assert!(process_log(&client, &args).await.is_err());
+        assert!(client.added.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn process_worklogs_formats_table() {
+        let args = WorklogsArgs {
+            key: "HU-1".to_string(),
+            json: false,
+        };
+        let out = process_worklogs(&make_mock(), &args).await.unwrap();
+        assert!(out.contains("Alice"));
+        assert!(out.contains("1h logged"));
+    }
+}
@@ -1,16 +1,22 @@
+use anstream::println;
 use clap::{CommandFactory, Parser};
+use std::process::ExitCode;
 
+mod auth;
 mod cli;
+mod config;
 mod context;
 mod cron;
 mod data;
 mod docs;
+mod doctor;
 mod eks;
 mod gh;
 mod git;
 mod install;
 mod jira;
 mod mcp;
+mod migrate_secrets;
 mod newrelic;
 mod pagerduty;
 mod pipeline;
@@ -19,25 +25,74 @@ mod sentry;
 mod setup;
 mod shell;
 mod slack;
+mod status;
 mod util;
 mod utils;
 
 use cli::{Cli, Command};
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+async fn main() -> ExitCode {
+    let cli = match parse_cli() {
+        Ok(cli) => cli,
+        Err(e) => return util::report_error(&e),
+    };
+    util::color::init(cli.no_color);
+    util::logging::init(cli.verbose, cli.quiet);
+    if let Some(path) = cli.config.clone() {
+        util::set_settings_path(path);
+    }
+    if let Some(secs) = cli.http_timeout {
+        util::http::set_timeout(secs);
+    }
+    if cli.dry_run {
+        util::dry_run::enable();
+    }
+    if let Some(fields) = cli.fields.clone() {
+        util::set_fields(fields);
+    }
+    if let Some(format) = cli.output {
+        util::OutputFormat::set_default(format);
+    }
+    if let Err(e) = util::http::load_ca_bundle() {
+        return util::report_error(&e);
+    }
 
-    match cli.command {
+    let result = match cli.command {
+        Some(cmd) if cli.dry_run && !cmd.supports_dry_run() => Err(anyhow::anyhow!(
+            "--dry-run only works with eks exec/last/logs/describe/forward, \
+             pipeline and gh checkout; this command would run for real"
+        )),
         Some(cmd) => run_command(cmd).await,
-        None => {
-            Cli::command().print_help()?;
-            println!();
-            Ok(())
-        }
+        None => print_help(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) if util::dry_run::is_printed(&e) => ExitCode::SUCCESS,
+        Err(e) => util::report_error(&e),
     }
 }
 
+/// Parse arguments after expanding any `[aliases]` from settings
+///
+/// Clap reports its own usage errors and exits, as `Cli::parse` does.
+fn parse_cli() -> anyhow::Result<Cli> {
+    let builtins: Vec<String> = Cli::command()
+        .get_subcommands()
+        .flat_map(|c| std::iter::once(c.get_name()).chain(c.get_all_aliases()))
+        .chain(std::iter::once("help"))
+        .map(str::to_string)
+        .collect();
+    let args = config::expand_aliases(std::env::args_os().collect(), &builtins)?;
+    Ok(Cli::parse_from(cli::hoist_output(args)))
+}
+
+fn print_help() -> anyhow::Result<()> {
+    Cli::command().print_help()?;
+    println!();
+    Ok(())
+}
+
 async fn run_command(cmd: Command) -> anyhow::Result<()> {
     match cmd {
         Command::Jira { cmd: Some(cmd) } => {
@@ -145,10 +200,39 @@ async fn run_command(cmd: Command) -> anyhow::Result<()> {
         Command::Setup { cmd: None } => {
             print_subcommand_help("setup")?;
         }
+        Command::Doctor => {
+            return doctor::run().await;
+        }
+        Command::Auth { cmd: Some(cmd) } => {
+            return auth::run_command(cmd).await;
+        }
+        Command::Auth { cmd: None } => {
+            print_subcommand_help("auth")?;
+        }
+        Command::Config { cmd: Some(cmd) } => {
+            return config::run_command(cmd);
+        }
+        Command::Config { cmd: None } => {
+            print_subcommand_help("config")?;
+        }
+        Command::Status(args) => {
+            return status::run(args).await;
+        }
+        Command::MigrateSecrets => {
+            return migrate_secrets::run();
+        }
+        Command::Completions { shell } => {
+            write_completions(shell, &mut std::io::stdout());
+        }
     }
     Ok(())
 }
 
+/// Write a completion script for every command and nested subcommand
+fn write_completions(shell: clap_complete::Shell, out: &mut dyn std::io::Write) {
+    clap_complete::generate(shell, &mut Cli::command(), "hu", out);
+}
+
 fn print_subcommand_help(name: &str) -> anyhow::Result<()> {
     let mut cmd = Cli::command();
     for sub in cmd.get_subcommands_mut() {
@@ -187,4 +271,199 @@ mod tests {
         let cli = Cli::try_parse_from(["hu", "nr", "incidents"]).unwrap();
         assert!(matches!(cli.command, Some(Command::NewRelic { .. })));
     }
+
+    #[test]
+    fn parses_completions() {
+        let cli = Cli::try_parse_from(["hu", "completions", "zsh"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Completions {
+                shell: clap_complete::Shell::Zsh
+            })
+        ));
+        assert!(Cli::try_parse_from(["hu", "completions", "tcsh"]).is_err());
+    }
+
+    #[test]
+    fn completions_include_nested_subcommands() {
+        for shell in [
+            clap_complete::Shell::Bash,
+            clap_complete::Shell::Zsh,
+            clap_complete::Shell::Fish,
+        ] {
+            let mut out = Vec::new();
+            write_completions(shell, &mut out);
+            let script = String::from_utf8(out).unwrap();
+            assert!(script.contains("slack"), "{shell}");
+            assert!(script.contains("oncall"), "{shell}");
+            assert!(script.contains("max-file-lines"), "{shell}");
+        }
+    }
+
+    #[test]
+    fn parses_global_output() {
+        let cli = Cli::try_parse_from(["hu", "-o", "csv", "pd", "oncall"]).unwrap();
+        assert_eq!(cli.output, Some(util::OutputFormat::Csv));
+        let cli = Cli::try_parse_from(["hu", "--output", "json", "data", "stats"]).unwrap();
+        assert_eq!(cli.output, Some(util::OutputFormat::Json));
+        assert!(Cli::try_parse_from(["hu", "-o", "yaml", "pd", "oncall"]).is_err());
+    }
+
+    fn parse_hoisted(args: &[&str]) -> Cli {
+        let args = args.iter().map(std::ffi::OsString::from).collect();
+        Cli::try_parse_from(cli::hoist_output(args)).unwrap()
+    }
+
+    #[test]
+    fn parses_output_after_subcommand() {
+        let cli = parse_hoisted(&["hu", "pd", "oncall", "-o", "json"]);
+        assert_eq!(cli.output, Some(util::OutputFormat::Json));
+        let cli = parse_hoisted(&["hu", "gh", "runs", "--output=csv", "--fields", "id"]);
+        assert_eq!(cli.output, Some(util::OutputFormat::Csv));
+        let cli = parse_hoisted(&["hu", "jira", "sprint", "-ojson"]);
+        assert_eq!(cli.output, Some(util::OutputFormat::Json));
+    }
+
+    #[test]
+    fn hoist_output_leaves_subcommand_flags() {
+        let cli = parse_hoisted(&["hu", "read", "-o", "src/main.rs"]);
+        assert!(cli.output.is_none());
+        match cli.command {
+            Some(Command::Read(args)) => assert!(args.outline),
+            _ => panic!("expected read"),
+        }
+
+        let cli = parse_hoisted(&["hu", "-o", "json", "read", "-o", "src/main.rs"]);
+        assert_eq!(cli.output, Some(util::OutputFormat::Json));
+
+        let args = ["hu", "slack", "send", "#dev", "--", "-o", "json"];
+        let hoisted = cli::hoist_output(args.iter().map(std::ffi::OsString::from).collect());
+        assert_eq!(hoisted, args.map(std::ffi::OsString::from).to_vec());
+    }
+
+    #[test]
+    fn dry_run_only_for_commands_that_honor_it() {
+        let supports = |args: &[&str]| {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(cli.dry_run);
+            cli.command.unwrap().supports_dry_run()
+        };
+        assert!(supports(&["hu", "--dry-run", "eks", "exec", "web-1"]));
+        assert!(supports(&["hu", "eks", "logs", "web-1", "--dry-run"]));
+        assert!(supports(&["hu", "--dry-run", "pipeline", "list"]));
+        assert!(supports(&["hu", "--dry-run", "gh", "checkout", "42"]));
+        assert!(!supports(&["hu", "--dry-run", "eks", "list"]));
+        assert!(!supports(&[
+            "hu",
+            "--dry-run",
+            "slack",
+            "send",
+            "#dev",
+            "hi"
+        ]));
+        assert!(!supports(&["hu", "--dry-run", "pd", "resolve", "P1"]));
+    }
+
+    #[test]
+    fn parses_no_color_anywhere() {
+        let cli = Cli::try_parse_from(["hu", "--no-color", "jira", "sprint"]).unwrap();
+        assert!(cli.no_color);
+        let cli = Cli::try_parse_from(["hu", "jira", "sprint", "--no-color"]).unwrap();
+        assert!(cli.no_color);
+    }
+
+    #[test]
+    fn config_flag_feeds_slack_and_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = dir.path().join("work.toml");
+        std::fs::write(
+            &settings,
+            "[general]\ndatabase = \"/tmp/work.db\"\n\n[slack]\ndefault_channel = \"#work\"\n",
+        )
+        .unwrap();
+
+        let arg = settings.to_str().unwrap();
+        let cli = Cli::try_parse_from(["hu", "slack", "channels", "--config", arg]).unwrap();
+        let path = util::resolve_settings_path(cli.config.as_deref(), dirs::home_dir()).unwrap();
+        assert_eq!(path, settings);
+
+        let slack = slack::load_config_from(Some(&path)).unwrap();
+        assert_eq!(slack.default_channel, "#work");
+        let data = data::load_data_config_from(&path).unwrap();
+        assert_eq!(data.database, std::path::PathBuf::from("/tmp/work.db"));
+    }
+
+    #[test]
+    fn json_flag_is_hidden_alias() {
+        let cli = Cli::try_parse_from(["hu", "pd", "oncall", "--json"]).unwrap();
+        assert!(cli.output.is_none());
+
+        let mut cmd = Cli::command();
+        let oncall = cmd
+            .find_subcommand_mut("pagerduty")
+            .and_then(|pd| pd.find_subcommand_mut("oncall"))
+            .unwrap();
+        let help = oncall.render_help().to_string();
+        assert!(!help.contains("--json"));
+    }
+
+    #[test]
+    fn parses_doctor() {
+        let cli = Cli::try_parse_from(["hu", "doctor"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Doctor)));
+    }
+
+    #[test]
+    fn parses_auth_status() {
+        let cli = Cli::try_parse_from(["hu", "auth", "status"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Auth {
+                cmd: Some(auth::AuthCommand::Status)
+            })
+        ));
+    }
+
+    #[test]
+    fn parses_config_check() {
+        let cli = Cli::try_parse_from(["hu", "config", "check"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config {
+                cmd: Some(config::ConfigCommand::Check)
+            })
+        ));
+    }
+
+    #[test]
+    fn parses_status_watch() {
+        let cli = Cli::try_parse_from(["hu", "status", "--watch", "-i", "10"]).unwrap();
+        match cli.command {
+            Some(Command::Status(args)) => {
+                assert!(args.watch);
+                assert_eq!(args.interval, 10);
+            }
+            _ => panic!("expected status"),
+        }
+        assert!(Cli::try_parse_from(["hu", "status", "-i", "0"]).is_err());
+    }
+
+    #[test]
+    fn parses_gh_watch_interval() {
+        assert!(Cli::try_parse_from(["hu", "gh", "watch", "42", "-i", "5"]).is_ok());
+        assert!(Cli::try_parse_from(["hu", "gh", "watch", "42", "-i", "0"]).is_err());
+    }
+
+    #[test]
+    fn parses_http_timeout() {
+        let cli = Cli::try_parse_from(["hu", "gh", "prs", "--http-timeout", "5"]).unwrap();
+        assert_eq!(cli.http_timeout, Some(5));
+        assert!(Cli::try_parse_from(["hu", "--http-timeout", "0"]).is_err());
+    }
+
+    #[test]
+    fn parses_migrate_secrets() {
+        let cli = Cli::try_parse_from(["hu", "migrate-secrets"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::MigrateSecrets)));
+    }
 }
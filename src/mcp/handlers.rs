@@ -117,10 +117,17 @@ fn handle_read_file(args: &serde_json::Value) -> Result<ToolResult> {
 
     let read_args = read::ReadArgs {
         path: path.to_string(),
+        map: false,
+        max_items: 20,
+        max_total: 500,
         outline: args
             .get("outline")
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
+        sort: match args.get("sort").and_then(|v| v.as_str()) {
+            Some("size") => read::OutlineSort::Size,
+            _ => read::OutlineSort::Line,
+        },
         interface: args
             .get("interface")
             .and_then(|v| v.as_bool())
@@ -128,14 +135,24 @@ fn handle_read_file(args: &serde_json::Value) -> Result<ToolResult> {
         around: args
             .get("around")
             .and_then(|v| v.as_u64())
-            .map(|v| v as usize),
+            .map(|v| read::AroundSpec {
+                line: v as usize,
+                context: None,
+            }),
         context: args.get("context").and_then(|v| v.as_u64()).unwrap_or(10) as usize,
+        function: args
+            .get("function")
+            .and_then(|v| v.as_str())
+            .map(String::from),
         diff: args.get("diff").and_then(|v| v.as_bool()).unwrap_or(false),
         commit: args
             .get("commit")
             .and_then(|v| v.as_str())
             .unwrap_or("HEAD")
             .to_string(),
+        lines: None,
+        no_numbers: false,
+        json: false,
     };
 
     let output = read::read(read_args)?;
@@ -6,6 +6,7 @@ mod types;
 
 pub use cli::McpCommand;
 
+use anstream::println;
 use anyhow::Result;
 
 /// Run an MCP subcommand.
@@ -120,6 +120,11 @@ fn read_file() -> ToolDef {
                     "type": "boolean",
                     "description": "Show file outline (functions, structs, classes)"
                 },
+                "sort": {
+                    "type": "string",
+                    "enum": ["line", "size"],
+                    "description": "Outline order: source line (default) or longest first"
+                },
                 "interface": {
                     "type": "boolean",
                     "description": "Show public interface only"
@@ -132,6 +137,10 @@ fn read_file() -> ToolDef {
                     "type": "integer",
                     "description": "Number of context lines for --around (default: 10)"
                 },
+                "function": {
+                    "type": "string",
+                    "description": "Return only the body of the named function (name:LINE to disambiguate)"
+                },
                 "diff": {
                     "type": "boolean",
                     "description": "Show git diff"
@@ -0,0 +1,121 @@
+//! `hu migrate-secrets` — move file-stored tokens into the OS keychain
+
+use anstream::println;
+use anyhow::{bail, Context, Result};
+use std::fs;
+
+use crate::util::secrets::{self, CREDENTIALS_KEY};
+
+/// Move every stored secret into the keychain and turn on `secure_credentials`
+///
+/// Everything is written to the keychain and read back before any file is
+/// touched, so a missing keychain leaves the files as they were.
+#[cfg(not(tarpaulin_include))]
+pub fn run() -> Result<()> {
+    let settings_path =
+        crate::util::settings_path().context("Cannot determine config directory")?;
+    let settings = if settings_path.exists() {
+        fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?
+    } else {
+        String::new()
+    };
+    let (new_settings, mut moved) = strip_settings(&settings)?;
+
+    let credentials_path = crate::util::credentials_path()?;
+    if credentials_path.exists() {
+        let contents = fs::read_to_string(&credentials_path)
+            .with_context(|| format!("Failed to read {}", credentials_path.display()))?;
+        moved.push((CREDENTIALS_KEY.to_string(), contents));
+    }
+
+    for (key, value) in &moved {
+        secrets::set(key, value)?;
+        if secrets::get(key).as_deref() != Some(value.as_str()) {
+            bail!("Keychain did not return {} after storing it", key);
+        }
+    }
+
+    if let Some(parent) = settings_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    fs::write(&settings_path, new_settings)
+        .with_context(|| format!("Failed to write {}", settings_path.display()))?;
+    if credentials_path.exists() {
+        fs::remove_file(&credentials_path)
+            .with_context(|| format!("Failed to remove {}", credentials_path.display()))?;
+    }
+
+    if moved.is_empty() {
+        println!("No file-stored secrets found.");
+    } else {
+        for (key, _) in &moved {
+            println!("Moved {} to the keychain", key);
+        }
+    }
+    println!("Enabled secure_credentials in {}", settings_path.display());
+    Ok(())
+}
+
+/// Remove secrets from settings contents and set `secure_credentials = true`
+///
+/// Returns the rewritten file and the `(keychain key, value)` pairs removed.
+fn strip_settings(contents: &str) -> Result<(String, Vec<(String, String)>)> {
+    let mut doc: toml::Value = if contents.trim().is_empty() {
+        toml::Value::Table(toml::map::Map::new())
+    } else {
+        toml::from_str(contents).context("Failed to parse settings file")?
+    };
+
+    let taken = secrets::take_settings_secrets(&mut doc);
+    doc.as_table_mut()
+        .context("Settings file is not a table")?
+        .insert("secure_credentials".to_string(), toml::Value::Boolean(true));
+
+    let output = toml::to_string_pretty(&doc).context("Failed to serialize settings")?;
+    Ok((output, taken))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_settings_moves_tokens_and_enables_flag() {
+        let contents = r#"
+[newrelic]
+api_key = "NRAK-1"
+account_id = 42
+
+[sentry]
+auth_token = "sntrys_1"
+organization = "acme"
+"#;
+        let (output, taken) = strip_settings(contents).unwrap();
+        assert_eq!(
+            taken,
+            vec![
+                ("newrelic.api_key".to_string(), "NRAK-1".to_string()),
+                ("sentry.auth_token".to_string(), "sntrys_1".to_string()),
+            ]
+        );
+        assert!(secrets::enabled_in(&output));
+        assert!(!output.contains("NRAK-1"));
+        assert!(!output.contains("sntrys_1"));
+        assert!(output.contains("account_id = 42"));
+        assert!(output.contains("organization = \"acme\""));
+    }
+
+    #[test]
+    fn strip_settings_empty_file() {
+        let (output, taken) = strip_settings("").unwrap();
+        assert!(taken.is_empty());
+        assert!(secrets::enabled_in(&output));
+    }
+
+    #[test]
+    fn strip_settings_rejects_invalid_toml() {
+        assert!(strip_settings("[broken").is_err());
+    }
+}
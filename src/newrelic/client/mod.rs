@@ -8,7 +8,7 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 use super::config::{load_config, NewRelicConfig};
-use super::types::{Incident, Issue};
+use super::types::{Deployment, DeploymentInput, Incident, Issue};
 
 #[cfg(test)]
 mod tests;
@@ -25,6 +25,15 @@ pub trait NewRelicApi {
 
     /// Run NRQL query
     fn run_nrql(&self, nrql: &str) -> impl Future<Output = Result<Vec<serde_json::Value>>> + Send;
+
+    /// Record a deployment marker
+    fn create_deployment(
+        &self,
+        input: &DeploymentInput,
+    ) -> impl Future<Output = Result<Deployment>> + Send;
+
+    /// Accounts the API key can access, as `(id, name)`
+    fn list_accounts(&self) -> impl Future<Output = Result<Vec<(i64, String)>>> + Send;
 }
 const MAX_RETRIES: u32 = 3;
 const DEFAULT_RETRY_SECS: u64 = 5;
@@ -60,7 +69,18 @@ impl NewRelicClient {
     #[cfg(not(tarpaulin_include))]
     pub fn new() -> Result<Self> {
         let config = load_config()?;
-        let http = Client::builder().user_agent("hu-cli/0.1.0").build()?;
+        let http = crate::util::http::client()?;
+        Ok(Self { config, http })
+    }
+
+    /// Create a client for a key that isn't saved yet (no account)
+    #[cfg(not(tarpaulin_include))]
+    pub fn with_api_key(api_key: &str) -> Result<Self> {
+        let config = NewRelicConfig {
+            api_key: Some(api_key.to_string()),
+            account_id: None,
+        };
+        let http = crate::util::http::client()?;
         Ok(Self { config, http })
     }
 
@@ -108,11 +128,6 @@ impl NewRelicClient {
             }
         "#;
 
-        let variables = serde_json::json!({
-            "accountId": account_id,
-            "cursor": null
-        });
-
         #[derive(Deserialize)]
         struct IssuesResponse {
             actor: Actor,
@@ -138,14 +153,19 @@ impl NewRelicClient {
         #[serde(rename_all = "camelCase")]
         struct IssuesData {
             issues: Vec<Issue>,
-            #[allow(dead_code)]
             next_cursor: Option<String>,
         }
 
-        let response: IssuesResponse = self.execute_graphql(query, variables).await?;
-        let mut issues = response.actor.account.ai_issues.issues.issues;
-        issues.truncate(limit);
-        Ok(issues)
+        collect_pages(limit, |cursor| async move {
+            let variables = serde_json::json!({
+                "accountId": account_id,
+                "cursor": cursor
+            });
+            let response: IssuesResponse = self.execute_graphql(query, variables).await?;
+            let page = response.actor.account.ai_issues.issues;
+            Ok((page.issues, page.next_cursor))
+        })
+        .await
     }
 
     /// List recent incidents
@@ -176,11 +196,6 @@ impl NewRelicClient {
             }
         "#;
 
-        let variables = serde_json::json!({
-            "accountId": account_id,
-            "cursor": null
-        });
-
         #[derive(Deserialize)]
         struct IncidentsResponse {
             actor: Actor,
@@ -206,14 +221,19 @@ impl NewRelicClient {
         #[serde(rename_all = "camelCase")]
         struct IncidentsData {
             incidents: Vec<Incident>,
-            #[allow(dead_code)]
             next_cursor: Option<String>,
         }
 
-        let response: IncidentsResponse = self.execute_graphql(query, variables).await?;
-        let mut incidents = response.actor.account.ai_issues.incidents.incidents;
-        incidents.truncate(limit);
-        Ok(incidents)
+        collect_pages(limit, |cursor| async move {
+            let variables = serde_json::json!({
+                "accountId": account_id,
+                "cursor": cursor
+            });
+            let response: IncidentsResponse = self.execute_graphql(query, variables).await?;
+            let page = response.actor.account.ai_issues.incidents;
+            Ok((page.incidents, page.next_cursor))
+        })
+        .await
     }
 
     /// Run NRQL query
@@ -262,6 +282,70 @@ impl NewRelicClient {
         Ok(response.actor.account.nrql.results)
     }
 
+    /// Record a deployment marker via change tracking
+    #[cfg(not(tarpaulin_include))]
+    pub async fn create_deployment(&self, input: &DeploymentInput) -> Result<Deployment> {
+        let query = r#"
+            mutation($deployment: ChangeTrackingDeploymentInput!) {
+                changeTrackingCreateDeployment(deployment: $deployment) {
+                    deploymentId
+                    entityGuid
+                    timestamp
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DeploymentResponse {
+            change_tracking_create_deployment: Deployment,
+        }
+
+        let response: DeploymentResponse = self
+            .execute_graphql(query, deployment_variables(input))
+            .await?;
+        Ok(response.change_tracking_create_deployment)
+    }
+
+    /// Accounts the API key can access
+    #[cfg(not(tarpaulin_include))]
+    pub async fn list_accounts(&self) -> Result<Vec<(i64, String)>> {
+        let query = r#"
+            query {
+                actor {
+                    accounts {
+                        id
+                        name
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct AccountsResponse {
+            actor: Actor,
+        }
+
+        #[derive(Deserialize)]
+        struct Actor {
+            accounts: Vec<Account>,
+        }
+
+        #[derive(Deserialize)]
+        struct Account {
+            id: i64,
+            name: String,
+        }
+
+        let response: AccountsResponse = self.execute_graphql(query, serde_json::json!({})).await?;
+        Ok(response
+            .actor
+            .accounts
+            .into_iter()
+            .map(|a| (a.id, a.name))
+            .collect())
+    }
+
     /// Execute GraphQL query
     #[cfg(not(tarpaulin_include))]
     async fn execute_graphql<T: for<'de> Deserialize<'de>>(
@@ -291,6 +375,7 @@ impl NewRelicClient {
                 .await?;
 
             let status = response.status();
+            log::debug!("HTTP {} {}", status.as_u16(), response.url());
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 if retries >= MAX_RETRIES {
@@ -307,7 +392,7 @@ impl NewRelicClient {
                     .and_then(|s| s.parse::<u64>().ok())
                     .unwrap_or(DEFAULT_RETRY_SECS);
 
-                eprintln!(
+                log::info!(
                     "Rate limited, waiting {} seconds... (retry {}/{})",
                     retry_after,
                     retries + 1,
@@ -342,7 +427,7 @@ impl NewRelicClient {
     /// Create client from provided config (for testing)
     #[cfg(test)]
     pub fn with_config(config: NewRelicConfig) -> Result<Self> {
-        let http = Client::builder().user_agent("hu-cli/0.1.0").build()?;
+        let http = crate::util::http::client()?;
         Ok(Self { config, http })
     }
 
@@ -353,6 +438,46 @@ impl NewRelicClient {
     }
 }
 
+/// Follow `nextCursor` until `limit` items are collected or pages run out
+///
+/// `fetch` gets the cursor for the next page (`None` for the first) and
+/// returns that page's items and cursor.
+pub async fn collect_pages<T, F, Fut>(limit: usize, mut fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>)>>,
+{
+    let mut items = Vec::new();
+    let mut cursor = None;
+    while items.len() < limit {
+        let (page, next) = fetch(cursor).await?;
+        let empty = page.is_empty();
+        items.extend(page);
+        match next {
+            Some(next) if !next.is_empty() && !empty => cursor = Some(next),
+            _ => break,
+        }
+    }
+    items.truncate(limit);
+    Ok(items)
+}
+
+/// Build the `changeTrackingCreateDeployment` variables, omitting unset fields
+pub fn deployment_variables(input: &DeploymentInput) -> serde_json::Value {
+    let mut deployment = serde_json::json!({
+        "entityGuid": input.entity_guid,
+        "version": input.revision,
+        "commit": input.revision,
+    });
+    if let Some(description) = &input.description {
+        deployment["description"] = description.clone().into();
+    }
+    if let Some(user) = &input.user {
+        deployment["user"] = user.clone().into();
+    }
+    serde_json::json!({ "deployment": deployment })
+}
+
 #[cfg(not(tarpaulin_include))]
 impl NewRelicApi for NewRelicClient {
     async fn list_issues(&self, limit: usize) -> Result<Vec<Issue>> {
@@ -366,4 +491,12 @@ impl NewRelicApi for NewRelicClient {
     async fn run_nrql(&self, nrql: &str) -> Result<Vec<serde_json::Value>> {
         NewRelicClient::run_nrql(self, nrql).await
     }
+
+    async fn create_deployment(&self, input: &DeploymentInput) -> Result<Deployment> {
+        NewRelicClient::create_deployment(self, input).await
+    }
+
+    async fn list_accounts(&self) -> Result<Vec<(i64, String)>> {
+        NewRelicClient::list_accounts(self).await
+    }
 }
@@ -465,3 +465,115 @@ fn test_constants() {
     assert_eq!(MAX_RETRIES, 3);
     assert_eq!(DEFAULT_RETRY_SECS, 5);
 }
+
+#[test]
+fn test_deployment_variables_full() {
+    let input = DeploymentInput {
+        entity_guid: "MXxBUE18".to_string(),
+        revision: "abc123".to_string(),
+        description: Some("Release 1.2".to_string()),
+        user: Some("Jane".to_string()),
+    };
+    let vars = deployment_variables(&input);
+    assert_eq!(vars["deployment"]["entityGuid"], "MXxBUE18");
+    assert_eq!(vars["deployment"]["version"], "abc123");
+    assert_eq!(vars["deployment"]["commit"], "abc123");
+    assert_eq!(vars["deployment"]["description"], "Release 1.2");
+    assert_eq!(vars["deployment"]["user"], "Jane");
+}
+
+#[test]
+fn test_deployment_variables_omit_unset() {
+    let input = DeploymentInput {
+        entity_guid: "MXxBUE18".to_string(),
+        revision: "abc123".to_string(),
+        description: None,
+        user: None,
+    };
+    let vars = deployment_variables(&input);
+    assert!(vars["deployment"].get("description").is_none());
+    assert!(vars["deployment"].get("user").is_none());
+}
+
+#[test]
+fn test_parse_deployment_response() {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct DeploymentResponse {
+        change_tracking_create_deployment: Deployment,
+    }
+
+    let json = r#"{"changeTrackingCreateDeployment":{"deploymentId":"dep-1","entityGuid":"MXxBUE18","timestamp":1704067200000}}"#;
+    let response: DeploymentResponse = serde_json::from_str(json).unwrap();
+    let deployment = response.change_tracking_create_deployment;
+    assert_eq!(deployment.deployment_id, "dep-1");
+    assert_eq!(deployment.timestamp, Some(1704067200000));
+}
+
+/// Page server for `collect_pages`: item ranges keyed by cursor
+fn two_pages(cursor: Option<String>) -> Result<(Vec<u32>, Option<String>)> {
+    match cursor.as_deref() {
+        None => Ok(((1..=3).collect(), Some("page-2".to_string()))),
+        Some("page-2") => Ok(((4..=5).collect(), None)),
+        Some(other) => Err(anyhow::anyhow!("unexpected cursor {}", other)),
+    }
+}
+
+#[tokio::test]
+async fn test_collect_pages_stitches_pages() {
+    let mut calls = Vec::new();
+    let items = collect_pages(10, |cursor| {
+        calls.push(cursor.clone());
+        std::future::ready(two_pages(cursor))
+    })
+    .await
+    .unwrap();
+    assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    assert_eq!(calls, vec![None, Some("page-2".to_string())]);
+}
+
+#[tokio::test]
+async fn test_collect_pages_truncates_combined_set() {
+    let items = collect_pages(4, |cursor| std::future::ready(two_pages(cursor)))
+        .await
+        .unwrap();
+    assert_eq!(items, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn test_collect_pages_stops_at_limit() {
+    let mut calls = 0;
+    let items = collect_pages(2, |cursor| {
+        calls += 1;
+        std::future::ready(two_pages(cursor))
+    })
+    .await
+    .unwrap();
+    assert_eq!(items, vec![1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[tokio::test]
+async fn test_collect_pages_stops_on_empty_page() {
+    let mut calls = 0;
+    let items: Vec<u32> = collect_pages(10, |_| {
+        calls += 1;
+        std::future::ready(Ok((vec![], Some("again".to_string()))))
+    })
+    .await
+    .unwrap();
+    assert!(items.is_empty());
+    assert_eq!(calls, 1);
+}
+
+#[tokio::test]
+async fn test_collect_pages_propagates_errors() {
+    let result = collect_pages(10, |cursor| {
+        std::future::ready(match cursor {
+            None => Ok((vec![1u32], Some("bad".to_string()))),
+            Some(_) => two_pages(Some("bad".to_string())),
+        })
+    })
+    .await;
+    assert!(result.is_err());
+}
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::util::secrets;
+
 /// New Relic configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NewRelicConfig {
@@ -30,7 +32,7 @@ struct SettingsFile {
 
 /// Get path to config file
 pub fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
+    crate::util::settings_path()
 }
 
 /// Load config from settings file and environment
@@ -46,6 +48,11 @@ pub fn load_config() -> Result<NewRelicConfig> {
             if let Some(nr) = settings.newrelic {
                 config = nr;
             }
+            secrets::fill(
+                &mut config.api_key,
+                "newrelic.api_key",
+                secrets::enabled_in(&contents),
+            );
         }
     }
 
@@ -92,9 +99,12 @@ pub fn save_config(api_key: &str, account_id: i64) -> Result<()> {
         .and_then(|v| v.as_table_mut())
         .ok_or_else(|| anyhow::anyhow!("newrelic section is not a table"))?;
 
-    nr.insert(
-        "api_key".to_string(),
-        toml::Value::String(api_key.to_string()),
+    secrets::store(
+        nr,
+        "api_key",
+        "newrelic.api_key",
+        api_key,
+        secrets::enabled_in(&contents),
     );
     nr.insert("account_id".to_string(), toml::Value::Integer(account_id));
 
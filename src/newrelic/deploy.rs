@@ -0,0 +1,23 @@
+//! `hu newrelic deploy`: record a deployment marker
+
+use anstream::println;
+use anyhow::Result;
+
+use super::client::NewRelicClient;
+use super::service;
+use super::types::DeploymentInput;
+
+/// Record a deployment marker
+#[cfg(not(tarpaulin_include))]
+pub async fn cmd_deploy(input: &DeploymentInput) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = NewRelicClient::new()?;
+    let deployment = service::create_deployment(&client, input).await?;
+    println!(
+        "Recorded deployment {} for {} ({})",
+        deployment.deployment_id, deployment.entity_guid, input.revision
+    );
+    Ok(())
+}
@@ -1,10 +1,16 @@
 //! New Relic output formatting
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use crate::util::fmt::truncate;
+use crate::util::print_structured;
+
 use super::types::{Incident, Issue, OutputFormat};
 
+mod nrql;
+
 #[cfg(test)]
 mod tests;
 
@@ -33,15 +39,6 @@ fn format_time(ts: Option<i64>) -> String {
     }
 }
 
-/// Truncate string
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 /// Color for priority
 fn priority_color(priority: &str) -> Color {
     match priority.to_uppercase().as_str() {
@@ -94,10 +91,7 @@ pub fn output_issues(issues: &[Issue], format: OutputFormat) -> Result<()> {
             println!("{table}");
             println!("\n{} issues", issues.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(issues).context("Failed to serialize")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(issues, format)?,
     }
     Ok(())
 }
@@ -130,10 +124,7 @@ pub fn output_incidents(incidents: &[Incident], format: OutputFormat) -> Result<
             println!("{table}");
             println!("\n{} incidents", incidents.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(incidents).context("Failed to serialize")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(incidents, format)?,
     }
     Ok(())
 }
@@ -147,59 +138,36 @@ pub fn output_nrql(results: &[serde_json::Value], format: OutputFormat) -> Resul
                 return Ok(());
             }
 
-            // Try to create table from results
-            if let Some(first) = results.first() {
-                if let Some(obj) = first.as_object() {
-                    let mut table = Table::new();
-                    table.load_preset(UTF8_FULL_CONDENSED);
-                    table.set_content_arrangement(ContentArrangement::Dynamic);
-
-                    // Headers from first object
-                    let headers: Vec<_> = obj.keys().collect();
-                    table.set_header(headers.iter().map(|h| h.as_str()).collect::<Vec<_>>());
-
-                    // Rows
-                    for result in results {
-                        if let Some(obj) = result.as_object() {
-                            let row: Vec<_> = headers
-                                .iter()
-                                .map(|h| {
-                                    obj.get(*h)
-                                        .map(format_json_value)
-                                        .unwrap_or_else(|| "-".to_string())
-                                })
-                                .collect();
-                            table.add_row(row);
-                        }
-                    }
-
-                    println!("{table}");
-                    println!("\n{} results", results.len());
-                    return Ok(());
-                }
+            if let Some(table) = nrql::nrql_table(results) {
+                println!("{table}");
+                println!("\n{} results", results.len());
+                return Ok(());
             }
 
             // Fallback to JSON
             let json = serde_json::to_string_pretty(results)?;
             println!("{json}");
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(results).context("Failed to serialize")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(results, format)?,
     }
     Ok(())
 }
 
-/// Format JSON value for table display
-fn format_json_value(v: &serde_json::Value) -> String {
-    match v {
-        serde_json::Value::Null => "-".to_string(),
-        serde_json::Value::String(s) => s.clone(),
-        serde_json::Value::Number(n) => n.to_string(),
-        serde_json::Value::Bool(b) => b.to_string(),
-        _ => v.to_string(),
+/// Output saved NRQL queries
+pub fn output_saved_queries(queries: &std::collections::BTreeMap<String, String>) {
+    if queries.is_empty() {
+        println!("No saved queries. Save one with: hu newrelic query <nrql> --save <name>");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["Name", "NRQL"]);
+    for (name, nrql) in queries {
+        table.add_row(vec![Cell::new(name).fg(Color::Cyan), Cell::new(nrql)]);
     }
+    println!("{table}");
 }
 
 /// Output config status
@@ -0,0 +1,158 @@
+//! NRQL result tables
+//!
+//! NRQL returns a list of loosely-shaped objects: `FACET` queries add `facet`
+//! plus one key per facet attribute, `TIMESERIES` adds time buckets, and some
+//! functions return nested objects. Columns are the union of flattened keys
+//! across all rows, with time and facet columns moved to the front.
+
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, CellAlignment, ContentArrangement, Table};
+use serde_json::{Map, Value};
+
+/// Keys NRQL uses for time buckets
+const TIME_KEYS: &[&str] = &["timestamp", "beginTimeSeconds", "endTimeSeconds"];
+
+/// A result row with nested objects flattened to dotted keys
+pub type FlatRow = Vec<(String, Value)>;
+
+/// Build a table from NRQL results, or `None` if no row is an object
+pub fn nrql_table(results: &[Value]) -> Option<Table> {
+    let rows: Vec<FlatRow> = results
+        .iter()
+        .filter_map(Value::as_object)
+        .map(flatten)
+        .collect();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let columns = columns(&rows);
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(&columns);
+
+    for row in &rows {
+        table.add_row(columns.iter().map(|col| {
+            match row.iter().find(|(key, _)| key == col).map(|(_, v)| v) {
+                Some(v @ Value::Number(_)) => {
+                    Cell::new(format_value(v)).set_alignment(CellAlignment::Right)
+                }
+                Some(v) => Cell::new(format_value(v)),
+                None => Cell::new("-"),
+            }
+        }));
+    }
+    Some(table)
+}
+
+/// Flatten an object, joining nested keys with `.`
+pub fn flatten(obj: &Map<String, Value>) -> FlatRow {
+    let mut out = Vec::new();
+    flatten_into(obj, "", &mut out);
+    out
+}
+
+fn flatten_into(obj: &Map<String, Value>, prefix: &str, out: &mut FlatRow) {
+    for (key, value) in obj {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if !nested.is_empty() => flatten_into(nested, &key, out),
+            _ => out.push((key, value.clone())),
+        }
+    }
+}
+
+/// Union of keys across rows: time columns, then facets, then the rest
+///
+/// Within each group, columns keep the order they were first seen in.
+pub fn columns(rows: &[FlatRow]) -> Vec<String> {
+    let mut seen: Vec<String> = Vec::new();
+    for (key, _) in rows.iter().flatten() {
+        if !seen.contains(key) {
+            seen.push(key.clone());
+        }
+    }
+
+    let facets = facet_columns(rows);
+    let rank = |key: &str| {
+        if TIME_KEYS.contains(&key) {
+            0
+        } else if key == "facet" || facets.iter().any(|f| f == key) {
+            1
+        } else {
+            2
+        }
+    };
+    // Stable sort keeps first-seen order within a group
+    seen.sort_by_key(|key| rank(key));
+    seen
+}
+
+/// Attribute columns that hold a row's facet value (e.g. `appName` for `FACET appName`)
+fn facet_columns(rows: &[FlatRow]) -> Vec<String> {
+    let mut facets = Vec::new();
+    for row in rows {
+        let Some((_, facet)) = row.iter().find(|(key, _)| key == "facet") else {
+            continue;
+        };
+        let values: Vec<&Value> = match facet {
+            Value::Array(items) => items.iter().collect(),
+            other => vec![other],
+        };
+        for (key, value) in row {
+            if key != "facet" && values.contains(&value) && !facets.contains(key) {
+                facets.push(key.clone());
+            }
+        }
+    }
+    facets
+}
+
+/// Render a cell value; numbers get thousands separators
+pub fn format_value(v: &Value) -> String {
+    match v {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Number(n) => group_thousands(&n.to_string()),
+        Value::Bool(b) => b.to_string(),
+        Value::Array(items) if items.iter().all(|i| !i.is_object() && !i.is_array()) => items
+            .iter()
+            .map(format_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => v.to_string(),
+    }
+}
+
+/// Insert `,` every three digits of the integer part (`1234567.5` -> `1,234,567.5`)
+pub fn group_thousands(number: &str) -> String {
+    // Leave exponent notation alone
+    if number.contains(['e', 'E']) {
+        return number.to_string();
+    }
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (int, frac) = match digits.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (digits, None),
+    };
+
+    let mut grouped = String::with_capacity(int.len() + int.len() / 3);
+    for (i, ch) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+
+    match frac {
+        Some(frac) => format!("{}{}.{}", sign, grouped, frac),
+        None => format!("{}{}", sign, grouped),
+    }
+}
@@ -1,3 +1,4 @@
+use super::nrql::format_value;
 use super::*;
 
 #[test]
@@ -36,21 +37,6 @@ fn test_format_time_just_now() {
     assert_eq!(format_time(Some(ms)), "just now");
 }
 
-#[test]
-fn test_truncate_short() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn test_truncate_exact() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn test_truncate_long() {
-    assert_eq!(truncate("hello world", 8), "hello...");
-}
-
 #[test]
 fn test_priority_color_critical() {
     assert_eq!(priority_color("CRITICAL"), Color::Red);
@@ -93,34 +79,36 @@ fn test_state_color_other() {
 }
 
 #[test]
-fn test_format_json_value_null() {
-    assert_eq!(format_json_value(&serde_json::Value::Null), "-");
+fn test_format_value_null() {
+    assert_eq!(format_value(&serde_json::Value::Null), "-");
 }
 
 #[test]
-fn test_format_json_value_string() {
+fn test_format_value_string() {
     assert_eq!(
-        format_json_value(&serde_json::Value::String("hello".to_string())),
+        format_value(&serde_json::Value::String("hello".to_string())),
         "hello"
     );
 }
 
 #[test]
-fn test_format_json_value_number() {
-    assert_eq!(format_json_value(&serde_json::json!(42)), "42");
-    assert_eq!(format_json_value(&serde_json::json!(1.234)), "1.234");
+fn test_format_value_number() {
+    assert_eq!(format_value(&serde_json::json!(42)), "42");
+    assert_eq!(format_value(&serde_json::json!(1.234)), "1.234");
 }
 
 #[test]
-fn test_format_json_value_bool() {
-    assert_eq!(format_json_value(&serde_json::json!(true)), "true");
-    assert_eq!(format_json_value(&serde_json::json!(false)), "false");
+fn test_format_value_bool() {
+    assert_eq!(format_value(&serde_json::json!(true)), "true");
+    assert_eq!(format_value(&serde_json::json!(false)), "false");
 }
 
 #[test]
-fn test_format_json_value_array() {
+fn test_format_value_array() {
     let arr = serde_json::json!([1, 2, 3]);
-    assert_eq!(format_json_value(&arr), "[1,2,3]");
+    assert_eq!(format_value(&arr), "1, 2, 3");
+    let nested = serde_json::json!([[1], {"a": 1}]);
+    assert_eq!(format_value(&nested), "[[1],{\"a\":1}]");
 }
 
 #[test]
@@ -261,32 +249,13 @@ fn test_format_time_out_of_range() {
 }
 
 #[test]
-fn test_format_json_value_object() {
+fn test_format_value_object() {
     let obj = serde_json::json!({"key": "value"});
-    let formatted = format_json_value(&obj);
+    let formatted = format_value(&obj);
     assert!(formatted.contains("key"));
     assert!(formatted.contains("value"));
 }
 
-#[test]
-fn test_truncate_empty() {
-    assert_eq!(truncate("", 10), "");
-}
-
-#[test]
-fn test_truncate_very_short_max() {
-    // Test edge case where max_len is very small
-    assert_eq!(truncate("hello", 3), "...");
-}
-
-#[test]
-fn test_truncate_zero() {
-    // Test edge case where max_len is 0
-    let result = truncate("hello", 0);
-    // Should handle gracefully
-    assert!(result.len() <= 3); // "..." or empty
-}
-
 #[test]
 fn test_output_config_status_configured() {
     let config = super::super::config::NewRelicConfig {
@@ -314,3 +283,105 @@ fn test_output_config_status_partial() {
     };
     output_config_status(&config);
 }
+
+fn faceted_results() -> Vec<serde_json::Value> {
+    vec![
+        serde_json::json!({
+            "count": 1234567,
+            "appName": "web",
+            "facet": "web",
+            "latency": {"p50": 12.5, "p99": 1500.25}
+        }),
+        serde_json::json!({
+            "count": 42,
+            "appName": "worker",
+            "facet": "worker",
+            "errors": 3
+        }),
+    ]
+}
+
+fn flat_rows(results: &[serde_json::Value]) -> Vec<nrql::FlatRow> {
+    results
+        .iter()
+        .filter_map(|r| r.as_object())
+        .map(nrql::flatten)
+        .collect()
+}
+
+#[test]
+fn test_nrql_columns_facets_first_and_union() {
+    let columns = nrql::columns(&flat_rows(&faceted_results()));
+    assert_eq!(
+        columns,
+        vec![
+            "appName",
+            "facet",
+            "count",
+            "latency.p50",
+            "latency.p99",
+            "errors"
+        ]
+    );
+}
+
+#[test]
+fn test_nrql_columns_time_first() {
+    let results = vec![serde_json::json!({
+        "count": 1,
+        "endTimeSeconds": 1704070800,
+        "beginTimeSeconds": 1704067200
+    })];
+    let columns = nrql::columns(&flat_rows(&results));
+    assert_eq!(columns, vec!["beginTimeSeconds", "endTimeSeconds", "count"]);
+}
+
+#[test]
+fn test_nrql_multi_facet_columns() {
+    let results = vec![serde_json::json!({
+        "count": 5,
+        "facet": ["web", "prod"],
+        "appName": "web",
+        "env": "prod"
+    })];
+    let columns = nrql::columns(&flat_rows(&results));
+    assert_eq!(columns, vec!["appName", "env", "facet", "count"]);
+}
+
+#[test]
+fn test_nrql_flatten_nested() {
+    let obj = serde_json::json!({"a": {"b": {"c": 1}}, "d": {}});
+    let row = nrql::flatten(obj.as_object().unwrap());
+    assert_eq!(
+        row,
+        vec![
+            ("a.b.c".to_string(), serde_json::json!(1)),
+            ("d".to_string(), serde_json::json!({}))
+        ]
+    );
+}
+
+#[test]
+fn test_nrql_table_renders_faceted_rows() {
+    let table = nrql::nrql_table(&faceted_results()).unwrap().to_string();
+    assert!(table.contains("1,234,567"));
+    assert!(table.contains("1,500.25"));
+    assert!(table.contains("latency.p99"));
+    assert!(table.contains("worker"));
+    // Missing keys render as "-"
+    assert!(table.contains(" - "));
+}
+
+#[test]
+fn test_nrql_table_non_objects() {
+    assert!(nrql::nrql_table(&[serde_json::json!(1)]).is_none());
+}
+
+#[test]
+fn test_group_thousands() {
+    assert_eq!(nrql::group_thousands("0"), "0");
+    assert_eq!(nrql::group_thousands("999"), "999");
+    assert_eq!(nrql::group_thousands("1000"), "1,000");
+    assert_eq!(nrql::group_thousands("-1234567.891"), "-1,234,567.891");
+    assert_eq!(nrql::group_thousands("1e21"), "1e21");
+}
@@ -11,20 +11,27 @@
 //! - [`list_issues`] - List recent issues
 //! - [`list_incidents`] - List recent incidents
 //! - [`run_nrql`] - Run NRQL query
+//! - [`create_deployment`] - Record a deployment marker
 
 mod client;
 mod config;
+mod deploy;
 mod display;
+mod queries;
+mod query;
 mod service;
 pub mod types;
 
+use anstream::println;
 use anyhow::Result;
 use clap::Subcommand;
 
 use client::NewRelicClient;
 pub use config::NewRelicConfig;
+pub use queries::queries_path;
+use queries::QueryRequest;
 use types::OutputFormat;
-pub use types::{Incident, Issue};
+pub use types::{Deployment, DeploymentInput, Incident, Issue};
 
 /// New Relic subcommands
 #[derive(Debug, Subcommand)]
@@ -37,9 +44,9 @@ pub enum NewRelicCommand {
         /// API key (NRAK-...)
         key: String,
 
-        /// Account ID
+        /// Account ID (looked up from the key if omitted)
         #[arg(short, long)]
-        account: i64,
+        account: Option<i64>,
     },
 
     /// List recent issues
@@ -49,7 +56,7 @@ pub enum NewRelicCommand {
         limit: usize,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -60,19 +67,59 @@ pub enum NewRelicCommand {
         limit: usize,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
-    /// Run NRQL query
+    /// Run NRQL query (or a saved one)
     Query {
-        /// NRQL query string
-        nrql: String,
+        /// NRQL query string (may contain {{var}} placeholders)
+        #[arg(required_unless_present_any = ["run", "list", "delete", "save"])]
+        nrql: Option<String>,
+
+        /// Save the query (or the previous one) under a name
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["run", "list", "delete"])]
+        save: Option<String>,
+
+        /// Run a saved query
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["nrql", "list", "delete"])]
+        run: Option<String>,
+
+        /// List saved queries
+        #[arg(long, conflicts_with_all = ["nrql", "delete"])]
+        list: bool,
+
+        /// Delete a saved query
+        #[arg(long, value_name = "NAME", conflicts_with = "nrql")]
+        delete: Option<String>,
+
+        /// Placeholder value (key=value), repeatable
+        #[arg(long = "arg", value_name = "KEY=VALUE")]
+        args: Vec<String>,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
+
+    /// Record a deployment marker (change tracking)
+    Deploy {
+        /// Entity GUID of the deployed app
+        #[arg(long)]
+        app: String,
+
+        /// Revision deployed (default: git rev-parse HEAD)
+        #[arg(long)]
+        revision: Option<String>,
+
+        /// Deployment description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Who deployed (default: git config user.name)
+        #[arg(short, long)]
+        user: Option<String>,
+    },
 }
 
 /// Run a New Relic command (CLI entry point - formats and prints)
@@ -80,10 +127,45 @@ pub enum NewRelicCommand {
 pub async fn run(cmd: NewRelicCommand) -> Result<()> {
     match cmd {
         NewRelicCommand::Config => cmd_config(),
-        NewRelicCommand::Auth { key, account } => cmd_auth(&key, account),
+        NewRelicCommand::Auth { key, account } => cmd_auth(&key, account).await,
         NewRelicCommand::Issues { limit, json } => cmd_issues(limit, json).await,
         NewRelicCommand::Incidents { limit, json } => cmd_incidents(limit, json).await,
-        NewRelicCommand::Query { nrql, json } => cmd_query(&nrql, json).await,
+        NewRelicCommand::Query {
+            nrql,
+            save,
+            run,
+            list,
+            delete,
+            args,
+            json,
+        } => {
+            let req = QueryRequest {
+                nrql,
+                save,
+                run,
+                list,
+                delete,
+                args,
+            };
+            query::cmd_query(&req, json).await
+        }
+        NewRelicCommand::Deploy {
+            app,
+            revision,
+            description,
+            user,
+        } => {
+            let input = DeploymentInput {
+                entity_guid: app,
+                revision: match revision {
+                    Some(r) => r,
+                    None => service::git_head()?,
+                },
+                description,
+                user: user.or_else(service::git_user),
+            };
+            deploy::cmd_deploy(&input).await
+        }
     }
 }
 
@@ -98,6 +180,16 @@ pub fn get_config() -> Result<NewRelicConfig> {
     service::get_config()
 }
 
+/// Name of the configured account, confirming the key still works (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_account_name() -> Result<String> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = NewRelicClient::new()?;
+    service::account_name(&client, config.account_id.unwrap_or_default()).await
+}
+
 /// List recent issues (for MCP/HTTP)
 #[allow(dead_code)]
 #[cfg(not(tarpaulin_include))]
@@ -128,6 +220,16 @@ pub async fn run_nrql(nrql: &str) -> Result<Vec<serde_json::Value>> {
     service::run_nrql(&client, nrql).await
 }
 
+/// Record a deployment marker (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn create_deployment(input: &DeploymentInput) -> Result<Deployment> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = NewRelicClient::new()?;
+    service::create_deployment(&client, input).await
+}
+
 // ============================================================================
 // CLI command handlers - create client, call service, format and print
 // ============================================================================
@@ -140,14 +242,43 @@ fn cmd_config() -> Result<()> {
     Ok(())
 }
 
-/// Set auth
+/// Set auth, looking up the account if not given
 #[cfg(not(tarpaulin_include))]
-fn cmd_auth(key: &str, account_id: i64) -> Result<()> {
+async fn cmd_auth(key: &str, account: Option<i64>) -> Result<()> {
+    let account_id = match account {
+        Some(id) => id,
+        None => {
+            let client = NewRelicClient::with_api_key(key)?;
+            match service::resolve_account(&client).await? {
+                service::AccountChoice::Only(id) => id,
+                service::AccountChoice::Pick(accounts) => pick_account(&accounts)?,
+            }
+        }
+    };
     service::save_auth(key, account_id)?;
     println!("New Relic API key saved for account: {}", account_id);
     Ok(())
 }
 
+/// Numbered picker over the key's accounts
+#[cfg(not(tarpaulin_include))]
+fn pick_account(accounts: &[(i64, String)]) -> Result<i64> {
+    let labels: Vec<String> = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, (id, name))| format!("{}. {} ({})", i + 1, name, id))
+        .collect();
+    let choice = dialoguer::Select::new()
+        .with_prompt("New Relic account")
+        .items(&labels)
+        .default(0)
+        .interact_opt()?;
+    match choice {
+        Some(i) => Ok(accounts[i].0),
+        None => anyhow::bail!("No account chosen"),
+    }
+}
+
 /// List issues
 #[cfg(not(tarpaulin_include))]
 async fn cmd_issues(limit: usize, json: bool) -> Result<()> {
@@ -157,11 +288,7 @@ async fn cmd_issues(limit: usize, json: bool) -> Result<()> {
     let client = NewRelicClient::new()?;
     let issues = service::list_issues(&client, limit).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_issues(&issues, format)?;
     Ok(())
@@ -176,155 +303,11 @@ async fn cmd_incidents(limit: usize, json: bool) -> Result<()> {
     let client = NewRelicClient::new()?;
     let incidents = service::list_incidents(&client, limit).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_incidents(&incidents, format)?;
     Ok(())
 }
 
-/// Run NRQL query
-#[cfg(not(tarpaulin_include))]
-async fn cmd_query(nrql: &str, json: bool) -> Result<()> {
-    let config = service::get_config()?;
-    service::ensure_configured(&config)?;
-
-    let client = NewRelicClient::new()?;
-    let results = service::run_nrql(&client, nrql).await?;
-
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
-
-    display::output_nrql(&results, format)?;
-    Ok(())
-}
-
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_newrelic_command_config_variant() {
-        let cmd = NewRelicCommand::Config;
-        let debug = format!("{:?}", cmd);
-        assert!(debug.contains("Config"));
-    }
-
-    #[test]
-    fn test_newrelic_command_auth_variant() {
-        let cmd = NewRelicCommand::Auth {
-            key: "NRAK-test".to_string(),
-            account: 12345,
-        };
-        let debug = format!("{:?}", cmd);
-        assert!(debug.contains("Auth"));
-        assert!(debug.contains("NRAK-test"));
-        assert!(debug.contains("12345"));
-    }
-
-    #[test]
-    fn test_newrelic_command_issues_variant() {
-        let cmd = NewRelicCommand::Issues {
-            limit: 50,
-            json: true,
-        };
-        let debug = format!("{:?}", cmd);
-        assert!(debug.contains("Issues"));
-        assert!(debug.contains("50"));
-        assert!(debug.contains("true"));
-    }
-
-    #[test]
-    fn test_newrelic_command_incidents_variant() {
-        let cmd = NewRelicCommand::Incidents {
-            limit: 10,
-            json: false,
-        };
-        let debug = format!("{:?}", cmd);
-        assert!(debug.contains("Incidents"));
-        assert!(debug.contains("10"));
-        assert!(debug.contains("false"));
-    }
-
-    #[test]
-    fn test_newrelic_command_query_variant() {
-        let cmd = NewRelicCommand::Query {
-            nrql: "SELECT count(*) FROM Transaction".to_string(),
-            json: true,
-        };
-        let debug = format!("{:?}", cmd);
-        assert!(debug.contains("Query"));
-        assert!(debug.contains("SELECT"));
-        assert!(debug.contains("Transaction"));
-    }
-
-    #[test]
-    fn test_ensure_configured_with_configured() {
-        let config = config::NewRelicConfig {
-            api_key: Some("NRAK-configured".to_string()),
-            account_id: Some(99999),
-        };
-        let result = service::ensure_configured(&config);
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_ensure_configured_with_unconfigured() {
-        let config = config::NewRelicConfig {
-            api_key: None,
-            account_id: None,
-        };
-        let result = service::ensure_configured(&config);
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("not configured"));
-    }
-
-    #[test]
-    fn test_ensure_configured_partial_api_key_only() {
-        let config = config::NewRelicConfig {
-            api_key: Some("NRAK-partial".to_string()),
-            account_id: None,
-        };
-        let result = service::ensure_configured(&config);
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_ensure_configured_partial_account_only() {
-        let config = config::NewRelicConfig {
-            api_key: None,
-            account_id: Some(12345),
-        };
-        let result = service::ensure_configured(&config);
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_output_format_from_json_flag_true() {
-        let json = true;
-        let format = if json {
-            OutputFormat::Json
-        } else {
-            OutputFormat::Table
-        };
-        assert!(matches!(format, OutputFormat::Json));
-    }
-
-    #[test]
-    fn test_output_format_from_json_flag_false() {
-        let json = false;
-        let format = if json {
-            OutputFormat::Json
-        } else {
-            OutputFormat::Table
-        };
-        assert!(matches!(format, OutputFormat::Table));
-    }
-}
+mod tests;
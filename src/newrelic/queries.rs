@@ -0,0 +1,308 @@
+//! Saved NRQL queries (`~/.config/hu/newrelic/queries.toml`)
+//!
+//! Queries are stored by name and may contain `{{var}}` placeholders that are
+//! filled from `--arg key=value` when run. The most recent query is kept as
+//! `last` so it can be saved after the fact.
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Contents of `queries.toml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedQueries {
+    /// Most recently run query (unsubstituted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last: Option<String>,
+    /// Named queries
+    #[serde(default)]
+    pub queries: BTreeMap<String, String>,
+}
+
+/// What `newrelic query` was asked to do
+#[derive(Debug, Clone, Default)]
+pub struct QueryRequest {
+    pub nrql: Option<String>,
+    pub save: Option<String>,
+    pub run: Option<String>,
+    pub list: bool,
+    pub delete: Option<String>,
+    pub args: Vec<String>,
+}
+
+/// Outcome of applying a [`QueryRequest`] to the saved queries
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryPlan {
+    /// Show saved queries
+    List,
+    /// A saved query was removed
+    Deleted(String),
+    /// The previous query was saved under a name
+    SavedLast(String),
+    /// Run this NRQL (placeholders already substituted)
+    Execute(String),
+}
+
+impl SavedQueries {
+    /// Load from `path`; a missing file is an empty store
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write to `path`, creating parent directories
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Look up a saved query by name
+    pub fn get(&self, name: &str) -> Result<&str> {
+        self.queries.get(name).map(String::as_str).with_context(|| {
+            format!(
+                "No saved query named '{}'. Run: hu newrelic query --list",
+                name
+            )
+        })
+    }
+
+    /// Apply a request: edit the store as needed and say what to do next
+    pub fn plan(&mut self, req: &QueryRequest) -> Result<QueryPlan> {
+        if req.list {
+            return Ok(QueryPlan::List);
+        }
+        if let Some(name) = &req.delete {
+            if self.queries.remove(name).is_none() {
+                bail!("No saved query named '{}'", name);
+            }
+            return Ok(QueryPlan::Deleted(name.clone()));
+        }
+
+        let template = match (&req.run, &req.nrql) {
+            (Some(name), _) => self.get(name)?.to_string(),
+            (None, Some(nrql)) => nrql.clone(),
+            (None, None) => return self.save_last(req.save.as_deref()),
+        };
+
+        let nrql = substitute(&template, &parse_args(&req.args)?)?;
+        if let Some(name) = &req.save {
+            self.queries.insert(name.clone(), template.clone());
+        }
+        self.last = Some(template);
+        Ok(QueryPlan::Execute(nrql))
+    }
+
+    fn save_last(&mut self, name: Option<&str>) -> Result<QueryPlan> {
+        let Some(name) = name else {
+            bail!("Provide an NRQL query, or use --run, --list or --delete");
+        };
+        let last = self
+            .last
+            .clone()
+            .context("No previous query to save; pass the NRQL to save")?;
+        self.queries.insert(name.to_string(), last);
+        Ok(QueryPlan::SavedLast(name.to_string()))
+    }
+}
+
+/// Path to `queries.toml`
+pub fn queries_path() -> Result<PathBuf> {
+    dirs::home_dir()
+        .map(|p| {
+            p.join(".config")
+                .join("hu")
+                .join("newrelic")
+                .join("queries.toml")
+        })
+        .context("Cannot determine config directory")
+}
+
+/// Parse `key=value` pairs from `--arg`
+pub fn parse_args(args: &[String]) -> Result<BTreeMap<String, String>> {
+    args.iter()
+        .map(|arg| match arg.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.to_string()))
+            }
+            _ => bail!("Invalid --arg '{}': expected key=value", arg),
+        })
+        .collect()
+}
+
+/// Replace `{{var}}` placeholders; every placeholder must have a value
+pub fn substitute(template: &str, vars: &BTreeMap<String, String>) -> Result<String> {
+    let placeholder_re = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+
+    let missing: Vec<&str> = placeholder_re
+        .captures_iter(template)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| m.as_str())
+        .filter(|name| !vars.contains_key(*name))
+        .collect();
+    if !missing.is_empty() {
+        bail!(
+            "Missing values for placeholders: {} (use --arg key=value)",
+            missing.join(", ")
+        );
+    }
+
+    Ok(placeholder_re
+        .replace_all(template, |caps: &regex::Captures| vars[&caps[1]].clone())
+        .into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> QueryRequest {
+        QueryRequest::default()
+    }
+
+    fn store_with(name: &str, nrql: &str) -> SavedQueries {
+        let mut store = SavedQueries::default();
+        store.queries.insert(name.to_string(), nrql.to_string());
+        store
+    }
+
+    #[test]
+    fn substitute_placeholders() {
+        let vars = parse_args(&["app=web".to_string(), "since=1 hour".to_string()]).unwrap();
+        let nrql = substitute(
+            "SELECT count(*) FROM Transaction WHERE appName = '{{app}}' SINCE {{ since }} ago",
+            &vars,
+        )
+        .unwrap();
+        assert_eq!(
+            nrql,
+            "SELECT count(*) FROM Transaction WHERE appName = 'web' SINCE 1 hour ago"
+        );
+    }
+
+    #[test]
+    fn substitute_reports_missing() {
+        let err = substitute("{{a}} {{b}}", &BTreeMap::new()).unwrap_err();
+        assert!(err.to_string().contains("a, b"));
+    }
+
+    #[test]
+    fn parse_args_rejects_malformed() {
+        assert!(parse_args(&["novalue".to_string()]).is_err());
+        assert!(parse_args(&["=x".to_string()]).is_err());
+        let vars = parse_args(&["q=a=b".to_string()]).unwrap();
+        assert_eq!(vars["q"], "a=b");
+    }
+
+    #[test]
+    fn plan_runs_raw_query_and_saves() {
+        let mut store = SavedQueries::default();
+        let req = QueryRequest {
+            nrql: Some("SELECT 1 FROM {{t}}".to_string()),
+            save: Some("one".to_string()),
+            args: vec!["t=Log".to_string()],
+            ..request()
+        };
+        let plan = store.plan(&req).unwrap();
+        assert_eq!(plan, QueryPlan::Execute("SELECT 1 FROM Log".to_string()));
+        // The template is stored, not the substituted query
+        assert_eq!(store.queries["one"], "SELECT 1 FROM {{t}}");
+        assert_eq!(store.last.as_deref(), Some("SELECT 1 FROM {{t}}"));
+    }
+
+    #[test]
+    fn plan_runs_saved_query() {
+        let mut store = store_with("errors", "SELECT count(*) FROM TransactionError");
+        let req = QueryRequest {
+            run: Some("errors".to_string()),
+            ..request()
+        };
+        assert_eq!(
+            store.plan(&req).unwrap(),
+            QueryPlan::Execute("SELECT count(*) FROM TransactionError".to_string())
+        );
+    }
+
+    #[test]
+    fn plan_run_unknown_name() {
+        let mut store = SavedQueries::default();
+        let req = QueryRequest {
+            run: Some("nope".to_string()),
+            ..request()
+        };
+        assert!(store.plan(&req).unwrap_err().to_string().contains("--list"));
+    }
+
+    #[test]
+    fn plan_saves_last_query() {
+        let mut store = SavedQueries {
+            last: Some("SELECT 1".to_string()),
+            ..Default::default()
+        };
+        let req = QueryRequest {
+            save: Some("one".to_string()),
+            ..request()
+        };
+        assert_eq!(
+            store.plan(&req).unwrap(),
+            QueryPlan::SavedLast("one".to_string())
+        );
+        assert_eq!(store.queries["one"], "SELECT 1");
+    }
+
+    #[test]
+    fn plan_save_without_history_fails() {
+        let mut store = SavedQueries::default();
+        let req = QueryRequest {
+            save: Some("one".to_string()),
+            ..request()
+        };
+        assert!(store.plan(&req).is_err());
+    }
+
+    #[test]
+    fn plan_delete() {
+        let mut store = store_with("one", "SELECT 1");
+        let req = QueryRequest {
+            delete: Some("one".to_string()),
+            ..request()
+        };
+        assert_eq!(
+            store.plan(&req).unwrap(),
+            QueryPlan::Deleted("one".to_string())
+        );
+        assert!(store.queries.is_empty());
+        assert!(store.plan(&req).is_err());
+    }
+
+    #[test]
+    fn plan_requires_something() {
+        let mut store = SavedQueries::default();
+        assert!(store.plan(&request()).is_err());
+        let list = QueryRequest {
+            list: true,
+            ..request()
+        };
+        assert_eq!(store.plan(&list).unwrap(), QueryPlan::List);
+    }
+
+    #[test]
+    fn load_and_save_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("queries.toml");
+        assert_eq!(SavedQueries::load(&path).unwrap(), SavedQueries::default());
+
+        let store = store_with("errors", "SELECT count(*) FROM TransactionError");
+        store.save(&path).unwrap();
+        assert_eq!(SavedQueries::load(&path).unwrap(), store);
+    }
+}
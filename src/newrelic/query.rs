@@ -0,0 +1,49 @@
+//! `hu newrelic query`: run NRQL, or list, save and delete saved queries
+
+use anstream::println;
+use anyhow::Result;
+
+use super::client::NewRelicClient;
+use super::queries::{self, QueryPlan, QueryRequest, SavedQueries};
+use super::types::OutputFormat;
+use super::{display, service};
+
+/// Run NRQL query, or manage saved queries
+#[cfg(not(tarpaulin_include))]
+pub async fn cmd_query(req: &QueryRequest, json: bool) -> Result<()> {
+    let path = queries::queries_path()?;
+    let mut store = SavedQueries::load(&path)?;
+
+    let nrql = match store.plan(req)? {
+        QueryPlan::List => {
+            display::output_saved_queries(&store.queries);
+            return Ok(());
+        }
+        QueryPlan::Deleted(name) => {
+            store.save(&path)?;
+            println!("Deleted saved query '{}'", name);
+            return Ok(());
+        }
+        QueryPlan::SavedLast(name) => {
+            store.save(&path)?;
+            println!("Saved query '{}'", name);
+            return Ok(());
+        }
+        QueryPlan::Execute(nrql) => nrql,
+    };
+    store.save(&path)?;
+    if let Some(name) = &req.save {
+        log::info!("Saved query '{}'", name);
+    }
+
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = NewRelicClient::new()?;
+    let results = service::run_nrql(&client, &nrql).await?;
+
+    let format = OutputFormat::resolve(json);
+
+    display::output_nrql(&results, format)?;
+    Ok(())
+}
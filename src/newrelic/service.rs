@@ -3,11 +3,13 @@
 //! Functions in this module accept trait objects and return typed data.
 //! They never print - that's the CLI layer's job.
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use std::process::Command;
 
 use super::client::NewRelicApi;
 use super::config::{self, NewRelicConfig};
-use super::types::{Incident, Issue};
+use super::types::{Deployment, DeploymentInput, Incident, Issue};
+use crate::util::bail_kind;
 
 /// Get current configuration
 pub fn get_config() -> Result<NewRelicConfig> {
@@ -19,10 +21,40 @@ pub fn save_auth(key: &str, account_id: i64) -> Result<()> {
     config::save_config(key, account_id)
 }
 
+/// Account for a new API key when `--account` isn't given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountChoice {
+    /// The key sees exactly one account
+    Only(i64),
+    /// The key sees several; the user has to pick
+    Pick(Vec<(i64, String)>),
+}
+
+/// Ask New Relic which accounts the key can see
+pub async fn resolve_account(api: &impl NewRelicApi) -> Result<AccountChoice> {
+    let mut accounts = api.list_accounts().await?;
+    match accounts.len() {
+        0 => bail!("This API key can't access any New Relic account. Pass --account <id>"),
+        1 => Ok(AccountChoice::Only(accounts.remove(0).0)),
+        _ => Ok(AccountChoice::Pick(accounts)),
+    }
+}
+
+/// Name of `account_id` as the key sees it
+pub async fn account_name(api: &impl NewRelicApi, account_id: i64) -> Result<String> {
+    api.list_accounts()
+        .await?
+        .into_iter()
+        .find(|(id, _)| *id == account_id)
+        .map(|(_, name)| name)
+        .ok_or_else(|| anyhow::anyhow!("Account {} isn't visible to this API key", account_id))
+}
+
 /// Check if API is configured, return error if not
 pub fn ensure_configured(config: &NewRelicConfig) -> Result<()> {
     if !config.is_configured() {
-        bail!(
+        bail_kind!(
+            Config,
             "New Relic not configured. Run: hu newrelic auth <key> --account <id>\n\
              Or set NEW_RELIC_API_KEY and NEW_RELIC_ACCOUNT_ID environment variables."
         );
@@ -45,6 +77,37 @@ pub async fn run_nrql(api: &impl NewRelicApi, nrql: &str) -> Result<Vec<serde_js
     api.run_nrql(nrql).await
 }
 
+/// Record a deployment marker
+pub async fn create_deployment(
+    api: &impl NewRelicApi,
+    input: &DeploymentInput,
+) -> Result<Deployment> {
+    if input.entity_guid.trim().is_empty() {
+        bail!("Entity GUID is required (--app <guid>)");
+    }
+    api.create_deployment(input).await
+}
+
+/// Current commit SHA (`git rev-parse HEAD`), the default deployment revision
+pub fn git_head() -> Result<String> {
+    git_output(&["rev-parse", "HEAD"])
+        .context("No --revision given and `git rev-parse HEAD` failed")
+}
+
+/// Configured git user name, the default deployment user
+pub fn git_user() -> Option<String> {
+    git_output(&["config", "user.name"]).ok()
+}
+
+fn git_output(args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || stdout.is_empty() {
+        bail!("git {} failed", args.join(" "));
+    }
+    Ok(stdout)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,6 +117,7 @@ mod tests {
         issues: Vec<Issue>,
         incidents: Vec<Incident>,
         nrql_results: Vec<serde_json::Value>,
+        accounts: Vec<(i64, String)>,
     }
 
     impl MockApi {
@@ -62,9 +126,18 @@ mod tests {
                 issues: vec![],
                 incidents: vec![],
                 nrql_results: vec![],
+                accounts: vec![],
             }
         }
 
+        fn with_accounts(mut self, accounts: &[(i64, &str)]) -> Self {
+            self.accounts = accounts
+                .iter()
+                .map(|(id, name)| (*id, name.to_string()))
+                .collect();
+            self
+        }
+
         fn with_issues(mut self, issues: Vec<Issue>) -> Self {
             self.issues = issues;
             self
@@ -93,6 +166,50 @@ mod tests {
         async fn run_nrql(&self, _nrql: &str) -> Result<Vec<serde_json::Value>> {
             Ok(self.nrql_results.clone())
         }
+
+        async fn create_deployment(&self, input: &DeploymentInput) -> Result<Deployment> {
+            Ok(Deployment {
+                deployment_id: format!("dep-{}", input.revision),
+                entity_guid: input.entity_guid.clone(),
+                timestamp: Some(1704067200000),
+            })
+        }
+
+        async fn list_accounts(&self) -> Result<Vec<(i64, String)>> {
+            Ok(self.accounts.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_account_single_is_used() {
+        let api = MockApi::new().with_accounts(&[(42, "Acme")]);
+        assert_eq!(
+            resolve_account(&api).await.unwrap(),
+            AccountChoice::Only(42)
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_account_several_need_picking() {
+        let api = MockApi::new().with_accounts(&[(1, "Prod"), (2, "Staging")]);
+        assert_eq!(
+            resolve_account(&api).await.unwrap(),
+            AccountChoice::Pick(vec![(1, "Prod".to_string()), (2, "Staging".to_string())])
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_account_none_fails() {
+        let err = resolve_account(&MockApi::new()).await.unwrap_err();
+        assert!(err.to_string().contains("--account"));
+    }
+
+    #[tokio::test]
+    async fn account_name_finds_configured_account() {
+        let api = MockApi::new().with_accounts(&[(1, "Prod"), (2, "Staging")]);
+        assert_eq!(account_name(&api, 2).await.unwrap(), "Staging");
+        let err = account_name(&api, 3).await.unwrap_err();
+        assert!(err.to_string().contains("isn't visible"));
     }
 
     fn make_issue(id: &str, title: &str, priority: &str, state: &str) -> Issue {
@@ -189,6 +306,42 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    fn deployment_input(guid: &str) -> DeploymentInput {
+        DeploymentInput {
+            entity_guid: guid.to_string(),
+            revision: "abc123".to_string(),
+            description: None,
+            user: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_deployment_returns_id() {
+        let api = MockApi::new();
+        let deployment = create_deployment(&api, &deployment_input("MXxBUE18"))
+            .await
+            .unwrap();
+        assert_eq!(deployment.deployment_id, "dep-abc123");
+        assert_eq!(deployment.entity_guid, "MXxBUE18");
+    }
+
+    #[tokio::test]
+    async fn create_deployment_requires_guid() {
+        let api = MockApi::new();
+        let err = create_deployment(&api, &deployment_input(" "))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--app"));
+    }
+
+    #[test]
+    fn git_head_is_a_sha() {
+        // Tests run inside the repository checkout
+        let sha = git_head().unwrap();
+        assert_eq!(sha.len(), 40);
+        assert!(sha.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
     #[test]
     fn ensure_configured_fails_without_key() {
         let config = NewRelicConfig::default();
@@ -0,0 +1,187 @@
+use super::*;
+
+#[test]
+fn test_newrelic_command_config_variant() {
+    let cmd = NewRelicCommand::Config;
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Config"));
+}
+
+#[test]
+fn test_newrelic_command_auth_variant() {
+    let cmd = NewRelicCommand::Auth {
+        key: "NRAK-test".to_string(),
+        account: Some(12345),
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Auth"));
+    assert!(debug.contains("NRAK-test"));
+    assert!(debug.contains("12345"));
+}
+
+#[test]
+fn test_newrelic_command_issues_variant() {
+    let cmd = NewRelicCommand::Issues {
+        limit: 50,
+        json: true,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Issues"));
+    assert!(debug.contains("50"));
+    assert!(debug.contains("true"));
+}
+
+#[test]
+fn test_newrelic_command_incidents_variant() {
+    let cmd = NewRelicCommand::Incidents {
+        limit: 10,
+        json: false,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Incidents"));
+    assert!(debug.contains("10"));
+    assert!(debug.contains("false"));
+}
+
+#[test]
+fn test_newrelic_command_query_variant() {
+    let cmd = NewRelicCommand::Query {
+        nrql: Some("SELECT count(*) FROM Transaction".to_string()),
+        save: None,
+        run: None,
+        list: false,
+        delete: None,
+        args: vec![],
+        json: true,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Query"));
+    assert!(debug.contains("SELECT"));
+    assert!(debug.contains("Transaction"));
+}
+
+#[derive(clap::Parser)]
+struct TestCli {
+    #[command(subcommand)]
+    cmd: NewRelicCommand,
+}
+
+fn parse(args: &[&str]) -> Result<NewRelicCommand, clap::Error> {
+    use clap::Parser;
+    let argv = std::iter::once("hu").chain(args.iter().copied());
+    TestCli::try_parse_from(argv).map(|cli| cli.cmd)
+}
+
+#[test]
+fn test_auth_account_is_optional() {
+    let cmd = parse(&["auth", "NRAK-1"]).unwrap();
+    assert!(matches!(cmd, NewRelicCommand::Auth { account: None, .. }));
+    let cmd = parse(&["auth", "NRAK-1", "-a", "42"]).unwrap();
+    assert!(matches!(
+        cmd,
+        NewRelicCommand::Auth {
+            account: Some(42),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_query_run_saved_with_args() {
+    let cmd = parse(&["query", "--run", "errors", "--arg", "app=web"]).unwrap();
+    let NewRelicCommand::Query {
+        nrql, run, args, ..
+    } = cmd
+    else {
+        panic!("expected query");
+    };
+    assert!(nrql.is_none());
+    assert_eq!(run.as_deref(), Some("errors"));
+    assert_eq!(args, vec!["app=web"]);
+}
+
+#[test]
+fn test_query_requires_nrql_or_action() {
+    assert!(parse(&["query"]).is_err());
+    assert!(parse(&["query", "--list"]).is_ok());
+    assert!(parse(&["query", "--save", "last"]).is_ok());
+    assert!(parse(&["query", "SELECT 1", "--run", "x"]).is_err());
+}
+
+#[test]
+fn test_newrelic_command_deploy_variant() {
+    let cmd = NewRelicCommand::Deploy {
+        app: "MXxBUE18".to_string(),
+        revision: Some("abc123".to_string()),
+        description: None,
+        user: None,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Deploy"));
+    assert!(debug.contains("MXxBUE18"));
+    assert!(debug.contains("abc123"));
+}
+
+#[test]
+fn test_ensure_configured_with_configured() {
+    let config = config::NewRelicConfig {
+        api_key: Some("NRAK-configured".to_string()),
+        account_id: Some(99999),
+    };
+    let result = service::ensure_configured(&config);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_ensure_configured_with_unconfigured() {
+    let config = config::NewRelicConfig {
+        api_key: None,
+        account_id: None,
+    };
+    let result = service::ensure_configured(&config);
+    assert!(result.is_err());
+    let err_msg = result.unwrap_err().to_string();
+    assert!(err_msg.contains("not configured"));
+}
+
+#[test]
+fn test_ensure_configured_partial_api_key_only() {
+    let config = config::NewRelicConfig {
+        api_key: Some("NRAK-partial".to_string()),
+        account_id: None,
+    };
+    let result = service::ensure_configured(&config);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ensure_configured_partial_account_only() {
+    let config = config::NewRelicConfig {
+        api_key: None,
+        account_id: Some(12345),
+    };
+    let result = service::ensure_configured(&config);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_output_format_from_json_flag_true() {
+    let json = true;
+    let format = if json {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Table
+    };
+    assert!(matches!(format, OutputFormat::Json));
+}
+
+#[test]
+fn test_output_format_from_json_flag_false() {
+    let json = false;
+    let format = if json {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Table
+    };
+    assert!(matches!(format, OutputFormat::Table));
+}
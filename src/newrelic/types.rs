@@ -53,6 +53,32 @@ pub struct Issue {
     pub activated_at: Option<i64>,
 }
 
+/// Deployment marker to record via change tracking
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeploymentInput {
+    /// Entity GUID of the deployed app
+    pub entity_guid: String,
+    /// Revision (usually a commit SHA)
+    pub revision: String,
+    /// Free-form description
+    pub description: Option<String>,
+    /// Who deployed
+    pub user: Option<String>,
+}
+
+/// Deployment recorded by New Relic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    /// Deployment ID
+    pub deployment_id: String,
+    /// Entity GUID
+    pub entity_guid: String,
+    /// Recorded at (epoch millis)
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
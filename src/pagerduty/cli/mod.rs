@@ -0,0 +1,183 @@
+//! PagerDuty CLI commands
+
+use clap::{Subcommand, ValueEnum};
+
+/// Incident status filter
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum StatusFilter {
+    /// Only triggered incidents
+    Triggered,
+    /// Only acknowledged incidents
+    Acknowledged,
+    /// Only resolved incidents
+    Resolved,
+    /// Triggered and acknowledged (active)
+    Active,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PagerDutyCommand {
+    /// Show configuration status
+    Config,
+
+    /// Set API token
+    Auth {
+        /// PagerDuty API token
+        token: String,
+    },
+
+    /// Show who's on call (now, at a given time, or next)
+    Oncall {
+        /// Filter by escalation policy ID
+        #[arg(short = 'p', long)]
+        policy: Option<String>,
+
+        /// Filter by schedule ID
+        #[arg(short, long)]
+        schedule: Option<String>,
+
+        /// Show who's on call at this time (ISO 8601, or offset like +8h, 2d)
+        #[arg(long)]
+        at: Option<String>,
+
+        /// End of the window started by --at (same formats)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Show the rotation that takes over after the current one
+        #[arg(long, conflicts_with_all = ["at", "until"])]
+        next: bool,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// List active alerts (triggered + acknowledged incidents)
+    Alerts {
+        /// Maximum number to show
+        #[arg(short, long, default_value = "25")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// List incidents with filters
+    Incidents {
+        /// Filter by status
+        #[arg(short, long, value_enum)]
+        status: Option<StatusFilter>,
+
+        /// Maximum number to show
+        #[arg(short, long, default_value = "25")]
+        limit: usize,
+
+        /// Filter by service ID (repeatable; see `pagerduty services`)
+        #[arg(long = "service", value_name = "ID")]
+        services: Vec<String>,
+
+        /// Start of time window (ISO 8601 or relative, e.g. 24h, 7d)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// End of time window (ISO 8601 or relative)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// List services (IDs for --service)
+    Services {
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Show incident details
+    Show {
+        /// Incident ID
+        id: String,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Acknowledge incidents
+    Ack {
+        /// Incident IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Resolve incidents
+    Resolve {
+        /// Incident IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Reassign an incident to one or more users
+    Assign {
+        /// Incident ID
+        id: String,
+
+        /// Users by email, name or ID
+        #[arg(required = true)]
+        users: Vec<String>,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Escalate an incident to a level of its escalation policy
+    Escalate {
+        /// Incident ID
+        id: String,
+
+        /// Escalation level (1 = first responders)
+        #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+        level: u32,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Snooze an acknowledged incident (e.g. 30m, 2h)
+    Snooze {
+        /// Incident ID
+        id: String,
+
+        /// How long to snooze: 30m, 2h, 1d
+        duration: String,
+
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    /// Show current user info
+    Whoami {
+        /// Output as JSON
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,343 @@
+use super::*;
+use clap::{CommandFactory, Parser};
+
+#[derive(Parser)]
+struct TestCli {
+    #[command(subcommand)]
+    cmd: PagerDutyCommand,
+}
+
+#[test]
+fn parses_config() {
+    let cli = TestCli::try_parse_from(["test", "config"]).unwrap();
+    assert!(matches!(cli.cmd, PagerDutyCommand::Config));
+}
+
+#[test]
+fn parses_auth() {
+    let cli = TestCli::try_parse_from(["test", "auth", "my-token"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Auth { token } => assert_eq!(token, "my-token"),
+        _ => panic!("Expected Auth command"),
+    }
+}
+
+#[test]
+fn parses_oncall_no_args() {
+    let cli = TestCli::try_parse_from(["test", "oncall"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall {
+            policy,
+            schedule,
+            at,
+            until,
+            next,
+            json,
+        } => {
+            assert!(policy.is_none());
+            assert!(schedule.is_none());
+            assert!(at.is_none());
+            assert!(until.is_none());
+            assert!(!next);
+            assert!(!json);
+        }
+        _ => panic!("Expected Oncall command"),
+    }
+}
+
+#[test]
+fn parses_oncall_window() {
+    let cli = TestCli::try_parse_from(["test", "oncall", "--at", "+8h", "--until", "+1d"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall { at, until, .. } => {
+            assert_eq!(at.as_deref(), Some("+8h"));
+            assert_eq!(until.as_deref(), Some("+1d"));
+        }
+        _ => panic!("Expected Oncall command"),
+    }
+}
+
+#[test]
+fn parses_oncall_next() {
+    let cli = TestCli::try_parse_from(["test", "oncall", "--next"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall { next, .. } => assert!(next),
+        _ => panic!("Expected Oncall command"),
+    }
+    assert!(TestCli::try_parse_from(["test", "oncall", "--next", "--at", "+1h"]).is_err());
+}
+
+#[test]
+fn parses_oncall_with_policy() {
+    let cli = TestCli::try_parse_from(["test", "oncall", "-p", "EP123"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall { policy, .. } => {
+            assert_eq!(policy, Some("EP123".to_string()));
+        }
+        _ => panic!("Expected Oncall command"),
+    }
+}
+
+#[test]
+fn parses_oncall_with_schedule() {
+    let cli = TestCli::try_parse_from(["test", "oncall", "--schedule", "S456"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall { schedule, .. } => {
+            assert_eq!(schedule, Some("S456".to_string()));
+        }
+        _ => panic!("Expected Oncall command"),
+    }
+}
+
+#[test]
+fn parses_oncall_json() {
+    let cli = TestCli::try_parse_from(["test", "oncall", "--json"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Oncall { json, .. } => assert!(json),
+        _ => panic!("Expected Oncall command"),
+    }
+}
+
+#[test]
+fn parses_alerts_default_limit() {
+    let cli = TestCli::try_parse_from(["test", "alerts"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Alerts { limit, json } => {
+            assert_eq!(limit, 25);
+            assert!(!json);
+        }
+        _ => panic!("Expected Alerts command"),
+    }
+}
+
+#[test]
+fn parses_alerts_custom_limit() {
+    let cli = TestCli::try_parse_from(["test", "alerts", "-l", "50"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Alerts { limit, .. } => assert_eq!(limit, 50),
+        _ => panic!("Expected Alerts command"),
+    }
+}
+
+#[test]
+fn parses_incidents_no_filter() {
+    let cli = TestCli::try_parse_from(["test", "incidents"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents {
+            status,
+            limit,
+            services,
+            since,
+            until,
+            json,
+        } => {
+            assert!(status.is_none());
+            assert_eq!(limit, 25);
+            assert!(services.is_empty());
+            assert!(since.is_none());
+            assert!(until.is_none());
+            assert!(!json);
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_incidents_status_triggered() {
+    let cli = TestCli::try_parse_from(["test", "incidents", "-s", "triggered"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents { status, .. } => {
+            assert!(matches!(status, Some(StatusFilter::Triggered)));
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_incidents_status_acknowledged() {
+    let cli = TestCli::try_parse_from(["test", "incidents", "--status", "acknowledged"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents { status, .. } => {
+            assert!(matches!(status, Some(StatusFilter::Acknowledged)));
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_incidents_status_resolved() {
+    let cli = TestCli::try_parse_from(["test", "incidents", "-s", "resolved"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents { status, .. } => {
+            assert!(matches!(status, Some(StatusFilter::Resolved)));
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_incidents_status_active() {
+    let cli = TestCli::try_parse_from(["test", "incidents", "-s", "active"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents { status, .. } => {
+            assert!(matches!(status, Some(StatusFilter::Active)));
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_incidents_service_and_window() {
+    let cli = TestCli::try_parse_from([
+        "test",
+        "incidents",
+        "--service",
+        "SVC1",
+        "--service",
+        "SVC2",
+        "--since",
+        "2024-01-01",
+        "--until",
+        "24h",
+    ])
+    .unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Incidents {
+            services,
+            since,
+            until,
+            ..
+        } => {
+            assert_eq!(services, vec!["SVC1", "SVC2"]);
+            assert_eq!(since.as_deref(), Some("2024-01-01"));
+            assert_eq!(until.as_deref(), Some("24h"));
+        }
+        _ => panic!("Expected Incidents command"),
+    }
+}
+
+#[test]
+fn parses_services() {
+    let cli = TestCli::try_parse_from(["test", "services", "--json"]).unwrap();
+    assert!(matches!(cli.cmd, PagerDutyCommand::Services { json: true }));
+}
+
+#[test]
+fn parses_show() {
+    let cli = TestCli::try_parse_from(["test", "show", "INC123"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Show { id, json } => {
+            assert_eq!(id, "INC123");
+            assert!(!json);
+        }
+        _ => panic!("Expected Show command"),
+    }
+}
+
+#[test]
+fn parses_show_json() {
+    let cli = TestCli::try_parse_from(["test", "show", "INC123", "--json"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Show { id, json } => {
+            assert_eq!(id, "INC123");
+            assert!(json);
+        }
+        _ => panic!("Expected Show command"),
+    }
+}
+
+#[test]
+fn status_filter_debug() {
+    let filter = StatusFilter::Triggered;
+    let debug = format!("{:?}", filter);
+    assert!(debug.contains("Triggered"));
+}
+
+#[test]
+fn status_filter_clone() {
+    let filter = StatusFilter::Active;
+    let cloned = filter;
+    assert!(matches!(cloned, StatusFilter::Active));
+}
+
+#[test]
+fn command_debug() {
+    let cmd = PagerDutyCommand::Config;
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Config"));
+}
+
+#[test]
+fn command_has_help() {
+    // Verify help text is generated without panic
+    let mut cmd = TestCli::command();
+    let help = cmd.render_help();
+    assert!(!help.to_string().is_empty());
+}
+
+#[test]
+fn parses_ack_multiple_ids() {
+    let cli = TestCli::try_parse_from(["test", "ack", "P1", "P2"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Ack { ids, json } => {
+            assert_eq!(ids, vec!["P1", "P2"]);
+            assert!(!json);
+        }
+        _ => panic!("Expected Ack command"),
+    }
+}
+
+#[test]
+fn parses_assign_several_users() {
+    let cli =
+        TestCli::try_parse_from(["test", "assign", "P1", "bob@example.com", "Alice"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Assign { id, users, .. } => {
+            assert_eq!(id, "P1");
+            assert_eq!(users, vec!["bob@example.com", "Alice"]);
+        }
+        _ => panic!("Expected Assign command"),
+    }
+    assert!(TestCli::try_parse_from(["test", "assign", "P1"]).is_err());
+}
+
+#[test]
+fn parses_escalate_level() {
+    let cli = TestCli::try_parse_from(["test", "escalate", "P1", "2"]).unwrap();
+    assert!(matches!(
+        cli.cmd,
+        PagerDutyCommand::Escalate { level: 2, .. }
+    ));
+    assert!(TestCli::try_parse_from(["test", "escalate", "P1", "0"]).is_err());
+}
+
+#[test]
+fn parses_snooze() {
+    let cli = TestCli::try_parse_from(["test", "snooze", "P1", "30m"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Snooze { id, duration, json } => {
+            assert_eq!(id, "P1");
+            assert_eq!(duration, "30m");
+            assert!(!json);
+        }
+        _ => panic!("Expected Snooze command"),
+    }
+    assert!(TestCli::try_parse_from(["test", "snooze", "P1"]).is_err());
+}
+
+#[test]
+fn parses_resolve() {
+    let cli = TestCli::try_parse_from(["test", "resolve", "P1", "--json"]).unwrap();
+    match cli.cmd {
+        PagerDutyCommand::Resolve { ids, json } => {
+            assert_eq!(ids, vec!["P1"]);
+            assert!(json);
+        }
+        _ => panic!("Expected Resolve command"),
+    }
+}
+
+#[test]
+fn ack_requires_id() {
+    assert!(TestCli::try_parse_from(["test", "ack"]).is_err());
+}
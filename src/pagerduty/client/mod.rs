@@ -9,9 +9,11 @@ use tokio::time::sleep;
 
 use super::config::{load_config, PagerDutyConfig};
 use super::types::{
-    CurrentUserResponse, Incident, IncidentResponse, IncidentStatus, IncidentsResponse, Oncall,
-    OncallsResponse, Service, ServicesResponse, User,
+    CurrentUserResponse, Incident, IncidentChange, IncidentOptions, IncidentResponse,
+    IncidentStatus, IncidentsResponse, Oncall, OncallsResponse, Service, ServicesResponse,
+    TimeWindow, User, UsersResponse,
 };
+use crate::util::{ErrorKind, HuError};
 
 #[cfg(test)]
 mod tests;
@@ -31,13 +33,13 @@ pub trait PagerDutyApi: Send + Sync {
         &self,
         schedule_ids: Option<&[String]>,
         escalation_policy_ids: Option<&[String]>,
+        window: &TimeWindow,
     ) -> impl Future<Output = Result<Vec<Oncall>>> + Send;
 
     /// List incidents
     fn list_incidents(
         &self,
-        statuses: &[IncidentStatus],
-        limit: usize,
+        opts: &IncidentOptions,
     ) -> impl Future<Output = Result<Vec<Incident>>> + Send;
 
     /// Get single incident
@@ -45,6 +47,33 @@ pub trait PagerDutyApi: Send + Sync {
 
     /// List services
     fn list_services(&self) -> impl Future<Output = Result<Vec<Service>>> + Send;
+
+    /// Set the status of one or more incidents, acting as `from_email`
+    fn update_incidents(
+        &self,
+        ids: &[String],
+        status: IncidentStatus,
+        from_email: &str,
+    ) -> impl Future<Output = Result<Vec<Incident>>> + Send;
+
+    /// Users whose name or email matches `query`
+    fn find_users(&self, query: &str) -> impl Future<Output = Result<Vec<User>>> + Send;
+
+    /// Reassign or escalate one incident, acting as `from_email`
+    fn change_incident(
+        &self,
+        id: &str,
+        change: &IncidentChange,
+        from_email: &str,
+    ) -> impl Future<Output = Result<Incident>> + Send;
+
+    /// Snooze an acknowledged incident for `duration_secs`, acting as `from_email`
+    fn snooze_incident(
+        &self,
+        id: &str,
+        duration_secs: u64,
+        from_email: &str,
+    ) -> impl Future<Output = Result<Incident>> + Send;
 }
 
 /// PagerDuty HTTP client
@@ -58,7 +87,7 @@ impl PagerDutyClient {
     #[cfg(not(tarpaulin_include))]
     pub fn new() -> Result<Self> {
         let config = load_config()?;
-        let http = Client::builder().user_agent("hu-cli/0.1.0").build()?;
+        let http = crate::util::http::client()?;
         Ok(Self { config, http })
     }
 
@@ -99,6 +128,52 @@ impl PagerDutyClient {
         .await
     }
 
+    /// Make authenticated PUT request with a JSON body
+    ///
+    /// Write endpoints require a `From` header naming the acting user's email.
+    async fn put<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+        from_email: &str,
+    ) -> Result<T> {
+        let token = self.api_token()?.to_string();
+        let url = format!("{}{}", PAGERDUTY_API_URL, path);
+
+        self.execute_with_retry(|| {
+            self.http
+                .put(&url)
+                .header("Authorization", format!("Token token={}", token))
+                .header("Content-Type", "application/json")
+                .header("From", from_email)
+                .json(body)
+                .send()
+        })
+        .await
+    }
+
+    /// Make authenticated POST request with a JSON body, acting as `from_email`
+    async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+        from_email: &str,
+    ) -> Result<T> {
+        let token = self.api_token()?.to_string();
+        let url = format!("{}{}", PAGERDUTY_API_URL, path);
+
+        self.execute_with_retry(|| {
+            self.http
+                .post(&url)
+                .header("Authorization", format!("Token token={}", token))
+                .header("Content-Type", "application/json")
+                .header("From", from_email)
+                .json(body)
+                .send()
+        })
+        .await
+    }
+
     /// Execute request with retry on rate limit
     async fn execute_with_retry<F, Fut, T>(&self, request_fn: F) -> Result<T>
     where
@@ -111,6 +186,7 @@ impl PagerDutyClient {
         loop {
             let response = request_fn().await?;
             let status = response.status();
+            log::debug!("HTTP {} {}", status.as_u16(), response.url());
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 if retries >= MAX_RETRIES {
@@ -127,7 +203,7 @@ impl PagerDutyClient {
                     .and_then(|s| s.parse::<u64>().ok())
                     .unwrap_or(DEFAULT_RETRY_SECS);
 
-                eprintln!(
+                log::info!(
                     "Rate limited, waiting {} seconds... (retry {}/{})",
                     retry_after,
                     retries + 1,
@@ -140,7 +216,7 @@ impl PagerDutyClient {
 
             if !status.is_success() {
                 let body = response.text().await.unwrap_or_default();
-                return Err(anyhow::anyhow!("HTTP {}: {}", status.as_u16(), body));
+                return Err(http_error(status.as_u16(), &body));
             }
 
             let text = response.text().await?;
@@ -161,18 +237,15 @@ impl PagerDutyApi for PagerDutyClient {
         &self,
         schedule_ids: Option<&[String]>,
         escalation_policy_ids: Option<&[String]>,
+        window: &TimeWindow,
     ) -> Result<Vec<Oncall>> {
-        let params = build_oncall_params(schedule_ids, escalation_policy_ids);
+        let params = build_oncall_params(schedule_ids, escalation_policy_ids, window);
         let resp: OncallsResponse = self.get_with_params("/oncalls", &params).await?;
         Ok(resp.oncalls)
     }
 
-    async fn list_incidents(
-        &self,
-        statuses: &[IncidentStatus],
-        limit: usize,
-    ) -> Result<Vec<Incident>> {
-        let params = build_incidents_params(statuses, limit);
+    async fn list_incidents(&self, opts: &IncidentOptions) -> Result<Vec<Incident>> {
+        let params = build_incidents_params(opts);
         let resp: IncidentsResponse = self.get_with_params("/incidents", &params).await?;
         Ok(resp.incidents)
     }
@@ -187,12 +260,108 @@ impl PagerDutyApi for PagerDutyClient {
         let resp: ServicesResponse = self.get("/services").await?;
         Ok(resp.services)
     }
+
+    async fn update_incidents(
+        &self,
+        ids: &[String],
+        status: IncidentStatus,
+        from_email: &str,
+    ) -> Result<Vec<Incident>> {
+        let body = build_status_update_body(ids, status);
+        let resp: IncidentsResponse = self.put("/incidents", &body, from_email).await?;
+        Ok(resp.incidents)
+    }
+
+    async fn find_users(&self, query: &str) -> Result<Vec<User>> {
+        let params = [("query", query.to_string())];
+        let resp: UsersResponse = self.get_with_params("/users", &params).await?;
+        Ok(resp.users)
+    }
+
+    async fn change_incident(
+        &self,
+        id: &str,
+        change: &IncidentChange,
+        from_email: &str,
+    ) -> Result<Incident> {
+        let body = build_change_body(id, change);
+        let resp: IncidentsResponse = self.put("/incidents", &body, from_email).await?;
+        resp.incidents
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("PagerDuty returned no incident for {}", id))
+    }
+
+    async fn snooze_incident(
+        &self,
+        id: &str,
+        duration_secs: u64,
+        from_email: &str,
+    ) -> Result<Incident> {
+        let path = format!("/incidents/{}/snooze", id);
+        let body = serde_json::json!({ "duration": duration_secs });
+        let resp: IncidentResponse = self.post(&path, &body, from_email).await?;
+        Ok(resp.incident)
+    }
+}
+
+/// Build the `PUT /incidents` body setting `status` on every incident
+fn build_status_update_body(ids: &[String], status: IncidentStatus) -> serde_json::Value {
+    let incidents: Vec<serde_json::Value> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id,
+                "type": "incident_reference",
+                "status": status.as_str(),
+            })
+        })
+        .collect();
+    serde_json::json!({ "incidents": incidents })
+}
+
+/// Build the `PUT /incidents` body reassigning or escalating one incident
+fn build_change_body(id: &str, change: &IncidentChange) -> serde_json::Value {
+    let mut incident = serde_json::json!({
+        "id": id,
+        "type": "incident_reference",
+    });
+    match change {
+        IncidentChange::Reassign(user_ids) => {
+            let assignments: Vec<serde_json::Value> = user_ids
+                .iter()
+                .map(|uid| serde_json::json!({ "assignee": { "id": uid, "type": "user_reference" } }))
+                .collect();
+            incident["assignments"] = assignments.into();
+        }
+        IncidentChange::Escalate(level) => incident["escalation_level"] = (*level).into(),
+    }
+    serde_json::json!({ "incidents": [incident] })
+}
+
+/// Error for a failed response, spelling out permission problems
+fn http_error(status: u16, body: &str) -> anyhow::Error {
+    let message = match status {
+        401 => "PagerDuty rejected the API token (HTTP 401). Run: hu pagerduty auth <token>"
+            .to_string(),
+        402 => format!(
+            "PagerDuty account plan doesn't include this action (HTTP 402): {}",
+            body
+        ),
+        403 => format!(
+            "Not allowed by PagerDuty (HTTP 403); the token or acting user lacks permission: {}",
+            body
+        ),
+        _ => format!("HTTP {}: {}", status, body),
+    };
+    HuError::new(ErrorKind::from_status(status), message).into()
 }
 
 /// Build query parameters for oncalls endpoint
 fn build_oncall_params(
     schedule_ids: Option<&[String]>,
     escalation_policy_ids: Option<&[String]>,
+    window: &TimeWindow,
 ) -> Vec<(&'static str, String)> {
     let mut params = Vec::new();
 
@@ -208,19 +377,36 @@ fn build_oncall_params(
         }
     }
 
+    if let Some(since) = &window.since {
+        params.push(("since", since.clone()));
+    }
+
+    if let Some(until) = &window.until {
+        params.push(("until", until.clone()));
+    }
+
     params
 }
 
 /// Build query parameters for incidents endpoint
-fn build_incidents_params(
-    statuses: &[IncidentStatus],
-    limit: usize,
-) -> Vec<(&'static str, String)> {
-    let mut params = vec![("limit", limit.to_string())];
+fn build_incidents_params(opts: &IncidentOptions) -> Vec<(&'static str, String)> {
+    let mut params = vec![("limit", opts.limit.to_string())];
 
-    for status in statuses {
+    for status in &opts.statuses {
         params.push(("statuses[]", status.as_str().to_string()));
     }
 
+    for id in &opts.service_ids {
+        params.push(("service_ids[]", id.clone()));
+    }
+
+    if let Some(since) = &opts.since {
+        params.push(("since", since.clone()));
+    }
+
+    if let Some(until) = &opts.until {
+        params.push(("until", until.clone()));
+    }
+
     params
 }
@@ -2,14 +2,14 @@ use super::*;
 
 #[test]
 fn build_oncall_params_empty() {
-    let params = build_oncall_params(None, None);
+    let params = build_oncall_params(None, None, &TimeWindow::default());
     assert!(params.is_empty());
 }
 
 #[test]
 fn build_oncall_params_with_schedule() {
     let schedules = vec!["S1".to_string(), "S2".to_string()];
-    let params = build_oncall_params(Some(&schedules), None);
+    let params = build_oncall_params(Some(&schedules), None, &TimeWindow::default());
     assert_eq!(params.len(), 2);
     assert_eq!(params[0], ("schedule_ids[]", "S1".to_string()));
     assert_eq!(params[1], ("schedule_ids[]", "S2".to_string()));
@@ -18,7 +18,7 @@ fn build_oncall_params_with_schedule() {
 #[test]
 fn build_oncall_params_with_policy() {
     let policies = vec!["EP1".to_string()];
-    let params = build_oncall_params(None, Some(&policies));
+    let params = build_oncall_params(None, Some(&policies), &TimeWindow::default());
     assert_eq!(params.len(), 1);
     assert_eq!(params[0], ("escalation_policy_ids[]", "EP1".to_string()));
 }
@@ -27,14 +27,34 @@ fn build_oncall_params_with_policy() {
 fn build_oncall_params_with_both() {
     let schedules = vec!["S1".to_string()];
     let policies = vec!["EP1".to_string()];
-    let params = build_oncall_params(Some(&schedules), Some(&policies));
+    let params = build_oncall_params(Some(&schedules), Some(&policies), &TimeWindow::default());
     assert_eq!(params.len(), 2);
 }
 
+#[test]
+fn build_oncall_params_with_window() {
+    let window = TimeWindow {
+        since: Some("2024-06-10T09:00:00Z".to_string()),
+        until: Some("2024-06-11T09:00:00Z".to_string()),
+    };
+    let params = build_oncall_params(None, None, &window);
+    assert_eq!(
+        params,
+        vec![
+            ("since", "2024-06-10T09:00:00Z".to_string()),
+            ("until", "2024-06-11T09:00:00Z".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn build_incidents_params_basic() {
-    let statuses = vec![IncidentStatus::Triggered];
-    let params = build_incidents_params(&statuses, 25);
+    let opts = IncidentOptions {
+        statuses: vec![IncidentStatus::Triggered],
+        limit: 25,
+        ..Default::default()
+    };
+    let params = build_incidents_params(&opts);
     assert_eq!(params.len(), 2);
     assert_eq!(params[0], ("limit", "25".to_string()));
     assert_eq!(params[1], ("statuses[]", "triggered".to_string()));
@@ -42,8 +62,11 @@ fn build_incidents_params_basic() {
 
 #[test]
 fn build_incidents_params_multiple_statuses() {
-    let statuses = vec![IncidentStatus::Triggered, IncidentStatus::Acknowledged];
-    let params = build_incidents_params(&statuses, 10);
+    let opts = IncidentOptions {
+        limit: 10,
+        ..Default::default()
+    };
+    let params = build_incidents_params(&opts);
     assert_eq!(params.len(), 3);
     assert_eq!(params[0], ("limit", "10".to_string()));
     assert_eq!(params[1], ("statuses[]", "triggered".to_string()));
@@ -52,12 +75,45 @@ fn build_incidents_params_multiple_statuses() {
 
 #[test]
 fn build_incidents_params_empty_statuses() {
-    let statuses: Vec<IncidentStatus> = vec![];
-    let params = build_incidents_params(&statuses, 50);
+    let opts = IncidentOptions {
+        statuses: vec![],
+        limit: 50,
+        ..Default::default()
+    };
+    let params = build_incidents_params(&opts);
     assert_eq!(params.len(), 1);
     assert_eq!(params[0], ("limit", "50".to_string()));
 }
 
+#[test]
+fn build_incidents_params_services_and_window() {
+    let opts = IncidentOptions {
+        statuses: vec![IncidentStatus::Resolved],
+        limit: 5,
+        service_ids: vec!["SVC1".to_string(), "SVC2".to_string()],
+        since: Some("2024-01-01T00:00:00Z".to_string()),
+        until: Some("2024-01-02T00:00:00Z".to_string()),
+    };
+    let params = build_incidents_params(&opts);
+    assert_eq!(
+        params,
+        vec![
+            ("limit", "5".to_string()),
+            ("statuses[]", "resolved".to_string()),
+            ("service_ids[]", "SVC1".to_string()),
+            ("service_ids[]", "SVC2".to_string()),
+            ("since", "2024-01-01T00:00:00Z".to_string()),
+            ("until", "2024-01-02T00:00:00Z".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn build_incidents_params_default_has_no_window() {
+    let params = build_incidents_params(&IncidentOptions::default());
+    assert!(params.iter().all(|(k, _)| *k != "since" && *k != "until"));
+}
+
 // Mock implementation for testing handlers
 pub struct MockPagerDutyApi {
     pub oncalls: Vec<Oncall>,
@@ -108,16 +164,13 @@ impl PagerDutyApi for MockPagerDutyApi {
         &self,
         _schedule_ids: Option<&[String]>,
         _escalation_policy_ids: Option<&[String]>,
+        _window: &TimeWindow,
     ) -> Result<Vec<Oncall>> {
         Ok(self.oncalls.clone())
     }
 
-    async fn list_incidents(
-        &self,
-        _statuses: &[IncidentStatus],
-        limit: usize,
-    ) -> Result<Vec<Incident>> {
-        Ok(self.incidents.iter().take(limit).cloned().collect())
+    async fn list_incidents(&self, opts: &IncidentOptions) -> Result<Vec<Incident>> {
+        Ok(self.incidents.iter().take(opts.limit).cloned().collect())
     }
 
     async fn get_incident(&self, id: &str) -> Result<Incident> {
@@ -131,6 +184,46 @@ impl PagerDutyApi for MockPagerDutyApi {
     async fn list_services(&self) -> Result<Vec<Service>> {
         Ok(self.services.clone())
     }
+
+    async fn update_incidents(
+        &self,
+        ids: &[String],
+        status: IncidentStatus,
+        _from_email: &str,
+    ) -> Result<Vec<Incident>> {
+        Ok(self
+            .incidents
+            .iter()
+            .filter(|i| ids.contains(&i.id))
+            .cloned()
+            .map(|mut i| {
+                i.status = status;
+                i
+            })
+            .collect())
+    }
+
+    async fn snooze_incident(
+        &self,
+        id: &str,
+        _duration_secs: u64,
+        _from_email: &str,
+    ) -> Result<Incident> {
+        self.get_incident(id).await
+    }
+
+    async fn find_users(&self, _query: &str) -> Result<Vec<User>> {
+        Ok(self.current_user.clone().into_iter().collect())
+    }
+
+    async fn change_incident(
+        &self,
+        id: &str,
+        _change: &IncidentChange,
+        _from_email: &str,
+    ) -> Result<Incident> {
+        self.get_incident(id).await
+    }
 }
 
 #[tokio::test]
@@ -138,7 +231,10 @@ async fn mock_list_oncalls() {
     let oncall = make_test_oncall("U1", "Alice");
     let mock = MockPagerDutyApi::new().with_oncalls(vec![oncall]);
 
-    let result = mock.list_oncalls(None, None).await.unwrap();
+    let result = mock
+        .list_oncalls(None, None, &TimeWindow::default())
+        .await
+        .unwrap();
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].user.display_name(), "Alice");
 }
@@ -152,10 +248,12 @@ async fn mock_list_incidents_respects_limit() {
     ];
     let mock = MockPagerDutyApi::new().with_incidents(incidents);
 
-    let result = mock
-        .list_incidents(&[IncidentStatus::Triggered], 2)
-        .await
-        .unwrap();
+    let opts = IncidentOptions {
+        statuses: vec![IncidentStatus::Triggered],
+        limit: 2,
+        ..Default::default()
+    };
+    let result = mock.list_incidents(&opts).await.unwrap();
     assert_eq!(result.len(), 2);
 }
 
@@ -283,6 +381,7 @@ fn make_test_incident(id: &str) -> Incident {
         html_url: String::new(),
         service: make_test_service("S1", "Production"),
         assignments: vec![],
+        pending_actions: vec![],
     }
 }
 
@@ -294,3 +393,75 @@ fn make_test_service(id: &str, name: &str) -> Service {
         html_url: String::new(),
     }
 }
+
+#[test]
+fn build_status_update_body_multiple_ids() {
+    let ids = vec!["P1".to_string(), "P2".to_string()];
+    let body = build_status_update_body(&ids, IncidentStatus::Acknowledged);
+    let incidents = body["incidents"].as_array().unwrap();
+    assert_eq!(incidents.len(), 2);
+    assert_eq!(incidents[0]["id"], "P1");
+    assert_eq!(incidents[0]["type"], "incident_reference");
+    assert_eq!(incidents[0]["status"], "acknowledged");
+    assert_eq!(incidents[1]["id"], "P2");
+}
+
+#[test]
+fn build_status_update_body_resolved() {
+    let body = build_status_update_body(&["P1".to_string()], IncidentStatus::Resolved);
+    assert_eq!(body["incidents"][0]["status"], "resolved");
+}
+
+#[tokio::test]
+async fn mock_update_incidents_sets_status() {
+    let mock = MockPagerDutyApi::new().with_incidents(vec![make_test_incident("1")]);
+    let updated = mock
+        .update_incidents(&["1".to_string()], IncidentStatus::Resolved, "a@b.c")
+        .await
+        .unwrap();
+    assert_eq!(updated.len(), 1);
+    assert_eq!(updated[0].status, IncidentStatus::Resolved);
+}
+
+#[test]
+fn build_change_body_reassign() {
+    let change = IncidentChange::Reassign(vec!["U1".to_string(), "U2".to_string()]);
+    let body = build_change_body("P1", &change);
+    let incident = &body["incidents"][0];
+    assert_eq!(incident["id"], "P1");
+    assert_eq!(incident["type"], "incident_reference");
+    assert_eq!(incident["assignments"][1]["assignee"]["id"], "U2");
+    assert_eq!(
+        incident["assignments"][0]["assignee"]["type"],
+        "user_reference"
+    );
+    assert!(incident.get("escalation_level").is_none());
+}
+
+#[test]
+fn build_change_body_escalate() {
+    let body = build_change_body("P1", &IncidentChange::Escalate(2));
+    assert_eq!(body["incidents"][0]["escalation_level"], 2);
+    assert!(body["incidents"][0].get("assignments").is_none());
+}
+
+#[test]
+fn http_error_explains_permissions() {
+    assert!(http_error(403, "{}")
+        .to_string()
+        .contains("lacks permission"));
+    assert!(http_error(402, "{}").to_string().contains("plan"));
+    assert!(http_error(401, "")
+        .to_string()
+        .contains("hu pagerduty auth"));
+    assert_eq!(http_error(500, "boom").to_string(), "HTTP 500: boom");
+}
+
+#[test]
+fn http_error_carries_kind() {
+    use crate::util::{ErrorKind, HuError};
+    let kind = |status| http_error(status, "").downcast::<HuError>().unwrap().kind;
+    assert_eq!(kind(401), ErrorKind::Auth);
+    assert_eq!(kind(404), ErrorKind::NotFound);
+    assert_eq!(kind(503), ErrorKind::External);
+}
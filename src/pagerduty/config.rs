@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::util::secrets;
+
 /// PagerDuty configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PagerDutyConfig {
@@ -36,7 +38,7 @@ struct SettingsFile {
 
 /// Get path to config file
 pub fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
+    crate::util::settings_path()
 }
 
 /// Load PagerDuty config from settings file and environment
@@ -48,6 +50,11 @@ pub fn load_config() -> Result<PagerDutyConfig> {
         if path.exists() {
             let contents = fs::read_to_string(&path)?;
             config = parse_config(&contents)?;
+            secrets::fill(
+                &mut config.api_token,
+                "pagerduty.api_token",
+                secrets::enabled_in(&contents),
+            );
         }
     }
 
@@ -111,9 +118,12 @@ fn update_config_toml(contents: &str, api_token: &str) -> Result<String> {
         .and_then(|v| v.as_table_mut())
         .ok_or_else(|| anyhow::anyhow!("pagerduty section is not a table"))?;
 
-    pagerduty.insert(
-        "api_token".to_string(),
-        toml::Value::String(api_token.to_string()),
+    secrets::store(
+        pagerduty,
+        "api_token",
+        "pagerduty.api_token",
+        api_token,
+        secrets::enabled_in(contents),
     );
 
     toml::to_string_pretty(&doc).map_err(Into::into)
@@ -1,10 +1,14 @@
 //! PagerDuty output formatting
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use crate::util::fmt::truncate;
+use crate::util::print_structured;
+
 use super::config::PagerDutyConfig;
-use super::types::{Incident, IncidentStatus, Oncall, OutputFormat};
+use super::types::{Incident, IncidentStatus, Oncall, OutputFormat, Service, TimeWindow};
 
 #[cfg(test)]
 mod tests;
@@ -47,24 +51,17 @@ fn time_ago(timestamp: &str) -> String {
     }
 }
 
-/// Truncate string to max length
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
-/// Output oncalls list
-pub fn output_oncalls(oncalls: &[Oncall], format: OutputFormat) -> Result<()> {
+/// Output oncalls list for the queried window
+pub fn output_oncalls(oncalls: &[Oncall], window: &TimeWindow, format: OutputFormat) -> Result<()> {
     match format {
         OutputFormat::Table => {
             if oncalls.is_empty() {
-                println!("No one is currently on call.");
+                println!("No one is on call {}.", window.label());
                 return Ok(());
             }
 
+            println!("On call {}\n", window.label());
+
             let mut table = Table::new();
             table.load_preset(UTF8_FULL_CONDENSED);
             table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -89,11 +86,7 @@ pub fn output_oncalls(oncalls: &[Oncall], format: OutputFormat) -> Result<()> {
             println!("{table}");
             println!("\n{} on-call", oncalls.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(oncalls).context("Failed to serialize oncalls")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(oncalls, format)?,
     }
     Ok(())
 }
@@ -130,11 +123,37 @@ pub fn output_incidents(incidents: &[Incident], format: OutputFormat) -> Result<
             println!("{table}");
             println!("\n{} incidents", incidents.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(incidents).context("Failed to serialize incidents")?;
-            println!("{json}");
+        OutputFormat::Json | OutputFormat::Csv => print_structured(incidents, format)?,
+    }
+    Ok(())
+}
+
+/// Output services list
+pub fn output_services(services: &[Service], format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Table => {
+            if services.is_empty() {
+                println!("No services found.");
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL_CONDENSED);
+            table.set_content_arrangement(ContentArrangement::Dynamic);
+            table.set_header(vec!["ID", "Name", "Status"]);
+
+            for service in services {
+                table.add_row(vec![
+                    Cell::new(&service.id).fg(Color::Cyan),
+                    Cell::new(truncate(&service.name, 40)),
+                    Cell::new(&service.status),
+                ]);
+            }
+
+            println!("{table}");
+            println!("\n{} services", services.len());
         }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(services, format)?,
     }
     Ok(())
 }
@@ -158,6 +177,9 @@ pub fn output_incident_detail(incident: &Incident, format: OutputFormat) -> Resu
             println!("Urgency:  {:?}", incident.urgency);
             println!("Service:  {}", incident.service.name);
             println!("Created:  {}", time_ago(&incident.created_at));
+            if let Some(until) = incident.snoozed_until() {
+                println!("Snoozed:  until {}", until);
+            }
 
             if !incident.assignments.is_empty() {
                 println!("\nAssigned to:");
@@ -174,11 +196,7 @@ pub fn output_incident_detail(incident: &Incident, format: OutputFormat) -> Resu
                 println!("\nLink: {}", incident.html_url);
             }
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(incident).context("Failed to serialize incident")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(incident, format)?,
     }
     Ok(())
 }
@@ -220,10 +238,7 @@ pub fn output_user(user: &super::types::User, format: OutputFormat) -> Result<()
                 println!("{}", user.html_url);
             }
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(user).context("Failed to serialize user")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(user, format)?,
     }
     Ok(())
 }
@@ -30,27 +30,6 @@ fn status_icon_resolved() {
     assert_eq!(status_icon(IncidentStatus::Resolved), "✓");
 }
 
-#[test]
-fn truncate_short_string() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn truncate_exact_length() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn truncate_long_string() {
-    assert_eq!(truncate("hello world", 8), "hello...");
-}
-
-#[test]
-fn truncate_very_short_max() {
-    // Edge case: max_len less than 3
-    assert_eq!(truncate("hello", 2), "...");
-}
-
 #[test]
 fn time_ago_invalid_timestamp() {
     assert_eq!(time_ago("invalid"), "invalid");
@@ -108,7 +87,14 @@ fn output_config_status_configured() {
 
 #[test]
 fn output_oncalls_empty() {
-    let result = output_oncalls(&[], OutputFormat::Table);
+    let result = output_oncalls(&[], &TimeWindow::default(), OutputFormat::Table);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn output_oncalls_empty_window() {
+    let window = TimeWindow::at("2024-06-10T18:00:00Z");
+    let result = output_oncalls(&[], &window, OutputFormat::Table);
     assert!(result.is_ok());
 }
 
@@ -120,7 +106,7 @@ fn output_incidents_empty() {
 
 #[test]
 fn output_oncalls_json_empty() {
-    let result = output_oncalls(&[], OutputFormat::Json);
+    let result = output_oncalls(&[], &TimeWindow::default(), OutputFormat::Json);
     assert!(result.is_ok());
 }
 
@@ -157,7 +143,7 @@ fn output_oncalls_with_data() {
         end: None,
     }];
 
-    let result = output_oncalls(&oncalls, OutputFormat::Table);
+    let result = output_oncalls(&oncalls, &TimeWindow::default(), OutputFormat::Table);
     assert!(result.is_ok());
 }
 
@@ -180,6 +166,7 @@ fn output_incidents_with_data() {
             html_url: String::new(),
         },
         assignments: vec![],
+        pending_actions: vec![],
     }];
 
     let result = output_incidents(&incidents, OutputFormat::Table);
@@ -188,7 +175,7 @@ fn output_incidents_with_data() {
 
 #[test]
 fn output_incident_detail_table() {
-    use super::super::types::{Assignment, Service, Urgency, User};
+    use super::super::types::{Assignment, PendingAction, Service, Urgency, User};
 
     let incident = Incident {
         id: "INC1".to_string(),
@@ -213,6 +200,10 @@ fn output_incident_detail_table() {
                 html_url: String::new(),
             },
         }],
+        pending_actions: vec![PendingAction {
+            action_type: "unacknowledge".to_string(),
+            at: "2024-01-01T12:00:00Z".to_string(),
+        }],
     };
 
     let result = output_incident_detail(&incident, OutputFormat::Table);
@@ -238,6 +229,7 @@ fn output_incident_detail_json() {
             html_url: String::new(),
         },
         assignments: vec![],
+        pending_actions: vec![],
     };
 
     let result = output_incident_detail(&incident, OutputFormat::Json);
@@ -315,7 +307,7 @@ fn output_oncalls_without_schedule() {
         end: None,
     }];
 
-    let result = output_oncalls(&oncalls, OutputFormat::Table);
+    let result = output_oncalls(&oncalls, &TimeWindow::default(), OutputFormat::Table);
     assert!(result.is_ok());
 }
 
@@ -346,7 +338,7 @@ fn output_oncalls_json_with_data() {
         end: None,
     }];
 
-    let result = output_oncalls(&oncalls, OutputFormat::Json);
+    let result = output_oncalls(&oncalls, &TimeWindow::default(), OutputFormat::Json);
     assert!(result.is_ok());
 }
 
@@ -369,6 +361,7 @@ fn output_incidents_json_with_data() {
             html_url: String::new(),
         },
         assignments: vec![],
+        pending_actions: vec![],
     }];
 
     let result = output_incidents(&incidents, OutputFormat::Json);
@@ -394,18 +387,13 @@ fn output_incident_detail_no_url() {
             html_url: String::new(),
         },
         assignments: vec![],
+        pending_actions: vec![],
     };
 
     let result = output_incident_detail(&incident, OutputFormat::Table);
     assert!(result.is_ok());
 }
 
-#[test]
-fn truncate_zero_max() {
-    // Edge case: max_len = 0
-    assert_eq!(truncate("hello", 0), "...");
-}
-
 #[test]
 fn time_ago_boundary_cases() {
     // Exactly 1 day ago
@@ -423,3 +411,20 @@ fn time_ago_boundary_cases() {
     let timestamp = dt.to_rfc3339();
     assert_eq!(time_ago(&timestamp), "1m ago");
 }
+
+#[test]
+fn output_services_empty() {
+    assert!(output_services(&[], OutputFormat::Table).is_ok());
+}
+
+#[test]
+fn output_services_with_data() {
+    let services = vec![Service {
+        id: "SVC1".to_string(),
+        name: "Payments".to_string(),
+        status: "active".to_string(),
+        html_url: String::new(),
+    }];
+    assert!(output_services(&services, OutputFormat::Table).is_ok());
+    assert!(output_services(&services, OutputFormat::Json).is_ok());
+}
@@ -13,6 +13,9 @@
 //! - [`list_incidents`] - List incidents with filters
 //! - [`get_incident`] - Get incident details
 //! - [`get_current_user`] - Get current user info
+//! - [`acknowledge_incident`] / [`resolve_incident`] - Update incident status
+//! - [`snooze_incident`] - Snooze an acknowledged incident
+//! - [`reassign_incident`] / [`escalate_incident`] - Hand off an incident
 
 mod cli;
 mod client;
@@ -21,6 +24,7 @@ mod display;
 mod service;
 pub mod types;
 
+use anstream::println;
 use anyhow::Result;
 
 pub use cli::PagerDutyCommand;
@@ -40,15 +44,52 @@ pub async fn run(cmd: PagerDutyCommand) -> Result<()> {
         PagerDutyCommand::Oncall {
             policy,
             schedule,
+            at,
+            until,
+            next,
             json,
-        } => cmd_oncall(policy.as_deref(), schedule.as_deref(), json).await,
+        } => {
+            let opts = OncallOptions {
+                policy_id: policy,
+                schedule_id: schedule,
+                window: service::oncall_window(
+                    at.as_deref(),
+                    until.as_deref(),
+                    chrono::Utc::now(),
+                )?,
+            };
+            cmd_oncall(&opts, next, json).await
+        }
         PagerDutyCommand::Alerts { limit, json } => cmd_alerts(limit, json).await,
         PagerDutyCommand::Incidents {
             status,
             limit,
+            services,
+            since,
+            until,
             json,
-        } => cmd_incidents(status, limit, json).await,
+        } => {
+            let now = chrono::Utc::now();
+            let opts = IncidentOptions {
+                statuses: status_filter_to_statuses(status),
+                limit,
+                service_ids: services,
+                since: since.map(|s| service::resolve_time(&s, now)).transpose()?,
+                until: until.map(|u| service::resolve_time(&u, now)).transpose()?,
+            };
+            cmd_incidents(&opts, json).await
+        }
+        PagerDutyCommand::Services { json } => cmd_services(json).await,
         PagerDutyCommand::Show { id, json } => cmd_show(&id, json).await,
+        PagerDutyCommand::Ack { ids, json } => {
+            cmd_update(&ids, IncidentStatus::Acknowledged, json).await
+        }
+        PagerDutyCommand::Resolve { ids, json } => {
+            cmd_update(&ids, IncidentStatus::Resolved, json).await
+        }
+        PagerDutyCommand::Assign { id, users, json } => cmd_assign(&id, &users, json).await,
+        PagerDutyCommand::Escalate { id, level, json } => cmd_escalate(&id, level, json).await,
+        PagerDutyCommand::Snooze { id, duration, json } => cmd_snooze(&id, &duration, json).await,
         PagerDutyCommand::Whoami { json } => cmd_whoami(json).await,
     }
 }
@@ -114,6 +155,60 @@ pub async fn get_current_user() -> Result<User> {
     service::get_current_user(&client).await
 }
 
+/// Acknowledge incidents as the current user (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn acknowledge_incident(ids: &[String]) -> Result<Vec<Incident>> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = PagerDutyClient::new()?;
+    service::acknowledge_incident(&client, ids).await
+}
+
+/// Resolve incidents as the current user (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn resolve_incident(ids: &[String]) -> Result<Vec<Incident>> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = PagerDutyClient::new()?;
+    service::resolve_incident(&client, ids).await
+}
+
+/// Reassign an incident to users given by email, name or ID (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn reassign_incident(id: &str, users: &[String]) -> Result<Incident> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = PagerDutyClient::new()?;
+    let mut ids = Vec::new();
+    for user in users {
+        ids.push(service::resolve_user(&client, user).await?.id);
+    }
+    service::reassign_incident(&client, id, &ids).await
+}
+
+/// Escalate an incident to a level of its policy (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn escalate_incident(id: &str, level: u32) -> Result<Incident> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = PagerDutyClient::new()?;
+    service::escalate_incident(&client, id, level).await
+}
+
+/// Snooze an incident for a `30m`/`2h`-style duration (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn snooze_incident(id: &str, duration: &str) -> Result<Incident> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    let client = PagerDutyClient::new()?;
+    service::snooze_incident(&client, id, duration).await
+}
+
 // ============================================================================
 // CLI command handlers - create client, call service, format and print
 // ============================================================================
@@ -136,24 +231,20 @@ fn cmd_auth(token: &str) -> Result<()> {
 
 /// Show who's on call
 #[cfg(not(tarpaulin_include))]
-async fn cmd_oncall(policy: Option<&str>, schedule: Option<&str>, json: bool) -> Result<()> {
+async fn cmd_oncall(opts: &OncallOptions, next: bool, json: bool) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
 
     let client = PagerDutyClient::new()?;
-    let opts = OncallOptions {
-        policy_id: policy.map(|p| p.to_string()),
-        schedule_id: schedule.map(|s| s.to_string()),
-    };
-
-    let oncalls = service::list_oncalls(&client, &opts).await?;
-
-    let format = if json {
-        OutputFormat::Json
+    let (oncalls, window) = if next {
+        service::next_oncalls(&client, opts).await?
     } else {
-        OutputFormat::Table
+        let oncalls = service::list_oncalls(&client, opts).await?;
+        (oncalls, opts.window.clone())
     };
-    display::output_oncalls(&oncalls, format)?;
+
+    let format = OutputFormat::resolve(json);
+    display::output_oncalls(&oncalls, &window, format)?;
     Ok(())
 }
 
@@ -166,37 +257,39 @@ async fn cmd_alerts(limit: usize, json: bool) -> Result<()> {
     let client = PagerDutyClient::new()?;
     let incidents = service::list_alerts(&client, limit).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
     display::output_incidents(&incidents, format)?;
     Ok(())
 }
 
-/// List incidents with optional status filter
+/// List incidents with status, service and time window filters
 #[cfg(not(tarpaulin_include))]
-async fn cmd_incidents(status: Option<StatusFilter>, limit: usize, json: bool) -> Result<()> {
+async fn cmd_incidents(opts: &IncidentOptions, json: bool) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
 
     let client = PagerDutyClient::new()?;
-    let opts = IncidentOptions {
-        statuses: status_filter_to_statuses(status),
-        limit,
-    };
-    let incidents = service::list_incidents(&client, &opts).await?;
+    let incidents = service::list_incidents(&client, opts).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
     display::output_incidents(&incidents, format)?;
     Ok(())
 }
 
+/// List services
+#[cfg(not(tarpaulin_include))]
+async fn cmd_services(json: bool) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = PagerDutyClient::new()?;
+    let services = service::list_services(&client).await?;
+
+    let format = OutputFormat::resolve(json);
+    display::output_services(&services, format)?;
+    Ok(())
+}
+
 /// Show incident details
 #[cfg(not(tarpaulin_include))]
 async fn cmd_show(id: &str, json: bool) -> Result<()> {
@@ -206,11 +299,59 @@ async fn cmd_show(id: &str, json: bool) -> Result<()> {
     let client = PagerDutyClient::new()?;
     let incident = service::get_incident(&client, id).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
+    let format = OutputFormat::resolve(json);
+    display::output_incident_detail(&incident, format)?;
+    Ok(())
+}
+
+/// Acknowledge or resolve incidents
+#[cfg(not(tarpaulin_include))]
+async fn cmd_update(ids: &[String], status: IncidentStatus, json: bool) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = PagerDutyClient::new()?;
+    let incidents = match status {
+        IncidentStatus::Resolved => service::resolve_incident(&client, ids).await?,
+        _ => service::acknowledge_incident(&client, ids).await?,
     };
+
+    let format = OutputFormat::resolve(json);
+    for incident in &incidents {
+        display::output_incident_detail(incident, format)?;
+    }
+    Ok(())
+}
+
+/// Reassign an incident
+#[cfg(not(tarpaulin_include))]
+async fn cmd_assign(id: &str, users: &[String], json: bool) -> Result<()> {
+    let incident = reassign_incident(id, users).await?;
+    let format = OutputFormat::resolve(json);
+    display::output_incident_detail(&incident, format)?;
+    Ok(())
+}
+
+/// Escalate an incident
+#[cfg(not(tarpaulin_include))]
+async fn cmd_escalate(id: &str, level: u32, json: bool) -> Result<()> {
+    let incident = escalate_incident(id, level).await?;
+    let format = OutputFormat::resolve(json);
+    display::output_incident_detail(&incident, format)?;
+    Ok(())
+}
+
+/// Snooze an incident
+#[cfg(not(tarpaulin_include))]
+async fn cmd_snooze(id: &str, duration: &str, json: bool) -> Result<()> {
+    service::parse_snooze_duration(duration)?;
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = PagerDutyClient::new()?;
+    let incident = service::snooze_incident(&client, id, duration).await?;
+
+    let format = OutputFormat::resolve(json);
     display::output_incident_detail(&incident, format)?;
     Ok(())
 }
@@ -224,11 +365,7 @@ async fn cmd_whoami(json: bool) -> Result<()> {
     let client = PagerDutyClient::new()?;
     let user = service::get_current_user(&client).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
     display::output_user(&user, format)?;
     Ok(())
 }
@@ -0,0 +1,326 @@
+//! PagerDuty service layer - business logic that returns data
+//!
+//! Functions in this module accept trait objects and return typed data.
+//! They never print - that's the CLI layer's job.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+
+use super::client::PagerDutyApi;
+use super::config::{self, PagerDutyConfig};
+pub use super::types::IncidentOptions;
+use super::types::{Incident, IncidentChange, IncidentStatus, Oncall, Service, TimeWindow, User};
+use crate::util::bail_kind;
+
+/// Options for listing on-calls
+#[derive(Debug, Clone, Default)]
+pub struct OncallOptions {
+    /// Filter by escalation policy ID
+    pub policy_id: Option<String>,
+    /// Filter by schedule ID
+    pub schedule_id: Option<String>,
+    /// Time window to query (default: now)
+    pub window: TimeWindow,
+}
+
+/// Get current configuration
+pub fn get_config() -> Result<PagerDutyConfig> {
+    config::load_config()
+}
+
+/// Save API token
+pub fn save_auth(token: &str) -> Result<()> {
+    config::save_config(token)
+}
+
+/// Check if API is configured, return error if not
+pub fn ensure_configured(config: &PagerDutyConfig) -> Result<()> {
+    if !config.is_configured() {
+        bail_kind!(
+            Config,
+            "PagerDuty not configured. Run: hu pagerduty auth <token>\n\
+             Or set PAGERDUTY_API_TOKEN environment variable."
+        );
+    }
+    Ok(())
+}
+
+/// List on-call users
+pub async fn list_oncalls(api: &impl PagerDutyApi, opts: &OncallOptions) -> Result<Vec<Oncall>> {
+    let policy_ids = opts.policy_id.as_ref().map(|p| vec![p.clone()]);
+    let schedule_ids = opts.schedule_id.as_ref().map(|s| vec![s.clone()]);
+
+    api.list_oncalls(schedule_ids.as_deref(), policy_ids.as_deref(), &opts.window)
+        .await
+}
+
+/// List who takes over at the next handoff
+///
+/// The handoff is the earliest end among the current on-call shifts. Returns
+/// the on-calls at that instant along with the window that was queried.
+pub async fn next_oncalls(
+    api: &impl PagerDutyApi,
+    opts: &OncallOptions,
+) -> Result<(Vec<Oncall>, TimeWindow)> {
+    let current_opts = OncallOptions {
+        window: TimeWindow::default(),
+        ..opts.clone()
+    };
+    let current = list_oncalls(api, &current_opts).await?;
+    let Some(handoff) = current
+        .iter()
+        .filter_map(|o| o.end.as_deref())
+        .filter_map(|end| DateTime::parse_from_rfc3339(end).ok())
+        .min()
+    else {
+        bail!("No current on-call shift has an end time; nothing to show for --next");
+    };
+
+    let handoff = handoff
+        .with_timezone(&Utc)
+        .to_rfc3339_opts(SecondsFormat::Secs, true);
+    let next_opts = OncallOptions {
+        window: TimeWindow::at(&handoff),
+        ..opts.clone()
+    };
+    let upcoming = list_oncalls(api, &next_opts)
+        .await?
+        .into_iter()
+        .filter(|o| !ends_by(o, &handoff))
+        .collect();
+    Ok((upcoming, next_opts.window))
+}
+
+/// Whether a shift ends at or before `time` (the window overlap includes it)
+fn ends_by(oncall: &Oncall, time: &str) -> bool {
+    let Ok(time) = DateTime::parse_from_rfc3339(time) else {
+        return false;
+    };
+    oncall
+        .end
+        .as_deref()
+        .and_then(|end| DateTime::parse_from_rfc3339(end).ok())
+        .is_some_and(|end| end <= time)
+}
+
+/// List incidents (alerts = triggered + acknowledged only)
+pub async fn list_alerts(api: &impl PagerDutyApi, limit: usize) -> Result<Vec<Incident>> {
+    let opts = IncidentOptions {
+        limit,
+        ..IncidentOptions::default()
+    };
+    api.list_incidents(&opts).await
+}
+
+/// List incidents with options
+pub async fn list_incidents(
+    api: &impl PagerDutyApi,
+    opts: &IncidentOptions,
+) -> Result<Vec<Incident>> {
+    api.list_incidents(opts).await
+}
+
+/// List services
+pub async fn list_services(api: &impl PagerDutyApi) -> Result<Vec<Service>> {
+    api.list_services().await
+}
+
+/// Resolve a time argument to an ISO 8601 timestamp
+///
+/// Accepts ISO 8601 timestamps and dates (passed through), `now`, and
+/// offsets like `30m`, `24h`, `7d`, `2w` (in the past) or `+8h` (in the future).
+pub fn resolve_time(input: &str, now: DateTime<Utc>) -> Result<String> {
+    let input = input.trim();
+    if input == "now" {
+        return Ok(now.to_rfc3339_opts(SecondsFormat::Secs, true));
+    }
+    if DateTime::parse_from_rfc3339(input).is_ok()
+        || NaiveDate::parse_from_str(input, "%Y-%m-%d").is_ok()
+    {
+        return Ok(input.to_string());
+    }
+
+    let (future, offset) = match input.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+    let Some(duration) = parse_offset(offset) else {
+        bail!(
+            "Invalid time '{}': use ISO 8601 (2024-01-01T09:00:00Z), a date, or an offset like 24h, 7d, +8h",
+            input
+        );
+    };
+    let at = if future {
+        now + duration
+    } else {
+        now - duration
+    };
+    Ok(at.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+/// Resolve `--at`/`--until` into an on-call query window
+///
+/// `at` alone is a single instant; `until` alone runs from now.
+pub fn oncall_window(
+    at: Option<&str>,
+    until: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<TimeWindow> {
+    let since = at.map(|a| resolve_time(a, now)).transpose()?;
+    let until = until.map(|u| resolve_time(u, now)).transpose()?;
+    Ok(match (since, until) {
+        (None, None) => TimeWindow::default(),
+        (Some(at), None) => TimeWindow::at(&at),
+        (since, Some(until)) => TimeWindow {
+            since: Some(since.map_or_else(|| resolve_time("now", now), Ok)?),
+            until: Some(until),
+        },
+    })
+}
+
+/// Parse `<n><unit>` where unit is m, h, d or w
+fn parse_offset(offset: &str) -> Option<Duration> {
+    let unit = offset.chars().last()?;
+    let amount: i64 = offset[..offset.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'm' => Some(Duration::minutes(amount)),
+        'h' => Some(Duration::hours(amount)),
+        'd' => Some(Duration::days(amount)),
+        'w' => Some(Duration::weeks(amount)),
+        _ => None,
+    }
+}
+
+/// Get a single incident by ID
+pub async fn get_incident(api: &impl PagerDutyApi, id: &str) -> Result<Incident> {
+    api.get_incident(id).await
+}
+
+/// Acknowledge one or more incidents as the current user
+pub async fn acknowledge_incident(
+    api: &impl PagerDutyApi,
+    ids: &[String],
+) -> Result<Vec<Incident>> {
+    set_incident_status(api, ids, IncidentStatus::Acknowledged).await
+}
+
+/// Resolve one or more incidents as the current user
+pub async fn resolve_incident(api: &impl PagerDutyApi, ids: &[String]) -> Result<Vec<Incident>> {
+    set_incident_status(api, ids, IncidentStatus::Resolved).await
+}
+
+async fn set_incident_status(
+    api: &impl PagerDutyApi,
+    ids: &[String],
+    status: IncidentStatus,
+) -> Result<Vec<Incident>> {
+    if ids.is_empty() {
+        bail!("At least one incident ID is required");
+    }
+    let from = acting_email(api).await?;
+    api.update_incidents(ids, status, &from).await
+}
+
+/// Find the one user a name, email or ID refers to
+///
+/// An exact email, ID or name wins; otherwise the search must match
+/// exactly one user.
+pub async fn resolve_user(api: &impl PagerDutyApi, query: &str) -> Result<User> {
+    let query = query.trim();
+    if query.is_empty() {
+        bail!("User is empty; give a name, email or user ID");
+    }
+    let mut users = api.find_users(query).await?;
+    if let Some(i) = users.iter().position(|u| {
+        u.email.eq_ignore_ascii_case(query)
+            || u.id == query
+            || u.display_name().eq_ignore_ascii_case(query)
+    }) {
+        return Ok(users.swap_remove(i));
+    }
+    match users.len() {
+        0 => bail!("No PagerDuty user matches '{}'", query),
+        1 => Ok(users.remove(0)),
+        _ => {
+            let names: Vec<String> = users
+                .iter()
+                .map(|u| format!("{} <{}>", u.display_name(), u.email))
+                .collect();
+            bail!(
+                "'{}' matches several users: {}. Use their email",
+                query,
+                names.join(", ")
+            )
+        }
+    }
+}
+
+/// Hand an incident to other users, as the current user
+pub async fn reassign_incident(
+    api: &impl PagerDutyApi,
+    id: &str,
+    assignee_ids: &[String],
+) -> Result<Incident> {
+    if assignee_ids.is_empty() {
+        bail!("At least one assignee is required");
+    }
+    let change = IncidentChange::Reassign(assignee_ids.to_vec());
+    change_incident(api, id, &change).await
+}
+
+/// Move an incident to another escalation level, as the current user
+pub async fn escalate_incident(api: &impl PagerDutyApi, id: &str, level: u32) -> Result<Incident> {
+    if level == 0 {
+        bail!("Escalation levels start at 1");
+    }
+    change_incident(api, id, &IncidentChange::Escalate(level)).await
+}
+
+async fn change_incident(
+    api: &impl PagerDutyApi,
+    id: &str,
+    change: &IncidentChange,
+) -> Result<Incident> {
+    let from = acting_email(api).await?;
+    api.change_incident(id, change, &from).await
+}
+
+/// Parse a snooze length like `30m`, `2h` or `1d` into seconds
+pub fn parse_snooze_duration(input: &str) -> Result<u64> {
+    let secs = parse_offset(input.trim()).map(|d| d.num_seconds());
+    match secs {
+        Some(secs) if secs > 0 => Ok(secs as u64),
+        _ => bail!(
+            "Invalid snooze duration '{}': use a positive length like 30m, 2h or 1d",
+            input
+        ),
+    }
+}
+
+/// Snooze an acknowledged incident as the current user
+pub async fn snooze_incident(
+    api: &impl PagerDutyApi,
+    id: &str,
+    duration: &str,
+) -> Result<Incident> {
+    let secs = parse_snooze_duration(duration)?;
+    let from = acting_email(api).await?;
+    api.snooze_incident(id, secs, &from).await
+}
+
+/// Email for the `From` header write endpoints require
+async fn acting_email(api: &impl PagerDutyApi) -> Result<String> {
+    let user = api.get_current_user().await?;
+    if user.email.is_empty() {
+        bail!("Current PagerDuty user has no email; it is required to update incidents");
+    }
+    Ok(user.email)
+}
+
+/// Get current user info
+pub async fn get_current_user(api: &impl PagerDutyApi) -> Result<User> {
+    api.get_current_user().await
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,571 @@
+use super::*;
+use crate::pagerduty::types::{
+    Assignment, EscalationPolicy, PendingAction, Schedule, Service, Urgency,
+};
+
+/// Mock PagerDuty API for testing
+struct MockApi {
+    oncalls: Vec<Oncall>,
+    upcoming: Vec<Oncall>,
+    incidents: Vec<Incident>,
+    user: User,
+    users: Vec<User>,
+}
+
+impl MockApi {
+    fn new() -> Self {
+        Self {
+            oncalls: vec![],
+            upcoming: vec![],
+            incidents: vec![],
+            user: User {
+                id: "USER123".to_string(),
+                name: Some("Test User".to_string()),
+                summary: None,
+                email: "test@example.com".to_string(),
+                html_url: "https://pagerduty.com/users/USER123".to_string(),
+            },
+            users: vec![
+                make_user("U1", "Alice Smith"),
+                make_user("U2", "Alicia Keys"),
+                make_user("U3", "Bob Jones"),
+            ],
+        }
+    }
+
+    fn with_oncalls(mut self, oncalls: Vec<Oncall>) -> Self {
+        self.oncalls = oncalls;
+        self
+    }
+
+    fn with_upcoming(mut self, upcoming: Vec<Oncall>) -> Self {
+        self.upcoming = upcoming;
+        self
+    }
+
+    fn with_incidents(mut self, incidents: Vec<Incident>) -> Self {
+        self.incidents = incidents;
+        self
+    }
+}
+
+impl PagerDutyApi for MockApi {
+    async fn get_current_user(&self) -> Result<User> {
+        Ok(self.user.clone())
+    }
+
+    async fn list_oncalls(
+        &self,
+        _schedule_ids: Option<&[String]>,
+        _escalation_policy_ids: Option<&[String]>,
+        window: &TimeWindow,
+    ) -> Result<Vec<Oncall>> {
+        if window.since.is_some() {
+            Ok(self.upcoming.clone())
+        } else {
+            Ok(self.oncalls.clone())
+        }
+    }
+
+    async fn list_incidents(&self, opts: &IncidentOptions) -> Result<Vec<Incident>> {
+        let filtered: Vec<Incident> = self
+            .incidents
+            .iter()
+            .filter(|i| opts.statuses.contains(&i.status))
+            .filter(|i| opts.service_ids.is_empty() || opts.service_ids.contains(&i.service.id))
+            .take(opts.limit)
+            .cloned()
+            .collect();
+        Ok(filtered)
+    }
+
+    async fn get_incident(&self, id: &str) -> Result<Incident> {
+        self.incidents
+            .iter()
+            .find(|i| i.id == id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Incident not found: {}", id))
+    }
+
+    async fn list_services(&self) -> Result<Vec<Service>> {
+        Ok(vec![])
+    }
+
+    async fn update_incidents(
+        &self,
+        ids: &[String],
+        status: IncidentStatus,
+        from_email: &str,
+    ) -> Result<Vec<Incident>> {
+        assert_eq!(from_email, self.user.email);
+        Ok(self
+            .incidents
+            .iter()
+            .filter(|i| ids.contains(&i.id))
+            .cloned()
+            .map(|mut i| {
+                i.status = status;
+                i
+            })
+            .collect())
+    }
+
+    async fn find_users(&self, query: &str) -> Result<Vec<User>> {
+        let query = query.to_lowercase();
+        Ok(self
+            .users
+            .iter()
+            .filter(|u| {
+                u.email.contains(&query) || u.display_name().to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn change_incident(
+        &self,
+        id: &str,
+        change: &IncidentChange,
+        from_email: &str,
+    ) -> Result<Incident> {
+        assert_eq!(from_email, self.user.email);
+        let mut incident = self.get_incident(id).await?;
+        if let IncidentChange::Reassign(ids) = change {
+            incident.assignments = ids
+                .iter()
+                .map(|id| Assignment {
+                    assignee: make_user(id, id),
+                })
+                .collect();
+        }
+        Ok(incident)
+    }
+
+    async fn snooze_incident(
+        &self,
+        id: &str,
+        duration_secs: u64,
+        from_email: &str,
+    ) -> Result<Incident> {
+        assert_eq!(from_email, self.user.email);
+        let mut incident = self.get_incident(id).await?;
+        incident.status = IncidentStatus::Acknowledged;
+        incident.pending_actions = vec![PendingAction {
+            action_type: "unacknowledge".to_string(),
+            at: format!("+{}s", duration_secs),
+        }];
+        Ok(incident)
+    }
+}
+
+fn make_user(id: &str, name: &str) -> User {
+    let login = name.split(' ').next().unwrap_or(name).to_lowercase();
+    User {
+        id: id.to_string(),
+        name: Some(name.to_string()),
+        summary: None,
+        email: format!("{}@example.com", login),
+        html_url: String::new(),
+    }
+}
+
+fn make_oncall(user_name: &str, policy_name: &str) -> Oncall {
+    Oncall {
+        user: User {
+            id: format!("U{}", user_name),
+            name: Some(user_name.to_string()),
+            summary: None,
+            email: format!("{}@example.com", user_name.to_lowercase()),
+            html_url: String::new(),
+        },
+        schedule: Some(Schedule {
+            id: "SCHED1".to_string(),
+            name: "Primary".to_string(),
+            html_url: String::new(),
+        }),
+        escalation_policy: EscalationPolicy {
+            id: "POL1".to_string(),
+            name: policy_name.to_string(),
+            html_url: String::new(),
+        },
+        escalation_level: 1,
+        start: None,
+        end: None,
+    }
+}
+
+fn make_incident(id: &str, title: &str, status: IncidentStatus) -> Incident {
+    Incident {
+        id: id.to_string(),
+        incident_number: 123,
+        title: title.to_string(),
+        status,
+        urgency: Urgency::High,
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        html_url: String::new(),
+        service: Service {
+            id: "SVC1".to_string(),
+            name: "Test Service".to_string(),
+            status: "active".to_string(),
+            html_url: String::new(),
+        },
+        assignments: vec![],
+        pending_actions: vec![],
+    }
+}
+
+fn fixed_now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-06-10T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn resolve_time_relative_past() {
+    assert_eq!(
+        resolve_time("24h", fixed_now()).unwrap(),
+        "2024-06-09T12:00:00Z"
+    );
+    assert_eq!(
+        resolve_time("1w", fixed_now()).unwrap(),
+        "2024-06-03T12:00:00Z"
+    );
+    assert_eq!(
+        resolve_time("30m", fixed_now()).unwrap(),
+        "2024-06-10T11:30:00Z"
+    );
+}
+
+#[test]
+fn resolve_time_relative_future_and_now() {
+    assert_eq!(
+        resolve_time("+8h", fixed_now()).unwrap(),
+        "2024-06-10T20:00:00Z"
+    );
+    assert_eq!(
+        resolve_time("now", fixed_now()).unwrap(),
+        "2024-06-10T12:00:00Z"
+    );
+}
+
+#[test]
+fn resolve_time_passes_through_absolute() {
+    assert_eq!(
+        resolve_time("2024-01-01", fixed_now()).unwrap(),
+        "2024-01-01"
+    );
+    assert_eq!(
+        resolve_time("2024-01-01T09:00:00+02:00", fixed_now()).unwrap(),
+        "2024-01-01T09:00:00+02:00"
+    );
+}
+
+#[test]
+fn resolve_time_rejects_garbage() {
+    assert!(resolve_time("yesterday", fixed_now()).is_err());
+    assert!(resolve_time("5y", fixed_now()).is_err());
+    assert!(resolve_time("h", fixed_now()).is_err());
+}
+
+fn shift(user_name: &str, start: &str, end: &str) -> Oncall {
+    Oncall {
+        start: Some(start.to_string()),
+        end: Some(end.to_string()),
+        ..make_oncall(user_name, "Engineering")
+    }
+}
+
+#[tokio::test]
+async fn next_oncalls_queries_earliest_handoff() {
+    let api = MockApi::new()
+        .with_oncalls(vec![
+            shift("Alice", "2024-06-10T09:00:00Z", "2024-06-11T09:00:00Z"),
+            shift(
+                "Bob",
+                "2024-06-10T09:00:00+00:00",
+                "2024-06-10T18:00:00+00:00",
+            ),
+        ])
+        .with_upcoming(vec![
+            shift("Bob", "2024-06-10T09:00:00Z", "2024-06-10T18:00:00Z"),
+            shift("Alice", "2024-06-10T09:00:00Z", "2024-06-11T09:00:00Z"),
+            shift("Carol", "2024-06-10T18:00:00Z", "2024-06-11T18:00:00Z"),
+        ]);
+
+    let (oncalls, window) = next_oncalls(&api, &OncallOptions::default()).await.unwrap();
+    assert_eq!(window, TimeWindow::at("2024-06-10T18:00:00Z"));
+    let names: Vec<&str> = oncalls.iter().map(|o| o.user.display_name()).collect();
+    // Bob's shift ends at the handoff; Alice carries on and Carol takes over
+    assert_eq!(names, vec!["Alice", "Carol"]);
+}
+
+#[tokio::test]
+async fn next_oncalls_requires_end_times() {
+    let api = MockApi::new().with_oncalls(vec![make_oncall("Alice", "Engineering")]);
+    let err = next_oncalls(&api, &OncallOptions::default())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("end time"));
+}
+
+#[test]
+fn oncall_window_variants() {
+    assert_eq!(
+        oncall_window(None, None, fixed_now()).unwrap(),
+        TimeWindow::default()
+    );
+    assert_eq!(
+        oncall_window(Some("+8h"), None, fixed_now()).unwrap(),
+        TimeWindow::at("2024-06-10T20:00:00Z")
+    );
+    let window = oncall_window(None, Some("+1d"), fixed_now()).unwrap();
+    assert_eq!(window.since.as_deref(), Some("2024-06-10T12:00:00Z"));
+    assert_eq!(window.until.as_deref(), Some("2024-06-11T12:00:00Z"));
+    assert!(oncall_window(Some("soon"), None, fixed_now()).is_err());
+}
+
+#[test]
+fn time_window_labels() {
+    assert_eq!(TimeWindow::default().label(), "now");
+    assert_eq!(
+        TimeWindow::at("2024-06-10T18:00:00Z").label(),
+        "at 2024-06-10T18:00:00Z"
+    );
+    let range = TimeWindow {
+        since: Some("2024-06-10".to_string()),
+        until: Some("2024-06-17".to_string()),
+    };
+    assert_eq!(range.label(), "2024-06-10 \u{2192} 2024-06-17");
+}
+
+#[tokio::test]
+async fn list_incidents_filters_by_service() {
+    let mut other = make_incident("INC2", "Other", IncidentStatus::Triggered);
+    other.service.id = "SVC2".to_string();
+    let api = MockApi::new().with_incidents(vec![
+        make_incident("INC1", "Mine", IncidentStatus::Triggered),
+        other,
+    ]);
+    let opts = IncidentOptions {
+        service_ids: vec!["SVC2".to_string()],
+        ..Default::default()
+    };
+    let result = list_incidents(&api, &opts).await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, "INC2");
+}
+
+#[tokio::test]
+async fn acknowledge_incident_updates_each_id() {
+    let api = MockApi::new().with_incidents(vec![
+        make_incident("P1", "One", IncidentStatus::Triggered),
+        make_incident("P2", "Two", IncidentStatus::Triggered),
+        make_incident("P3", "Three", IncidentStatus::Triggered),
+    ]);
+    let ids = vec!["P1".to_string(), "P3".to_string()];
+    let updated = acknowledge_incident(&api, &ids).await.unwrap();
+    assert_eq!(updated.len(), 2);
+    assert!(updated
+        .iter()
+        .all(|i| i.status == IncidentStatus::Acknowledged));
+}
+
+#[tokio::test]
+async fn resolve_incident_sets_resolved() {
+    let api = MockApi::new().with_incidents(vec![make_incident(
+        "P1",
+        "One",
+        IncidentStatus::Acknowledged,
+    )]);
+    let updated = resolve_incident(&api, &["P1".to_string()]).await.unwrap();
+    assert_eq!(updated[0].status, IncidentStatus::Resolved);
+}
+
+#[tokio::test]
+async fn resolve_user_by_email_name_or_unique_match() {
+    let api = MockApi::new();
+    assert_eq!(
+        resolve_user(&api, "BOB@example.com").await.unwrap().id,
+        "U3"
+    );
+    assert_eq!(resolve_user(&api, "alice smith").await.unwrap().id, "U1");
+    assert_eq!(resolve_user(&api, "jones").await.unwrap().id, "U3");
+}
+
+#[tokio::test]
+async fn resolve_user_rejects_ambiguous_and_unknown() {
+    let api = MockApi::new();
+    let err = resolve_user(&api, "ali").await.unwrap_err().to_string();
+    assert!(err.contains("several users"));
+    assert!(err.contains("alicia@example.com"));
+    let err = resolve_user(&api, "carol").await.unwrap_err().to_string();
+    assert!(err.contains("No PagerDuty user"));
+    assert!(resolve_user(&api, " ").await.is_err());
+}
+
+#[tokio::test]
+async fn reassign_incident_sets_assignees() {
+    let api =
+        MockApi::new().with_incidents(vec![make_incident("P1", "One", IncidentStatus::Triggered)]);
+    let incident = reassign_incident(&api, "P1", &["U3".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(incident.assignments[0].assignee.id, "U3");
+    assert!(reassign_incident(&api, "P1", &[]).await.is_err());
+}
+
+#[tokio::test]
+async fn escalate_incident_levels_start_at_one() {
+    let api =
+        MockApi::new().with_incidents(vec![make_incident("P1", "One", IncidentStatus::Triggered)]);
+    assert!(escalate_incident(&api, "P1", 2).await.is_ok());
+    let err = escalate_incident(&api, "P1", 0).await.unwrap_err();
+    assert!(err.to_string().contains("start at 1"));
+}
+
+#[test]
+fn parse_snooze_duration_units() {
+    assert_eq!(parse_snooze_duration("30m").unwrap(), 1800);
+    assert_eq!(parse_snooze_duration("2h").unwrap(), 7200);
+    assert_eq!(parse_snooze_duration(" 1d ").unwrap(), 86400);
+}
+
+#[test]
+fn parse_snooze_duration_rejects_bad_input() {
+    for input in ["0m", "-5m", "2x", "h", "", "soon"] {
+        assert!(parse_snooze_duration(input).is_err(), "{}", input);
+    }
+}
+
+#[tokio::test]
+async fn snooze_incident_sends_seconds() {
+    let api =
+        MockApi::new().with_incidents(vec![make_incident("P1", "One", IncidentStatus::Triggered)]);
+    let incident = snooze_incident(&api, "P1", "2h").await.unwrap();
+    assert_eq!(incident.status, IncidentStatus::Acknowledged);
+    assert_eq!(incident.snoozed_until(), Some("+7200s"));
+}
+
+#[tokio::test]
+async fn snooze_incident_validates_duration_first() {
+    let api = MockApi::new();
+    let err = snooze_incident(&api, "P1", "0h").await.unwrap_err();
+    assert!(err.to_string().contains("positive"));
+}
+
+#[tokio::test]
+async fn update_requires_ids() {
+    let api = MockApi::new();
+    assert!(resolve_incident(&api, &[]).await.is_err());
+}
+
+#[tokio::test]
+async fn update_requires_user_email() {
+    let mut api = MockApi::new();
+    api.user.email = String::new();
+    let err = acknowledge_incident(&api, &["P1".to_string()])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("email"));
+}
+
+#[tokio::test]
+async fn list_oncalls_returns_data() {
+    let api = MockApi::new().with_oncalls(vec![
+        make_oncall("Alice", "Engineering"),
+        make_oncall("Bob", "Platform"),
+    ]);
+
+    let result = list_oncalls(&api, &OncallOptions::default()).await.unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].user.display_name(), "Alice");
+}
+
+#[tokio::test]
+async fn list_alerts_filters_statuses() {
+    let api = MockApi::new().with_incidents(vec![
+        make_incident("INC1", "Alert 1", IncidentStatus::Triggered),
+        make_incident("INC2", "Alert 2", IncidentStatus::Resolved),
+        make_incident("INC3", "Alert 3", IncidentStatus::Acknowledged),
+    ]);
+
+    let result = list_alerts(&api, 10).await.unwrap();
+    assert_eq!(result.len(), 2); // Only triggered and acknowledged
+    assert!(result.iter().all(|i| i.status != IncidentStatus::Resolved));
+}
+
+#[tokio::test]
+async fn list_incidents_respects_limit() {
+    let api = MockApi::new().with_incidents(vec![
+        make_incident("INC1", "Alert 1", IncidentStatus::Triggered),
+        make_incident("INC2", "Alert 2", IncidentStatus::Triggered),
+        make_incident("INC3", "Alert 3", IncidentStatus::Triggered),
+    ]);
+
+    let opts = IncidentOptions {
+        statuses: vec![IncidentStatus::Triggered],
+        limit: 2,
+        ..Default::default()
+    };
+    let result = list_incidents(&api, &opts).await.unwrap();
+    assert_eq!(result.len(), 2);
+}
+
+#[tokio::test]
+async fn get_incident_returns_matching() {
+    let api = MockApi::new().with_incidents(vec![
+        make_incident("INC1", "Alert 1", IncidentStatus::Triggered),
+        make_incident("INC2", "Alert 2", IncidentStatus::Resolved),
+    ]);
+
+    let result = get_incident(&api, "INC2").await.unwrap();
+    assert_eq!(result.id, "INC2");
+    assert_eq!(result.title, "Alert 2");
+}
+
+#[tokio::test]
+async fn get_incident_not_found() {
+    let api = MockApi::new();
+    let result = get_incident(&api, "MISSING").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn get_current_user_returns_data() {
+    let api = MockApi::new();
+    let result = get_current_user(&api).await.unwrap();
+    assert_eq!(result.display_name(), "Test User");
+}
+
+#[test]
+fn ensure_configured_fails_without_token() {
+    let config = PagerDutyConfig::default();
+    let result = ensure_configured(&config);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("not configured"));
+}
+
+#[test]
+fn ensure_configured_succeeds_with_token() {
+    let config = PagerDutyConfig {
+        api_token: Some("test-token".to_string()),
+        ..Default::default()
+    };
+    let result = ensure_configured(&config);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn oncall_options_default() {
+    let opts = OncallOptions::default();
+    assert!(opts.policy_id.is_none());
+    assert!(opts.schedule_id.is_none());
+    assert_eq!(opts.window, TimeWindow::default());
+}
+
+#[test]
+fn incident_options_default() {
+    let opts = IncidentOptions::default();
+    assert_eq!(opts.limit, 25);
+    assert_eq!(opts.statuses.len(), 2);
+}
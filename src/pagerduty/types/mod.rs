@@ -79,6 +79,34 @@ pub struct Oncall {
     pub end: Option<String>,
 }
 
+/// Time window for an on-call query; both ends empty means "right now"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    /// Start of the window (ISO 8601)
+    pub since: Option<String>,
+    /// End of the window (ISO 8601)
+    pub until: Option<String>,
+}
+
+impl TimeWindow {
+    /// A single instant
+    pub fn at(time: &str) -> Self {
+        Self {
+            since: Some(time.to_string()),
+            until: Some(time.to_string()),
+        }
+    }
+
+    /// Human-readable description for table headers
+    pub fn label(&self) -> String {
+        match (&self.since, &self.until) {
+            (None, None) => "now".to_string(),
+            (Some(since), Some(until)) if since != until => format!("{} \u{2192} {}", since, until),
+            (Some(at), _) | (None, Some(at)) => format!("at {}", at),
+        }
+    }
+}
+
 /// Service
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Service {
@@ -129,6 +157,42 @@ impl IncidentStatus {
     }
 }
 
+/// Change to one incident besides its status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncidentChange {
+    /// Replace the assignees with these user IDs
+    Reassign(Vec<String>),
+    /// Move to this escalation level (1-based)
+    Escalate(u32),
+}
+
+/// Options for listing incidents
+#[derive(Debug)]
+pub struct IncidentOptions {
+    /// Filter by statuses
+    pub statuses: Vec<IncidentStatus>,
+    /// Maximum number of results
+    pub limit: usize,
+    /// Only incidents on these services
+    pub service_ids: Vec<String>,
+    /// Start of the time window (ISO 8601)
+    pub since: Option<String>,
+    /// End of the time window (ISO 8601)
+    pub until: Option<String>,
+}
+
+impl Default for IncidentOptions {
+    fn default() -> Self {
+        Self {
+            statuses: vec![IncidentStatus::Triggered, IncidentStatus::Acknowledged],
+            limit: 25,
+            service_ids: vec![],
+            since: None,
+            until: None,
+        }
+    }
+}
+
 /// Assignment (user assigned to incident)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Assignment {
@@ -159,6 +223,29 @@ pub struct Incident {
     /// Users assigned to this incident
     #[serde(default)]
     pub assignments: Vec<Assignment>,
+    /// Scheduled status changes, e.g. un-acknowledging after a snooze
+    #[serde(default)]
+    pub pending_actions: Vec<PendingAction>,
+}
+
+impl Incident {
+    /// When a snooze runs out (the pending `unacknowledge` action)
+    pub fn snoozed_until(&self) -> Option<&str> {
+        self.pending_actions
+            .iter()
+            .find(|a| a.action_type == "unacknowledge")
+            .map(|a| a.at.as_str())
+    }
+}
+
+/// Status change PagerDuty will make at a set time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingAction {
+    /// `unacknowledge`, `escalate`, `resolve` or `urgency_change`
+    #[serde(rename = "type")]
+    pub action_type: String,
+    /// When it happens (ISO 8601)
+    pub at: String,
 }
 
 /// API response wrapper for oncalls
@@ -190,6 +277,13 @@ pub struct ServicesResponse {
     pub services: Vec<Service>,
 }
 
+/// API response wrapper for users
+#[derive(Debug, Deserialize)]
+pub struct UsersResponse {
+    /// Matching users
+    pub users: Vec<User>,
+}
+
 /// Current user response
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
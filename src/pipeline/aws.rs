@@ -3,6 +3,8 @@
 use anyhow::{Context, Result};
 use std::process::Command;
 
+use crate::util::dry_run;
+
 use super::types::{
     AwsConfig, ListExecutionsResponse, ListPipelinesResponse, Pipeline, PipelineExecution,
     PipelineState,
@@ -27,6 +29,7 @@ pub fn list_pipelines(config: &AwsConfig) -> Result<Vec<Pipeline>> {
     let mut cmd = build_aws_cmd(config);
     cmd.arg("list-pipelines");
 
+    dry_run::intercept(&cmd)?;
     let output = cmd
         .output()
         .context("Failed to execute aws cli. Is AWS CLI installed and configured?")?;
@@ -54,6 +57,7 @@ pub fn get_pipeline_state(config: &AwsConfig, name: &str) -> Result<PipelineStat
     let mut cmd = build_aws_cmd(config);
     cmd.arg("get-pipeline-state").arg("--name").arg(name);
 
+    dry_run::intercept(&cmd)?;
     let output = cmd.output().context("Failed to execute aws cli")?;
 
     if !output.status.success() {
@@ -84,6 +88,7 @@ pub fn list_executions(
         .arg("--max-results")
         .arg(limit.to_string());
 
+    dry_run::intercept(&cmd)?;
     let output = cmd.output().context("Failed to execute aws cli")?;
 
     if !output.status.success() {
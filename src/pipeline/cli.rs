@@ -11,7 +11,7 @@ pub enum PipelineCommand {
         region: Option<String>,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -25,7 +25,7 @@ pub enum PipelineCommand {
         region: Option<String>,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -43,7 +43,7 @@ pub enum PipelineCommand {
         limit: usize,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 }
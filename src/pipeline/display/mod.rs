@@ -1,8 +1,11 @@
 //! Pipeline output formatting
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use crate::util::print_structured;
+
 use super::types::{OutputFormat, Pipeline, PipelineExecution, PipelineState, StageStatus};
 
 #[cfg(test)]
@@ -55,11 +58,7 @@ pub fn output_pipelines(pipelines: &[Pipeline], format: OutputFormat) -> Result<
             println!("{table}");
             println!("\n{} pipelines", pipelines.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(pipelines).context("Failed to serialize pipelines")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(pipelines, format)?,
     }
     Ok(())
 }
@@ -124,11 +123,7 @@ pub fn output_pipeline_state(state: &PipelineState, format: OutputFormat) -> Res
 
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(state)
-                .context("Failed to serialize pipeline state")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(state, format)?,
     }
     Ok(())
 }
@@ -168,11 +163,7 @@ pub fn output_executions(executions: &[PipelineExecution], format: OutputFormat)
             println!("{table}");
             println!("\n{} executions", executions.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(executions)
-                .context("Failed to serialize executions")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(executions, format)?,
     }
     Ok(())
 }
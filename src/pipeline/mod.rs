@@ -33,11 +33,7 @@ fn cmd_list(region: Option<String>, json: bool) -> Result<()> {
     let config = AwsConfig { region };
     let pipelines = aws::list_pipelines(&config)?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_pipelines(&pipelines, format)?;
     Ok(())
@@ -49,11 +45,7 @@ fn cmd_status(name: &str, region: Option<String>, json: bool) -> Result<()> {
     let config = AwsConfig { region };
     let state = aws::get_pipeline_state(&config, name)?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_pipeline_state(&state, format)?;
     Ok(())
@@ -65,11 +57,7 @@ fn cmd_history(name: &str, region: Option<String>, limit: usize, json: bool) ->
     let config = AwsConfig { region };
     let executions = aws::list_executions(&config, name, limit)?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_executions(&executions, format)?;
     Ok(())
@@ -27,6 +27,53 @@ pub fn extract_lines_around(
     (result, total_lines)
 }
 
+/// Extract an inclusive 1-based line range, clamped to the file
+pub fn extract_line_range(
+    content: &str,
+    start: usize,
+    end: usize,
+) -> (Vec<(usize, String)>, usize) {
+    let lines: Vec<&str> = content.lines().collect();
+    let total_lines = lines.len();
+
+    let start_idx = start.max(1) - 1;
+    let end_idx = end.min(total_lines);
+    if start_idx >= end_idx {
+        return (vec![], total_lines);
+    }
+
+    let result = lines[start_idx..end_idx]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| (start_idx + i + 1, (*line).to_string()))
+        .collect();
+
+    (result, total_lines)
+}
+
+/// Format lines with a line number gutter
+pub fn format_lines(lines: &[(usize, String)], total_lines: usize) -> String {
+    if lines.is_empty() {
+        return "No content".to_string();
+    }
+
+    let width = total_lines.to_string().len();
+    lines
+        .iter()
+        .map(|(num, line)| format!("{:>width$}: {}", num, line, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format lines as plain text, without line numbers
+pub fn format_lines_bare(lines: &[(usize, String)]) -> String {
+    lines
+        .iter()
+        .map(|(_, line)| line.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Format lines with line numbers and highlight center
 pub fn format_lines_around(lines: &[(usize, String)], center: usize, total_lines: usize) -> String {
     if lines.is_empty() {
@@ -118,6 +165,49 @@ mod tests {
         assert_eq!(total, 1);
     }
 
+    #[test]
+    fn range_basic() {
+        let content = "line1\nline2\nline3\nline4\nline5";
+        let (lines, total) = extract_line_range(content, 2, 4);
+        assert_eq!(total, 5);
+        assert_eq!(
+            lines,
+            vec![
+                (2, "line2".to_string()),
+                (3, "line3".to_string()),
+                (4, "line4".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn range_clamped_to_file() {
+        let content = "line1\nline2\nline3";
+        let (lines, _) = extract_line_range(content, 0, 100);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].0, 1);
+    }
+
+    #[test]
+    fn range_past_end_is_empty() {
+        let (lines, total) = extract_line_range("line1\nline2", 5, 9);
+        assert!(lines.is_empty());
+        assert_eq!(total, 2);
+    }
+
+    #[test]
+    fn format_lines_gutter() {
+        let lines = vec![(9, "nine".to_string()), (10, "ten".to_string())];
+        assert_eq!(format_lines(&lines, 10), " 9: nine\n10: ten");
+        assert_eq!(format_lines(&[], 10), "No content");
+    }
+
+    #[test]
+    fn format_lines_bare_drops_numbers() {
+        let lines = vec![(9, "  nine".to_string()), (10, "ten".to_string())];
+        assert_eq!(format_lines_bare(&lines), "  nine\nten");
+    }
+
     #[test]
     fn format_basic() {
         let lines = vec![
@@ -0,0 +1,177 @@
+//! Declaration matching for C-family languages (C, C++, Java)
+//!
+//! Shared by the outline and interface extractors: both walk the file line by
+//! line and ask [`CFamilyMatcher`] whether a line declares something.
+
+use regex::Regex;
+
+use super::types::{ItemKind, OutlineItem};
+
+/// Words that look like a return type or name but start a statement
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "return", "else", "if", "while", "for", "switch", "case", "do", "new", "delete", "throw",
+    "goto", "sizeof", "catch", "using", "typedef", "assert",
+];
+
+/// Language handled by a [`CFamilyMatcher`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CFamily {
+    C,
+    Cpp,
+    Java,
+}
+
+/// Line matcher for C-family declarations
+#[derive(Debug)]
+pub struct CFamilyMatcher {
+    lang: CFamily,
+    fn_re: Regex,
+    type_re: Regex,
+    enum_re: Regex,
+    namespace_re: Regex,
+    ctor_re: Regex,
+}
+
+impl CFamilyMatcher {
+    pub fn new(lang: CFamily) -> Self {
+        let (fn_re, type_re) = match lang {
+            CFamily::C | CFamily::Cpp => (
+                r"^(\s*)((?:[A-Za-z_][\w:<>,]*[\s*&]+)+)(~?[A-Za-z_][\w:]*)\s*\(([^;{}]*)\)[^;{]*(?:\{.*|;)?\s*$",
+                r"^(\s*)(?:template\s*<.*>\s*)?(?:typedef\s+)?(class|struct|union)\s+(\w+)(\s+final)?(\s*:\s*[^{;]*)?\s*(\{.*)?$",
+            ),
+            CFamily::Java => (
+                r"^(\s*)((?:(?:public|protected|private|static|final|abstract|synchronized|native|default|strictfp)\s+)*)(<[^>]+>\s+)?([\w.]+(?:<[^()]*>)?(?:\[\])*\s+)?(\w+)\s*\(([^)]*)\)(\s*throws\s+[\w.,\s]+)?\s*(\{.*|;)?\s*$",
+                r"^(\s*)((?:(?:public|protected|private|abstract|static|final|sealed|non-sealed|strictfp)\s+)*)(class|interface|enum|record|@interface)\s+(\w+)(<[^{]*>)?(\([^)]*\))?(\s+(?:extends|implements|permits)\s+[^{]*)?",
+            ),
+        };
+
+        Self {
+            lang,
+            fn_re: Regex::new(fn_re).expect("invariant: valid function regex"),
+            type_re: Regex::new(type_re).expect("invariant: valid type regex"),
+            enum_re: Regex::new(
+                r"^(\s*)(?:typedef\s+)?enum\s+(?:class\s+|struct\s+)?(\w+)(\s*:\s*\w+)?\s*(\{.*)?$",
+            )
+            .expect("invariant: valid enum regex"),
+            namespace_re: Regex::new(r"^(\s*)namespace\s+([\w:]+)\s*(\{.*)?$")
+                .expect("invariant: valid namespace regex"),
+            ctor_re: Regex::new(r"^(\s*)(\w+)::(~?\w+)\s*\(([^;{}]*)\)[^;{]*(\{.*)?$")
+                .expect("invariant: valid constructor regex"),
+        }
+    }
+
+    /// Match a single line, returning an outline item if it declares something
+    pub fn match_line(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') || trimmed.starts_with("//") || trimmed.starts_with('*') {
+            return None;
+        }
+
+        match self.lang {
+            CFamily::C => self
+                .match_type(line, line_num)
+                .or_else(|| self.match_fn(line, line_num)),
+            CFamily::Cpp => self
+                .match_namespace(line, line_num)
+                .or_else(|| self.match_type(line, line_num))
+                .or_else(|| self.match_ctor(line, line_num))
+                .or_else(|| self.match_fn(line, line_num)),
+            CFamily::Java => self
+                .match_java_type(line, line_num)
+                .or_else(|| self.match_java_method(line, line_num)),
+        }
+    }
+
+    fn match_type(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        if let Some(caps) = self.enum_re.captures(line) {
+            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+            return Some(item(line_num, &caps[0], indent, ItemKind::Enum));
+        }
+
+        let caps = self.type_re.captures(line)?;
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        let kind = match &caps[2] {
+            "class" => ItemKind::Class,
+            _ => ItemKind::Struct,
+        };
+        Some(item(line_num, &caps[0], indent, kind))
+    }
+
+    fn match_namespace(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let caps = self.namespace_re.captures(line)?;
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        Some(item(line_num, &caps[0], indent, ItemKind::Module))
+    }
+
+    /// C++ out-of-line constructors/destructors (`Foo::Foo(...)`) have no return type
+    fn match_ctor(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let caps = self.ctor_re.captures(line)?;
+        let class = &caps[2];
+        let name = caps[3].trim_start_matches('~');
+        if class != name {
+            return None;
+        }
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        Some(item(line_num, &caps[0], indent, ItemKind::Function))
+    }
+
+    fn match_fn(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let caps = self.fn_re.captures(line)?;
+        let ret = caps[2].trim();
+        let name = &caps[3];
+        let first_word = ret.split_whitespace().next().unwrap_or("");
+        if is_statement_keyword(first_word) || is_statement_keyword(name) {
+            return None;
+        }
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        Some(item(line_num, &caps[0], indent, ItemKind::Function))
+    }
+
+    fn match_java_type(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let caps = self.type_re.captures(line)?;
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        let kind = match &caps[3] {
+            "interface" | "@interface" => ItemKind::Trait,
+            "enum" => ItemKind::Enum,
+            _ => ItemKind::Class,
+        };
+        Some(item(line_num, &caps[0], indent, kind))
+    }
+
+    fn match_java_method(&self, line: &str, line_num: usize) -> Option<OutlineItem> {
+        let caps = self.fn_re.captures(line)?;
+        let modifiers = caps.get(2).map_or("", |m| m.as_str());
+        let ret = caps.get(4).map_or("", |m| m.as_str().trim());
+        let name = &caps[5];
+
+        // A bare `foo(x);` is a call, not a declaration
+        if modifiers.is_empty() && ret.is_empty() {
+            return None;
+        }
+        if is_statement_keyword(ret) || is_statement_keyword(name) {
+            return None;
+        }
+
+        let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+        Some(item(line_num, &caps[0], indent, ItemKind::Function))
+    }
+}
+
+/// Whether a word begins a statement rather than a declaration
+fn is_statement_keyword(word: &str) -> bool {
+    STATEMENT_KEYWORDS.contains(&word)
+}
+
+fn item(line_num: usize, sig: &str, indent: usize, kind: ItemKind) -> OutlineItem {
+    // Drop any inline body: the signature ends where the block opens
+    let sig = sig.split('{').next().unwrap_or(sig);
+    let sig = sig.trim().trim_end_matches(';').trim();
+    OutlineItem::new(line_num, sig.to_string(), indent / 4, kind)
+}
+
+/// Whether a declaration's signature carries a modifier keyword before its name
+pub fn has_modifier(text: &str, modifier: &str) -> bool {
+    text.split_whitespace()
+        .take_while(|w| !w.contains('('))
+        .any(|w| w == modifier)
+}
@@ -1,26 +1,54 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 
 #[derive(Debug, Args)]
 pub struct ReadArgs {
-    /// File path to read
+    /// File path to read (a directory with --map)
     pub path: String,
 
+    /// Map a directory: public items of every supported file
+    #[arg(long)]
+    pub map: bool,
+
+    /// Most items listed per file with --map
+    #[arg(long, default_value = "20", value_name = "N")]
+    pub max_items: usize,
+
+    /// Most items listed in total with --map
+    #[arg(long, default_value = "500", value_name = "N")]
+    pub max_total: usize,
+
     /// Show file outline (functions, structs, classes)
     #[arg(long, short = 'o')]
     pub outline: bool,
 
+    /// Order outline items by source position or by length
+    #[arg(long, value_enum, default_value_t = OutlineSort::Line)]
+    pub sort: OutlineSort,
+
     /// Show public interface only (pub items in Rust, exports in JS)
     #[arg(long, short = 'i')]
     pub interface: bool,
 
-    /// Show lines around a specific line number
-    #[arg(long, short = 'a', value_name = "LINE")]
-    pub around: Option<usize>,
+    /// Show lines around a line number (`LINE` or `LINE:CONTEXT`)
+    #[arg(long, short = 'a', value_name = "LINE[:CTX]", value_parser = parse_around)]
+    pub around: Option<AroundSpec>,
 
     /// Number of context lines for --around (default: 10)
     #[arg(long, short = 'n', default_value = "10")]
     pub context: usize,
 
+    /// Show a 1-based line range, e.g. `40-80` (clamped to the file)
+    #[arg(long, short = 'l', value_name = "START-END", value_parser = parse_line_range)]
+    pub lines: Option<LineRange>,
+
+    /// Omit the line number gutter for --lines and --around
+    #[arg(long)]
+    pub no_numbers: bool,
+
+    /// Print only the body of the named function (`name:LINE` to disambiguate)
+    #[arg(long, short = 'f', value_name = "NAME")]
+    pub function: Option<String>,
+
     /// Show git diff
     #[arg(long, short = 'd')]
     pub diff: bool,
@@ -28,13 +56,88 @@ pub struct ReadArgs {
     /// Commit to diff against (default: HEAD)
     #[arg(long, default_value = "HEAD")]
     pub commit: String,
+
+    /// Output --outline, --interface or --map items as JSON (`hu -o csv read` for CSV)
+    #[arg(long, short = 'j')]
+    pub json: bool,
+}
+
+/// Ordering of outline items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutlineSort {
+    /// Source order
+    #[default]
+    Line,
+    /// Longest items first
+    Size,
+}
+
+/// Center line for --around, with optional per-call context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AroundSpec {
+    pub line: usize,
+    /// Overrides --context when given as `LINE:CTX`
+    pub context: Option<usize>,
+}
+
+/// Inclusive 1-based line range for --lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Parse `LINE` or `LINE:CTX`
+fn parse_around(s: &str) -> Result<AroundSpec, String> {
+    let (line, context) = match s.split_once(':') {
+        Some((line, ctx)) => (line, Some(ctx)),
+        None => (s, None),
+    };
+    let line = line
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid line number: {}", line))?;
+    let context = context
+        .map(|c| {
+            c.trim()
+                .parse()
+                .map_err(|_| format!("invalid context: {}", c))
+        })
+        .transpose()?;
+    Ok(AroundSpec { line, context })
+}
+
+/// Parse `START-END` or a single `LINE`
+fn parse_line_range(s: &str) -> Result<LineRange, String> {
+    let parse = |n: &str| {
+        n.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("invalid line number: {}", n))
+    };
+    let (start, end) = match s.split_once('-') {
+        Some((start, end)) => (parse(start)?, parse(end)?),
+        None => {
+            let line = parse(s)?;
+            (line, line)
+        }
+    };
+    if start > end {
+        return Err(format!("range starts after it ends: {}", s));
+    }
+    Ok(LineRange { start, end })
 }
 
 impl ReadArgs {
-    /// Check if any mode is active (outline, interface, around, diff)
+    /// Check if any mode is active (outline, interface, around, lines, diff)
     #[cfg(test)]
     pub fn has_mode(&self) -> bool {
-        self.outline || self.interface || self.around.is_some() || self.diff
+        self.map
+            || self.outline
+            || self.interface
+            || self.around.is_some()
+            || self.lines.is_some()
+            || self.function.is_some()
+            || self.diff
     }
 }
 
@@ -57,6 +160,19 @@ mod tests {
         assert!(!cli.read.interface);
         assert!(cli.read.around.is_none());
         assert!(!cli.read.diff);
+        assert_eq!(cli.read.sort, OutlineSort::Line);
+    }
+
+    #[test]
+    fn parse_sort_size() {
+        let cli = TestCli::try_parse_from(["test", "-o", "--sort", "size", "file.rs"]).unwrap();
+        assert_eq!(cli.read.sort, OutlineSort::Size);
+    }
+
+    #[test]
+    fn parse_sort_invalid() {
+        let result = TestCli::try_parse_from(["test", "-o", "--sort", "name", "file.rs"]);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -86,13 +202,58 @@ mod tests {
     #[test]
     fn parse_around_long() {
         let cli = TestCli::try_parse_from(["test", "--around", "50", "file.rs"]).unwrap();
-        assert_eq!(cli.read.around, Some(50));
+        assert_eq!(
+            cli.read.around,
+            Some(AroundSpec {
+                line: 50,
+                context: None
+            })
+        );
     }
 
     #[test]
     fn parse_around_short() {
         let cli = TestCli::try_parse_from(["test", "-a", "100", "file.rs"]).unwrap();
-        assert_eq!(cli.read.around, Some(100));
+        assert_eq!(cli.read.around.map(|a| a.line), Some(100));
+    }
+
+    #[test]
+    fn parse_around_with_context() {
+        let cli = TestCli::try_parse_from(["test", "-a", "120:5", "file.rs"]).unwrap();
+        assert_eq!(
+            cli.read.around,
+            Some(AroundSpec {
+                line: 120,
+                context: Some(5)
+            })
+        );
+    }
+
+    #[test]
+    fn parse_around_invalid() {
+        assert!(TestCli::try_parse_from(["test", "-a", "x", "file.rs"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "-a", "10:x", "file.rs"]).is_err());
+    }
+
+    #[test]
+    fn parse_lines_range() {
+        let cli = TestCli::try_parse_from(["test", "--lines", "40-80", "file.rs"]).unwrap();
+        assert_eq!(cli.read.lines, Some(LineRange { start: 40, end: 80 }));
+        assert!(cli.read.has_mode());
+        assert!(!cli.read.no_numbers);
+    }
+
+    #[test]
+    fn parse_lines_single() {
+        let cli = TestCli::try_parse_from(["test", "-l", "7", "--no-numbers", "file.rs"]).unwrap();
+        assert_eq!(cli.read.lines, Some(LineRange { start: 7, end: 7 }));
+        assert!(cli.read.no_numbers);
+    }
+
+    #[test]
+    fn parse_lines_invalid() {
+        assert!(TestCli::try_parse_from(["test", "--lines", "80-40", "file.rs"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "--lines", "a-b", "file.rs"]).is_err());
     }
 
     #[test]
@@ -122,6 +283,34 @@ mod tests {
         assert_eq!(cli.read.commit, "HEAD~1");
     }
 
+    #[test]
+    fn parse_json_flag() {
+        let cli = TestCli::try_parse_from(["test", "-o", "--json", "file.rs"]).unwrap();
+        assert!(cli.read.json);
+    }
+
+    #[test]
+    fn parse_map() {
+        let cli = TestCli::try_parse_from(["test", "--map", "src"]).unwrap();
+        assert!(cli.read.map);
+        assert_eq!(cli.read.max_items, 20);
+        assert_eq!(cli.read.max_total, 500);
+        assert!(cli.read.has_mode());
+
+        let cli = TestCli::try_parse_from([
+            "test",
+            "--map",
+            "--max-items",
+            "5",
+            "--max-total",
+            "50",
+            ".",
+        ])
+        .unwrap();
+        assert_eq!(cli.read.max_items, 5);
+        assert_eq!(cli.read.max_total, 50);
+    }
+
     #[test]
     fn has_mode_none() {
         let cli = TestCli::try_parse_from(["test", "file.rs"]).unwrap();
@@ -146,6 +335,13 @@ mod tests {
         assert!(cli.read.has_mode());
     }
 
+    #[test]
+    fn parse_function() {
+        let cli = TestCli::try_parse_from(["test", "--function", "new:12", "file.rs"]).unwrap();
+        assert_eq!(cli.read.function.as_deref(), Some("new:12"));
+        assert!(cli.read.has_mode());
+    }
+
     #[test]
     fn has_mode_diff() {
         let cli = TestCli::try_parse_from(["test", "-d", "file.rs"]).unwrap();
@@ -171,7 +367,7 @@ mod tests {
             .unwrap();
         assert!(cli.read.outline);
         assert!(cli.read.interface);
-        assert_eq!(cli.read.around, Some(50));
+        assert_eq!(cli.read.around.map(|a| a.line), Some(50));
         assert_eq!(cli.read.context, 5);
     }
 }
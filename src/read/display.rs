@@ -1,8 +1,16 @@
 //! Display formatting for read output (CLI-only)
 
-use super::around::format_lines_around;
+use anyhow::{bail, Result};
+
+use crate::util::{print_structured, OutputFormat};
+
+use super::around::{format_lines, format_lines_around, format_lines_bare};
 use super::diff::format_diff;
-use super::types::{FileOutline, OutlineItem, ReadOutput};
+use super::types::{FileOutline, OutlineItem, ReadOutput, RepoMap};
+
+// ANSI color codes
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
 
 /// Format ReadOutput for CLI display
 pub fn format(output: &ReadOutput) -> String {
@@ -15,8 +23,64 @@ pub fn format(output: &ReadOutput) -> String {
             center,
             total_lines,
         } => format_lines_around(lines, *center, *total_lines),
+        ReadOutput::Lines { lines, total_lines } => format_lines(lines, *total_lines),
         ReadOutput::Diff(diff) => format_diff(diff),
+        ReadOutput::Function(body) => format!("{}\n", body),
+        ReadOutput::Map(map) => format_map(map),
+    }
+}
+
+/// Format like [`format`], but without line number gutters
+pub fn format_bare(output: &ReadOutput) -> String {
+    match output {
+        ReadOutput::Around { lines, .. } | ReadOutput::Lines { lines, .. } => {
+            format_lines_bare(lines)
+        }
+        _ => format(output),
+    }
+}
+
+/// Print outline or interface items as a JSON array or CSV, or the repo map
+pub fn print_items(output: &ReadOutput, format: OutputFormat) -> Result<()> {
+    match output {
+        ReadOutput::Outline(outline) => print_structured(&outline.items, format),
+        ReadOutput::Interface(items) => print_structured(items, format),
+        ReadOutput::Map(map) => print_structured(map, format),
+        _ => bail!("JSON and CSV output work with --outline, --interface and --map"),
+    }
+}
+
+/// Format a repo map as files with their public items underneath
+fn format_map(map: &RepoMap) -> String {
+    if map.files.is_empty() {
+        return "No public interface items found".to_string();
+    }
+
+    let mut output = Vec::new();
+    for file in &map.files {
+        output.push(file.path.clone());
+        for item in &file.items {
+            let indent = "  ".repeat(item.level + 1);
+            output.push(format!(
+                "{}{} {} {DIM}:L{}{RESET}",
+                indent,
+                item.kind.icon(),
+                item.text,
+                item.line
+            ));
+        }
+        if file.omitted > 0 {
+            output.push(format!("  {DIM}… {} more{RESET}", file.omitted));
+        }
+    }
+    if map.omitted > 0 {
+        output.push(format!(
+            "{DIM}… {} more items (raise --max-total){RESET}",
+            map.omitted
+        ));
     }
+
+    output.join("\n")
 }
 
 /// Format outline for display
@@ -31,12 +95,27 @@ fn format_outline(outline: &FileOutline) -> String {
         let indent = "  ".repeat(item.level);
         let icon = item.kind.icon();
         let line_info = format!(":{}", item.line);
-        output.push(format!("{}{} {}{}", indent, icon, item.text, line_info));
+        output.push(format!(
+            "{}{} {}{}{}",
+            indent,
+            icon,
+            item.text,
+            line_info,
+            size_suffix(item)
+        ));
     }
 
     output.join("\n")
 }
 
+/// Dim `(N lines)` suffix for items spanning more than one line
+fn size_suffix(item: &OutlineItem) -> String {
+    match item.line_count() {
+        1 => String::new(),
+        n => format!(" {DIM}({} lines){RESET}", n),
+    }
+}
+
 /// Format interface for display
 fn format_interface(items: &[OutlineItem]) -> String {
     if items.is_empty() {
@@ -66,6 +145,20 @@ mod tests {
         assert_eq!(formatted, "hello\nworld");
     }
 
+    #[test]
+    fn print_items_structured_for_outline() {
+        let output = ReadOutput::Outline(FileOutline::new());
+        assert!(print_items(&output, OutputFormat::Json).is_ok());
+        assert!(print_items(&output, OutputFormat::Csv).is_ok());
+    }
+
+    #[test]
+    fn print_items_rejects_plain_content() {
+        let output = ReadOutput::Full("hello".to_string());
+        let err = print_items(&output, OutputFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("--outline"));
+    }
+
     #[test]
     fn format_empty_outline() {
         let output = ReadOutput::Outline(FileOutline::new());
@@ -86,6 +179,17 @@ mod tests {
         let formatted = format(&output);
         assert!(formatted.contains("fn pub fn test()"));
         assert!(formatted.contains(":10"));
+        assert!(!formatted.contains("lines)"));
+    }
+
+    #[test]
+    fn format_outline_shows_line_count() {
+        let mut item = OutlineItem::new(10, "fn big()".to_string(), 0, ItemKind::Function);
+        item.end_line = 51;
+        let mut outline = FileOutline::new();
+        outline.push(item);
+        let formatted = format(&ReadOutput::Outline(outline));
+        assert!(formatted.ends_with("fn big():10 \x1b[2m(42 lines)\x1b[0m"));
     }
 
     #[test]
@@ -147,6 +251,12 @@ mod tests {
         assert!(formatted.contains(" 9: line9"));
     }
 
+    #[test]
+    fn format_function_body() {
+        let output = ReadOutput::Function("fn a() {\n}".to_string());
+        assert_eq!(format(&output), "fn a() {\n}\n");
+    }
+
     #[test]
     fn format_diff_content() {
         let output = ReadOutput::Diff("+added line".to_string());
@@ -0,0 +1,173 @@
+//! Slice a single function out of a file using outline boundaries
+
+use anyhow::{bail, Result};
+use regex::Regex;
+
+use super::outline::extract_outline;
+use super::types::{ItemKind, OutlineItem};
+
+/// Words that precede a `(` in a signature without being the function name
+const SIGNATURE_KEYWORDS: &[&str] = &["fn", "func", "function", "def"];
+
+/// Find the function named by `query` and return it with its source lines
+///
+/// `query` is matched case-insensitively against function and method names.
+/// When several items share the name, `name:LINE` picks the one starting on
+/// that line.
+pub fn extract_function(content: &str, path: &str, query: &str) -> Result<(OutlineItem, String)> {
+    let (name, line) = parse_query(query);
+    let outline = extract_outline(content, path);
+    let matches: Vec<&OutlineItem> = outline
+        .items
+        .iter()
+        .filter(|item| item.kind == ItemKind::Function)
+        .filter(|item| function_name(&item.text).is_some_and(|n| n.eq_ignore_ascii_case(name)))
+        .filter(|item| line.map_or(true, |l| item.line == l))
+        .collect();
+
+    let item = match matches.as_slice() {
+        [] => bail!("No function named '{}' found in {}", name, path),
+        [item] => (*item).clone(),
+        many => {
+            let lines: Vec<String> = many.iter().map(|i| i.line.to_string()).collect();
+            bail!(
+                "Multiple functions named '{}' (lines {}); pick one with --function {}:<line>",
+                name,
+                lines.join(", "),
+                name
+            )
+        }
+    };
+
+    let body = content
+        .lines()
+        .skip(item.line - 1)
+        .take(item.line_count())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok((item, body))
+}
+
+/// Split `name:LINE` into its parts; a plain name has no line
+fn parse_query(query: &str) -> (&str, Option<usize>) {
+    match query.rsplit_once(':') {
+        Some((name, line)) if !name.is_empty() => match line.parse() {
+            Ok(line) => (name, Some(line)),
+            Err(_) => (query, None),
+        },
+        _ => (query, None),
+    }
+}
+
+/// Name of the function declared by an outline signature
+pub fn function_name(signature: &str) -> Option<&str> {
+    let def_re = Regex::new(r"\bdef\s+(?:self\.)?(\w+[?!=]?)").unwrap();
+    if let Some(caps) = def_re.captures(signature) {
+        return caps.get(1).map(|m| m.as_str());
+    }
+
+    // The identifier right before the parameter list (skipping Go receivers)
+    let call_re = Regex::new(r"(\w+)\s*(?:<[^()]*>)?\s*\(").unwrap();
+    if let Some(name) = call_re
+        .captures_iter(signature)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| m.as_str())
+        .find(|word| !SIGNATURE_KEYWORDS.contains(word))
+    {
+        return Some(name);
+    }
+
+    // Arrow functions: `const name = (...) =>`
+    let arrow_re = Regex::new(r"(\w+)\s*=").unwrap();
+    arrow_re
+        .captures(signature)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST: &str = "struct A;\n\nimpl A {\n    pub fn new() -> Self {\n        A\n    }\n}\n\nimpl B {\n    fn new() -> Self {\n        B\n    }\n}\n\nfn helper(x: u32) -> u32 {\n    x + 1\n}\n";
+
+    #[test]
+    fn function_name_by_language() {
+        assert_eq!(function_name("pub fn new() -> Self"), Some("new"));
+        assert_eq!(function_name("pub fn map<T>(x: T)"), Some("map"));
+        assert_eq!(
+            function_name("func (s *Server) Start() error"),
+            Some("Start")
+        );
+        assert_eq!(function_name("async def fetch(self)"), Some("fetch"));
+        assert_eq!(function_name("def valid?"), Some("valid?"));
+        assert_eq!(
+            function_name("export const handler = (req) =>"),
+            Some("handler")
+        );
+        assert_eq!(
+            function_name("public static void main(String[] args)"),
+            Some("main")
+        );
+    }
+
+    #[test]
+    fn extract_function_body() {
+        let (item, body) = extract_function(RUST, "lib.rs", "HELPER").unwrap();
+        assert_eq!(item.line, 15);
+        assert_eq!(body, "fn helper(x: u32) -> u32 {\n    x + 1\n}");
+    }
+
+    #[test]
+    fn extract_function_with_raw_string_regex() {
+        let content = "fn outline() {\n    let re = Regex::new(r\"^fn\\s+\\w+.*\\{\");\n    walk(re);\n}\n\nfn after() {}\n";
+        let (item, body) = extract_function(content, "a.rs", "outline").unwrap();
+        assert_eq!(item.end_line, 4);
+        assert!(body.ends_with("    walk(re);\n}"));
+        assert!(!body.contains("fn after"));
+    }
+
+    #[test]
+    fn extract_function_js_quoted_brace() {
+        let content = "function foo() {\n  const s = 'a}b';\n  return s;\n}\n";
+        let (item, body) = extract_function(content, "t.js", "foo").unwrap();
+        assert_eq!(item.end_line, 4);
+        assert!(body.ends_with("  return s;\n}"));
+    }
+
+    #[test]
+    fn extract_function_ambiguous() {
+        let err = extract_function(RUST, "lib.rs", "new").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("lines 4, 10"));
+        assert!(msg.contains("--function new:<line>"));
+    }
+
+    #[test]
+    fn extract_function_disambiguated_by_line() {
+        let (item, body) = extract_function(RUST, "lib.rs", "new:10").unwrap();
+        assert_eq!(item.line, 10);
+        assert_eq!(body, "    fn new() -> Self {\n        B\n    }");
+    }
+
+    #[test]
+    fn extract_function_not_found() {
+        let err = extract_function(RUST, "lib.rs", "missing").unwrap_err();
+        assert!(err.to_string().contains("No function named 'missing'"));
+    }
+
+    #[test]
+    fn extract_function_python_method() {
+        let content =
+            "class A:\n    def run(self):\n        return 1\n\n    def stop(self):\n        pass\n";
+        let (_, body) = extract_function(content, "a.py", "run").unwrap();
+        assert_eq!(body, "    def run(self):\n        return 1");
+    }
+
+    #[test]
+    fn parse_query_variants() {
+        assert_eq!(parse_query("new"), ("new", None));
+        assert_eq!(parse_query("new:12"), ("new", Some(12)));
+        assert_eq!(parse_query("a::b"), ("a::b", None));
+    }
+}
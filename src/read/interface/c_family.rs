@@ -0,0 +1,70 @@
+//! Public interface extraction for C, C++ and Java
+
+use regex::Regex;
+
+use crate::read::c_family::{has_modifier, CFamily, CFamilyMatcher};
+use crate::read::types::{ItemKind, OutlineItem};
+
+/// Extract C public interface (top-level, non-static declarations)
+pub fn extract_c_interface(content: &str) -> Vec<OutlineItem> {
+    let matcher = CFamilyMatcher::new(CFamily::C);
+
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line_num, line)| matcher.match_line(line, line_num + 1))
+        .filter(|item| item.level == 0 && !has_modifier(&item.text, "static"))
+        .collect()
+}
+
+/// Extract C++ public interface (non-static free functions, types, public members)
+pub fn extract_cpp_interface(content: &str) -> Vec<OutlineItem> {
+    let mut items = Vec::new();
+    let matcher = CFamilyMatcher::new(CFamily::Cpp);
+    let access_re = Regex::new(r"^\s*(public|protected|private)\s*:").unwrap();
+
+    // Open class scopes: (brace depth inside the class, current access is public)
+    let mut scopes: Vec<(i32, bool)> = Vec::new();
+    let mut depth: i32 = 0;
+
+    for (line_num, line) in content.lines().enumerate() {
+        if let Some(caps) = access_re.captures(line) {
+            if let Some(scope) = scopes.last_mut() {
+                scope.1 = &caps[1] == "public";
+            }
+        } else if let Some(item) = matcher.match_line(line, line_num + 1) {
+            let is_public = scopes.last().map_or(true, |s| s.1);
+            let is_type = matches!(item.kind, ItemKind::Class | ItemKind::Struct);
+
+            if is_public && !has_modifier(&item.text, "static") {
+                items.push(item.clone());
+            }
+            if is_type {
+                // Struct members default to public, class members to private
+                scopes.push((depth + 1, item.kind == ItemKind::Struct));
+            }
+        }
+
+        depth += line.matches('{').count() as i32;
+        if line.contains('}') {
+            depth -= line.matches('}').count() as i32;
+            while scopes.last().is_some_and(|s| depth < s.0) {
+                scopes.pop();
+            }
+        }
+    }
+
+    items
+}
+
+/// Extract Java public interface (public/protected classes and methods)
+pub fn extract_java_interface(content: &str) -> Vec<OutlineItem> {
+    let matcher = CFamilyMatcher::new(CFamily::Java);
+
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line_num, line)| matcher.match_line(line, line_num + 1))
+        .filter(|item| has_modifier(&item.text, "public") || has_modifier(&item.text, "protected"))
+        .collect()
+}
@@ -1,24 +1,27 @@
 use regex::Regex;
-use std::path::Path;
 
+use super::language::effective_extension;
 use super::types::{ItemKind, OutlineItem};
 
+mod c_family;
+
+use c_family::{extract_c_interface, extract_cpp_interface, extract_java_interface};
+
 #[cfg(test)]
 mod tests;
 
 /// Extract public interface from file content
 pub fn extract_interface(content: &str, path: &str) -> Vec<OutlineItem> {
-    let ext = Path::new(path)
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("");
-
-    match ext {
+    match effective_extension(path, content) {
         "rs" => extract_rust_interface(content),
         "py" => extract_python_interface(content),
-        "js" | "ts" | "jsx" | "tsx" | "mjs" => extract_js_interface(content),
+        "js" | "jsx" | "mjs" => extract_js_interface(content, false),
+        "ts" | "tsx" => extract_js_interface(content, true),
         "rb" => extract_ruby_interface(content),
         "go" => extract_go_interface(content),
+        "c" | "h" => extract_c_interface(content),
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" => extract_cpp_interface(content),
+        "java" => extract_java_interface(content),
         _ => vec![],
     }
 }
@@ -168,7 +171,10 @@ fn extract_python_interface(content: &str) -> Vec<OutlineItem> {
 }
 
 /// Extract JavaScript/TypeScript public interface (exports only)
-fn extract_js_interface(content: &str) -> Vec<OutlineItem> {
+///
+/// With `typescript`, also picks up exported interfaces, type aliases, enums
+/// and type-annotated constants.
+fn extract_js_interface(content: &str, typescript: bool) -> Vec<OutlineItem> {
     let mut items = Vec::new();
 
     let export_fn_re =
@@ -180,9 +186,16 @@ fn extract_js_interface(content: &str) -> Vec<OutlineItem> {
     let export_default_re =
         Regex::new(r"^(\s*)export\s+default\s+(class|function)?\s*(\w+)?").unwrap();
 
+    let ts_patterns = if typescript { ts_patterns() } else { vec![] };
+
     for (line_num, line) in content.lines().enumerate() {
         let line_num = line_num + 1;
 
+        if let Some(item) = ts_declaration(&ts_patterns, line, line_num) {
+            items.push(item);
+            continue;
+        }
+
         if let Some(caps) = export_fn_re.captures(line) {
             let sig = caps.get(0).unwrap().as_str().trim();
             items.push(OutlineItem::new(
@@ -221,6 +234,48 @@ fn extract_js_interface(content: &str) -> Vec<OutlineItem> {
     items
 }
 
+/// Exported TypeScript-only declarations and the kind each maps to
+fn ts_patterns() -> Vec<(Regex, ItemKind)> {
+    [
+        (
+            r"^\s*export\s+(declare\s+)?interface\s+\w+",
+            ItemKind::Trait,
+        ),
+        (r"^\s*export\s+(declare\s+)?type\s+\w+", ItemKind::Type),
+        (
+            r"^\s*export\s+(declare\s+)?(const\s+)?enum\s+\w+",
+            ItemKind::Enum,
+        ),
+        (
+            r"^\s*export\s+(declare\s+)?(const|let)\s+\w+\s*:",
+            ItemKind::Const,
+        ),
+    ]
+    .into_iter()
+    .map(|(re, kind)| (Regex::new(re).unwrap(), kind))
+    .collect()
+}
+
+/// Match one line against the TypeScript declaration patterns
+fn ts_declaration(
+    patterns: &[(Regex, ItemKind)],
+    line: &str,
+    line_num: usize,
+) -> Option<OutlineItem> {
+    let (_, kind) = patterns.iter().find(|(re, _)| re.is_match(line))?;
+    let kind = kind.clone();
+
+    // Keep one-line aliases whole, drop the opening brace of bodies
+    let mut sig = line.trim().trim_end_matches(['{', ';']).trim_end();
+    if kind == ItemKind::Const {
+        sig = sig
+            .split_once(" =")
+            .map_or(sig, |(decl, _)| decl)
+            .trim_end();
+    }
+    Some(OutlineItem::new(line_num, sig.to_string(), 0, kind))
+}
+
 /// Extract Ruby public interface (exclude private methods)
 fn extract_ruby_interface(content: &str) -> Vec<OutlineItem> {
     let mut items = Vec::new();
@@ -147,6 +147,68 @@ fn js_export_default() {
     assert_eq!(items.len(), 1);
 }
 
+#[test]
+fn ts_export_interface_multiline() {
+    let content = r#"export interface User extends Base {
+    id: string;
+    name: string;
+}
+
+interface Internal {
+    secret: string;
+}
+"#;
+    let items = extract_interface(content, "user.ts");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].kind, ItemKind::Trait);
+    assert_eq!(items[0].text, "export interface User extends Base");
+    assert_eq!(items[0].line, 1);
+}
+
+#[test]
+fn ts_export_type_alias() {
+    let content = r#"export type Id = string | number;
+export type Props<T> = {
+    value: T;
+};
+type Local = string;
+"#;
+    let items = extract_interface(content, "types.tsx");
+    let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec!["export type Id = string | number", "export type Props<T> ="]
+    );
+    assert!(items.iter().all(|i| i.kind == ItemKind::Type));
+}
+
+#[test]
+fn ts_export_enum_and_typed_const() {
+    let content = r#"export enum Color { Red, Green }
+export const enum Flag { On }
+export const DEFAULT_COLOR: Color = Color.Red;
+export const handler = (req) =>
+"#;
+    let items = extract_interface(content, "color.ts");
+    let kinds: Vec<&ItemKind> = items.iter().map(|i| &i.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &ItemKind::Enum,
+            &ItemKind::Enum,
+            &ItemKind::Const,
+            &ItemKind::Function
+        ]
+    );
+    assert_eq!(items[2].text, "export const DEFAULT_COLOR: Color");
+}
+
+#[test]
+fn js_ignores_ts_declarations() {
+    let content = "export interface User {}\nexport type Id = string;";
+    assert!(extract_interface(content, "test.js").is_empty());
+}
+
 #[test]
 fn ruby_public_method() {
     let content = "def public_method\nend";
@@ -223,6 +285,89 @@ fn go_exported_interface() {
     assert_eq!(items.len(), 1);
 }
 
+#[test]
+fn c_non_static_function() {
+    let content = "int api_call(void);\nstatic int helper(void) {\n}";
+    let items = extract_interface(content, "api.c");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].text, "int api_call(void)");
+}
+
+#[test]
+fn c_struct_included() {
+    let content = "struct config {\n    int verbose;\n};";
+    let items = extract_interface(content, "config.h");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].kind, ItemKind::Struct);
+}
+
+#[test]
+fn cpp_public_members_only() {
+    let content = r#"class Widget {
+    int secret();
+public:
+    void show();
+private:
+    void hide();
+};
+
+struct Point {
+    int norm() const;
+};
+
+static void local_helper() {}
+void free_function() {}"#;
+    let items = extract_interface(content, "widget.hpp");
+    let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "class Widget",
+            "void show()",
+            "struct Point",
+            "int norm() const",
+            "void free_function()",
+        ]
+    );
+}
+
+#[test]
+fn java_public_and_protected() {
+    let content = r#"public class Api {
+    public void open() {}
+    protected void reset() {}
+    private void close() {}
+    void packagePrivate() {}
+}
+class Hidden {}"#;
+    let items = extract_interface(content, "Api.java");
+    let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "public class Api",
+            "public void open()",
+            "protected void reset()"
+        ]
+    );
+}
+
+#[test]
+fn shebang_ruby_interface() {
+    let content = "#!/usr/bin/env ruby\nclass Deploy\n  def run\n  end\n\n  private\n\n  def secret\n  end\nend\n";
+    let items = extract_interface(content, "bin/deploy");
+    assert!(items.iter().any(|i| i.text.contains("def run")));
+    assert!(!items.iter().any(|i| i.text.contains("secret")));
+}
+
+#[test]
+fn shebang_python_interface() {
+    let content = "#!/usr/bin/python3\ndef main():\n    pass\n\ndef _helper():\n    pass\n";
+    let items = extract_interface(content, "bin/check");
+    assert_eq!(items.len(), 1);
+    assert!(items[0].text.contains("def main"));
+}
+
 #[test]
 fn unknown_extension() {
     let content = "some content";
@@ -0,0 +1,127 @@
+//! Language detection for files without a recognised extension
+
+use std::path::Path;
+
+/// Extensions the outline/interface extractors understand
+const KNOWN_EXTENSIONS: &[&str] = &[
+    "rs", "py", "js", "ts", "jsx", "tsx", "mjs", "rb", "go", "c", "h", "cc", "cpp", "cxx", "hpp",
+    "hh", "hxx", "java", "sh", "bash", "md", "markdown",
+];
+
+/// Whether `path` has an extension the extractors understand
+pub fn has_known_extension(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| KNOWN_EXTENSIONS.contains(&ext))
+}
+
+/// Extension to dispatch on for `path`
+///
+/// Uses the real extension when it is known, otherwise the interpreter named
+/// by a shebang, otherwise a guess from the content. Returns `""` when nothing
+/// matches.
+pub fn effective_extension<'a>(path: &'a str, content: &str) -> &'a str {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    if KNOWN_EXTENSIONS.contains(&ext) {
+        return ext;
+    }
+    from_shebang(content)
+        .or_else(|| sniff(content))
+        .unwrap_or("")
+}
+
+/// Map a `#!` interpreter line to an extension
+fn from_shebang(content: &str) -> Option<&'static str> {
+    let line = content.lines().next()?.strip_prefix("#!")?;
+    let mut words = line.split_whitespace();
+    let mut interpreter = basename(words.next()?);
+    if interpreter == "env" {
+        // `#!/usr/bin/env -S node --flag`: skip env's own options
+        interpreter = words.find(|w| !w.starts_with('-')).map(basename)?;
+    }
+
+    let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    match name {
+        "python" => Some("py"),
+        "ruby" => Some("rb"),
+        "node" | "deno" | "bun" => Some("js"),
+        "bash" | "sh" | "zsh" | "dash" | "ksh" => Some("sh"),
+        _ => None,
+    }
+}
+
+/// Guess the language from tell-tale markers in the content
+fn sniff(content: &str) -> Option<&'static str> {
+    let has_line = |prefix: &str| content.lines().any(|l| l.trim_start().starts_with(prefix));
+
+    if has_line("package main") {
+        Some("go")
+    } else if has_line("def ") && content.lines().any(|l| l.trim() == "end") {
+        Some("rb")
+    } else if has_line("def ") {
+        Some("py")
+    } else if has_line("function ") {
+        Some("js")
+    } else {
+        None
+    }
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_known_extension_checks_list() {
+        assert!(has_known_extension("src/main.rs"));
+        assert!(!has_known_extension("notes.txt"));
+        assert!(!has_known_extension("Makefile"));
+    }
+
+    #[test]
+    fn known_extension_wins() {
+        assert_eq!(effective_extension("main.rs", "#!/usr/bin/env ruby"), "rs");
+    }
+
+    #[test]
+    fn shebang_interpreters() {
+        let cases = [
+            ("#!/usr/bin/env ruby\n", "rb"),
+            ("#!/usr/bin/python3\n", "py"),
+            ("#!/usr/bin/env python3.11\n", "py"),
+            ("#!/usr/bin/env -S node --no-warnings\n", "js"),
+            ("#!/bin/bash\n", "sh"),
+            ("#!/bin/sh -e\n", "sh"),
+        ];
+        for (content, ext) in cases {
+            assert_eq!(effective_extension("bin/tool", content), ext, "{content}");
+        }
+    }
+
+    #[test]
+    fn unknown_shebang_falls_back_to_sniffing() {
+        assert_eq!(
+            effective_extension("bin/x", "#!/usr/bin/perl\nfunction a() {}\n"),
+            "js"
+        );
+    }
+
+    #[test]
+    fn sniff_markers() {
+        assert_eq!(
+            effective_extension("x", "package main\n\nfunc main() {}\n"),
+            "go"
+        );
+        assert_eq!(effective_extension("x", "def run\n  1\nend\n"), "rb");
+        assert_eq!(effective_extension("x", "def run():\n    pass\n"), "py");
+        assert_eq!(effective_extension("x", "plain text"), "");
+    }
+}
@@ -0,0 +1,159 @@
+//! Directory-level repo map: public items per file
+
+use ignore::WalkBuilder;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use super::interface::extract_interface;
+use super::language::has_known_extension;
+use super::types::OutlineItem;
+
+/// Public items of one file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileMap {
+    /// Path relative to the mapped directory
+    pub path: String,
+    pub items: Vec<OutlineItem>,
+    /// Items left out by the per-file cap
+    pub omitted: usize,
+}
+
+/// Shape of a directory tree
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepoMap {
+    pub files: Vec<FileMap>,
+    /// Items left out by the total cap (excluding per-file omissions)
+    pub omitted: usize,
+}
+
+/// Walk `root` (honouring `.gitignore`) and extract each file's interface
+///
+/// Files with no public items are skipped. At most `max_items` items are
+/// kept per file and `max_total` overall; the rest are counted in `omitted`.
+pub fn build_map(root: &Path, max_items: usize, max_total: usize) -> RepoMap {
+    let mut paths: Vec<_> = WalkBuilder::new(root)
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .map(|entry| entry.into_path())
+        .filter(|path| has_known_extension(&path.to_string_lossy()))
+        .collect();
+    paths.sort();
+
+    let files: Vec<FileMap> = paths
+        .par_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            let items = extract_interface(&content, &path.to_string_lossy());
+            if items.is_empty() {
+                return None;
+            }
+            let rel = path.strip_prefix(root).unwrap_or(path);
+            Some(cap_file(
+                rel.to_string_lossy().into_owned(),
+                items,
+                max_items,
+            ))
+        })
+        .collect();
+
+    cap_total(files, max_total)
+}
+
+/// Keep the first `max_items` items of a file
+fn cap_file(path: String, mut items: Vec<OutlineItem>, max_items: usize) -> FileMap {
+    let omitted = items.len().saturating_sub(max_items);
+    items.truncate(max_items);
+    FileMap {
+        path,
+        items,
+        omitted,
+    }
+}
+
+/// Drop items (and then whole files) once `max_total` items are listed
+fn cap_total(files: Vec<FileMap>, max_total: usize) -> RepoMap {
+    let mut budget = max_total;
+    let mut omitted = 0;
+    let mut kept = Vec::new();
+
+    for mut file in files {
+        if budget == 0 {
+            omitted += file.items.len();
+            continue;
+        }
+        if file.items.len() > budget {
+            omitted += file.items.len() - budget;
+            file.items.truncate(budget);
+        }
+        budget -= file.items.len();
+        kept.push(file);
+    }
+
+    RepoMap {
+        files: kept,
+        omitted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::types::ItemKind;
+
+    fn items(n: usize) -> Vec<OutlineItem> {
+        (1..=n)
+            .map(|i| OutlineItem::new(i, format!("pub fn f{}()", i), 0, ItemKind::Function))
+            .collect()
+    }
+
+    #[test]
+    fn cap_file_counts_omitted() {
+        let file = cap_file("a.rs".to_string(), items(5), 3);
+        assert_eq!(file.items.len(), 3);
+        assert_eq!(file.omitted, 2);
+
+        let file = cap_file("a.rs".to_string(), items(2), 3);
+        assert_eq!(file.omitted, 0);
+    }
+
+    #[test]
+    fn cap_total_truncates_then_drops_files() {
+        let files = vec![
+            cap_file("a.rs".to_string(), items(3), 10),
+            cap_file("b.rs".to_string(), items(3), 10),
+            cap_file("c.rs".to_string(), items(3), 10),
+        ];
+        let map = cap_total(files, 4);
+        assert_eq!(map.files.len(), 2);
+        assert_eq!(map.files[1].items.len(), 1);
+        assert_eq!(map.omitted, 5);
+    }
+
+    #[test]
+    fn build_map_respects_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        // `ignore` only honours .gitignore inside a git repository
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("target")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub fn visible() {}\nfn hidden() {}\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/private.rs"), "fn nothing_public() {}\n").unwrap();
+        fs::write(root.join("target/gen.rs"), "pub fn generated() {}\n").unwrap();
+        fs::write(root.join("notes.txt"), "pub fn not_code() {}\n").unwrap();
+
+        let map = build_map(root, 10, 100);
+        assert_eq!(map.files.len(), 1);
+        assert_eq!(map.files[0].path, "src/lib.rs");
+        assert_eq!(map.files[0].items.len(), 1);
+        assert!(map.files[0].items[0].text.contains("visible"));
+    }
+}
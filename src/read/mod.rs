@@ -1,22 +1,37 @@
 mod around;
+mod c_family;
 mod cli;
 mod diff;
 mod display;
+mod function;
 mod interface;
+mod language;
+mod map;
 mod outline;
 mod service;
 mod types;
 
-pub use cli::ReadArgs;
+pub use cli::{AroundSpec, OutlineSort, ReadArgs};
 pub use types::ReadOutput;
 
+use anstream::print;
 use anyhow::Result;
 
+use crate::util::OutputFormat;
+
 /// Run the read command (CLI entry point - formats and prints)
 #[cfg(not(tarpaulin_include))]
 pub fn run(args: ReadArgs) -> Result<()> {
+    let (format, no_numbers) = (OutputFormat::resolve(args.json), args.no_numbers);
     let output = service::run(args)?;
-    let formatted = display::format(&output);
+    if format != OutputFormat::Table {
+        return display::print_items(&output, format);
+    }
+    let formatted = if no_numbers {
+        display::format_bare(&output)
+    } else {
+        display::format(&output)
+    };
     print!("{}", formatted);
     Ok(())
 }
@@ -0,0 +1,353 @@
+//! End-line computation for outline items
+//!
+//! Each language family delimits bodies differently: braces (Rust, Go, JS,
+//! C-family), indentation (Python), `end` keywords (Ruby) or the next heading
+//! (Markdown). The extractors only know where an item starts; this pass walks
+//! forward from there to find where it ends.
+
+use crate::read::types::{FileOutline, ItemKind};
+
+/// How item bodies are delimited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockStyle {
+    /// Braces, skipping strings and comments as the language writes them
+    Braces(Syntax),
+    Indent,
+    RubyEnd,
+    Headings,
+}
+
+impl BlockStyle {
+    /// Block style for a file extension, if the outline supports it
+    pub fn for_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "py" => Some(Self::Indent),
+            "rb" => Some(Self::RubyEnd),
+            "md" | "markdown" => Some(Self::Headings),
+            "rs" => Some(Self::Braces(Syntax::Rust)),
+            "go" => Some(Self::Braces(Syntax::Go)),
+            "js" | "ts" | "jsx" | "tsx" | "mjs" => Some(Self::Braces(Syntax::Js)),
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" | "java" => {
+                Some(Self::Braces(Syntax::C))
+            }
+            "sh" | "bash" => Some(Self::Braces(Syntax::Shell)),
+            _ => None,
+        }
+    }
+}
+
+/// String and comment rules of a brace language, so braces inside them
+/// are not counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    /// `"` strings, raw strings, char literals (not lifetimes), nested
+    /// block comments
+    Rust,
+    /// C, C++ and Java: `"` strings, `'` char literals
+    C,
+    /// `"` strings, `'` runes, backtick raw strings
+    Go,
+    /// `'`, `"` and backtick template strings
+    Js,
+    /// `'` (no escapes) and `"` strings, `#` comments
+    Shell,
+}
+
+impl Syntax {
+    /// Whether `c` opens a string (or char literal) handled as one
+    fn is_quote(self, c: char) -> bool {
+        match self {
+            Self::Rust => c == '"',
+            Self::C => c == '"' || c == '\'',
+            Self::Go | Self::Js => c == '"' || c == '\'' || c == '`',
+            Self::Shell => c == '"' || c == '\'',
+        }
+    }
+
+    /// Whether a backslash escapes the next character inside `quote`
+    fn escapes_in(self, quote: char) -> bool {
+        !matches!((self, quote), (Self::Go, '`') | (Self::Shell, '\''))
+    }
+
+    /// Whether a string opened by `quote` may run onto the next line; the
+    /// others end at the line break, so a stray apostrophe (JSX text, a
+    /// bad parse) can't swallow the rest of the file
+    fn spans_lines(self, quote: char) -> bool {
+        match self {
+            Self::Rust | Self::Shell => true,
+            Self::Go | Self::Js => quote == '`',
+            Self::C => false,
+        }
+    }
+
+    /// Whether `//` line and `/* */` block comments exist
+    fn has_slash_comments(self) -> bool {
+        self != Self::Shell
+    }
+}
+
+/// Fill in `end_line` for every item in the outline
+pub fn assign_end_lines(content: &str, style: BlockStyle, outline: &mut FileOutline) {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.is_empty() {
+        return;
+    }
+
+    let ends: Vec<usize> = outline
+        .items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let start = item.line.saturating_sub(1).min(lines.len() - 1);
+            let end = match (style, &item.kind) {
+                (BlockStyle::Headings, ItemKind::Heading(level)) => {
+                    heading_end(&outline.items[idx + 1..], *level, lines.len())
+                }
+                (BlockStyle::Headings, _) => start,
+                (BlockStyle::Braces(syntax), _) => brace_end(&lines, start, syntax),
+                (BlockStyle::Indent, _) => indent_end(&lines, start),
+                (BlockStyle::RubyEnd, _) => ruby_end(&lines, start),
+            };
+            end + 1
+        })
+        .collect();
+
+    for (item, end) in outline.items.iter_mut().zip(ends) {
+        item.end_line = end.max(item.line);
+    }
+}
+
+/// Find the line closing the first brace block opened at or after `start`
+///
+/// Returns `start`'s statement end instead when a `;` comes before any `{`
+/// (e.g. `mod foo;`, a prototype, or a trait method without a body).
+fn brace_end(lines: &[&str], start: usize, syntax: Syntax) -> usize {
+    let mut scanner = CodeScanner::new(syntax);
+    let mut depth = 0usize;
+    let mut opened = false;
+
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        for ch in scanner.code(line).chars() {
+            match ch {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' if opened => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return idx;
+                    }
+                }
+                ';' if !opened => return idx,
+                _ => {}
+            }
+        }
+    }
+
+    if opened {
+        lines.len() - 1
+    } else {
+        start
+    }
+}
+
+/// Where the scanner is between lines
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Code,
+    /// String opened by this quote
+    Str(char),
+    /// Raw string closed by `"` and this many `#`
+    RawStr(usize),
+    /// Block comment, nested this deep
+    BlockComment(usize),
+}
+
+/// Strips strings, char literals and comments line by line, carrying
+/// multi-line strings and block comments over to the next line
+#[derive(Debug)]
+struct CodeScanner {
+    syntax: Syntax,
+    mode: Mode,
+}
+
+impl CodeScanner {
+    fn new(syntax: Syntax) -> Self {
+        Self {
+            syntax,
+            mode: Mode::Code,
+        }
+    }
+
+    /// The code characters of `line`
+    fn code(&mut self, line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            match self.mode {
+                Mode::Str(quote) => {
+                    if c == '\\' && self.syntax.escapes_in(quote) {
+                        i += 1;
+                    } else if c == quote {
+                        self.mode = Mode::Code;
+                    }
+                }
+                Mode::RawStr(hashes) => {
+                    if c == '"' && closes_raw(&chars[i + 1..], hashes) {
+                        self.mode = Mode::Code;
+                        i += hashes;
+                    }
+                }
+                Mode::BlockComment(depth) => {
+                    if c == '*' && next == Some('/') {
+                        self.mode = match depth {
+                            1 => Mode::Code,
+                            _ => Mode::BlockComment(depth - 1),
+                        };
+                        i += 1;
+                    } else if c == '/' && next == Some('*') && self.syntax == Syntax::Rust {
+                        self.mode = Mode::BlockComment(depth + 1);
+                        i += 1;
+                    }
+                }
+                Mode::Code => {
+                    let slash_comments = self.syntax.has_slash_comments();
+                    match (c, next) {
+                        ('/', Some('/')) if slash_comments => break,
+                        ('/', Some('*')) if slash_comments => {
+                            self.mode = Mode::BlockComment(1);
+                            i += 1;
+                        }
+                        ('#', _) if self.syntax == Syntax::Shell && starts_word(&chars, i) => break,
+                        ('\\', _) if self.syntax == Syntax::Shell => i += 1,
+                        (q, _) if self.syntax.is_quote(q) => self.mode = Mode::Str(q),
+                        ('\'', _) if self.syntax == Syntax::Rust => {
+                            i += char_literal_len(&chars[i..]).saturating_sub(1)
+                        }
+                        _ => match raw_string_hashes(&chars, i) {
+                            Some(hashes) if self.syntax == Syntax::Rust => {
+                                self.mode = Mode::RawStr(hashes);
+                                i += hashes + 1;
+                            }
+                            _ => out.push(c),
+                        },
+                    }
+                }
+            }
+            i += 1;
+        }
+        if let Mode::Str(quote) = self.mode {
+            if !self.syntax.spans_lines(quote) {
+                self.mode = Mode::Code;
+            }
+        }
+        out
+    }
+}
+
+/// `#` count of a raw string (`r"`, `r#"`, ...) starting at `chars[i]`
+fn raw_string_hashes(chars: &[char], i: usize) -> Option<usize> {
+    if chars[i] != 'r' {
+        return None;
+    }
+    let prev = i.checked_sub(1).map(|p| chars[p]);
+    let starts_word = match prev {
+        Some('b') => i < 2 || !is_ident(chars[i - 2]),
+        Some(p) => !is_ident(p),
+        None => true,
+    };
+    if !starts_word {
+        return None;
+    }
+    let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+    (chars.get(i + 1 + hashes) == Some(&'"')).then_some(hashes)
+}
+
+/// Whether the text after a `"` is the `#`s closing a raw string
+fn closes_raw(rest: &[char], hashes: usize) -> bool {
+    rest.len() >= hashes && rest[..hashes].iter().all(|&c| c == '#')
+}
+
+/// Length of a char literal at the start of `chars` (`'x'`, `'\n'`,
+/// `'\u{7b}'`), or 1 for a lone quote such as a lifetime
+fn char_literal_len(chars: &[char]) -> usize {
+    match chars.get(1) {
+        Some('\\') => chars
+            .iter()
+            .skip(3)
+            .position(|&c| c == '\'')
+            .map_or(1, |pos| pos + 4),
+        Some(_) if chars.get(2) == Some(&'\'') => 3,
+        _ => 1,
+    }
+}
+
+/// Whether `chars[i]` starts a shell word (line start or after a blank),
+/// where `#` opens a comment rather than `$#` or `${#var}`
+fn starts_word(chars: &[char], i: usize) -> bool {
+    i == 0 || chars[i - 1].is_whitespace() || chars[i - 1] == ';'
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Last line of an indentation-delimited block (Python)
+fn indent_end(lines: &[&str], start: usize) -> usize {
+    let base = indent_of(lines[start]);
+
+    // Skip past a signature whose parameters wrap onto following lines
+    let mut body_start = start;
+    let mut parens = 0isize;
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        parens += line.matches('(').count() as isize - line.matches(')').count() as isize;
+        body_start = idx;
+        if parens <= 0 {
+            break;
+        }
+    }
+
+    let mut end = body_start;
+    for (idx, line) in lines.iter().enumerate().skip(body_start + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent_of(line) <= base {
+            break;
+        }
+        end = idx;
+    }
+    end
+}
+
+/// Line of the `end` closing a Ruby block, or `start` for one-liners
+fn ruby_end(lines: &[&str], start: usize) -> usize {
+    let base = indent_of(lines[start]);
+
+    for (idx, line) in lines.iter().enumerate().skip(start + 1) {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || indent_of(line) > base {
+            continue;
+        }
+        let is_end = trimmed == "end" || trimmed.starts_with("end ") || trimmed.starts_with("end.");
+        return if is_end { idx } else { start };
+    }
+    start
+}
+
+/// A heading's section runs until the next heading of the same or higher level
+fn heading_end(following: &[crate::read::types::OutlineItem], level: u8, total: usize) -> usize {
+    following
+        .iter()
+        .find(|item| matches!(item.kind, ItemKind::Heading(l) if l <= level))
+        .map_or(total - 1, |next| next.line.saturating_sub(2))
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
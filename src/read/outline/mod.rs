@@ -1,17 +1,20 @@
+use super::c_family::{CFamily, CFamilyMatcher};
+use super::language::effective_extension;
+use super::types::{FileOutline, ItemKind, OutlineItem};
 use regex::Regex;
-use std::path::Path;
 
-use super::types::{FileOutline, ItemKind, OutlineItem};
+mod bounds;
+mod rust;
+
+use bounds::BlockStyle;
+use rust::extract_rust_outline;
 
 #[cfg(test)]
 mod tests;
 
 /// Extract outline from file content based on extension
 pub fn extract_outline(content: &str, path: &str) -> FileOutline {
-    let ext = Path::new(path)
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("");
+    let ext = effective_extension(path, content);
 
     let mut outline = FileOutline::new();
 
@@ -21,104 +24,19 @@ pub fn extract_outline(content: &str, path: &str) -> FileOutline {
         "js" | "ts" | "jsx" | "tsx" | "mjs" => extract_js_outline(content, &mut outline),
         "rb" => extract_ruby_outline(content, &mut outline),
         "go" => extract_go_outline(content, &mut outline),
+        "c" | "h" => extract_c_outline(content, &mut outline),
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" => extract_cpp_outline(content, &mut outline),
+        "java" => extract_java_outline(content, &mut outline),
+        "sh" | "bash" => extract_shell_outline(content, &mut outline),
         "md" | "markdown" => extract_markdown_outline(content, &mut outline),
         _ => {}
     }
 
-    outline
-}
-
-/// Extract Rust outline (functions, structs, enums, traits, impls)
-fn extract_rust_outline(content: &str, outline: &mut FileOutline) {
-    let fn_re = Regex::new(
-        r"^(\s*)(pub\s+)?(async\s+)?fn\s+(\w+)\s*(<[^>]+>)?\s*\([^)]*\)(\s*->\s*[^{]+)?",
-    )
-    .unwrap();
-    let struct_re = Regex::new(r"^(\s*)(pub\s+)?struct\s+(\w+)(<[^>]+>)?").unwrap();
-    let enum_re = Regex::new(r"^(\s*)(pub\s+)?enum\s+(\w+)(<[^>]+>)?").unwrap();
-    let trait_re = Regex::new(r"^(\s*)(pub\s+)?trait\s+(\w+)(<[^>]+>)?").unwrap();
-    let impl_re = Regex::new(r"^(\s*)impl\s*(<[^>]+>)?\s*(\w+)(<[^>]+>)?(\s+for\s+\w+)?").unwrap();
-    let mod_re = Regex::new(r"^(\s*)(pub\s+)?mod\s+(\w+)").unwrap();
-    let const_re = Regex::new(r"^(\s*)(pub\s+)?const\s+(\w+)").unwrap();
-    let type_re = Regex::new(r"^(\s*)(pub\s+)?type\s+(\w+)").unwrap();
-
-    for (line_num, line) in content.lines().enumerate() {
-        let line_num = line_num + 1;
-
-        if let Some(caps) = fn_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim_end_matches('{').trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Function,
-            ));
-        } else if let Some(caps) = struct_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Struct,
-            ));
-        } else if let Some(caps) = enum_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Enum,
-            ));
-        } else if let Some(caps) = trait_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Trait,
-            ));
-        } else if let Some(caps) = impl_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Impl,
-            ));
-        } else if let Some(caps) = mod_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Module,
-            ));
-        } else if let Some(caps) = const_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Const,
-            ));
-        } else if let Some(caps) = type_re.captures(line) {
-            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
-            let sig = caps.get(0).unwrap().as_str().trim();
-            outline.push(OutlineItem::new(
-                line_num,
-                sig.to_string(),
-                indent / 4,
-                ItemKind::Type,
-            ));
-        }
+    if let Some(style) = BlockStyle::for_extension(ext) {
+        bounds::assign_end_lines(content, style, &mut outline);
     }
+
+    outline
 }
 
 /// Extract Python outline (functions, classes)
@@ -286,6 +204,50 @@ fn extract_go_outline(content: &str, outline: &mut FileOutline) {
     }
 }
 
+/// Extract C outline (functions, structs, unions, enums)
+fn extract_c_outline(content: &str, outline: &mut FileOutline) {
+    extract_c_family_outline(CFamily::C, content, outline);
+}
+
+/// Extract C++ outline (functions, methods, classes, structs, namespaces)
+fn extract_cpp_outline(content: &str, outline: &mut FileOutline) {
+    extract_c_family_outline(CFamily::Cpp, content, outline);
+}
+
+/// Extract Java outline (classes, interfaces, enums, methods)
+fn extract_java_outline(content: &str, outline: &mut FileOutline) {
+    extract_c_family_outline(CFamily::Java, content, outline);
+}
+
+fn extract_c_family_outline(lang: CFamily, content: &str, outline: &mut FileOutline) {
+    let matcher = CFamilyMatcher::new(lang);
+
+    for (line_num, line) in content.lines().enumerate() {
+        if let Some(item) = matcher.match_line(line, line_num + 1) {
+            outline.push(item);
+        }
+    }
+}
+
+/// Extract shell outline (`name() {` and `function name` definitions)
+fn extract_shell_outline(content: &str, outline: &mut FileOutline) {
+    let fn_re =
+        Regex::new(r"^(\s*)(?:function\s+([\w:.-]+)\s*(?:\(\))?|([\w:.-]+)\s*\(\))\s*\{?").unwrap();
+
+    for (line_num, line) in content.lines().enumerate() {
+        if let Some(caps) = fn_re.captures(line) {
+            let indent = caps.get(1).map_or(0, |m| m.as_str().len());
+            let name = caps.get(2).or_else(|| caps.get(3)).unwrap().as_str();
+            outline.push(OutlineItem::new(
+                line_num + 1,
+                format!("{}()", name),
+                indent / 4,
+                ItemKind::Function,
+            ));
+        }
+    }
+}
+
 /// Extract Markdown outline (headings)
 fn extract_markdown_outline(content: &str, outline: &mut FileOutline) {
     let heading_re = Regex::new(r"^(#{1,6})\s+(.+)$").unwrap();
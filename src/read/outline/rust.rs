@@ -0,0 +1,253 @@
+//! Rust outline extraction
+//!
+//! Rust items are matched on *logical* lines: attribute lines are skipped and
+//! signatures that wrap across lines are joined up to their opening `{`.
+
+use regex::Regex;
+
+use crate::read::types::{FileOutline, ItemKind, OutlineItem};
+
+/// Upper bound on lines joined into one signature (guards against runaway joins)
+const MAX_SIGNATURE_LINES: usize = 20;
+
+/// Optional visibility prefix: `pub`, `pub(crate)`, `pub(super)`, `pub(in path)`
+const VIS: &str = r"(?:pub(?:\([^)]*\))?\s+)?";
+
+/// A source line with attributes stripped and a wrapped signature joined
+#[derive(Debug, Clone, PartialEq)]
+struct LogicalLine {
+    /// 1-indexed line where the item itself starts (after any attributes)
+    line: usize,
+    /// Indent of the item, taken from its first attribute when it has one
+    indent: usize,
+    /// Item text without leading whitespace
+    text: String,
+}
+
+/// Extract Rust outline (functions, structs, enums, traits, impls)
+pub fn extract_rust_outline(content: &str, outline: &mut FileOutline) {
+    let fn_re = Regex::new(&format!(
+        r#"^{VIS}(?:default\s+)?(?:const\s+)?(?:async\s+)?(?:unsafe\s+)?(?:extern\s+"[^"]*"\s+)?fn\s+(\w+)\s*(<.*?>)?\s*\((?:[^()]|\([^()]*\))*\)(\s*->\s*[^{{]+)?"#
+    ))
+    .unwrap();
+    let patterns = [
+        (format!(r"^{VIS}struct\s+(\w+)(<[^>]+>)?"), ItemKind::Struct),
+        (format!(r"^{VIS}enum\s+(\w+)(<[^>]+>)?"), ItemKind::Enum),
+        (
+            format!(r"^{VIS}(?:unsafe\s+)?trait\s+(\w+)(<[^>]+>)?"),
+            ItemKind::Trait,
+        ),
+        (
+            r"^(?:unsafe\s+)?impl\s*(<[^>]+>)?\s*(\w+)(<[^>]+>)?(\s+for\s+\w+)?".to_string(),
+            ItemKind::Impl,
+        ),
+        (format!(r"^{VIS}mod\s+(\w+)"), ItemKind::Module),
+        (format!(r"^{VIS}const\s+(\w+)"), ItemKind::Const),
+        (format!(r"^{VIS}type\s+(\w+)"), ItemKind::Type),
+    ];
+    let patterns: Vec<(Regex, ItemKind)> = patterns
+        .into_iter()
+        .map(|(re, kind)| (Regex::new(&re).unwrap(), kind))
+        .collect();
+
+    for logical in logical_lines(content) {
+        let level = logical.indent / 4;
+
+        if let Some(m) = fn_re.find(&logical.text) {
+            let sig = m
+                .as_str()
+                .trim_end_matches('{')
+                .trim_end_matches(';')
+                .trim();
+            outline.push(OutlineItem::new(
+                logical.line,
+                sig.to_string(),
+                level,
+                ItemKind::Function,
+            ));
+            continue;
+        }
+
+        if let Some((m, kind)) = patterns
+            .iter()
+            .find_map(|(re, kind)| re.find(&logical.text).map(|m| (m, kind)))
+        {
+            outline.push(OutlineItem::new(
+                logical.line,
+                m.as_str().trim().to_string(),
+                level,
+                kind.clone(),
+            ));
+        }
+    }
+}
+
+/// Split content into logical lines (attributes skipped, signatures joined)
+fn logical_lines(content: &str) -> Vec<LogicalLine> {
+    let lines: Vec<&str> = content.lines().collect();
+    let fn_start_re = Regex::new(&format!(
+        r#"^{VIS}(?:default\s+)?(?:const\s+)?(?:async\s+)?(?:unsafe\s+)?(?:extern\s+"[^"]*"\s+)?fn\s"#
+    ))
+    .unwrap();
+
+    let mut out = Vec::new();
+    let mut attr_indent: Option<usize> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let raw = lines[i];
+        let indent = raw.len() - raw.trim_start().len();
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("#[") || trimmed.starts_with("#![") {
+            let (rest, consumed) = strip_attributes(&lines[i..]);
+            attr_indent.get_or_insert(indent);
+            i += consumed - 1;
+            if rest.is_empty() {
+                i += 1;
+                continue;
+            }
+            // Attribute followed by an item on the same line: match the remainder
+            out.push(joined(
+                &lines,
+                &mut i,
+                rest,
+                &fn_start_re,
+                attr_indent.take(),
+            ));
+            i += 1;
+            continue;
+        }
+
+        if !trimmed.is_empty() {
+            let indent = attr_indent.take().unwrap_or(indent);
+            out.push(joined(&lines, &mut i, trimmed, &fn_start_re, Some(indent)));
+        }
+        i += 1;
+    }
+
+    out
+}
+
+/// Build a logical line starting at `lines[*i]`, joining a wrapped `fn` signature
+fn joined(
+    lines: &[&str],
+    i: &mut usize,
+    first: &str,
+    fn_start_re: &Regex,
+    indent: Option<usize>,
+) -> LogicalLine {
+    let start = *i;
+    let mut text = first.to_string();
+
+    if fn_start_re.is_match(&text) {
+        while !text.contains('{')
+            && !text.contains(';')
+            && *i + 1 < lines.len()
+            && *i - start < MAX_SIGNATURE_LINES
+        {
+            *i += 1;
+            push_continuation(&mut text, lines[*i].trim());
+        }
+    }
+
+    LogicalLine {
+        line: start + 1,
+        indent: indent.unwrap_or(0),
+        text,
+    }
+}
+
+/// Append a continuation line, spacing it the way rustfmt would print it inline
+fn push_continuation(text: &mut String, next: &str) {
+    if next.is_empty() {
+        return;
+    }
+    if next.starts_with(')') && text.ends_with(',') {
+        text.pop();
+    }
+    let glue = !(text.ends_with('(') || text.ends_with('<') || next.starts_with(')'));
+    if glue {
+        text.push(' ');
+    }
+    text.push_str(next);
+}
+
+/// Skip leading attributes (possibly spanning several lines)
+///
+/// Returns the code after the last attribute on its final line, and how many
+/// lines the attributes occupied.
+fn strip_attributes<'a>(lines: &[&'a str]) -> (&'a str, usize) {
+    let mut depth = 0usize;
+    let mut in_attr = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let mut rest_start = None;
+        for (pos, ch) in line.char_indices() {
+            match ch {
+                '#' if depth == 0 => in_attr = true,
+                '[' if in_attr => depth += 1,
+                ']' if in_attr => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        in_attr = false;
+                        rest_start = Some(pos + 1);
+                    }
+                }
+                c if depth == 0 && !in_attr && !c.is_whitespace() => break,
+                _ => {}
+            }
+        }
+        if depth == 0 {
+            let rest = rest_start.map_or("", |p| line[p..].trim());
+            return (rest, idx + 1);
+        }
+    }
+
+    ("", lines.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logical_lines_skip_attributes() {
+        let lines = logical_lines("    #[test]\n    fn it_works() {}");
+        assert_eq!(
+            lines,
+            vec![LogicalLine {
+                line: 2,
+                indent: 4,
+                text: "fn it_works() {}".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn logical_lines_join_wrapped_signature() {
+        let lines = logical_lines("pub fn wrapped(\n    a: u32,\n    b: u32,\n) -> u32 {\n}");
+        assert_eq!(lines[0].text, "pub fn wrapped(a: u32, b: u32) -> u32 {");
+        assert_eq!(lines[0].line, 1);
+        assert_eq!(lines[1].text, "}");
+    }
+
+    #[test]
+    fn strip_attributes_multiline() {
+        let lines = [
+            "#[cfg_attr(",
+            "    test,",
+            "    derive(Debug)",
+            ")] struct X;",
+        ];
+        assert_eq!(strip_attributes(&lines), ("struct X;", 4));
+    }
+
+    #[test]
+    fn strip_attributes_inline_item() {
+        assert_eq!(
+            strip_attributes(&["#[inline] #[must_use] pub fn f() {}"]),
+            ("pub fn f() {}", 1)
+        );
+    }
+}
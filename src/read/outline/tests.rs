@@ -259,6 +259,282 @@ fn markdown_headings() {
     assert_eq!(outline.items[2].level, 2);
 }
 
+#[test]
+fn c_function() {
+    let content = "static int parse_args(int argc, char **argv) {";
+    let outline = extract_outline(content, "main.c");
+    assert_eq!(outline.len(), 1);
+    assert_eq!(
+        outline.items[0].text,
+        "static int parse_args(int argc, char **argv)"
+    );
+    assert_eq!(outline.items[0].kind, ItemKind::Function);
+}
+
+#[test]
+fn c_pointer_return_and_prototype() {
+    let content = "struct node *node_new(int value);\nvoid node_free(struct node *n);";
+    let outline = extract_outline(content, "node.h");
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline.items[0].text, "struct node *node_new(int value)");
+    assert_eq!(outline.items[1].text, "void node_free(struct node *n)");
+}
+
+#[test]
+fn c_struct_and_enum() {
+    let content = "struct node {\n    int value;\n};\n\ntypedef enum color {\n    RED,\n} color_t;";
+    let outline = extract_outline(content, "node.h");
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline.items[0].kind, ItemKind::Struct);
+    assert!(outline.items[0].text.contains("struct node"));
+    assert_eq!(outline.items[1].kind, ItemKind::Enum);
+}
+
+#[test]
+fn c_statements_excluded() {
+    let content = r#"int main(void) {
+    int x = compute(1);
+    if (x > 0) {
+        return helper(x);
+    } else if (x < 0) {
+        printf("neg\n");
+    }
+    return 0;
+}"#;
+    let outline = extract_outline(content, "main.c");
+    assert_eq!(outline.len(), 1);
+    assert_eq!(outline.items[0].text, "int main(void)");
+}
+
+#[test]
+fn cpp_class_and_methods() {
+    let content = r#"namespace engine {
+
+class Renderer : public Base {
+public:
+    Renderer(int width);
+    void draw() const override;
+    int width() const { return width_; }
+};
+
+Renderer::Renderer(int width) : width_(width) {}
+
+void Renderer::draw() const {
+}
+
+}"#;
+    let outline = extract_outline(content, "renderer.cpp");
+    let texts: Vec<&str> = outline.items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "namespace engine",
+            "class Renderer : public Base",
+            "void draw() const override",
+            "int width() const",
+            "Renderer::Renderer(int width) : width_(width)",
+            "void Renderer::draw() const",
+        ]
+    );
+    assert_eq!(outline.items[0].kind, ItemKind::Module);
+    assert_eq!(outline.items[1].kind, ItemKind::Class);
+    assert_eq!(outline.items[2].level, 1);
+}
+
+#[test]
+fn cpp_struct_template_and_enum_class() {
+    let content = "template <typename T>\nstruct Box {\n};\nenum class Mode : uint8_t {\n};";
+    let outline = extract_outline(content, "box.hpp");
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline.items[0].kind, ItemKind::Struct);
+    assert_eq!(outline.items[1].kind, ItemKind::Enum);
+    assert!(outline.items[1].text.contains("enum class Mode"));
+}
+
+#[test]
+fn java_class_and_methods() {
+    let content = r#"public class UserService extends Base implements Service {
+    private final Repo repo;
+
+    public UserService(Repo repo) {
+        this.repo = repo;
+    }
+
+    public List<User> findAll() throws IOException {
+        return repo.findAll();
+    }
+
+    private static int count(String name) {
+        System.out.println(name);
+        return new Counter(name).value();
+    }
+}"#;
+    let outline = extract_outline(content, "UserService.java");
+    let texts: Vec<&str> = outline.items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "public class UserService extends Base implements Service",
+            "public UserService(Repo repo)",
+            "public List<User> findAll() throws IOException",
+            "private static int count(String name)",
+        ]
+    );
+    assert_eq!(outline.items[0].kind, ItemKind::Class);
+    assert_eq!(outline.items[1].level, 1);
+}
+
+#[test]
+fn java_interface_and_enum() {
+    let content = "public interface Service {\n    void start();\n}\nenum State { ON, OFF }";
+    let outline = extract_outline(content, "Service.java");
+    assert_eq!(outline.len(), 3);
+    assert_eq!(outline.items[0].kind, ItemKind::Trait);
+    assert_eq!(outline.items[1].text, "void start()");
+    assert_eq!(outline.items[2].kind, ItemKind::Enum);
+}
+
+fn spans(outline: &FileOutline) -> Vec<(usize, usize)> {
+    outline.items.iter().map(|i| (i.line, i.end_line)).collect()
+}
+
+#[test]
+fn end_line_rust_braces() {
+    let content = "mod util;\n\nimpl Foo {\n    fn a(&self) {\n        let s = \"}\";\n    }\n\n    fn b(&self) {} // }\n}\n";
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(spans(&outline), vec![(1, 1), (3, 9), (4, 6), (8, 8)]);
+    assert_eq!(outline.items[1].line_count(), 7);
+}
+
+#[test]
+fn end_line_skips_raw_strings_and_block_comments() {
+    let content = r####"fn outline(content: &str) {
+    let re = Regex::new(r"^\s*fn\s+\w+.*\{").unwrap();
+    let raw = r#"{ "a": "}" }"#;
+    let ch = '{';
+    /* a } here
+       and { there */
+    walk(re, raw, ch);
+}
+
+fn next<'a>(s: &'a str) -> &'a str {
+    s
+}
+"####;
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(spans(&outline), vec![(1, 8), (10, 12)]);
+}
+
+#[test]
+fn end_line_js_single_quotes_and_templates() {
+    let js = "function foo() {\n  const s = 'a}b';\n  return s;\n}\n";
+    assert_eq!(spans(&extract_outline(js, "t.js")), vec![(1, 4)]);
+
+    let ts = "export function tpl(x: number) {\n  const t = `}${x}\n  }`;\n  return t;\n}\n";
+    assert_eq!(spans(&extract_outline(ts, "t.ts")), vec![(1, 5)]);
+
+    let jsx = "export function Card() {\n  return <p>Don't</p>;\n}\n";
+    assert_eq!(spans(&extract_outline(jsx, "card.jsx")), vec![(1, 3)]);
+}
+
+#[test]
+fn end_line_shell_quotes_and_comments() {
+    let sh = "setup() {\n  echo 'a}b'\n  # closing } in a comment\n  echo \"${#args[@]} \\\"}\"\n  :\n}\n";
+    assert_eq!(spans(&extract_outline(sh, "setup.sh")), vec![(1, 6)]);
+
+    let bash = "function teardown {\n  echo 'it\\ }'\n  :\n}\n";
+    assert_eq!(spans(&extract_outline(bash, "t.bash")), vec![(1, 4)]);
+}
+
+#[test]
+fn end_line_c_char_literal() {
+    let c = "int close(char c) {\n  if (c == '}') return 1;\n  return 0;\n}\n";
+    assert_eq!(spans(&extract_outline(c, "c.c")), vec![(1, 4)]);
+
+    let go = "func raw() string {\n\treturn `}\\`\n}\n";
+    assert_eq!(spans(&extract_outline(go, "raw.go")), vec![(1, 3)]);
+}
+
+#[test]
+fn end_line_wrapped_signature() {
+    let content = "pub fn wrapped(\n    a: u32,\n) -> u32 {\n    a\n}\n";
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(spans(&outline), vec![(1, 5)]);
+}
+
+#[test]
+fn end_line_go_and_js() {
+    let go = "func main() {\n\tfmt.Println(\"{\")\n}\n";
+    assert_eq!(spans(&extract_outline(go, "main.go")), vec![(1, 3)]);
+
+    let js = "export function f(x) {\n  return { x };\n}\n";
+    assert_eq!(spans(&extract_outline(js, "f.js")), vec![(1, 3)]);
+}
+
+#[test]
+fn end_line_python_indent() {
+    let content = "class A:\n    def f(self):\n        pass\n\n    x = 1\n\nclass B(\n    A,\n):\n    pass\n\ndef top():\n    pass\n";
+    let outline = extract_outline(content, "test.py");
+    assert_eq!(spans(&outline), vec![(1, 5), (2, 3), (7, 10), (12, 13)]);
+}
+
+#[test]
+fn end_line_ruby_end() {
+    let content = "class A\n  def f\n    1\n  end\n\n  attr_reader :x\nend\n";
+    let outline = extract_outline(content, "test.rb");
+    assert_eq!(spans(&outline)[0], (1, 7));
+    assert_eq!(spans(&outline)[1], (2, 4));
+}
+
+#[test]
+fn end_line_markdown_sections() {
+    let content = "# Title\ntext\n## A\na\n## B\nb\n";
+    let outline = extract_outline(content, "README.md");
+    assert_eq!(spans(&outline), vec![(1, 6), (3, 4), (5, 6)]);
+}
+
+#[test]
+fn end_line_unterminated_block() {
+    let content = "fn broken() {\n    loop {\n";
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(spans(&outline), vec![(1, 2)]);
+}
+
+#[test]
+fn shebang_ruby_script() {
+    let content = "#!/usr/bin/env ruby\n\nclass Deploy\n  def run\n    puts 1\n  end\nend\n";
+    let outline = extract_outline(content, "bin/deploy");
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline.items[0].kind, ItemKind::Class);
+    assert_eq!(outline.items[1].text, "def run");
+    assert_eq!(outline.items[1].end_line, 6);
+}
+
+#[test]
+fn shebang_python_script() {
+    let content = "#!/usr/bin/env python3\nimport sys\n\ndef main(argv):\n    return 0\n";
+    let outline = extract_outline(content, "scripts/check");
+    assert_eq!(outline.len(), 1);
+    assert!(outline.items[0].text.starts_with("def main"));
+}
+
+#[test]
+fn shebang_bash_functions() {
+    let content = "#!/bin/bash\nsetup() {\n  echo hi\n}\n\nfunction teardown {\n  :\n}\n";
+    let outline = extract_outline(content, "bin/ci");
+    let texts: Vec<&str> = outline.items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(texts, vec!["setup()", "teardown()"]);
+    assert_eq!(outline.items[0].end_line, 4);
+}
+
+#[test]
+fn sniffed_go_without_extension() {
+    let content = "package main\n\nfunc main() {\n}\n";
+    let outline = extract_outline(content, "tool");
+    assert_eq!(outline.len(), 1);
+    assert_eq!(outline.items[0].kind, ItemKind::Function);
+}
+
 #[test]
 fn unknown_extension() {
     let content = "some content";
@@ -321,3 +597,62 @@ fn js_method_async() {
     assert_eq!(outline.len(), 2);
     assert!(outline.items[1].text.contains("async fetch"));
 }
+
+#[test]
+fn rust_attributed_function() {
+    let content = r#"#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+}
+
+impl Foo {
+    #[allow(dead_code)]
+    #[cfg(test)]
+    pub(crate) fn helper(&self) {}
+}
+"#;
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(outline.len(), 3);
+    assert_eq!(outline.items[0].line, 2);
+    assert_eq!(
+        outline.items[0].text,
+        "async fn main() -> anyhow::Result<()>"
+    );
+    assert_eq!(outline.items[2].line, 8);
+    assert_eq!(outline.items[2].level, 1);
+    assert_eq!(outline.items[2].text, "pub(crate) fn helper(&self)");
+}
+
+#[test]
+fn rust_multiline_async_fn() {
+    let content = r#"impl Client {
+    pub async fn fetch_page(
+        &self,
+        url: &str,
+        retries: u32,
+    ) -> Result<String> {
+        todo()
+    }
+}
+"#;
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(outline.len(), 2);
+    let item = &outline.items[1];
+    assert_eq!(item.kind, ItemKind::Function);
+    assert_eq!(item.line, 2);
+    assert_eq!(item.level, 1);
+    assert_eq!(
+        item.text,
+        "pub async fn fetch_page(&self, url: &str, retries: u32) -> Result<String>"
+    );
+}
+
+#[test]
+fn rust_fn_with_nested_parens_and_generics() {
+    let content = "pub fn apply<F: Fn(u32) -> u32>(f: F, g: impl Fn(u8)) -> u32 {";
+    let outline = extract_outline(content, "test.rs");
+    assert_eq!(outline.len(), 1);
+    assert_eq!(
+        outline.items[0].text,
+        "pub fn apply<F: Fn(u32) -> u32>(f: F, g: impl Fn(u8)) -> u32"
+    );
+}
@@ -2,27 +2,49 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
 
-use super::around::extract_lines_around;
-use super::cli::ReadArgs;
+use super::around::{extract_line_range, extract_lines_around};
+use super::cli::{OutlineSort, ReadArgs};
 use super::diff::git_diff;
+use super::function::extract_function;
 use super::interface::extract_interface;
+use super::map::build_map;
 use super::outline::extract_outline;
-use super::types::ReadOutput;
+use super::types::{FileOutline, ReadOutput};
 
 /// Run the read command - returns data, never prints
 pub fn run(args: ReadArgs) -> Result<ReadOutput> {
     let path = resolve_path(&args.path)?;
+    if args.map {
+        if !path.is_dir() {
+            anyhow::bail!("--map needs a directory: {}", args.path);
+        }
+        return Ok(ReadOutput::Map(build_map(
+            &path,
+            args.max_items,
+            args.max_total,
+        )));
+    }
+
     let content = fs::read_to_string(&path)
         .with_context(|| format!("Failed to read file: {}", path.display()))?;
 
-    if let Some(center) = args.around {
+    if let Some(range) = args.lines {
+        // Explicit line range
+        let (lines, total_lines) = extract_line_range(&content, range.start, range.end);
+        Ok(ReadOutput::Lines { lines, total_lines })
+    } else if let Some(around) = args.around {
         // Lines around a specific line
-        let (lines, total_lines) = extract_lines_around(&content, center, args.context);
+        let context = around.context.unwrap_or(args.context);
+        let (lines, total_lines) = extract_lines_around(&content, around.line, context);
         Ok(ReadOutput::Around {
             lines,
-            center,
+            center: around.line,
             total_lines,
         })
+    } else if let Some(name) = &args.function {
+        // Body of a single function
+        let (_, body) = extract_function(&content, path.to_str().unwrap_or(""), name)?;
+        Ok(ReadOutput::Function(body))
     } else if args.diff {
         // Git diff
         let commit = if args.commit == "HEAD" {
@@ -38,7 +60,10 @@ pub fn run(args: ReadArgs) -> Result<ReadOutput> {
         Ok(ReadOutput::Interface(items))
     } else if args.outline {
         // File outline
-        let outline = extract_outline(&content, path.to_str().unwrap_or(""));
+        let mut outline = extract_outline(&content, path.to_str().unwrap_or(""));
+        if args.sort == OutlineSort::Size {
+            sort_by_size(&mut outline);
+        }
         Ok(ReadOutput::Outline(outline))
     } else {
         // Full file content
@@ -46,6 +71,13 @@ pub fn run(args: ReadArgs) -> Result<ReadOutput> {
     }
 }
 
+/// Order outline items longest first (stable, so ties keep source order)
+fn sort_by_size(outline: &mut FileOutline) {
+    outline
+        .items
+        .sort_by_key(|item| std::cmp::Reverse(item.line_count()));
+}
+
 /// Resolve a path to absolute
 fn resolve_path(path_str: &str) -> Result<std::path::PathBuf> {
     let path = Path::new(path_str);
@@ -64,6 +96,7 @@ fn resolve_path(path_str: &str) -> Result<std::path::PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::read::cli::{AroundSpec, LineRange};
 
     #[test]
     fn resolve_path_absolute() {
@@ -84,47 +117,216 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn sort_by_size_longest_first() {
+        let mut outline = extract_outline(
+            "fn short() {}\n\nfn long() {\n    a();\n    b();\n}\n\nfn mid() {\n    a();\n}\n",
+            "test.rs",
+        );
+        sort_by_size(&mut outline);
+        let lines: Vec<usize> = outline.items.iter().map(|i| i.line_count()).collect();
+        assert_eq!(lines, vec![4, 3, 1]);
+        assert!(outline.items[0].text.contains("long"));
+    }
+
     // Integration tests - verify correct ReadOutput variant is returned
     #[test]
     fn run_returns_outline() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: true,
+            sort: OutlineSort::Line,
             interface: false,
             around: None,
             context: 10,
+            function: None,
             diff: false,
             commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Outline(_)));
     }
 
+    #[test]
+    fn run_outline_sorted_by_size() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/read/service.rs").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
+            outline: true,
+            sort: OutlineSort::Size,
+            interface: false,
+            around: None,
+            context: 10,
+            function: None,
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
+        };
+        let ReadOutput::Outline(outline) = run(args).unwrap() else {
+            panic!("expected outline");
+        };
+        let counts: Vec<usize> = outline.items.iter().map(|i| i.line_count()).collect();
+        assert!(counts.windows(2).all(|w| w[0] >= w[1]));
+    }
+
     #[test]
     fn run_returns_around() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: false,
+            sort: OutlineSort::Line,
             interface: false,
-            around: Some(5),
+            around: Some(AroundSpec {
+                line: 5,
+                context: None,
+            }),
             context: 3,
+            function: None,
             diff: false,
             commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Around { .. }));
     }
 
+    #[test]
+    fn run_returns_lines_with_around_context() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
+            outline: false,
+            sort: OutlineSort::Line,
+            interface: false,
+            around: Some(AroundSpec {
+                line: 5,
+                context: Some(1),
+            }),
+            context: 10,
+            function: None,
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
+        };
+        let ReadOutput::Around { lines, .. } = run(args).unwrap() else {
+            panic!("expected Around");
+        };
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn run_returns_lines() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
+            outline: false,
+            sort: OutlineSort::Line,
+            interface: false,
+            around: None,
+            context: 10,
+            function: None,
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: Some(LineRange {
+                start: 2,
+                end: 100_000,
+            }),
+            no_numbers: false,
+            json: false,
+        };
+        let ReadOutput::Lines { lines, total_lines } = run(args).unwrap() else {
+            panic!("expected Lines");
+        };
+        assert_eq!(lines[0].0, 2);
+        assert_eq!(lines.len(), total_lines - 1);
+    }
+
+    #[test]
+    fn run_returns_map() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/read").to_string(),
+            map: true,
+            max_items: 20,
+            max_total: 500,
+            outline: false,
+            sort: OutlineSort::Line,
+            interface: false,
+            around: None,
+            context: 10,
+            function: None,
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
+        };
+        let ReadOutput::Map(map) = run(args).unwrap() else {
+            panic!("expected Map");
+        };
+        assert!(map.files.iter().any(|f| f.path == "map.rs"));
+    }
+
+    #[test]
+    fn run_map_rejects_file() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: true,
+            max_items: 20,
+            max_total: 500,
+            outline: false,
+            sort: OutlineSort::Line,
+            interface: false,
+            around: None,
+            context: 10,
+            function: None,
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
+        };
+        assert!(run(args).is_err());
+    }
+
     #[test]
     fn run_returns_full() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: false,
+            sort: OutlineSort::Line,
             interface: false,
             around: None,
             context: 10,
+            function: None,
             diff: false,
             commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Full(_)));
@@ -134,27 +336,69 @@ mod tests {
     fn run_returns_interface() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/main.rs").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: false,
+            sort: OutlineSort::Line,
             interface: true,
             around: None,
             context: 10,
+            function: None,
             diff: false,
             commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Interface(_)));
     }
 
+    #[test]
+    fn run_returns_function() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/read/service.rs").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
+            outline: false,
+            sort: OutlineSort::Line,
+            interface: false,
+            around: None,
+            context: 10,
+            function: Some("resolve_path".to_string()),
+            diff: false,
+            commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
+        };
+        let ReadOutput::Function(body) = run(args).unwrap() else {
+            panic!("expected function");
+        };
+        assert!(body.starts_with("fn resolve_path("));
+        assert!(body.ends_with('}'));
+    }
+
     #[test]
     fn run_returns_diff() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: false,
+            sort: OutlineSort::Line,
             interface: false,
             around: None,
             context: 10,
+            function: None,
             diff: true,
             commit: "HEAD".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Diff(_)));
@@ -164,12 +408,20 @@ mod tests {
     fn run_diff_specific_commit() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            map: false,
+            max_items: 20,
+            max_total: 500,
             outline: false,
+            sort: OutlineSort::Line,
             interface: false,
             around: None,
             context: 10,
+            function: None,
             diff: true,
             commit: "HEAD~1".to_string(),
+            lines: None,
+            no_numbers: false,
+            json: false,
         };
         // This may fail if HEAD~1 doesn't exist, but shouldn't panic
         let _ = run(args);
@@ -1,14 +1,22 @@
+use serde::Serialize;
+
+pub use super::map::RepoMap;
+
 /// An item in a file outline (function, struct, class, heading, etc.)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct OutlineItem {
     /// Line number where this item starts (1-indexed)
     pub line: usize,
     /// The signature or heading text
+    #[serde(rename = "signature")]
     pub text: String,
     /// Indent level (0 for top-level)
+    #[serde(rename = "depth")]
     pub level: usize,
     /// Kind of item (function, struct, class, heading, etc.)
     pub kind: ItemKind,
+    /// Line number where this item ends (1-indexed, inclusive)
+    pub end_line: usize,
 }
 
 impl OutlineItem {
@@ -18,12 +26,20 @@ impl OutlineItem {
             text,
             level,
             kind,
+            end_line: line,
         }
     }
+
+    /// Number of lines the item spans, including its signature
+    pub fn line_count(&self) -> usize {
+        self.end_line.saturating_sub(self.line) + 1
+    }
 }
 
 /// Kind of outline item
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Serializes as the variant name, except `Heading(2)` → `{"heading": 2}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ItemKind {
     Function,
     Struct,
@@ -34,6 +50,7 @@ pub enum ItemKind {
     Module,
     Const,
     Type,
+    #[serde(rename = "heading")]
     Heading(u8), // Heading level (1-6)
     Other,
 }
@@ -101,8 +118,17 @@ pub enum ReadOutput {
         center: usize,
         total_lines: usize,
     },
+    /// An explicit line range
+    Lines {
+        lines: Vec<(usize, String)>,
+        total_lines: usize,
+    },
     /// Git diff output
     Diff(String),
+    /// Source of a single function
+    Function(String),
+    /// Public items of every file under a directory
+    Map(RepoMap),
 }
 
 #[cfg(test)]
@@ -116,6 +142,38 @@ mod tests {
         assert_eq!(item.text, "fn test()");
         assert_eq!(item.level, 0);
         assert_eq!(item.kind, ItemKind::Function);
+        assert_eq!(item.end_line, 10);
+        assert_eq!(item.line_count(), 1);
+    }
+
+    #[test]
+    fn outline_item_line_count() {
+        let mut item = OutlineItem::new(10, "fn test()".to_string(), 0, ItemKind::Function);
+        item.end_line = 51;
+        assert_eq!(item.line_count(), 42);
+    }
+
+    #[test]
+    fn outline_item_serializes() {
+        let mut item = OutlineItem::new(12, "pub fn foo()".to_string(), 1, ItemKind::Function);
+        item.end_line = 20;
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "line": 12,
+                "kind": "Function",
+                "signature": "pub fn foo()",
+                "depth": 1,
+                "end_line": 20
+            })
+        );
+    }
+
+    #[test]
+    fn heading_kind_serializes_with_level() {
+        let json = serde_json::to_value(ItemKind::Heading(2)).unwrap();
+        assert_eq!(json, serde_json::json!({"heading": 2}));
     }
 
     #[test]
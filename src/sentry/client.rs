@@ -53,7 +53,7 @@ impl SentryClient {
     #[cfg(not(tarpaulin_include))]
     pub fn new() -> Result<Self> {
         let config = load_config()?;
-        let http = Client::builder().user_agent("hu-cli/0.1.0").build()?;
+        let http = crate::util::http::client()?;
         Ok(Self { config, http })
     }
 
@@ -181,6 +181,7 @@ impl SentryClient {
         loop {
             let response = request_fn().await?;
             let status = response.status();
+            log::debug!("HTTP {} {}", status.as_u16(), response.url());
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 if retries >= MAX_RETRIES {
@@ -197,7 +198,7 @@ impl SentryClient {
                     .and_then(|s| s.parse::<u64>().ok())
                     .unwrap_or(DEFAULT_RETRY_SECS);
 
-                eprintln!(
+                log::info!(
                     "Rate limited, waiting {} seconds... (retry {}/{})",
                     retry_after,
                     retries + 1,
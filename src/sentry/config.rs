@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::util::secrets;
+
 /// Sentry configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SentryConfig {
@@ -34,7 +36,7 @@ struct SettingsFile {
 
 /// Get path to config file
 pub fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
+    crate::util::settings_path()
 }
 
 /// Load Sentry config from settings file and environment
@@ -50,6 +52,11 @@ pub fn load_config() -> Result<SentryConfig> {
             if let Some(sentry) = settings.sentry {
                 config = sentry;
             }
+            secrets::fill(
+                &mut config.auth_token,
+                "sentry.auth_token",
+                secrets::enabled_in(&contents),
+            );
         }
     }
 
@@ -100,9 +107,12 @@ pub fn save_auth_token(token: &str, org: &str) -> Result<()> {
         .and_then(|v| v.as_table_mut())
         .ok_or_else(|| anyhow::anyhow!("sentry section is not a table"))?;
 
-    sentry.insert(
-        "auth_token".to_string(),
-        toml::Value::String(token.to_string()),
+    secrets::store(
+        sentry,
+        "auth_token",
+        "sentry.auth_token",
+        token,
+        secrets::enabled_in(&contents),
     );
     sentry.insert(
         "organization".to_string(),
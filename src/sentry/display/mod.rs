@@ -1,8 +1,12 @@
 //! Sentry output formatting
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use crate::util::fmt::truncate;
+use crate::util::print_structured;
+
 use super::types::{Event, Issue, OutputFormat};
 
 #[cfg(test)]
@@ -28,15 +32,6 @@ fn time_ago(timestamp: &str) -> String {
     }
 }
 
-/// Truncate string
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 /// Color for issue level
 fn level_color(level: &str) -> Color {
     match level {
@@ -85,11 +80,7 @@ pub fn output_issues(issues: &[Issue], format: OutputFormat) -> Result<()> {
             println!("{table}");
             println!("\n{} issues", issues.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(issues).context("Failed to serialize issues")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(issues, format)?,
     }
     Ok(())
 }
@@ -135,10 +126,7 @@ pub fn output_issue_detail(issue: &Issue, format: OutputFormat) -> Result<()> {
 
             println!("\nLink: {}", issue.permalink);
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(issue).context("Failed to serialize issue")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(issue, format)?,
     }
     Ok(())
 }
@@ -189,11 +177,7 @@ pub fn output_events(events: &[Event], format: OutputFormat) -> Result<()> {
             println!("{table}");
             println!("\n{} events", events.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(events).context("Failed to serialize events")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(events, format)?,
     }
     Ok(())
 }
@@ -37,21 +37,6 @@ fn test_time_ago_invalid() {
     assert_eq!(time_ago("invalid"), "invalid");
 }
 
-#[test]
-fn test_truncate_short() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn test_truncate_exact() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn test_truncate_long() {
-    assert_eq!(truncate("hello world", 8), "hello...");
-}
-
 #[test]
 fn test_level_color() {
     assert_eq!(level_color("error"), Color::Red);
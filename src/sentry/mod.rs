@@ -18,6 +18,7 @@ mod display;
 mod service;
 pub mod types;
 
+use anstream::println;
 use anyhow::Result;
 use clap::Subcommand;
 
@@ -40,7 +41,7 @@ pub enum SentryCommand {
         project: Option<String>,
 
         /// Search query (Sentry search syntax)
-        #[arg(short, long)]
+        #[arg(long)]
         query: Option<String>,
 
         /// Maximum number of issues to return
@@ -48,7 +49,7 @@ pub enum SentryCommand {
         limit: usize,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -58,7 +59,7 @@ pub enum SentryCommand {
         issue: String,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -72,7 +73,7 @@ pub enum SentryCommand {
         limit: usize,
 
         /// Output as JSON
-        #[arg(long)]
+        #[arg(long, hide = true)]
         json: bool,
     },
 
@@ -184,11 +185,7 @@ async fn cmd_issues(
     };
     let issues = service::list_issues(&client, &opts).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_issues(&issues, format)?;
     Ok(())
@@ -203,11 +200,7 @@ async fn cmd_show(issue_id: &str, json: bool) -> Result<()> {
     let client = SentryClient::new()?;
     let issue = service::get_issue(&client, issue_id).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_issue_detail(&issue, format)?;
     Ok(())
@@ -226,11 +219,7 @@ async fn cmd_events(issue_id: &str, limit: usize, json: bool) -> Result<()> {
     };
     let events = service::list_events(&client, &opts).await?;
 
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_events(&events, format)?;
     Ok(())
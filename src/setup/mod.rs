@@ -21,6 +21,7 @@ mod types;
 
 pub use cli::SetupCommand;
 
+use anstream::println;
 use anyhow::{bail, Context, Result};
 use owo_colors::OwoColorize;
 
@@ -18,7 +18,7 @@ pub struct LsArgs {
 #[derive(Debug, Args)]
 pub struct DfArgs {
     /// Output as JSON
-    #[arg(short, long)]
+    #[arg(short, long, hide = true)]
     pub json: bool,
 }
 
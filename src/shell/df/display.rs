@@ -41,10 +41,6 @@ pub fn format_table(disks: &[DiskUsage]) -> String {
     table.to_string()
 }
 
-pub fn format_json(disks: &[DiskUsage]) -> String {
-    serde_json::to_string_pretty(disks).unwrap_or_else(|_| "[]".to_string())
-}
-
 fn usage_color(percent: f64) -> Color {
     if percent >= 90.0 {
         Color::Red
@@ -100,11 +96,11 @@ mod tests {
     }
 
     #[test]
-    fn format_json_works() {
+    fn disks_serialize_for_structured_output() {
         let disks = vec![make_disk("/", 1000, 500)];
-        let output = format_json(&disks);
-        assert!(output.contains("mount_point"));
-        assert!(output.contains("ext4"));
+        let value = serde_json::to_value(&disks).unwrap();
+        assert_eq!(value[0]["mount_point"], "/");
+        assert_eq!(value[0]["fs_type"], "ext4");
     }
 
     #[test]
@@ -3,18 +3,17 @@ mod service;
 mod types;
 
 use crate::shell::cli::DfArgs;
+use crate::util::{print_structured, OutputFormat};
+use anstream::println;
 use anyhow::Result;
 
 pub fn run(args: DfArgs) -> Result<()> {
     let disks = service::get_all_mounts()?;
 
-    let output = if args.json {
-        display::format_json(&disks)
-    } else {
-        display::format_table(&disks)
-    };
-
-    println!("{}", output);
+    match OutputFormat::resolve(args.json) {
+        OutputFormat::Table => println!("{}", display::format_table(&disks)),
+        format => print_structured(&disks, format)?,
+    }
 
     Ok(())
 }
@@ -4,6 +4,7 @@ mod service;
 mod types;
 
 use crate::shell::cli::LsArgs;
+use anstream::println;
 use anyhow::Result;
 
 pub fn run(args: LsArgs) -> Result<()> {
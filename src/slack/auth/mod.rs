@@ -2,6 +2,7 @@
 //!
 //! Implements the browser-based OAuth flow to obtain bot tokens.
 
+use anstream::println;
 use anyhow::Result;
 use std::time::Duration;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
@@ -220,8 +221,8 @@ pub async fn run_oauth_flow(port: u16) -> Result<OAuthResult> {
     println!("Waiting for authorization...");
 
     // Try to open browser
-    if let Err(_e) = open::that(&auth_url) {
-        // debug!("Failed to open browser: {}", _e);
+    if let Err(e) = open::that(&auth_url) {
+        log::debug!("Failed to open browser: {}", e);
     }
 
     // Wait for callback with timeout
@@ -313,7 +314,7 @@ async fn process_callback(
     }
 
     // Exchange code for tokens
-    let http = reqwest::Client::new();
+    let http = crate::util::http::client()?;
     let tokens = match exchange_code_for_tokens(
         &http,
         &code,
@@ -65,9 +65,7 @@ impl SlackClient {
     #[cfg(not(tarpaulin_include))]
     pub fn new() -> Result<Self> {
         let config = load_config()?;
-        let http = Client::builder()
-            .user_agent("hu-cli/0.1.0")
-            .no_proxy()
+        let http = crate::util::http::builder()
             .build()
             .map_err(|e| anyhow::anyhow!(format!("Failed to create HTTP client: {}", e)))?;
         Ok(Self { config, http })
@@ -114,7 +112,11 @@ impl SlackClient {
                     .get("error")
                     .and_then(|v| v.as_str())
                     .unwrap_or("unknown error");
-                return Err(anyhow::anyhow!(error.to_string()));
+                let details = block_errors(&value);
+                if details.is_empty() {
+                    return Err(anyhow::anyhow!(error.to_string()));
+                }
+                return Err(anyhow::anyhow!("{}:\n  {}", error, details.join("\n  ")));
             }
         }
 
@@ -135,6 +137,7 @@ impl SlackClient {
         loop {
             let response = request_fn().await?;
             let status = response.status();
+            log::debug!("HTTP {} {}", status.as_u16(), response.url());
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 if retries >= MAX_RETRIES {
@@ -152,7 +155,7 @@ impl SlackClient {
                     .and_then(|s| s.parse::<u64>().ok())
                     .unwrap_or(DEFAULT_RETRY_SECS);
 
-                eprintln!(
+                log::info!(
                     "Rate limited, waiting {} seconds... (retry {}/{})",
                     retry_after,
                     retries + 1,
@@ -174,6 +177,40 @@ impl SlackClient {
     }
 }
 
+/// Block validation details from an `invalid_blocks` error
+///
+/// Slack reports each problem as `message [json-pointer:/blocks/N/...]`;
+/// these come back as `block N: message (/blocks/N/...)`.
+fn block_errors(value: &serde_json::Value) -> Vec<String> {
+    let errors = value.get("errors").or_else(|| {
+        value
+            .get("response_metadata")
+            .and_then(|m| m.get("messages"))
+    });
+    let Some(errors) = errors.and_then(serde_json::Value::as_array) else {
+        return Vec::new();
+    };
+
+    errors
+        .iter()
+        .filter_map(serde_json::Value::as_str)
+        .map(|raw| {
+            let raw = raw.trim_start_matches("[ERROR] ");
+            let Some((message, pointer)) = raw.split_once(" [json-pointer:") else {
+                return raw.to_string();
+            };
+            let pointer = pointer.trim_end_matches(']');
+            let index = pointer
+                .strip_prefix("/blocks/")
+                .and_then(|rest| rest.split('/').next());
+            match index {
+                Some(index) => format!("block {}: {} ({})", index, message, pointer),
+                None => format!("{} ({})", message, pointer),
+            }
+        })
+        .collect()
+}
+
 #[cfg(not(tarpaulin_include))]
 impl SlackApi for SlackClient {
     async fn get<T: DeserializeOwned + Send>(&self, method: &str) -> Result<T> {
@@ -295,6 +332,7 @@ mod tests {
                 team_name: Some("Test Team".to_string()),
             },
             default_channel: String::new(),
+            tidy_exclude: Vec::new(),
             is_configured: true,
         };
         let http = Client::builder().build().unwrap();
@@ -336,6 +374,39 @@ mod tests {
         assert!(err.to_string().contains("channel_not_found"));
     }
 
+    #[test]
+    fn test_parse_response_invalid_blocks_lists_index() {
+        let client = make_test_client();
+        let json = r#"{
+            "ok": false,
+            "error": "invalid_blocks",
+            "errors": ["must be more than 0 characters [json-pointer:/blocks/2/text/text]"],
+            "response_metadata": {"messages": ["[ERROR] must be more than 0 characters [json-pointer:/blocks/2/text/text]"]}
+        }"#;
+
+        let result: Result<serde_json::Value> = client.parse_response(json);
+        let err = result.unwrap_err().to_string();
+        assert!(err.starts_with("invalid_blocks:"));
+        assert!(err.contains("block 2: must be more than 0 characters (/blocks/2/text/text)"));
+    }
+
+    #[test]
+    fn test_block_errors_from_response_metadata() {
+        let value = serde_json::json!({
+            "ok": false,
+            "error": "invalid_blocks",
+            "response_metadata": {"messages": ["[ERROR] unsupported type [json-pointer:/blocks/0/type]", "bad"]}
+        });
+        assert_eq!(
+            block_errors(&value),
+            vec![
+                "block 0: unsupported type (/blocks/0/type)".to_string(),
+                "bad".to_string()
+            ]
+        );
+        assert!(block_errors(&serde_json::json!({"ok": false})).is_empty());
+    }
+
     #[test]
     fn test_parse_response_slack_error_unknown() {
         let client = make_test_client();
@@ -393,6 +464,7 @@ mod tests {
                 team_name: None,
             },
             default_channel: String::new(),
+            tidy_exclude: Vec::new(),
             is_configured: false,
         };
         let http = Client::builder().build().unwrap();
@@ -413,6 +485,7 @@ mod tests {
                 team_name: None,
             },
             default_channel: String::new(),
+            tidy_exclude: Vec::new(),
             is_configured: true,
         };
         let http = Client::builder().build().unwrap();
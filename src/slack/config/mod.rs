@@ -5,7 +5,9 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::util::secrets;
 
 #[cfg(test)]
 mod tests;
@@ -15,6 +17,8 @@ mod tests;
 pub struct SlackConfig {
     /// Default channel (e.g., "#general")
     pub default_channel: String,
+    /// Channels `hu slack tidy` never marks as read
+    pub tidy_exclude: Vec<String>,
     /// OAuth configuration
     pub oauth: OAuthConfig,
     /// Whether configuration is complete
@@ -65,6 +69,8 @@ struct SettingsFile {
 #[derive(Debug, Deserialize)]
 struct SlackSection {
     default_channel: Option<String>,
+    #[serde(default)]
+    tidy_exclude: Vec<String>,
     oauth: Option<OAuthSection>,
 }
 
@@ -80,22 +86,28 @@ struct OAuthSection {
 
 /// Get the config file path
 ///
-/// Uses `~/.config/hu/settings.toml` following XDG convention.
+/// Uses `~/.config/hu/settings.toml` following XDG convention, unless
+/// overridden with `--config` or `HU_CONFIG`.
 #[must_use]
 pub fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
+    crate::util::settings_path()
 }
 
 /// Load Slack configuration from settings file and environment variables
 #[cfg(not(tarpaulin_include))]
 pub fn load_config() -> Result<SlackConfig> {
+    load_config_from(config_path().as_deref())
+}
+
+/// Load Slack configuration from a specific settings file (testable)
+pub fn load_config_from(path: Option<&Path>) -> Result<SlackConfig> {
     let mut config = SlackConfig::default();
 
     // Try to load from settings file
-    if let Some(path) = config_path() {
+    if let Some(path) = path {
         if path.exists() {
-            // debug!("Loading Slack config from {}", path.display());
-            let contents = fs::read_to_string(&path).map_err(|e| {
+            log::debug!("Loading Slack config from {}", path.display());
+            let contents = fs::read_to_string(path).map_err(|e| {
                 anyhow::anyhow!(format!("Failed to read {}: {}", path.display(), e))
             })?;
 
@@ -105,6 +117,7 @@ pub fn load_config() -> Result<SlackConfig> {
 
             if let Some(slack) = settings.slack {
                 config.default_channel = slack.default_channel.unwrap_or_default();
+                config.tidy_exclude = slack.tidy_exclude;
 
                 if let Some(oauth) = slack.oauth {
                     config.oauth = OAuthConfig {
@@ -117,6 +130,16 @@ pub fn load_config() -> Result<SlackConfig> {
                     };
                 }
             }
+
+            let secure = secrets::enabled_in(&contents);
+            let oauth = &mut config.oauth;
+            secrets::fill(
+                &mut oauth.client_secret,
+                "slack.oauth.client_secret",
+                secure,
+            );
+            secrets::fill(&mut oauth.bot_token, "slack.oauth.bot_token", secure);
+            secrets::fill(&mut oauth.user_token, "slack.oauth.user_token", secure);
         }
     }
 
@@ -185,9 +208,12 @@ pub fn update_oauth_tokens(bot_token: &str, team_id: &str, team_name: &str) -> R
         .ok_or_else(|| anyhow::anyhow!("slack.oauth section is not a table".to_string()))?;
 
     // Update tokens
-    oauth.insert(
-        "bot_token".to_string(),
-        toml::Value::String(bot_token.to_string()),
+    secrets::store(
+        oauth,
+        "bot_token",
+        "slack.oauth.bot_token",
+        bot_token,
+        secrets::enabled_in(&contents),
     );
     oauth.insert(
         "team_id".to_string(),
@@ -211,7 +237,7 @@ pub fn update_oauth_tokens(bot_token: &str, team_id: &str, team_name: &str) -> R
     fs::write(&path, output)
         .map_err(|e| anyhow::anyhow!(format!("Failed to write {}: {}", path.display(), e)))?;
 
-    // debug!("Updated Slack OAuth tokens in {}", path.display());
+    log::debug!("Updated Slack OAuth tokens in {}", path.display());
     Ok(())
 }
 
@@ -263,9 +289,12 @@ pub fn update_user_token(user_token: &str) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("slack.oauth section is not a table".to_string()))?;
 
     // Update user token
-    oauth.insert(
-        "user_token".to_string(),
-        toml::Value::String(user_token.to_string()),
+    secrets::store(
+        oauth,
+        "user_token",
+        "slack.oauth.user_token",
+        user_token,
+        secrets::enabled_in(&contents),
     );
 
     // Write back
@@ -281,6 +310,6 @@ pub fn update_user_token(user_token: &str) -> Result<()> {
     fs::write(&path, output)
         .map_err(|e| anyhow::anyhow!(format!("Failed to write {}: {}", path.display(), e)))?;
 
-    // debug!("Updated Slack user token in {}", path.display());
+    log::debug!("Updated Slack user token in {}", path.display());
     Ok(())
 }
@@ -165,6 +165,7 @@ fn test_oauth_config_clone() {
 fn test_slack_config_clone() {
     let config = SlackConfig {
         default_channel: "general".to_string(),
+        tidy_exclude: Vec::new(),
         oauth: OAuthConfig::default(),
         is_configured: true,
     };
@@ -178,6 +179,7 @@ fn test_slack_config_clone() {
 fn test_slack_config_debug() {
     let config = SlackConfig {
         default_channel: "test".to_string(),
+        tidy_exclude: Vec::new(),
         oauth: OAuthConfig::default(),
         is_configured: false,
     };
@@ -232,6 +234,21 @@ fn test_settings_file_parse_no_oauth() {
     assert!(slack.oauth.is_none());
 }
 
+#[test]
+fn test_settings_file_parse_tidy_exclude() {
+    let toml_str = r##"
+            [slack]
+            tidy_exclude = ["incidents", "#oncall"]
+        "##;
+
+    let settings: SettingsFile = toml::from_str(toml_str).unwrap();
+    let slack = settings.slack.unwrap();
+    assert_eq!(slack.tidy_exclude, vec!["incidents", "#oncall"]);
+
+    let settings: SettingsFile = toml::from_str("[slack]\n").unwrap();
+    assert!(settings.slack.unwrap().tidy_exclude.is_empty());
+}
+
 #[test]
 fn test_settings_file_parse_partial_oauth() {
     let toml_str = r##"
@@ -3,14 +3,18 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anstream::println;
+use anyhow::Result;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+
+use crate::util::fmt::truncate;
+use crate::util::print_structured;
 use regex::Regex;
 
 use super::tidy;
 use super::types::{
-    AuthInfo, AuthResult, OutputFormat, SlackChannel, SlackMessage, SlackSearchResult, SlackUser,
-    TidySummary,
+    AuthInfo, AuthResult, OutputFormat, SlackChannel, SlackMessage, SlackScheduledMessage,
+    SlackSearchResult, SlackUnread, SlackUser, TidySummary,
 };
 
 #[cfg(test)]
@@ -25,15 +29,6 @@ fn new_table(headers: Vec<&str>) -> Table {
     table
 }
 
-/// Truncate string to max length with ellipsis
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 /// Clean up Slack message text for display
 /// - Converts <@U04H482TK6Z|Adam Ladachowski> to @Adam Ladachowski
 /// - Converts <@U04H482TK6Z> to @username using lookup
@@ -151,11 +146,7 @@ pub fn output_channels(channels: &[SlackChannel], format: OutputFormat) -> Resul
             println!("{table}");
             println!("\n{} channels", channels.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(channels)
-                .context("Failed to serialize channels to JSON")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(channels, format)?,
     }
     Ok(())
 }
@@ -186,20 +177,18 @@ pub fn output_channel_detail(channel: &SlackChannel, format: OutputFormat) -> Re
                 println!("\nPurpose: {purpose}");
             }
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(channel)
-                .context("Failed to serialize channel to JSON")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(channel, format)?,
     }
     Ok(())
 }
 
-/// Output message history
+/// Output message history, with any fetched replies indented beneath
+/// their parent
 pub fn output_messages(
     messages: &[SlackMessage],
     channel_name: &str,
     format: OutputFormat,
+    user_lookup: &HashMap<String, String>,
 ) -> Result<()> {
     match format {
         OutputFormat::Table => {
@@ -210,28 +199,41 @@ pub fn output_messages(
             println!("Messages in #{channel_name}");
             println!("{}", "-".repeat(60));
             for msg in messages.iter().rev() {
-                let time = format_timestamp(&msg.ts);
-                let user = msg
-                    .username
-                    .as_deref()
-                    .or(msg.user.as_deref())
-                    .unwrap_or("unknown");
-                let thread = msg
-                    .reply_count
-                    .map_or(String::new(), |n| format!(" [{n} replies]"));
-                println!("[{time}] {user}: {}{thread}", msg.text);
+                println!("{}", message_line(msg, user_lookup));
+                for reply in &msg.replies {
+                    println!("    {}", message_line(reply, user_lookup));
+                }
             }
             println!("\n{} messages", messages.len());
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(messages)
-                .context("Failed to serialize messages to JSON")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(messages, format)?,
     }
     Ok(())
 }
 
+/// One history line: time, sender, cleaned text and reply count
+fn message_line(msg: &SlackMessage, user_lookup: &HashMap<String, String>) -> String {
+    let time = format_timestamp(&msg.ts);
+    let user = msg
+        .username
+        .as_deref()
+        .or_else(|| {
+            msg.user
+                .as_deref()
+                .and_then(|id| user_lookup.get(id))
+                .map(String::as_str)
+        })
+        .or(msg.user.as_deref())
+        .unwrap_or("unknown");
+    let thread = msg
+        .reply_count
+        .map_or(String::new(), |n| format!(" [{n} replies]"));
+    format!(
+        "[{time}] {user}: {}{thread}",
+        clean_message_text(&msg.text, user_lookup)
+    )
+}
+
 /// Output search results
 pub fn output_search_results(
     results: &SlackSearchResult,
@@ -265,11 +267,7 @@ pub fn output_search_results(
                 results.total
             );
         }
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(results)
-                .context("Failed to serialize search results to JSON")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(results, format)?,
     }
     Ok(())
 }
@@ -296,11 +294,7 @@ pub fn output_users(users: &[SlackUser], format: OutputFormat) -> Result<()> {
             println!("{table}");
             println!("\n{} users", users.len());
         }
-        OutputFormat::Json => {
-            let json =
-                serde_json::to_string_pretty(users).context("Failed to serialize users to JSON")?;
-            println!("{json}");
-        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(users, format)?,
     }
     Ok(())
 }
@@ -370,21 +364,109 @@ pub fn output_send_confirmation(channel: &str, ts: &str) {
     println!("Message sent to {} (ts: {})", channel, ts);
 }
 
+/// Output schedule message confirmation
+pub fn output_schedule_confirmation(channel: &str, id: &str, post_at: i64) {
+    println!(
+        "Message scheduled for {} in {} (id: {})",
+        format_timestamp(&post_at.to_string()),
+        channel,
+        id
+    );
+}
+
+/// Output scheduled messages list
+pub fn output_scheduled(scheduled: &[SlackScheduledMessage], format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Table => {
+            if scheduled.is_empty() {
+                println!("No scheduled messages.");
+                return Ok(());
+            }
+            let mut table = new_table(vec!["ID", "Channel", "Post at", "Message"]);
+            for message in scheduled {
+                table.add_row(vec![
+                    Cell::new(&message.id).fg(Color::Cyan),
+                    Cell::new(&message.channel_id),
+                    Cell::new(format_timestamp(&message.post_at.to_string())),
+                    Cell::new(truncate(&message.text, 60)),
+                ]);
+            }
+
+            println!("{table}");
+            println!("\n{} scheduled", scheduled.len());
+        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(scheduled, format)?,
+    }
+    Ok(())
+}
+
+/// Output unschedule confirmation
+pub fn output_unschedule_confirmation(id: &str) {
+    println!("Cancelled scheduled message {}", id);
+}
+
+/// Output channels with unread messages
+pub fn output_unread(
+    unread: &[SlackUnread],
+    format: OutputFormat,
+    user_lookup: &HashMap<String, String>,
+) -> Result<()> {
+    match format {
+        OutputFormat::Table => {
+            if unread.is_empty() {
+                println!("Nothing unread.");
+                return Ok(());
+            }
+            let mut table = new_table(vec!["Channel", "Unread", "Latest", "Message"]);
+            for u in unread {
+                let count = if u.capped {
+                    format!("{}+", u.count)
+                } else {
+                    u.count.to_string()
+                };
+                let text = clean_message_text(&u.preview, user_lookup);
+                table.add_row(vec![
+                    Cell::new(format_channel_name(&u.channel_name, user_lookup)).fg(Color::Cyan),
+                    Cell::new(count),
+                    Cell::new(format_timestamp(&u.latest_ts)),
+                    Cell::new(truncate(&text, 60)),
+                ]);
+            }
+
+            println!("{table}");
+            let total: usize = unread.iter().map(|u| u.count).sum();
+            println!("\n{} unread in {} channels", total, unread.len());
+        }
+        OutputFormat::Json | OutputFormat::Csv => print_structured(unread, format)?,
+    }
+    Ok(())
+}
+
 /// Output tidy dry run notice
 pub fn output_tidy_dry_run() {
     println!("DRY RUN - no channels will be marked as read\n");
 }
 
 /// Output individual tidy results (marked/mentioned channels)
-pub fn output_tidy_results(results: &[tidy::TidyResult]) {
+///
+/// With `verbose`, skipped channels are listed with the reason and mentions
+/// show the full message and when it was sent.
+pub fn output_tidy_results(results: &[tidy::TidyResult], verbose: bool) {
     for r in results {
         match &r.action {
-            tidy::TidyAction::Skipped => {}
+            tidy::TidyAction::Skipped(reason) => {
+                if verbose {
+                    println!("Skipped:     #{} ({})", r.channel_name, reason.describe());
+                }
+            }
             tidy::TidyAction::MarkedRead => {
                 println!("Marked read: #{}", r.channel_name);
             }
             tidy::TidyAction::HasMention(mention) => {
                 println!("Has mention: #{} - {}", r.channel_name, mention);
+                if verbose {
+                    println!("    {}  {}", format_timestamp(&mention.ts), mention.text);
+                }
             }
         }
     }
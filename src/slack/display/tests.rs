@@ -1,25 +1,5 @@
 use super::*;
 
-#[test]
-fn test_truncate_short_string() {
-    assert_eq!(truncate("hello", 10), "hello");
-}
-
-#[test]
-fn test_truncate_exact_length() {
-    assert_eq!(truncate("hello", 5), "hello");
-}
-
-#[test]
-fn test_truncate_long_string() {
-    assert_eq!(truncate("hello world", 8), "hello...");
-}
-
-#[test]
-fn test_truncate_very_short_max() {
-    assert_eq!(truncate("hello", 3), "...");
-}
-
 #[test]
 fn test_clean_message_text_user_mention_with_display() {
     let lookup = HashMap::new();
@@ -180,7 +160,46 @@ fn test_output_channel_detail_table() {
 #[test]
 fn test_output_messages_empty() {
     let messages: Vec<SlackMessage> = vec![];
-    let result = output_messages(&messages, "general", OutputFormat::Table);
+    let result = output_messages(&messages, "general", OutputFormat::Table, &HashMap::new());
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_output_unread_empty() {
+    assert!(output_unread(&[], OutputFormat::Table, &HashMap::new()).is_ok());
+}
+
+#[test]
+fn test_output_unread_table_and_json() {
+    let unread = vec![SlackUnread {
+        channel_id: "D12345".to_string(),
+        channel_name: "U04H482TK6Z".to_string(),
+        count: 100,
+        capped: true,
+        latest_ts: "1704067200.123456".to_string(),
+        preview: "<@U04H482TK6Z> can you look?".to_string(),
+    }];
+    let mut lookup = HashMap::new();
+    lookup.insert("U04H482TK6Z".to_string(), "alice".to_string());
+    assert!(output_unread(&unread, OutputFormat::Table, &lookup).is_ok());
+    assert!(output_unread(&unread, OutputFormat::Json, &lookup).is_ok());
+}
+
+#[test]
+fn test_output_scheduled_empty() {
+    let result = output_scheduled(&[], OutputFormat::Table);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_output_scheduled_table() {
+    let scheduled = vec![SlackScheduledMessage {
+        id: "Q1298393284".to_string(),
+        channel_id: "C12345".to_string(),
+        post_at: 1704067200,
+        text: "Deploy at noon".to_string(),
+    }];
+    let result = output_scheduled(&scheduled, OutputFormat::Table);
     assert!(result.is_ok());
 }
 
@@ -194,8 +213,9 @@ fn test_output_messages_json() {
         thread_ts: None,
         reply_count: Some(5),
         username: Some("alice".to_string()),
+        replies: Vec::new(),
     }];
-    let result = output_messages(&messages, "general", OutputFormat::Json);
+    let result = output_messages(&messages, "general", OutputFormat::Json, &HashMap::new());
     assert!(result.is_ok());
 }
 
@@ -326,6 +346,7 @@ fn test_output_messages_table_with_data() {
             thread_ts: None,
             reply_count: Some(5),
             username: Some("alice".to_string()),
+            replies: Vec::new(),
         },
         SlackMessage {
             msg_type: "message".to_string(),
@@ -335,12 +356,59 @@ fn test_output_messages_table_with_data() {
             thread_ts: None,
             reply_count: None,
             username: None,
+            replies: Vec::new(),
         },
     ];
-    let result = output_messages(&messages, "general", OutputFormat::Table);
+    let result = output_messages(&messages, "general", OutputFormat::Table, &HashMap::new());
     assert!(result.is_ok());
 }
 
+fn reply(user: &str, text: &str) -> SlackMessage {
+    SlackMessage {
+        msg_type: "message".to_string(),
+        user: Some(user.to_string()),
+        text: text.to_string(),
+        ts: "1704067300.000000".to_string(),
+        thread_ts: Some("1704067200.123456".to_string()),
+        reply_count: None,
+        username: None,
+        replies: Vec::new(),
+    }
+}
+
+#[test]
+fn test_message_line_resolves_user_and_mentions() {
+    let lookup = HashMap::from([("U1".to_string(), "bob".to_string())]);
+    let line = message_line(&reply("U1", "thanks <@U1>"), &lookup);
+    assert!(line.ends_with("] bob: thanks @bob"));
+    let line = message_line(&reply("U9", "ok"), &lookup);
+    assert!(line.ends_with("] U9: ok"));
+}
+
+#[test]
+fn test_output_messages_with_replies() {
+    let parent = SlackMessage {
+        reply_count: Some(2),
+        thread_ts: None,
+        replies: vec![reply("U1", "first"), reply("U2", "second")],
+        ..reply("U3", "question?")
+    };
+    let lookup = HashMap::from([("U1".to_string(), "bob".to_string())]);
+    assert!(output_messages(
+        std::slice::from_ref(&parent),
+        "general",
+        OutputFormat::Table,
+        &lookup
+    )
+    .is_ok());
+    let json = serde_json::to_value(&parent).unwrap();
+    assert_eq!(json["replies"][1]["text"], "second");
+    assert!(serde_json::to_value(reply("U1", "x"))
+        .unwrap()
+        .get("replies")
+        .is_none());
+}
+
 #[test]
 fn test_output_users_table_with_data() {
     let users = vec![
@@ -480,7 +548,7 @@ fn test_output_tidy_dry_run() {
 #[test]
 fn test_output_tidy_results_empty() {
     let results: Vec<tidy::TidyResult> = vec![];
-    output_tidy_results(&results);
+    output_tidy_results(&results, false);
 }
 
 #[test]
@@ -492,14 +560,19 @@ fn test_output_tidy_results_mixed() {
         },
         tidy::TidyResult {
             channel_name: "random".to_string(),
-            action: tidy::TidyAction::Skipped,
+            action: tidy::TidyAction::Skipped(tidy::SkipReason::AlreadyRead),
         },
         tidy::TidyResult {
             channel_name: "dev".to_string(),
-            action: tidy::TidyAction::HasMention("@alice mentioned you".to_string()),
+            action: tidy::TidyAction::HasMention(tidy::Mention {
+                reason: "@mention".to_string(),
+                text: "@alice mentioned you".to_string(),
+                ts: "1704067200.123456".to_string(),
+            }),
         },
     ];
-    output_tidy_results(&results);
+    output_tidy_results(&results, false);
+    output_tidy_results(&results, true);
 }
 
 #[test]
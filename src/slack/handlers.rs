@@ -1,10 +1,13 @@
 use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
 
 use super::client::SlackClient;
 use super::display;
 use super::service;
+use super::tidy::TidyFilter;
 use super::types::OutputFormat;
-use super::SlackCommands;
+use super::{SearchFilters, SlackCommands};
 
 /// Run a Slack command (CLI entry point - formats and prints)
 #[cfg(not(tarpaulin_include))]
@@ -17,17 +20,76 @@ pub async fn run(command: SlackCommands) -> Result<()> {
         } => cmd_auth(token.as_deref(), user_token.as_deref(), port).await,
         SlackCommands::Channels { json } => cmd_channels(json).await,
         SlackCommands::Info { channel, json } => cmd_info(&channel, json).await,
-        SlackCommands::Send { channel, message } => cmd_send(&channel, &message).await,
+        SlackCommands::Send {
+            channel,
+            message,
+            blocks,
+        } => cmd_send(&channel, &message, blocks.as_deref()).await,
+        SlackCommands::Schedule {
+            channel,
+            message,
+            at,
+        } => cmd_schedule(&channel, &message, &at).await,
+        SlackCommands::Scheduled { channel, json } => cmd_scheduled(channel.as_deref(), json).await,
+        SlackCommands::Unschedule { id } => cmd_unschedule(&id).await,
         SlackCommands::History {
             channel,
             limit,
+            threads,
+            max_threads,
+            max_replies,
+            json,
+        } => {
+            let threads = threads.then_some((max_threads, max_replies));
+            cmd_history(&channel, limit, threads, json).await
+        }
+        SlackCommands::Search {
+            query,
+            channel,
+            from,
+            after,
+            before,
+            has,
+            count,
             json,
-        } => cmd_history(&channel, limit, json).await,
-        SlackCommands::Search { query, count, json } => cmd_search(&query, count, json).await,
+        } => {
+            let filters = SearchFilters {
+                channel,
+                from,
+                after,
+                before,
+                has,
+            };
+            cmd_search(&query, &filters, count, json).await
+        }
         SlackCommands::Users { json } => cmd_users(json).await,
         SlackCommands::Config => cmd_config(),
         SlackCommands::Whoami => cmd_whoami().await,
-        SlackCommands::Tidy { dry_run } => cmd_tidy(dry_run).await,
+        SlackCommands::Unread {
+            channels,
+            pattern,
+            json,
+        } => {
+            let filter = TidyFilter {
+                channels,
+                pattern,
+                exclude: Vec::new(),
+            };
+            cmd_unread(&filter, json).await
+        }
+        SlackCommands::Tidy {
+            dry_run,
+            channels,
+            pattern,
+            exclude,
+        } => {
+            let filter = TidyFilter {
+                channels,
+                pattern,
+                exclude,
+            };
+            cmd_tidy(dry_run, crate::util::logging::is_verbose(), filter).await
+        }
     }
 }
 
@@ -47,11 +109,7 @@ async fn cmd_channels(json: bool) -> Result<()> {
 
     let client = SlackClient::new()?;
     let channels = service::list_channels(&client).await?;
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_channels(&channels, format)?;
     Ok(())
@@ -65,11 +123,7 @@ async fn cmd_info(channel: &str, json: bool) -> Result<()> {
 
     let client = SlackClient::new()?;
     let info = service::get_channel_info(&client, channel).await?;
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_channel_detail(&info, format)?;
     Ok(())
@@ -77,55 +131,118 @@ async fn cmd_info(channel: &str, json: bool) -> Result<()> {
 
 /// Send a message
 #[cfg(not(tarpaulin_include))]
-async fn cmd_send(channel: &str, text: &str) -> Result<()> {
+async fn cmd_send(channel: &str, text: &str, blocks: Option<&Path>) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
 
+    let blocks = blocks.map(service::load_blocks).transpose()?;
     let client = SlackClient::new()?;
-    let (sent_channel, ts) = service::send_message(&client, channel, text).await?;
+    let (sent_channel, ts) = service::send_message(&client, channel, text, blocks.as_ref()).await?;
 
     display::output_send_confirmation(&sent_channel, &ts);
     Ok(())
 }
 
+/// Schedule a message
+#[cfg(not(tarpaulin_include))]
+async fn cmd_schedule(channel: &str, text: &str, at: &str) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = SlackClient::new()?;
+    let (sent_channel, id, post_at) = service::schedule_message(&client, channel, text, at).await?;
+
+    display::output_schedule_confirmation(&sent_channel, &id, post_at);
+    Ok(())
+}
+
+/// List scheduled messages
+#[cfg(not(tarpaulin_include))]
+async fn cmd_scheduled(channel: Option<&str>, json: bool) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = SlackClient::new()?;
+    let scheduled = service::list_scheduled(&client, channel).await?;
+    let format = OutputFormat::resolve(json);
+
+    display::output_scheduled(&scheduled, format)?;
+    Ok(())
+}
+
+/// Cancel a scheduled message
+#[cfg(not(tarpaulin_include))]
+async fn cmd_unschedule(id: &str) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+
+    let client = SlackClient::new()?;
+    service::unschedule(&client, id).await?;
+
+    display::output_unschedule_confirmation(id);
+    Ok(())
+}
+
 /// Get message history
 #[cfg(not(tarpaulin_include))]
-async fn cmd_history(channel: &str, limit: usize, json: bool) -> Result<()> {
+async fn cmd_history(
+    channel: &str,
+    limit: usize,
+    threads: Option<(usize, usize)>,
+    json: bool,
+) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
 
     let client = SlackClient::new()?;
-    let messages = service::get_history(&client, channel, limit).await?;
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
+    let format = OutputFormat::resolve(json);
+    let (messages, user_lookup) = match threads {
+        Some((max_threads, max_replies)) => (
+            service::get_history_with_threads(&client, channel, limit, max_threads, max_replies)
+                .await?,
+            service::build_user_lookup(&client).await?,
+        ),
+        None => (
+            service::get_history(&client, channel, limit).await?,
+            HashMap::new(),
+        ),
     };
 
     let channel_name = channel.trim_start_matches('#');
-    display::output_messages(&messages, channel_name, format)?;
+    display::output_messages(&messages, channel_name, format, &user_lookup)?;
     Ok(())
 }
 
 /// Search messages
 #[cfg(not(tarpaulin_include))]
-async fn cmd_search(query: &str, count: usize, json: bool) -> Result<()> {
+async fn cmd_search(query: &str, filters: &SearchFilters, count: usize, json: bool) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
 
     let client = SlackClient::new()?;
-    let results = service::search_messages(&client, query, count).await?;
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let results = service::search_messages(&client, query, filters, count).await?;
+    let format = OutputFormat::resolve(json);
 
     let user_lookup = service::build_user_lookup(&client).await?;
     display::output_search_results(&results, format, &user_lookup)?;
     Ok(())
 }
 
+/// List channels with unread messages
+#[cfg(not(tarpaulin_include))]
+async fn cmd_unread(filter: &TidyFilter, json: bool) -> Result<()> {
+    let config = service::get_config()?;
+    service::ensure_user_token(&config)?;
+
+    let client = SlackClient::new()?;
+    let unread = service::list_unread(&client, filter).await?;
+    let format = OutputFormat::resolve(json);
+
+    let user_lookup = service::build_user_lookup(&client).await?;
+    display::output_unread(&unread, format, &user_lookup)?;
+    Ok(())
+}
+
 /// List users
 #[cfg(not(tarpaulin_include))]
 async fn cmd_users(json: bool) -> Result<()> {
@@ -134,11 +251,7 @@ async fn cmd_users(json: bool) -> Result<()> {
 
     let client = SlackClient::new()?;
     let users = service::list_users(&client).await?;
-    let format = if json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Table
-    };
+    let format = OutputFormat::resolve(json);
 
     display::output_users(&users, format)?;
     Ok(())
@@ -174,7 +287,7 @@ async fn cmd_whoami() -> Result<()> {
 
 /// Tidy channels - mark as read if no mentions
 #[cfg(not(tarpaulin_include))]
-async fn cmd_tidy(dry_run: bool) -> Result<()> {
+async fn cmd_tidy(dry_run: bool, verbose: bool, filter: TidyFilter) -> Result<()> {
     let config = service::get_config()?;
     service::ensure_user_token(&config)?;
 
@@ -183,9 +296,9 @@ async fn cmd_tidy(dry_run: bool) -> Result<()> {
     }
 
     let client = SlackClient::new()?;
-    let (results, summary) = service::run_tidy(&client, &config, dry_run).await?;
+    let (results, summary) = service::run_tidy(&client, &config, dry_run, filter).await?;
 
-    display::output_tidy_results(&results);
+    display::output_tidy_results(&results, verbose);
     display::output_tidy_summary(&summary);
     Ok(())
 }
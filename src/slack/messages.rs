@@ -2,8 +2,9 @@
 //!
 //! Send messages and retrieve message history.
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use serde::Deserialize;
+use std::path::Path;
 
 use super::client::SlackApi;
 use super::types::SlackMessage;
@@ -43,6 +44,7 @@ impl From<MessageResponse> for SlackMessage {
             thread_ts: r.thread_ts,
             reply_count: r.reply_count,
             username: None,
+            replies: Vec::new(),
         }
     }
 }
@@ -71,23 +73,85 @@ pub async fn get_history(
     Ok(messages)
 }
 
+/// Get up to `limit` replies in a thread, oldest first, without the parent
+#[cfg(not(tarpaulin_include))]
+pub async fn get_replies(
+    client: &impl SlackApi,
+    channel_id: &str,
+    thread_ts: &str,
+    limit: usize,
+) -> Result<Vec<SlackMessage>> {
+    // The parent comes back first and counts towards the limit
+    let limit_str = (limit + 1).to_string();
+    let response: HistoryResponse = client
+        .get_with_params(
+            "conversations.replies",
+            &[
+                ("channel", channel_id),
+                ("ts", thread_ts),
+                ("limit", &limit_str),
+            ],
+        )
+        .await?;
+
+    Ok(replies_only(response.messages, thread_ts, limit))
+}
+
+/// Drop the thread parent from a conversations.replies page
+fn replies_only(
+    messages: Vec<MessageResponse>,
+    thread_ts: &str,
+    limit: usize,
+) -> Vec<SlackMessage> {
+    messages
+        .into_iter()
+        .filter(|m| m.ts != thread_ts)
+        .take(limit)
+        .map(SlackMessage::from)
+        .collect()
+}
+
 /// Send a message to a channel
+///
+/// With `blocks`, `text` is the notification fallback.
 #[cfg(not(tarpaulin_include))]
 pub async fn send_message(
     client: &impl SlackApi,
     channel_id: &str,
     text: &str,
+    blocks: Option<&serde_json::Value>,
 ) -> Result<(String, String), anyhow::Error> {
-    let body = serde_json::json!({
+    let mut body = serde_json::json!({
         "channel": channel_id,
         "text": text,
     });
+    if let Some(blocks) = blocks {
+        body["blocks"] = blocks.clone();
+    }
 
     let response: PostMessageResponse = client.post("chat.postMessage", &body).await?;
 
     Ok((response.channel, response.ts))
 }
 
+/// Read a Block Kit JSON array from `path`
+#[cfg(not(tarpaulin_include))]
+pub fn load_blocks(path: &Path) -> Result<serde_json::Value> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    parse_blocks(&contents).with_context(|| format!("Invalid blocks in {}", path.display()))
+}
+
+/// Parse Block Kit JSON, which must be an array of blocks
+pub fn parse_blocks(contents: &str) -> Result<serde_json::Value> {
+    let blocks: serde_json::Value = serde_json::from_str(contents)?;
+    match &blocks {
+        serde_json::Value::Array(items) if items.is_empty() => bail!("blocks array is empty"),
+        serde_json::Value::Array(_) => Ok(blocks),
+        _ => bail!("expected a JSON array of blocks"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +177,25 @@ mod tests {
         assert!(message.username.is_none());
     }
 
+    #[test]
+    fn parse_blocks_accepts_array() {
+        let blocks = parse_blocks(
+            r#"[{"type": "section", "text": {"type": "mrkdwn", "text": "*Deployed*"}}]"#,
+        )
+        .unwrap();
+        assert_eq!(blocks[0]["type"], "section");
+    }
+
+    #[test]
+    fn parse_blocks_rejects_non_array() {
+        assert!(parse_blocks(r#"{"blocks": []}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("array"));
+        assert!(parse_blocks("[]").is_err());
+        assert!(parse_blocks("not json").is_err());
+    }
+
     #[test]
     fn test_message_response_to_slack_message_minimal() {
         let response = MessageResponse {
@@ -182,4 +265,20 @@ mod tests {
         let response: HistoryResponse = serde_json::from_str(json).unwrap();
         assert!(response.messages.is_empty());
     }
+
+    #[test]
+    fn replies_only_drops_parent_and_limits() {
+        let json = r#"{
+            "messages": [
+                {"ts": "100.0", "text": "Parent", "thread_ts": "100.0", "reply_count": 3},
+                {"ts": "101.0", "text": "First", "thread_ts": "100.0"},
+                {"ts": "102.0", "text": "Second", "thread_ts": "100.0"},
+                {"ts": "103.0", "text": "Third", "thread_ts": "100.0"}
+            ]
+        }"#;
+        let response: HistoryResponse = serde_json::from_str(json).unwrap();
+        let replies = replies_only(response.messages, "100.0", 2);
+        let texts: Vec<&str> = replies.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(texts, ["First", "Second"]);
+    }
 }
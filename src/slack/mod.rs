@@ -5,6 +5,7 @@
 //! - List channels
 //! - Get channel info
 //! - Send messages
+//! - Schedule messages for later
 //! - View message history
 //! - Search messages
 //! - List users
@@ -30,7 +31,10 @@ mod config;
 mod display;
 mod handlers;
 mod messages;
+mod schedule;
 mod search;
+
+pub use search::{HasFilter, SearchFilters};
 mod service;
 mod tidy;
 mod types;
@@ -41,9 +45,11 @@ use clap::Subcommand;
 #[allow(unused_imports)]
 pub use client::SlackApi;
 use client::SlackClient;
+#[allow(unused_imports)]
+pub use config::load_config_from;
 pub use config::SlackConfig;
 pub use handlers::run;
-pub use types::{SlackChannel, SlackMessage, SlackSearchResult, SlackUser};
+pub use types::{AuthInfo, SlackChannel, SlackMessage, SlackSearchResult, SlackUser};
 
 /// Slack subcommands
 #[derive(Subcommand, Debug)]
@@ -63,7 +69,7 @@ pub enum SlackCommands {
     /// List channels in the workspace
     Channels {
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
     /// Show channel details
@@ -71,15 +77,41 @@ pub enum SlackCommands {
         /// Channel name or ID (e.g., "#general" or "C12345678")
         channel: String,
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
     /// Send a message to a channel
     Send {
+        /// Channel name or ID
+        channel: String,
+        /// Message text (the notification fallback when --blocks is given)
+        message: String,
+        /// Block Kit JSON file (an array of blocks)
+        #[arg(long)]
+        blocks: Option<std::path::PathBuf>,
+    },
+    /// Schedule a message to be sent later
+    Schedule {
         /// Channel name or ID
         channel: String,
         /// Message text
         message: String,
+        /// When to send: +30m, +2h, +1d, "YYYY-MM-DD HH:MM" or RFC 3339
+        #[arg(long)]
+        at: String,
+    },
+    /// List scheduled messages
+    Scheduled {
+        /// Only messages for this channel
+        channel: Option<String>,
+        /// Output as JSON
+        #[arg(short, long, hide = true)]
+        json: bool,
+    },
+    /// Cancel a scheduled message
+    Unschedule {
+        /// Scheduled message ID (from `hu slack scheduled`)
+        id: String,
     },
     /// Show message history for a channel
     History {
@@ -88,36 +120,82 @@ pub enum SlackCommands {
         /// Number of messages to show
         #[arg(short, long, default_value = "20")]
         limit: usize,
+        /// Show thread replies under their parent message
+        #[arg(long)]
+        threads: bool,
+        /// Most threads to expand, newest first
+        #[arg(long, default_value = "10", requires = "threads")]
+        max_threads: usize,
+        /// Most replies to show per thread
+        #[arg(long, default_value = "20", requires = "threads")]
+        max_replies: usize,
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
     /// Search messages
     Search {
-        /// Search query
+        /// Search query (may be empty when filters are given)
+        #[arg(default_value = "")]
         query: String,
+        /// Only in this channel (#name or ID)
+        #[arg(long = "in", value_name = "CHANNEL")]
+        channel: Option<String>,
+        /// Only from this user (@name, ID or `me`)
+        #[arg(long, value_name = "USER")]
+        from: Option<String>,
+        /// Only after this day (YYYY-MM-DD, today, yesterday)
+        #[arg(long, value_name = "DATE")]
+        after: Option<String>,
+        /// Only before this day (YYYY-MM-DD, today, yesterday)
+        #[arg(long, value_name = "DATE")]
+        before: Option<String>,
+        /// Only messages with a link or that are pinned
+        #[arg(long, value_enum)]
+        has: Option<HasFilter>,
         /// Maximum results to return
         #[arg(short = 'n', long, default_value = "20")]
         count: usize,
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
     /// List users in the workspace
     Users {
         /// Output as JSON
-        #[arg(short, long)]
+        #[arg(short, long, hide = true)]
         json: bool,
     },
     /// Show Slack configuration status
     Config,
     /// Show current user info from token
     Whoami,
+    /// List channels with unread messages, most unread first
+    Unread {
+        /// Only this channel (repeatable)
+        #[arg(short, long = "channel")]
+        channels: Vec<String>,
+        /// Only channels whose name contains this
+        #[arg(short = 'm', long = "match")]
+        pattern: Option<String>,
+        /// Output as JSON
+        #[arg(short, long, hide = true)]
+        json: bool,
+    },
     /// Mark channels as read if no direct mentions
     Tidy {
         /// Dry run - show what would be marked without marking
         #[arg(short, long)]
         dry_run: bool,
+        /// Only tidy this channel (repeatable)
+        #[arg(short, long = "channel")]
+        channels: Vec<String>,
+        /// Only tidy channels whose name contains this
+        #[arg(short = 'm', long = "match")]
+        pattern: Option<String>,
+        /// Never tidy this channel (repeatable; adds to `tidy_exclude` in config)
+        #[arg(short, long)]
+        exclude: Vec<String>,
     },
 }
 
@@ -132,6 +210,15 @@ pub fn get_config() -> Result<SlackConfig> {
     service::get_config()
 }
 
+/// Verify the configured token and say who it belongs to (for MCP/HTTP)
+#[allow(dead_code)]
+#[cfg(not(tarpaulin_include))]
+pub async fn whoami() -> Result<AuthInfo> {
+    let config = service::get_config()?;
+    service::ensure_configured(&config)?;
+    service::whoami(&config).await
+}
+
 /// List all channels (for MCP/HTTP)
 #[allow(dead_code)]
 #[cfg(not(tarpaulin_include))]
@@ -170,18 +257,22 @@ pub async fn send_message(channel: &str, text: &str) -> Result<(String, String)>
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
     let client = SlackClient::new()?;
-    service::send_message(&client, channel, text).await
+    service::send_message(&client, channel, text, None).await
 }
 
 /// Search messages (for MCP/HTTP) - requires user token
 #[allow(dead_code)]
 #[cfg(not(tarpaulin_include))]
-pub async fn search_messages(query: &str, count: usize) -> Result<SlackSearchResult> {
+pub async fn search_messages(
+    query: &str,
+    filters: &SearchFilters,
+    count: usize,
+) -> Result<SlackSearchResult> {
     let config = service::get_config()?;
     service::ensure_configured(&config)?;
     service::ensure_user_token(&config)?;
     let client = SlackClient::new()?;
-    service::search_messages(&client, query, count).await
+    service::search_messages(&client, query, filters, count).await
 }
 
 /// List users in the workspace (for MCP/HTTP)
@@ -0,0 +1,252 @@
+//! Slack scheduled messages
+//!
+//! Queue a message for later, list what is queued, and cancel it.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use serde::Deserialize;
+
+use super::client::SlackApi;
+use super::types::SlackScheduledMessage;
+
+/// Slack refuses `post_at` more than 120 days ahead
+const MAX_DAYS_AHEAD: i64 = 120;
+
+/// Response from chat.scheduleMessage API
+#[derive(Deserialize)]
+struct ScheduleResponse {
+    channel: String,
+    scheduled_message_id: String,
+}
+
+/// Response from chat.scheduledMessages.list API
+#[derive(Deserialize)]
+struct ScheduledListResponse {
+    scheduled_messages: Vec<ScheduledMessageResponse>,
+}
+
+/// Raw scheduled message from API
+#[derive(Deserialize)]
+struct ScheduledMessageResponse {
+    id: String,
+    channel_id: String,
+    post_at: i64,
+    text: Option<String>,
+}
+
+impl From<ScheduledMessageResponse> for SlackScheduledMessage {
+    fn from(r: ScheduledMessageResponse) -> Self {
+        Self {
+            id: r.id,
+            channel_id: r.channel_id,
+            post_at: r.post_at,
+            text: r.text.unwrap_or_default(),
+        }
+    }
+}
+
+/// Parse `--at` into a Unix timestamp
+///
+/// Accepts a relative offset (`+90s`, `+30m`, `+2h`, `+1d`, `+1w`), an
+/// RFC 3339 time (`2026-03-01T09:00:00Z`) or local `YYYY-MM-DD HH:MM`.
+pub fn parse_post_at(input: &str, now: DateTime<Utc>) -> Result<i64> {
+    let input = input.trim();
+    if let Some(offset) = input.strip_prefix('+') {
+        return Ok(now.timestamp().saturating_add(parse_offset(offset)?));
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Ok(dt.timestamp());
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        let local = naive
+            .and_local_timezone(Local)
+            .earliest()
+            .with_context(|| format!("{} does not exist in the local timezone", input))?;
+        return Ok(local.timestamp());
+    }
+    bail!(
+        "Invalid time '{}'. Use +30m, +2h, +1d, 'YYYY-MM-DD HH:MM' or RFC 3339",
+        input
+    )
+}
+
+/// Seconds in an offset like `30m`
+fn parse_offset(offset: &str) -> Result<i64> {
+    let split = offset
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(offset.len());
+    let (digits, unit) = offset.split_at(split);
+    let amount: i64 = digits
+        .parse()
+        .with_context(|| format!("Invalid offset '+{}'", offset))?;
+    let unit_secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => bail!("Invalid offset unit in '+{}'. Use s, m, h, d or w", offset),
+    };
+    amount
+        .checked_mul(unit_secs)
+        .with_context(|| format!("Offset '+{}' is too large", offset))
+}
+
+/// Check `post_at` is in the future and within Slack's 120-day window
+pub fn validate_post_at(post_at: i64, now: DateTime<Utc>) -> Result<()> {
+    if post_at <= now.timestamp() {
+        bail!("Scheduled time must be in the future");
+    }
+    if post_at > now.timestamp() + MAX_DAYS_AHEAD * 86_400 {
+        bail!(
+            "Slack only schedules messages up to {} days ahead",
+            MAX_DAYS_AHEAD
+        );
+    }
+    Ok(())
+}
+
+/// Schedule a message; returns (channel_id, scheduled_message_id)
+#[cfg(not(tarpaulin_include))]
+pub async fn schedule_message(
+    client: &impl SlackApi,
+    channel_id: &str,
+    text: &str,
+    post_at: i64,
+) -> Result<(String, String)> {
+    let body = serde_json::json!({
+        "channel": channel_id,
+        "text": text,
+        "post_at": post_at,
+    });
+
+    let response: ScheduleResponse = client.post("chat.scheduleMessage", &body).await?;
+
+    Ok((response.channel, response.scheduled_message_id))
+}
+
+/// List scheduled messages, optionally for one channel
+#[cfg(not(tarpaulin_include))]
+pub async fn list_scheduled(
+    client: &impl SlackApi,
+    channel_id: Option<&str>,
+) -> Result<Vec<SlackScheduledMessage>> {
+    let body = match channel_id {
+        Some(id) => serde_json::json!({ "channel": id }),
+        None => serde_json::json!({}),
+    };
+
+    let response: ScheduledListResponse = client.post("chat.scheduledMessages.list", &body).await?;
+
+    Ok(response
+        .scheduled_messages
+        .into_iter()
+        .map(SlackScheduledMessage::from)
+        .collect())
+}
+
+/// Cancel a scheduled message
+#[cfg(not(tarpaulin_include))]
+pub async fn delete_scheduled(client: &impl SlackApi, channel_id: &str, id: &str) -> Result<()> {
+    let body = serde_json::json!({
+        "channel": channel_id,
+        "scheduled_message_id": id,
+    });
+
+    let _: serde_json::Value = client.post("chat.deleteScheduledMessage", &body).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_767_225_600, 0).unwrap()
+    }
+
+    #[test]
+    fn parse_post_at_relative() {
+        let base = now().timestamp();
+        assert_eq!(parse_post_at("+90s", now()).unwrap(), base + 90);
+        assert_eq!(parse_post_at("+30m", now()).unwrap(), base + 1800);
+        assert_eq!(parse_post_at("+2h", now()).unwrap(), base + 7200);
+        assert_eq!(parse_post_at("+1d", now()).unwrap(), base + 86_400);
+        assert_eq!(parse_post_at("+1w", now()).unwrap(), base + 604_800);
+    }
+
+    #[test]
+    fn parse_post_at_rfc3339() {
+        assert_eq!(
+            parse_post_at("2026-01-01T01:00:00Z", now()).unwrap(),
+            now().timestamp() + 3600
+        );
+        assert_eq!(
+            parse_post_at("2026-01-01T02:00:00+01:00", now()).unwrap(),
+            now().timestamp() + 3600
+        );
+    }
+
+    #[test]
+    fn parse_post_at_local() {
+        let expected = NaiveDateTime::parse_from_str("2026-02-01 09:30", "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap()
+            .timestamp();
+        assert_eq!(parse_post_at("2026-02-01 09:30", now()).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_post_at_rejects_garbage() {
+        assert!(parse_post_at("tomorrow", now()).is_err());
+        assert!(parse_post_at("+2", now()).is_err());
+        assert!(parse_post_at("+h", now()).is_err());
+        assert!(parse_post_at("+2y", now()).is_err());
+    }
+
+    #[test]
+    fn validate_post_at_bounds() {
+        let base = now().timestamp();
+        assert!(validate_post_at(base + 60, now()).is_ok());
+        assert!(validate_post_at(base + 120 * 86_400, now()).is_ok());
+        assert!(validate_post_at(base + 120 * 86_400 + 1, now())
+            .unwrap_err()
+            .to_string()
+            .contains("120 days"));
+        assert!(validate_post_at(base, now())
+            .unwrap_err()
+            .to_string()
+            .contains("future"));
+    }
+
+    #[test]
+    fn test_schedule_response_deserialize() {
+        let json = r#"{"ok": true, "channel": "C123", "scheduled_message_id": "Q1298393284", "post_at": 1562180400}"#;
+        let response: ScheduleResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.channel, "C123");
+        assert_eq!(response.scheduled_message_id, "Q1298393284");
+    }
+
+    #[test]
+    fn test_scheduled_list_response_deserialize() {
+        let json = r#"{
+            "ok": true,
+            "scheduled_messages": [
+                {"id": "Q1", "channel_id": "C1", "post_at": 1562180400, "date_created": 1562178000, "text": "Ship it"},
+                {"id": "Q2", "channel_id": "C2", "post_at": 1562180500, "date_created": 1562178000}
+            ]
+        }"#;
+        let response: ScheduledListResponse = serde_json::from_str(json).unwrap();
+        let messages: Vec<SlackScheduledMessage> = response
+            .scheduled_messages
+            .into_iter()
+            .map(SlackScheduledMessage::from)
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].text, "Ship it");
+        assert_eq!(messages[1].channel_id, "C2");
+        assert_eq!(messages[1].text, "");
+    }
+}
@@ -1,13 +1,121 @@
 //! Slack message search
 //!
-//! Search messages across channels.
+//! Search messages across channels, optionally narrowed with Slack's
+//! query modifiers (`in:`, `from:`, `after:`, `before:`, `has:`).
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
 use serde::Deserialize;
 
 use super::client::SlackApi;
 use super::types::{SlackSearchChannel, SlackSearchMatch, SlackSearchResult};
 
+/// What a message must contain (`has:` modifier)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum HasFilter {
+    Link,
+    Pin,
+}
+
+impl HasFilter {
+    fn as_str(self) -> &'static str {
+        match self {
+            HasFilter::Link => "link",
+            HasFilter::Pin => "pin",
+        }
+    }
+}
+
+/// Modifiers narrowing a search, composed by [`build_query`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilters {
+    /// Channel name (with or without `#`) or ID
+    pub channel: Option<String>,
+    /// User name (with or without `@`), ID, or `me`
+    pub from: Option<String>,
+    /// Only messages after this day (YYYY-MM-DD, `today` or `yesterday`)
+    pub after: Option<String>,
+    /// Only messages before this day
+    pub before: Option<String>,
+    pub has: Option<HasFilter>,
+}
+
+/// Compose free text and filters into Slack's query syntax (pure, testable)
+///
+/// Names become `in:#chan` / `from:@user`; IDs use Slack's link form
+/// (`in:<#C123>`, `from:<@U123>`) so they match without a lookup.
+pub fn build_query(text: &str, filters: &SearchFilters) -> Result<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let text = text.trim();
+    if !text.is_empty() {
+        parts.push(text.to_string());
+    }
+    if let Some(channel) = &filters.channel {
+        parts.push(format!("in:{}", channel_token(channel)?));
+    }
+    if let Some(user) = &filters.from {
+        parts.push(format!("from:{}", user_token(user)?));
+    }
+    if let Some(day) = &filters.after {
+        parts.push(format!("after:{}", date_token(day)?));
+    }
+    if let Some(day) = &filters.before {
+        parts.push(format!("before:{}", date_token(day)?));
+    }
+    if let Some(has) = filters.has {
+        parts.push(format!("has:{}", has.as_str()));
+    }
+
+    if parts.is_empty() {
+        bail!("Nothing to search for; give a query or at least one filter");
+    }
+    Ok(parts.join(" "))
+}
+
+/// Slack IDs are a type letter followed by uppercase letters and digits
+fn is_slack_id(s: &str, prefixes: &[char]) -> bool {
+    s.len() >= 9
+        && s.starts_with(prefixes)
+        && s.chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+fn channel_token(channel: &str) -> Result<String> {
+    let name = channel.trim().trim_start_matches('#');
+    if name.is_empty() {
+        bail!("--in needs a channel name");
+    }
+    if is_slack_id(name, &['C', 'G', 'D']) {
+        return Ok(format!("<#{}>", name));
+    }
+    Ok(format!("#{}", name))
+}
+
+fn user_token(user: &str) -> Result<String> {
+    let name = user.trim().trim_start_matches('@');
+    if name.is_empty() {
+        bail!("--from needs a user name");
+    }
+    if name == "me" {
+        return Ok("me".to_string());
+    }
+    if is_slack_id(name, &['U', 'W']) {
+        return Ok(format!("<@{}>", name));
+    }
+    Ok(format!("@{}", name))
+}
+
+fn date_token(day: &str) -> Result<String> {
+    let day = day.trim();
+    if matches!(day, "today" | "yesterday") {
+        return Ok(day.to_string());
+    }
+    match NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+        Ok(date) => Ok(date.format("%Y-%m-%d").to_string()),
+        Err(_) => bail!("Invalid date '{}'; use YYYY-MM-DD, today or yesterday", day),
+    }
+}
+
 /// Response from search.messages API
 #[derive(Deserialize)]
 struct SearchResponse {
@@ -90,6 +198,89 @@ pub async fn search_messages(
 mod tests {
     use super::*;
 
+    fn filters() -> SearchFilters {
+        SearchFilters::default()
+    }
+
+    #[test]
+    fn test_build_query_text_only() {
+        assert_eq!(build_query(" deploy ", &filters()).unwrap(), "deploy");
+    }
+
+    #[test]
+    fn test_build_query_all_modifiers() {
+        let filters = SearchFilters {
+            channel: Some("#eng-alerts".to_string()),
+            from: Some("@alice".to_string()),
+            after: Some("2026-01-05".to_string()),
+            before: Some("today".to_string()),
+            has: Some(HasFilter::Link),
+        };
+        assert_eq!(
+            build_query("rollback", &filters).unwrap(),
+            "rollback in:#eng-alerts from:@alice after:2026-01-05 before:today has:link"
+        );
+    }
+
+    #[test]
+    fn test_build_query_filters_without_text() {
+        let filters = SearchFilters {
+            channel: Some("general".to_string()),
+            has: Some(HasFilter::Pin),
+            ..filters()
+        };
+        assert_eq!(build_query("", &filters).unwrap(), "in:#general has:pin");
+    }
+
+    #[test]
+    fn test_build_query_ids_use_link_form() {
+        let filters = SearchFilters {
+            channel: Some("C0123ABCD".to_string()),
+            from: Some("U0123ABCD".to_string()),
+            ..filters()
+        };
+        assert_eq!(
+            build_query("x", &filters).unwrap(),
+            "x in:<#C0123ABCD> from:<@U0123ABCD>"
+        );
+    }
+
+    #[test]
+    fn test_build_query_from_me() {
+        let filters = SearchFilters {
+            from: Some("me".to_string()),
+            ..filters()
+        };
+        assert_eq!(build_query("", &filters).unwrap(), "from:me");
+    }
+
+    #[test]
+    fn test_build_query_lowercase_name_is_not_an_id() {
+        let filters = SearchFilters {
+            channel: Some("communications".to_string()),
+            ..filters()
+        };
+        assert_eq!(build_query("", &filters).unwrap(), "in:#communications");
+    }
+
+    #[test]
+    fn test_build_query_rejects_bad_input() {
+        assert!(build_query("  ", &filters()).is_err());
+        let bad_date = SearchFilters {
+            after: Some("05/01/2026".to_string()),
+            ..filters()
+        };
+        assert!(build_query("x", &bad_date)
+            .unwrap_err()
+            .to_string()
+            .contains("YYYY-MM-DD"));
+        let empty_channel = SearchFilters {
+            channel: Some("#".to_string()),
+            ..filters()
+        };
+        assert!(build_query("x", &empty_channel).is_err());
+    }
+
     #[test]
     fn test_match_response_to_slack_search_match_full() {
         let response = MatchResponse {
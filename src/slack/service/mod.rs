@@ -12,11 +12,14 @@ use super::channels;
 use super::client::SlackApi;
 use super::config::{self, SlackConfig};
 use super::messages;
+use super::schedule;
 use super::search;
 use super::tidy;
 use super::types::{
-    AuthInfo, AuthResult, SlackChannel, SlackMessage, SlackSearchResult, SlackUser, TidySummary,
+    AuthInfo, AuthResult, SlackChannel, SlackMessage, SlackScheduledMessage, SlackSearchResult,
+    SlackUnread, SlackUser, TidySummary,
 };
+use crate::util::bail_kind;
 
 #[cfg(test)]
 mod tests;
@@ -30,7 +33,10 @@ pub fn get_config() -> Result<SlackConfig> {
 /// Check if API is configured, return error if not
 pub fn ensure_configured(config: &SlackConfig) -> Result<()> {
     if !config.is_configured {
-        bail!("Slack is not configured. Run `hu slack auth` to authenticate.");
+        bail_kind!(
+            Config,
+            "Slack is not configured. Run `hu slack auth` to authenticate."
+        );
     }
     Ok(())
 }
@@ -38,7 +44,10 @@ pub fn ensure_configured(config: &SlackConfig) -> Result<()> {
 /// Check if user token is configured, return error if not
 pub fn ensure_user_token(config: &SlackConfig) -> Result<()> {
     if !config.oauth.has_user_token() {
-        bail!("User token required. Run `hu slack auth --user-token <token>`");
+        bail_kind!(
+            Config,
+            "User token required. Run `hu slack auth --user-token <token>`"
+        );
     }
     Ok(())
 }
@@ -67,15 +76,96 @@ pub async fn get_history(
     messages::get_history(client, &channel_id, limit).await
 }
 
-/// Send a message to a channel
+/// Get message history with replies inlined under the newest threads
+///
+/// Only the first `max_threads` messages that have replies get theirs
+/// fetched, each capped at `max_replies`, so a busy channel costs a
+/// bounded number of API calls.
+#[cfg(not(tarpaulin_include))]
+pub async fn get_history_with_threads(
+    client: &impl SlackApi,
+    channel: &str,
+    limit: usize,
+    max_threads: usize,
+    max_replies: usize,
+) -> Result<Vec<SlackMessage>> {
+    let channel_id = channels::resolve_channel(client, channel).await?;
+    let mut history = messages::get_history(client, &channel_id, limit).await?;
+    let threads = history
+        .iter_mut()
+        .filter(|m| m.reply_count.unwrap_or(0) > 0)
+        .take(max_threads);
+    for msg in threads {
+        msg.replies = messages::get_replies(client, &channel_id, &msg.ts, max_replies).await?;
+    }
+    Ok(history)
+}
+
+/// Send a message to a channel, optionally with Block Kit blocks
 #[cfg(not(tarpaulin_include))]
 pub async fn send_message(
     client: &impl SlackApi,
     channel: &str,
     text: &str,
+    blocks: Option<&serde_json::Value>,
 ) -> Result<(String, String)> {
     let channel_id = channels::resolve_channel(client, channel).await?;
-    messages::send_message(client, &channel_id, text).await
+    messages::send_message(client, &channel_id, text, blocks).await
+}
+
+/// Read and validate a Block Kit JSON file
+#[cfg(not(tarpaulin_include))]
+pub fn load_blocks(path: &std::path::Path) -> Result<serde_json::Value> {
+    messages::load_blocks(path)
+}
+
+/// Schedule a message; returns (channel_id, scheduled_message_id, post_at)
+#[cfg(not(tarpaulin_include))]
+pub async fn schedule_message(
+    client: &impl SlackApi,
+    channel: &str,
+    text: &str,
+    at: &str,
+) -> Result<(String, String, i64)> {
+    let now = chrono::Utc::now();
+    let post_at = schedule::parse_post_at(at, now)?;
+    schedule::validate_post_at(post_at, now)?;
+
+    let channel_id = channels::resolve_channel(client, channel).await?;
+    let (channel_id, id) = schedule::schedule_message(client, &channel_id, text, post_at).await?;
+    Ok((channel_id, id, post_at))
+}
+
+/// List scheduled messages, optionally for one channel
+#[cfg(not(tarpaulin_include))]
+pub async fn list_scheduled(
+    client: &impl SlackApi,
+    channel: Option<&str>,
+) -> Result<Vec<SlackScheduledMessage>> {
+    let channel_id = match channel {
+        Some(channel) => Some(channels::resolve_channel(client, channel).await?),
+        None => None,
+    };
+    schedule::list_scheduled(client, channel_id.as_deref()).await
+}
+
+/// Cancel a scheduled message by ID
+///
+/// Slack needs the channel too, so it is looked up from the scheduled list.
+#[cfg(not(tarpaulin_include))]
+pub async fn unschedule(client: &impl SlackApi, id: &str) -> Result<()> {
+    let scheduled = schedule::list_scheduled(client, None).await?;
+    let channel_id = scheduled_channel(&scheduled, id)?;
+    schedule::delete_scheduled(client, channel_id, id).await
+}
+
+/// Channel of the scheduled message with `id`
+pub fn scheduled_channel<'a>(scheduled: &'a [SlackScheduledMessage], id: &str) -> Result<&'a str> {
+    scheduled
+        .iter()
+        .find(|m| m.id == id)
+        .map(|m| m.channel_id.as_str())
+        .ok_or_else(|| anyhow::anyhow!("No scheduled message with ID {}", id))
 }
 
 /// Search messages (requires user token)
@@ -83,9 +173,11 @@ pub async fn send_message(
 pub async fn search_messages(
     client: &impl SlackApi,
     query: &str,
+    filters: &search::SearchFilters,
     count: usize,
 ) -> Result<SlackSearchResult> {
-    search::search_messages(client, query, count).await
+    let query = search::build_query(query, filters)?;
+    search::search_messages(client, &query, count).await
 }
 
 /// List users
@@ -129,7 +221,7 @@ pub fn parse_auth_response(result: &serde_json::Value) -> AuthInfo {
 /// Verify a token via auth.test API and return the raw response
 #[cfg(not(tarpaulin_include))]
 pub async fn verify_token(token: &str) -> Result<serde_json::Value> {
-    let client = reqwest::Client::new();
+    let client = crate::util::http::client()?;
     let response = client
         .get("https://slack.com/api/auth.test")
         .header("Authorization", format!("Bearer {}", token))
@@ -218,11 +310,14 @@ pub async fn whoami(config: &SlackConfig) -> Result<AuthInfo> {
 }
 
 /// Run tidy operation and return structured results
+///
+/// Channels in `tidy_exclude` from the config are always left alone.
 #[cfg(not(tarpaulin_include))]
 pub async fn run_tidy(
     client: &impl SlackApi,
     config: &SlackConfig,
     dry_run: bool,
+    mut filter: tidy::TidyFilter,
 ) -> Result<(Vec<tidy::TidyResult>, TidySummary)> {
     let token = config
         .oauth
@@ -239,12 +334,22 @@ pub async fn run_tidy(
         full_name: auth_info.team.clone(),
     };
 
-    let results = tidy::tidy_channels(client, &user_info, dry_run).await?;
+    filter.exclude.extend(config.tidy_exclude.iter().cloned());
+    let results = tidy::tidy_channels(client, &user_info, dry_run, &filter).await?;
     let summary = compute_tidy_summary(&results);
 
     Ok((results, summary))
 }
 
+/// Member channels with unread messages, most unread first (read-only)
+#[cfg(not(tarpaulin_include))]
+pub async fn list_unread(
+    client: &impl SlackApi,
+    filter: &tidy::TidyFilter,
+) -> Result<Vec<SlackUnread>> {
+    tidy::list_unread(client, filter).await
+}
+
 /// Compute summary counts from tidy results
 pub fn compute_tidy_summary(results: &[tidy::TidyResult]) -> TidySummary {
     let mut marked_read = 0;
@@ -253,7 +358,7 @@ pub fn compute_tidy_summary(results: &[tidy::TidyResult]) -> TidySummary {
 
     for r in results {
         match &r.action {
-            tidy::TidyAction::Skipped => already_read += 1,
+            tidy::TidyAction::Skipped(_) => already_read += 1,
             tidy::TidyAction::MarkedRead => marked_read += 1,
             tidy::TidyAction::HasMention(_) => has_mentions += 1,
         }
@@ -1,10 +1,34 @@
 use super::*;
 
+#[test]
+fn scheduled_channel_finds_message() {
+    let scheduled = vec![
+        SlackScheduledMessage {
+            id: "Q1".to_string(),
+            channel_id: "C1".to_string(),
+            post_at: 1_767_225_600,
+            text: "a".to_string(),
+        },
+        SlackScheduledMessage {
+            id: "Q2".to_string(),
+            channel_id: "C2".to_string(),
+            post_at: 1_767_225_600,
+            text: "b".to_string(),
+        },
+    ];
+    assert_eq!(scheduled_channel(&scheduled, "Q2").unwrap(), "C2");
+    assert!(scheduled_channel(&scheduled, "Q3")
+        .unwrap_err()
+        .to_string()
+        .contains("Q3"));
+}
+
 #[test]
 fn ensure_configured_fails_when_not_configured() {
     let config = SlackConfig {
         oauth: config::OAuthConfig::default(),
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: false,
     };
     let result = ensure_configured(&config);
@@ -24,6 +48,7 @@ fn ensure_configured_succeeds_when_configured() {
             team_name: Some("Test".to_string()),
         },
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: true,
     };
     let result = ensure_configured(&config);
@@ -42,6 +67,7 @@ fn ensure_user_token_fails_when_missing() {
             team_name: None,
         },
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: true,
     };
     let result = ensure_user_token(&config);
@@ -64,6 +90,7 @@ fn ensure_user_token_succeeds_when_present() {
             team_name: None,
         },
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: true,
     };
     let result = ensure_user_token(&config);
@@ -142,19 +169,23 @@ fn compute_tidy_summary_mixed_results() {
         },
         tidy::TidyResult {
             channel_name: "dev".to_string(),
-            action: tidy::TidyAction::HasMention("@you".to_string()),
+            action: tidy::TidyAction::HasMention(tidy::Mention {
+                reason: "@mention".to_string(),
+                text: "@you".to_string(),
+                ts: "1704067200.123456".to_string(),
+            }),
         },
         tidy::TidyResult {
             channel_name: "announcements".to_string(),
-            action: tidy::TidyAction::Skipped,
+            action: tidy::TidyAction::Skipped(tidy::SkipReason::AlreadyRead),
         },
         tidy::TidyResult {
             channel_name: "ops".to_string(),
-            action: tidy::TidyAction::Skipped,
+            action: tidy::TidyAction::Skipped(tidy::SkipReason::AlreadyRead),
         },
         tidy::TidyResult {
             channel_name: "team".to_string(),
-            action: tidy::TidyAction::Skipped,
+            action: tidy::TidyAction::Skipped(tidy::SkipReason::AlreadyRead),
         },
     ];
     let summary = compute_tidy_summary(&results);
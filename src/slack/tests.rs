@@ -6,6 +6,7 @@ fn test_ensure_configured_when_not_configured() {
     let config = SlackConfig {
         oauth: OAuthConfig::default(),
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: false,
     };
     let result = service::ensure_configured(&config);
@@ -25,6 +26,7 @@ fn test_ensure_configured_when_configured() {
             team_name: Some("Test".to_string()),
         },
         default_channel: String::new(),
+        tidy_exclude: Vec::new(),
         is_configured: true,
     };
     let result = service::ensure_configured(&config);
@@ -66,17 +68,33 @@ fn test_slack_commands_send_debug() {
     let cmd = SlackCommands::Send {
         channel: "#test".to_string(),
         message: "Hello".to_string(),
+        blocks: None,
     };
     let debug = format!("{:?}", cmd);
     assert!(debug.contains("Send"));
     assert!(debug.contains("Hello"));
 }
 
+#[test]
+fn test_slack_commands_schedule_debug() {
+    let cmd = SlackCommands::Schedule {
+        channel: "#test".to_string(),
+        message: "Later".to_string(),
+        at: "+2h".to_string(),
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Schedule"));
+    assert!(debug.contains("+2h"));
+}
+
 #[test]
 fn test_slack_commands_history_debug() {
     let cmd = SlackCommands::History {
         channel: "#dev".to_string(),
         limit: 50,
+        threads: false,
+        max_threads: 10,
+        max_replies: 20,
         json: false,
     };
     let debug = format!("{:?}", cmd);
@@ -88,6 +106,11 @@ fn test_slack_commands_history_debug() {
 fn test_slack_commands_search_debug() {
     let cmd = SlackCommands::Search {
         query: "deploy".to_string(),
+        channel: None,
+        from: None,
+        after: None,
+        before: None,
+        has: None,
         count: 20,
         json: true,
     };
@@ -96,6 +119,60 @@ fn test_slack_commands_search_debug() {
     assert!(debug.contains("deploy"));
 }
 
+#[test]
+fn test_slack_commands_search_parses_filters() {
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        cmd: SlackCommands,
+    }
+
+    let cli = TestCli::try_parse_from([
+        "test",
+        "search",
+        "--in",
+        "#eng",
+        "--from",
+        "me",
+        "--after",
+        "2026-01-05",
+        "--has",
+        "pin",
+    ])
+    .unwrap();
+    match cli.cmd {
+        SlackCommands::Search {
+            query,
+            channel,
+            from,
+            after,
+            has,
+            ..
+        } => {
+            assert_eq!(query, "");
+            assert_eq!(channel.as_deref(), Some("#eng"));
+            assert_eq!(from.as_deref(), Some("me"));
+            assert_eq!(after.as_deref(), Some("2026-01-05"));
+            assert_eq!(has, Some(HasFilter::Pin));
+        }
+        _ => panic!("expected Search"),
+    }
+}
+
+#[test]
+fn test_slack_commands_unread_debug() {
+    let cmd = SlackCommands::Unread {
+        channels: vec!["general".to_string()],
+        pattern: None,
+        json: false,
+    };
+    let debug = format!("{:?}", cmd);
+    assert!(debug.contains("Unread"));
+    assert!(debug.contains("general"));
+}
+
 #[test]
 fn test_slack_commands_users_debug() {
     let cmd = SlackCommands::Users { json: false };
@@ -119,7 +196,12 @@ fn test_slack_commands_whoami_debug() {
 
 #[test]
 fn test_slack_commands_tidy_debug() {
-    let cmd = SlackCommands::Tidy { dry_run: true };
+    let cmd = SlackCommands::Tidy {
+        dry_run: true,
+        channels: vec![],
+        pattern: Some("alerts-".to_string()),
+        exclude: vec!["incidents".to_string()],
+    };
     let debug = format!("{:?}", cmd);
     assert!(debug.contains("Tidy"));
     assert!(debug.contains("true"));
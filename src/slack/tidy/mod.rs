@@ -1,17 +1,29 @@
 //! Slack tidy operations
 //!
-//! Mark channels as read if no direct mentions in unread messages.
+//! Mark channels as read if no direct mentions in unread messages, and
+//! list what's unread without touching read markers.
 
+use anstream::println;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::time::Duration;
 use tokio::time::sleep;
 
+use crate::util::fmt::truncate;
+
 use super::client::SlackApi;
+use super::types::SlackUnread;
 
 #[cfg(test)]
 mod tests;
 
+/// Unread messages fetched per channel
+const HISTORY_LIMIT: usize = 100;
+
+/// Characters of the latest message kept as a preview
+const PREVIEW_LEN: usize = 80;
+
 /// User info for mention detection
 pub struct UserInfo {
     pub user_id: String,
@@ -19,10 +31,43 @@ pub struct UserInfo {
     pub full_name: String,
 }
 
+/// Which channels tidy looks at
+///
+/// Empty means every member channel. `exclude` always wins.
+#[derive(Debug, Clone, Default)]
+pub struct TidyFilter {
+    /// Only these channels
+    pub channels: Vec<String>,
+    /// Only channels whose name contains this
+    pub pattern: Option<String>,
+    /// Never these channels
+    pub exclude: Vec<String>,
+}
+
+impl TidyFilter {
+    /// Whether a channel with this display name passes the filter
+    pub fn allows(&self, name: &str) -> bool {
+        let name = name.trim_start_matches('#');
+        let listed = |list: &[String]| list.iter().any(|c| c.trim_start_matches('#') == name);
+
+        if listed(&self.exclude) {
+            return false;
+        }
+        if !self.channels.is_empty() && !listed(&self.channels) {
+            return false;
+        }
+        match &self.pattern {
+            Some(pattern) => name.contains(pattern.trim_start_matches('#')),
+            None => true,
+        }
+    }
+}
+
 /// Channel with unread info
 struct ChannelUnreadInfo {
     last_read: String,
-    has_unreads: bool,
+    /// Why there is nothing to check; `None` when there are unreads
+    skip: Option<SkipReason>,
 }
 
 /// Response from conversations.list with membership info
@@ -95,22 +140,62 @@ pub struct TidyResult {
 
 #[derive(Debug)]
 pub enum TidyAction {
-    Skipped,            // No unreads
-    MarkedRead,         // Marked as read (no mentions)
-    HasMention(String), // Has mention, not marked
+    Skipped(SkipReason), // Nothing unread
+    MarkedRead,          // Marked as read (no mentions)
+    HasMention(Mention), // Has mention, not marked
 }
 
-/// Run tidy operation on all channels
+/// Why a channel had nothing to tidy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Latest message is at or before the read marker
+    AlreadyRead,
+    /// Channel has no messages
+    NoMessages,
+    /// Slack reported no read marker for the channel
+    NoReadMarker,
+}
+
+impl SkipReason {
+    /// Short description for verbose output
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::AlreadyRead => "already read",
+            Self::NoMessages => "no messages",
+            Self::NoReadMarker => "no read marker",
+        }
+    }
+}
+
+/// The unread message that kept a channel unread
+#[derive(Debug, Clone)]
+pub struct Mention {
+    /// What matched: "@mention", "name 'alice'" or "full name"
+    pub reason: String,
+    /// Full message text
+    pub text: String,
+    /// Message timestamp
+    pub ts: String,
+}
+
+impl fmt::Display for Mention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.reason, truncate(&self.text, 50))
+    }
+}
+
+/// Run tidy operation on the member channels `filter` allows
 #[cfg(not(tarpaulin_include))]
 pub async fn tidy_channels(
     client: &impl SlackApi,
     user_info: &UserInfo,
     dry_run: bool,
+    filter: &TidyFilter,
 ) -> Result<Vec<TidyResult>> {
     let mut results = Vec::new();
 
     // Get channels user is member of
-    let channels = list_member_channels(client).await?;
+    let channels = list_member_channels(client, filter).await?;
     println!("Found {} channels you're a member of", channels.len());
 
     for channel in channels {
@@ -122,10 +207,10 @@ pub async fn tidy_channels(
         // Get channel info with last_read
         let info = get_channel_unread_info(client, &channel.id).await?;
 
-        if !info.has_unreads {
+        if let Some(reason) = info.skip {
             results.push(TidyResult {
                 channel_name: display_name,
-                action: TidyAction::Skipped,
+                action: TidyAction::Skipped(reason),
             });
             continue;
         }
@@ -160,6 +245,71 @@ pub async fn tidy_channels(
     Ok(results)
 }
 
+/// Member channels with unread messages, most unread first
+///
+/// Read-only: unlike [`tidy_channels`], nothing is marked as read.
+#[cfg(not(tarpaulin_include))]
+pub async fn list_unread(client: &impl SlackApi, filter: &TidyFilter) -> Result<Vec<SlackUnread>> {
+    let channels = list_member_channels(client, filter).await?;
+    let mut unread = Vec::new();
+
+    for channel in channels {
+        // Rate limit
+        sleep(Duration::from_millis(500)).await;
+
+        let info = get_channel_unread_info(client, &channel.id).await?;
+        if info.skip.is_some() {
+            continue;
+        }
+
+        sleep(Duration::from_millis(500)).await;
+        let messages = get_messages_since(client, &channel.id, &info.last_read).await?;
+        unread.extend(unread_entry(&channel, &messages));
+    }
+
+    sort_unread(&mut unread);
+    Ok(unread)
+}
+
+/// Summarise a channel's unread messages (newest first, as history returns them)
+fn unread_entry(channel: &ChannelListItem, messages: &[HistoryMessage]) -> Option<SlackUnread> {
+    let latest = messages.first()?;
+    // DMs go by the other user's ID so the display can resolve their name
+    let channel_name = channel
+        .name
+        .clone()
+        .or_else(|| channel.user.clone())
+        .unwrap_or_else(|| channel.id.clone());
+
+    Some(SlackUnread {
+        channel_id: channel.id.clone(),
+        channel_name,
+        count: messages.len(),
+        capped: messages.len() >= HISTORY_LIMIT,
+        latest_ts: latest.ts.clone(),
+        preview: preview(latest.text.as_deref().unwrap_or_default()),
+    })
+}
+
+/// First non-blank line of a message, truncated
+fn preview(text: &str) -> String {
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or_default();
+    truncate(line, PREVIEW_LEN)
+}
+
+/// Most unread first, then by name
+fn sort_unread(unread: &mut [SlackUnread]) {
+    unread.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.channel_name.cmp(&b.channel_name))
+    });
+}
+
 /// Get display name for a channel/DM
 fn get_display_name(channel: &ChannelListItem) -> String {
     if let Some(ref name) = channel.name {
@@ -172,9 +322,12 @@ fn get_display_name(channel: &ChannelListItem) -> String {
     }
 }
 
-/// List channels where user is a member
+/// List channels where user is a member and `filter` allows
 #[cfg(not(tarpaulin_include))]
-async fn list_member_channels(client: &impl SlackApi) -> Result<Vec<ChannelListItem>> {
+async fn list_member_channels(
+    client: &impl SlackApi,
+    filter: &TidyFilter,
+) -> Result<Vec<ChannelListItem>> {
     let mut all_channels = Vec::new();
     let mut cursor: Option<String> = None;
     let mut first = true;
@@ -204,7 +357,7 @@ async fn list_member_channels(client: &impl SlackApi) -> Result<Vec<ChannelListI
         for ch in response.channels {
             // DMs (is_im) don't have is_member field - user is implicitly a member
             let is_member = ch.is_im.unwrap_or(false) || ch.is_member.unwrap_or(false);
-            if is_member {
+            if is_member && filter.allows(&get_display_name(&ch)) {
                 all_channels.push(ch);
             }
         }
@@ -231,13 +384,22 @@ async fn get_channel_unread_info(
     let last_read = response.channel.last_read.unwrap_or_default();
     let latest_ts = response.channel.latest.map(|l| l.ts).unwrap_or_default();
 
-    // Has unreads if latest message ts > last_read ts
-    let has_unreads = !last_read.is_empty() && !latest_ts.is_empty() && latest_ts > last_read;
+    let skip = skip_reason(&last_read, &latest_ts);
 
-    Ok(ChannelUnreadInfo {
-        last_read,
-        has_unreads,
-    })
+    Ok(ChannelUnreadInfo { last_read, skip })
+}
+
+/// Why a channel has no unreads, or `None` if latest ts > last_read ts
+fn skip_reason(last_read: &str, latest_ts: &str) -> Option<SkipReason> {
+    if latest_ts.is_empty() {
+        Some(SkipReason::NoMessages)
+    } else if last_read.is_empty() {
+        Some(SkipReason::NoReadMarker)
+    } else if latest_ts <= last_read {
+        Some(SkipReason::AlreadyRead)
+    } else {
+        None
+    }
 }
 
 /// Get messages since last_read timestamp
@@ -247,13 +409,14 @@ async fn get_messages_since(
     channel_id: &str,
     oldest: &str,
 ) -> Result<Vec<HistoryMessage>> {
+    let limit = HISTORY_LIMIT.to_string();
     let response: HistoryResponse = client
         .get_with_user_token(
             "conversations.history",
             &[
                 ("channel", channel_id),
                 ("oldest", oldest),
-                ("limit", "100"),
+                ("limit", &limit),
             ],
         )
         .await?;
@@ -262,28 +425,32 @@ async fn get_messages_since(
 }
 
 /// Check if any message contains a mention of the user
-fn find_mention(messages: &[HistoryMessage], user_info: &UserInfo) -> Option<String> {
+fn find_mention(messages: &[HistoryMessage], user_info: &UserInfo) -> Option<Mention> {
     let user_mention = format!("<@{}>", user_info.user_id);
     let name_lower = user_info.name.to_lowercase();
     let full_name_lower = user_info.full_name.to_lowercase();
 
     for msg in messages {
         if let Some(ref text) = msg.text {
-            // Check direct mention
-            if text.contains(&user_mention) {
-                return Some(format!("@mention: {}", truncate(text, 50)));
-            }
-
-            // Check name (case-insensitive)
             let text_lower = text.to_lowercase();
-            if text_lower.contains(&name_lower) {
-                return Some(format!("name '{}': {}", user_info.name, truncate(text, 50)));
-            }
-
-            // Check full name (case-insensitive)
-            if text_lower.contains(&full_name_lower) {
-                return Some(format!("full name: {}", truncate(text, 50)));
-            }
+            let reason = if text.contains(&user_mention) {
+                // Direct mention
+                "@mention".to_string()
+            } else if text_lower.contains(&name_lower) {
+                // Name (case-insensitive)
+                format!("name '{}'", user_info.name)
+            } else if text_lower.contains(&full_name_lower) {
+                // Full name (case-insensitive)
+                "full name".to_string()
+            } else {
+                continue;
+            };
+
+            return Some(Mention {
+                reason,
+                text: text.clone(),
+                ts: msg.ts.clone(),
+            });
         }
     }
 
@@ -303,11 +470,3 @@ async fn mark_channel_read(client: &impl SlackApi, channel_id: &str, ts: &str) -
         .await?;
     Ok(())
 }
-
-fn truncate(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max.saturating_sub(3)])
-    }
-}
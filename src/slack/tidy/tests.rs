@@ -25,8 +25,8 @@ fn test_tidy_result_debug() {
 
 #[test]
 fn test_tidy_action_skipped_debug() {
-    let action = TidyAction::Skipped;
-    assert_eq!(format!("{:?}", action), "Skipped");
+    let action = TidyAction::Skipped(SkipReason::AlreadyRead);
+    assert_eq!(format!("{:?}", action), "Skipped(AlreadyRead)");
 }
 
 #[test]
@@ -37,12 +37,78 @@ fn test_tidy_action_marked_read_debug() {
 
 #[test]
 fn test_tidy_action_has_mention_debug() {
-    let action = TidyAction::HasMention("@alice mentioned you".to_string());
+    let action = TidyAction::HasMention(Mention {
+        reason: "@mention".to_string(),
+        text: "@alice mentioned you".to_string(),
+        ts: "1704067200.123456".to_string(),
+    });
     let debug = format!("{:?}", action);
     assert!(debug.contains("HasMention"));
     assert!(debug.contains("@alice mentioned you"));
 }
 
+#[test]
+fn test_find_mention_keeps_full_text_and_ts() {
+    let long = format!("<@U12345> {}", "x".repeat(80));
+    let messages = vec![HistoryMessage {
+        ts: "1704067200.123456".to_string(),
+        text: Some(long.clone()),
+    }];
+    let user_info = UserInfo {
+        user_id: "U12345".to_string(),
+        name: "alice".to_string(),
+        full_name: "Alice Smith".to_string(),
+    };
+    let mention = find_mention(&messages, &user_info).unwrap();
+    assert_eq!(mention.reason, "@mention");
+    assert_eq!(mention.text, long);
+    assert_eq!(mention.ts, "1704067200.123456");
+    assert!(mention.to_string().ends_with("..."));
+}
+
+#[test]
+fn test_tidy_filter_default_allows_all() {
+    let filter = TidyFilter::default();
+    assert!(filter.allows("general"));
+    assert!(filter.allows("DM:U67890"));
+}
+
+#[test]
+fn test_tidy_filter_channels_and_pattern() {
+    let filter = TidyFilter {
+        channels: vec!["#random".to_string()],
+        ..TidyFilter::default()
+    };
+    assert!(filter.allows("random"));
+    assert!(!filter.allows("general"));
+
+    let filter = TidyFilter {
+        pattern: Some("alerts-".to_string()),
+        ..TidyFilter::default()
+    };
+    assert!(filter.allows("alerts-prod"));
+    assert!(!filter.allows("DM:U67890"));
+}
+
+#[test]
+fn test_tidy_filter_exclude_wins() {
+    let filter = TidyFilter {
+        channels: vec!["incidents".to_string()],
+        pattern: Some("inc".to_string()),
+        exclude: vec!["#incidents".to_string()],
+    };
+    assert!(!filter.allows("incidents"));
+}
+
+#[test]
+fn test_skip_reason() {
+    assert_eq!(skip_reason("1.0", ""), Some(SkipReason::NoMessages));
+    assert_eq!(skip_reason("", "2.0"), Some(SkipReason::NoReadMarker));
+    assert_eq!(skip_reason("2.0", "2.0"), Some(SkipReason::AlreadyRead));
+    assert_eq!(skip_reason("1.0", "2.0"), None);
+    assert_eq!(SkipReason::NoMessages.describe(), "no messages");
+}
+
 #[test]
 fn test_get_display_name_with_name() {
     let channel = ChannelListItem {
@@ -79,24 +145,90 @@ fn test_get_display_name_fallback_to_id() {
     assert_eq!(get_display_name(&channel), "G12345");
 }
 
+fn history(ts: &str, text: Option<&str>) -> HistoryMessage {
+    HistoryMessage {
+        ts: ts.to_string(),
+        text: text.map(str::to_string),
+    }
+}
+
+fn unread(name: &str, count: usize) -> SlackUnread {
+    SlackUnread {
+        channel_id: format!("C-{}", name),
+        channel_name: name.to_string(),
+        count,
+        capped: false,
+        latest_ts: "1704067200.000000".to_string(),
+        preview: String::new(),
+    }
+}
+
 #[test]
-fn test_truncate_short_string() {
-    assert_eq!(truncate("hello", 10), "hello");
+fn test_unread_entry_uses_latest_message() {
+    let channel = ChannelListItem {
+        id: "C12345".to_string(),
+        name: Some("general".to_string()),
+        user: None,
+        is_member: Some(true),
+        is_im: None,
+    };
+    let messages = vec![
+        history("1704067300.000000", Some("\n  Deploy done  \nsecond line")),
+        history("1704067200.000000", Some("Deploying")),
+    ];
+    let entry = unread_entry(&channel, &messages).unwrap();
+    assert_eq!(entry.channel_id, "C12345");
+    assert_eq!(entry.channel_name, "general");
+    assert_eq!(entry.count, 2);
+    assert!(!entry.capped);
+    assert_eq!(entry.latest_ts, "1704067300.000000");
+    assert_eq!(entry.preview, "Deploy done");
 }
 
 #[test]
-fn test_truncate_exact_length() {
-    assert_eq!(truncate("hello", 5), "hello");
+fn test_unread_entry_dm_uses_user_id() {
+    let channel = ChannelListItem {
+        id: "D12345".to_string(),
+        name: None,
+        user: Some("U67890".to_string()),
+        is_member: None,
+        is_im: Some(true),
+    };
+    let entry = unread_entry(&channel, &[history("1.0", None)]).unwrap();
+    assert_eq!(entry.channel_name, "U67890");
+    assert_eq!(entry.preview, "");
+}
+
+#[test]
+fn test_unread_entry_capped_and_empty() {
+    let channel = ChannelListItem {
+        id: "C1".to_string(),
+        name: Some("busy".to_string()),
+        user: None,
+        is_member: Some(true),
+        is_im: None,
+    };
+    let messages: Vec<HistoryMessage> = (0..HISTORY_LIMIT)
+        .map(|i| history(&i.to_string(), Some("x")))
+        .collect();
+    assert!(unread_entry(&channel, &messages).unwrap().capped);
+    assert!(unread_entry(&channel, &[]).is_none());
 }
 
 #[test]
-fn test_truncate_long_string() {
-    assert_eq!(truncate("hello world", 8), "hello...");
+fn test_preview_is_char_safe() {
+    let text = "ż".repeat(PREVIEW_LEN + 10);
+    let result = preview(&text);
+    assert_eq!(result.chars().count(), PREVIEW_LEN);
+    assert!(result.ends_with("..."));
 }
 
 #[test]
-fn test_truncate_very_short_max() {
-    assert_eq!(truncate("hello", 3), "...");
+fn test_sort_unread_most_first_then_name() {
+    let mut list = vec![unread("b", 2), unread("c", 9), unread("a", 2)];
+    sort_unread(&mut list);
+    let names: Vec<&str> = list.iter().map(|u| u.channel_name.as_str()).collect();
+    assert_eq!(names, vec!["c", "a", "b"]);
 }
 
 #[test]
@@ -113,7 +245,7 @@ fn test_find_mention_direct_user_mention() {
 
     let result = find_mention(&messages, &user_info);
     assert!(result.is_some());
-    assert!(result.unwrap().contains("@mention"));
+    assert!(result.unwrap().to_string().contains("@mention"));
 }
 
 #[test]
@@ -130,7 +262,7 @@ fn test_find_mention_name_match() {
 
     let result = find_mention(&messages, &user_info);
     assert!(result.is_some());
-    assert!(result.unwrap().contains("name 'Alice'"));
+    assert!(result.unwrap().to_string().contains("name 'Alice'"));
 }
 
 #[test]
@@ -147,7 +279,7 @@ fn test_find_mention_full_name_match() {
 
     let result = find_mention(&messages, &user_info);
     assert!(result.is_some());
-    assert!(result.unwrap().contains("full name"));
+    assert!(result.unwrap().to_string().contains("full name"));
 }
 
 #[test]
@@ -44,6 +44,22 @@ pub struct SlackMessage {
     /// User display name (enriched after fetch)
     #[serde(skip_deserializing)]
     pub username: Option<String>,
+    /// Thread replies, oldest first (filled in by `history --threads`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replies: Vec<SlackMessage>,
+}
+
+/// Message queued with chat.scheduleMessage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackScheduledMessage {
+    /// Scheduled message ID (e.g., "Q1298393284")
+    pub id: String,
+    /// Channel it will be posted to
+    pub channel_id: String,
+    /// Unix time it will be posted
+    pub post_at: i64,
+    /// Message text
+    pub text: String,
 }
 
 /// Slack user information
@@ -124,6 +140,23 @@ pub enum AuthResult {
     OAuthCompleted { team_name: Option<String> },
 }
 
+/// A channel with unread messages (`hu slack unread`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackUnread {
+    /// Channel ID
+    pub channel_id: String,
+    /// Channel name, or the other user's ID for DMs
+    pub channel_name: String,
+    /// Unread messages (stops counting at the fetch limit)
+    pub count: usize,
+    /// Whether there may be more unread than `count`
+    pub capped: bool,
+    /// Timestamp of the latest unread message
+    pub latest_ts: String,
+    /// First line of the latest unread message
+    pub preview: String,
+}
+
 /// Summary of a tidy operation
 #[derive(Debug, Clone)]
 pub struct TidySummary {
@@ -183,6 +216,7 @@ mod tests {
             thread_ts: None,
             reply_count: Some(5),
             username: None,
+            replies: Vec::new(),
         };
         let debug = format!("{:?}", msg);
         assert!(debug.contains("SlackMessage"));
@@ -0,0 +1,183 @@
+//! Dashboard rendering: three stacked tables
+
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
+
+use super::{Dashboard, Panel};
+use crate::gh::{CiStatus, PullRequest};
+use crate::pagerduty::types::{IncidentStatus, Urgency};
+use crate::{newrelic, pagerduty};
+
+/// Render the whole dashboard
+pub fn render(dashboard: &Dashboard) -> String {
+    [
+        section("Pull requests", &dashboard.prs, pr_table),
+        section("PagerDuty alerts", &dashboard.alerts, alert_table),
+        section("New Relic incidents", &dashboard.incidents, incident_table),
+    ]
+    .join("\n\n")
+}
+
+/// Heading plus table; non-ready panels become a single dim or red row
+fn section<T>(title: &str, panel: &Panel<T>, build: fn(&[T]) -> Table) -> String {
+    let (heading, table) = match panel {
+        Panel::Ready(items) if items.is_empty() => (
+            title.to_string(),
+            message_table(Cell::new("none").fg(Color::DarkGrey)),
+        ),
+        Panel::Ready(items) => (format!("{} ({})", title, items.len()), build(items)),
+        Panel::NotConfigured(hint) => (
+            title.to_string(),
+            message_table(Cell::new(format!("not configured · run: {}", hint)).fg(Color::DarkGrey)),
+        ),
+        Panel::Failed(err) => (
+            title.to_string(),
+            message_table(Cell::new(format!("error: {}", err)).fg(Color::Red)),
+        ),
+    };
+    format!("{}\n{}", heading, table)
+}
+
+fn new_table(header: Vec<&str>) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(header);
+    table
+}
+
+fn message_table(cell: Cell) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.add_row(vec![cell]);
+    table
+}
+
+fn pr_table(prs: &[PullRequest]) -> Table {
+    let mut table = new_table(vec!["CI", "Repo", "#", "Title"]);
+    for pr in prs {
+        let (icon, color) = match pr.ci_status.unwrap_or(CiStatus::Unknown) {
+            CiStatus::Success => ("✓", Color::Green),
+            CiStatus::Pending => ("◐", Color::Yellow),
+            CiStatus::Failed => ("✗", Color::Red),
+            CiStatus::Unknown => ("○", Color::DarkGrey),
+        };
+        table.add_row(vec![
+            Cell::new(icon).fg(color),
+            Cell::new(&pr.repo_full_name),
+            Cell::new(pr.number.to_string()).fg(Color::Cyan),
+            Cell::new(&pr.title),
+        ]);
+    }
+    table
+}
+
+fn alert_table(alerts: &[pagerduty::Incident]) -> Table {
+    let mut table = new_table(vec!["#", "Status", "Urgency", "Service", "Title"]);
+    for alert in alerts {
+        let status_color = match alert.status {
+            IncidentStatus::Triggered => Color::Red,
+            IncidentStatus::Acknowledged => Color::Yellow,
+            IncidentStatus::Resolved => Color::Green,
+        };
+        let urgency_color = match alert.urgency {
+            Urgency::High => Color::Red,
+            Urgency::Low => Color::Reset,
+        };
+        table.add_row(vec![
+            Cell::new(alert.incident_number.to_string()).fg(Color::Cyan),
+            Cell::new(format!("{:?}", alert.status)).fg(status_color),
+            Cell::new(format!("{:?}", alert.urgency)).fg(urgency_color),
+            Cell::new(&alert.service.name),
+            Cell::new(&alert.title),
+        ]);
+    }
+    table
+}
+
+fn incident_table(incidents: &[newrelic::Incident]) -> Table {
+    let mut table = new_table(vec!["Priority", "State", "Title"]);
+    for incident in incidents {
+        let color = match incident.priority.as_str() {
+            "CRITICAL" => Color::Red,
+            "HIGH" => Color::Yellow,
+            _ => Color::Reset,
+        };
+        table.add_row(vec![
+            Cell::new(&incident.priority).fg(color),
+            Cell::new(&incident.state),
+            Cell::new(&incident.title),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: u64, status: Option<CiStatus>) -> PullRequest {
+        PullRequest {
+            number,
+            title: format!("PR {}", number),
+            html_url: String::new(),
+            state: "open".to_string(),
+            repo_full_name: "acme/api".to_string(),
+            author: String::new(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            ci_status: status,
+        }
+    }
+
+    fn incident(title: &str) -> newrelic::Incident {
+        newrelic::Incident {
+            incident_id: "1".to_string(),
+            title: title.to_string(),
+            priority: "CRITICAL".to_string(),
+            state: "ACTIVATED".to_string(),
+            account_ids: vec![1],
+            created_at: None,
+            closed_at: None,
+        }
+    }
+
+    #[test]
+    fn renders_three_sections_in_order() {
+        let dashboard = Dashboard {
+            prs: Panel::Ready(vec![pr(7, Some(CiStatus::Failed)), pr(8, None)]),
+            alerts: Panel::NotConfigured("hu pagerduty auth <token>"),
+            incidents: Panel::Ready(vec![incident("High error rate")]),
+        };
+        let out = render(&dashboard);
+
+        let prs = out.find("Pull requests (2)").unwrap();
+        let alerts = out.find("PagerDuty alerts").unwrap();
+        let incidents = out.find("New Relic incidents (1)").unwrap();
+        assert!(prs < alerts && alerts < incidents);
+        assert!(out.contains("acme/api"));
+        assert!(out.contains("✗"));
+        assert!(out.contains("High error rate"));
+    }
+
+    #[test]
+    fn unconfigured_and_failed_panels_degrade() {
+        let dashboard = Dashboard {
+            prs: Panel::Failed("rate limited".to_string()),
+            alerts: Panel::NotConfigured("hu pagerduty auth <token>"),
+            incidents: Panel::Ready(vec![]),
+        };
+        let out = render(&dashboard);
+        assert!(out.contains("error: rate limited"));
+        assert!(out.contains("not configured · run: hu pagerduty auth <token>"));
+        assert!(out.contains("none"));
+    }
+
+    #[test]
+    fn panel_from_result() {
+        let ok: Panel<u8> = Ok(vec![1, 2]).into();
+        assert!(matches!(ok, Panel::Ready(items) if items.len() == 2));
+        let err: Panel<u8> = Err(anyhow::anyhow!("boom")).into();
+        assert!(matches!(err, Panel::Failed(msg) if msg == "boom"));
+    }
+}
@@ -0,0 +1,116 @@
+//! `hu status` — open PRs, PagerDuty alerts and New Relic incidents on one screen
+
+mod display;
+
+use std::time::Duration;
+
+use anstream::{print, println};
+use anyhow::Result;
+use clap::Args;
+
+use crate::gh::PullRequest;
+
+/// Alerts and incidents shown per panel
+const PANEL_LIMIT: usize = 10;
+
+#[derive(Debug, Args)]
+pub struct StatusArgs {
+    /// Keep refreshing until Ctrl-C
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Seconds between refreshes with --watch
+    #[arg(short, long, default_value = "30", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+}
+
+/// One integration's section of the dashboard
+#[derive(Debug)]
+pub enum Panel<T> {
+    Ready(Vec<T>),
+    /// Integration has no credentials; carries the setup hint
+    NotConfigured(&'static str),
+    Failed(String),
+}
+
+impl<T> From<Result<Vec<T>>> for Panel<T> {
+    fn from(result: Result<Vec<T>>) -> Self {
+        match result {
+            Ok(items) => Self::Ready(items),
+            Err(e) => Self::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Everything shown on one refresh
+#[derive(Debug)]
+pub struct Dashboard {
+    pub prs: Panel<PullRequest>,
+    pub alerts: Panel<crate::pagerduty::Incident>,
+    pub incidents: Panel<crate::newrelic::Incident>,
+}
+
+/// Handle `hu status`
+#[cfg(not(tarpaulin_include))]
+pub async fn run(args: StatusArgs) -> Result<()> {
+    if !args.watch {
+        println!("{}", display::render(&fetch().await));
+        return Ok(());
+    }
+
+    let interval = Duration::from_secs(args.interval);
+    loop {
+        let dashboard = fetch().await;
+        // Clear the screen and home the cursor before redrawing
+        print!("\x1b[2J\x1b[H");
+        println!("{}", display::render(&dashboard));
+        println!(
+            "Updated {} · every {}s · Ctrl-C to quit",
+            chrono::Local::now().format("%H:%M:%S"),
+            args.interval
+        );
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+/// Fetch all panels concurrently
+#[cfg(not(tarpaulin_include))]
+async fn fetch() -> Dashboard {
+    let (prs, alerts, incidents) = tokio::join!(fetch_prs(), fetch_alerts(), fetch_incidents());
+    Dashboard {
+        prs,
+        alerts,
+        incidents,
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn fetch_prs() -> Panel<PullRequest> {
+    let configured = crate::util::load_credentials().map(|c| c.github.is_some());
+    match configured {
+        Ok(false) => Panel::NotConfigured("hu gh login"),
+        Ok(true) => crate::gh::list_user_prs_with_ci().await.into(),
+        Err(e) => Panel::Failed(e.to_string()),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn fetch_alerts() -> Panel<crate::pagerduty::Incident> {
+    match crate::pagerduty::get_config().map(|c| c.is_configured()) {
+        Ok(false) => Panel::NotConfigured("hu pagerduty auth <token>"),
+        Ok(true) => crate::pagerduty::list_alerts(PANEL_LIMIT).await.into(),
+        Err(e) => Panel::Failed(e.to_string()),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+async fn fetch_incidents() -> Panel<crate::newrelic::Incident> {
+    match crate::newrelic::get_config().map(|c| c.is_configured()) {
+        Ok(false) => Panel::NotConfigured("hu newrelic auth <key> --account <id>"),
+        Ok(true) => crate::newrelic::list_incidents(PANEL_LIMIT).await.into(),
+        Err(e) => Panel::Failed(e.to_string()),
+    }
+}
@@ -0,0 +1,57 @@
+//! Color control for terminal output.
+//!
+//! Output goes through `anstream`, which strips ANSI escapes when `NO_COLOR`
+//! is set, `TERM=dumb`, or stdout is not a terminal. `--no-color` forces
+//! color off regardless.
+
+use anstream::ColorChoice;
+
+/// Apply `--no-color`; call once at startup before printing anything
+pub fn init(no_color: bool) {
+    if no_color {
+        ColorChoice::Never.write_global();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn render(choice: ColorChoice, text: &str) -> String {
+        let mut out = anstream::AutoStream::new(Vec::new(), choice);
+        write!(out, "{text}").unwrap();
+        String::from_utf8(out.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn disabled_color_strips_escapes() {
+        let header = "\x1b[1mActive Sprint Issues\x1b[0m (3 total)";
+        let plain = render(ColorChoice::Never, header);
+        assert_eq!(plain, "Active Sprint Issues (3 total)");
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn disabled_color_strips_table_styling() {
+        let mut table = comfy_table::Table::new();
+        table.enforce_styling();
+        table.set_header(vec!["NAME", "STATUS"]);
+        table.add_row(vec![
+            comfy_table::Cell::new("api").fg(comfy_table::Color::Cyan),
+            comfy_table::Cell::new("Failed").fg(comfy_table::Color::Red),
+        ]);
+        let styled = table.to_string();
+        assert!(styled.contains('\x1b'));
+
+        let plain = render(ColorChoice::Never, &styled);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.contains("api") && plain.contains("Failed"));
+    }
+
+    #[test]
+    fn forced_color_keeps_escapes() {
+        let line = "\x1b[32m\u{2713}\x1b[0m Done";
+        assert_eq!(render(ColorChoice::Always, line), line);
+    }
+}
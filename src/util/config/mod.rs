@@ -1,11 +1,15 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 #[cfg(test)]
 mod tests;
 
+/// Settings file chosen with `--config` / `HU_CONFIG`
+static SETTINGS_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Credentials {
     #[serde(default)]
@@ -43,13 +47,41 @@ pub fn config_dir() -> Result<PathBuf> {
     Ok(proj_dirs.config_dir().to_path_buf())
 }
 
+/// Use `path` instead of `~/.config/hu/settings.toml` (first call wins)
+pub fn set_settings_path(path: PathBuf) {
+    let _ = SETTINGS_OVERRIDE.set(path);
+}
+
+/// Path to `settings.toml`, shared by every module's config loader
+pub fn settings_path() -> Option<PathBuf> {
+    resolve_settings_path(
+        SETTINGS_OVERRIDE.get().map(PathBuf::as_path),
+        dirs::home_dir(),
+    )
+}
+
+/// The override if given, else `<home>/.config/hu/settings.toml`
+pub fn resolve_settings_path(over: Option<&Path>, home: Option<PathBuf>) -> Option<PathBuf> {
+    match over {
+        Some(path) => Some(path.to_path_buf()),
+        None => home.map(|h| h.join(".config").join("hu").join("settings.toml")),
+    }
+}
+
 /// Returns the path to credentials.toml
-fn credentials_path() -> Result<PathBuf> {
+pub fn credentials_path() -> Result<PathBuf> {
     Ok(config_dir()?.join("credentials.toml"))
 }
 
-/// Load credentials from config dir
+/// Load credentials from the keychain or config dir
+///
+/// With `secure_credentials` on, the keychain copy wins over the file.
 pub fn load_credentials() -> Result<Credentials> {
+    if super::secrets::enabled() {
+        if let Some(contents) = super::secrets::get(super::secrets::CREDENTIALS_KEY) {
+            return toml::from_str(&contents).context("Failed to parse keychain credentials");
+        }
+    }
     let path = credentials_path()?;
     load_credentials_from(&path)
 }
@@ -66,9 +98,25 @@ pub fn load_credentials_from(path: &PathBuf) -> Result<Credentials> {
     toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
 }
 
-/// Save credentials to config dir
+/// Save credentials to the keychain or config dir
+///
+/// With `secure_credentials` on, a successful keychain write removes
+/// `credentials.toml`; otherwise the file is written as before.
 pub fn save_credentials(creds: &Credentials) -> Result<()> {
     let path = credentials_path()?;
+    if super::secrets::enabled() {
+        let contents = toml::to_string_pretty(creds).context("Failed to serialize credentials")?;
+        match super::secrets::set(super::secrets::CREDENTIALS_KEY, &contents) {
+            Ok(()) => {
+                if path.exists() {
+                    fs::remove_file(&path)
+                        .with_context(|| format!("Failed to remove {}", path.display()))?;
+                }
+                return Ok(());
+            }
+            Err(e) => log::warn!("{:#}; saving to {} instead", e, path.display()),
+        }
+    }
     save_credentials_to(creds, &path)
 }
 
@@ -0,0 +1,153 @@
+//! `--dry-run`: print external commands instead of running them
+//!
+//! Lookups a command needs to fill in its arguments (listing pods to match
+//! a name, reading the current context) still run, so the printed line is
+//! the one that would have executed. Only commands listed in
+//! [`crate::cli::Command::supports_dry_run`] accept the flag; the rest
+//! refuse it rather than write anything.
+
+use anstream::println;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn dry-run on for the rest of the process
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether `--dry-run` was given
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Error that ends a command once its final step has been printed
+///
+/// `main` treats it as success.
+#[derive(Debug)]
+pub struct Printed;
+
+impl fmt::Display for Printed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dry run: command printed, not run")
+    }
+}
+
+impl std::error::Error for Printed {}
+
+/// Whether `err` is a dry run stopping after [`intercept`]
+pub fn is_printed(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| cause.is::<Printed>())
+}
+
+/// Under `--dry-run`, print `cmd` and stop with [`Printed`]; otherwise
+/// return so the caller runs it
+///
+/// For a command's final step, whose output nothing else depends on.
+pub fn intercept(cmd: &Command) -> anyhow::Result<()> {
+    if enabled() {
+        println!("{}", command_line(cmd));
+        return Err(Printed.into());
+    }
+    Ok(())
+}
+
+/// Under `--dry-run`, print `cmd` and return true so the caller skips it
+/// and carries on with the next step
+pub fn skip(cmd: &Command) -> bool {
+    if enabled() {
+        println!("{}", command_line(cmd));
+    }
+    enabled()
+}
+
+/// `cmd` as a shell line: working directory, env vars it sets, then
+/// program and args
+pub fn command_line(cmd: &Command) -> String {
+    let cd = cmd
+        .get_current_dir()
+        .filter(|dir| *dir != Path::new("."))
+        .map(|dir| format!("cd {} &&", quote(&dir.to_string_lossy())));
+    let envs = cmd.get_envs().filter_map(|(key, value)| {
+        value.map(|v| format!("{}={}", key.to_string_lossy(), quote(&v.to_string_lossy())))
+    });
+    let words = std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|word| quote(&word.to_string_lossy()));
+    cd.into_iter()
+        .chain(envs)
+        .chain(words)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Single-quote `word` unless the shell would read it as-is
+fn quote(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+    if plain {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_leaves_plain_words() {
+        assert_eq!(quote("web-7f9c"), "web-7f9c");
+        assert_eq!(
+            quote("--context=arn:aws:eks:us-east-1:1:cluster/api"),
+            "--context=arn:aws:eks:us-east-1:1:cluster/api"
+        );
+    }
+
+    #[test]
+    fn quote_wraps_special_words() {
+        assert_eq!(quote(""), "''");
+        assert_eq!(quote("rails c"), "'rails c'");
+        assert_eq!(quote("it's"), r"'it'\''s'");
+    }
+
+    #[test]
+    fn command_line_lists_env_program_and_args() {
+        let mut cmd = Command::new("kubectl");
+        cmd.env("KUBECONFIG", "/tmp/k c")
+            .args(["-n", "web", "exec", "-it", "web-1", "--", "/bin/sh"]);
+        assert_eq!(
+            command_line(&cmd),
+            "KUBECONFIG='/tmp/k c' kubectl -n web exec -it web-1 -- /bin/sh"
+        );
+    }
+
+    #[test]
+    fn command_line_changes_directory() {
+        let mut cmd = Command::new("git");
+        cmd.args(["stash", "push"]).current_dir("/tmp/my repo");
+        assert_eq!(command_line(&cmd), "cd '/tmp/my repo' && git stash push");
+
+        let mut here = Command::new("git");
+        here.arg("fetch").current_dir(".");
+        assert_eq!(command_line(&here), "git fetch");
+    }
+
+    #[test]
+    fn printed_is_found_under_context() {
+        let err = anyhow::Error::from(Printed).context("Failed to execute kubectl exec");
+        assert!(is_printed(&err));
+        assert!(!is_printed(&anyhow::anyhow!("kubectl failed")));
+    }
+
+    #[test]
+    fn command_line_without_args() {
+        assert_eq!(command_line(&Command::new("aws")), "aws");
+    }
+}
@@ -0,0 +1,274 @@
+//! Error kinds and the exit codes they map to
+//!
+//! Most failures are plain `anyhow` errors and exit 1. Where a script can
+//! act on the difference (re-auth, retry, give up), call sites raise a
+//! [`HuError`] with a kind, and `main` picks the exit code from it.
+//! Transport errors (`reqwest`, octocrab, TOML) are classified without any
+//! help from the call site.
+
+use anstream::eprintln;
+use serde::Serialize;
+use std::fmt;
+use std::process::ExitCode;
+
+use super::OutputFormat;
+
+/// What went wrong, as far as a script cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Anything not classified below
+    General,
+    /// Missing, expired or rejected credentials
+    Auth,
+    /// The ticket, PR, incident, etc. doesn't exist
+    NotFound,
+    /// Couldn't reach the service
+    Network,
+    /// Settings are missing or malformed
+    Config,
+    /// An external tool or upstream service failed
+    External,
+}
+
+impl ErrorKind {
+    /// Process exit code (2 is left to clap for usage errors)
+    pub fn exit_code(self) -> u8 {
+        match self {
+            ErrorKind::General => 1,
+            ErrorKind::Auth => 3,
+            ErrorKind::NotFound => 4,
+            ErrorKind::Network => 5,
+            ErrorKind::Config => 6,
+            ErrorKind::External => 7,
+        }
+    }
+
+    /// Kind for a failed HTTP response, if the status says anything useful
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            401 | 403 => ErrorKind::Auth,
+            404 => ErrorKind::NotFound,
+            500..=599 => ErrorKind::External,
+            _ => ErrorKind::General,
+        }
+    }
+}
+
+/// An error carrying an [`ErrorKind`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HuError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl HuError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for HuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for HuError {}
+
+/// A child process failed with this exit code, which hu passes on as its own
+///
+/// The child has already reported its error, so `main` prints nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChildExit(pub u8);
+
+impl ChildExit {
+    /// From a child's raw exit code; codes outside 1..=255 (or a signal) are 1
+    pub fn from_code(code: Option<i32>) -> Self {
+        Self(
+            code.and_then(|c| u8::try_from(c).ok())
+                .filter(|&c| c != 0)
+                .unwrap_or(1),
+        )
+    }
+}
+
+impl fmt::Display for ChildExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "command exited with status {}", self.0)
+    }
+}
+
+impl std::error::Error for ChildExit {}
+
+/// `anyhow::bail!` with an [`ErrorKind`], e.g. `bail_kind!(Auth, "Token expired")`
+macro_rules! bail_kind {
+    ($kind:ident, $($arg:tt)*) => {
+        return Err($crate::util::HuError::new(
+            $crate::util::ErrorKind::$kind,
+            format!($($arg)*),
+        )
+        .into())
+    };
+}
+pub(crate) use bail_kind;
+
+/// Work out the kind of an error from its chain
+///
+/// An explicit [`HuError`] wins, even when buried under `.context()`.
+pub fn classify(err: &anyhow::Error) -> ErrorKind {
+    if let Some(e) = err.downcast_ref::<HuError>() {
+        return e.kind;
+    }
+    for cause in err.chain() {
+        if let Some(e) = cause.downcast_ref::<HuError>() {
+            return e.kind;
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            return match e.status() {
+                Some(status) => ErrorKind::from_status(status.as_u16()),
+                None if e.is_decode() => ErrorKind::External,
+                None => ErrorKind::Network,
+            };
+        }
+        if let Some(e) = cause.downcast_ref::<octocrab::GitHubError>() {
+            return ErrorKind::from_status(e.status_code.as_u16());
+        }
+        if cause.downcast_ref::<toml::de::Error>().is_some() {
+            return ErrorKind::Config;
+        }
+    }
+    ErrorKind::General
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: ErrorJson<'a>,
+}
+
+#[derive(Serialize)]
+struct ErrorJson<'a> {
+    kind: ErrorKind,
+    code: u8,
+    message: &'a str,
+}
+
+/// Error as `{"error": {"kind", "code", "message"}}`
+pub fn error_json(err: &anyhow::Error) -> String {
+    let kind = classify(err);
+    let message = format!("{:#}", err);
+    let body = ErrorBody {
+        error: ErrorJson {
+            kind,
+            code: kind.exit_code(),
+            message: &message,
+        },
+    };
+    serde_json::to_string(&body).unwrap_or_default()
+}
+
+/// Print a failed command's error to stderr and pick the exit code
+///
+/// Under `-o json` the error is a single JSON line so scripts can parse it.
+#[cfg(not(tarpaulin_include))]
+pub fn report_error(err: &anyhow::Error) -> ExitCode {
+    if let Some(ChildExit(code)) = err.downcast_ref::<ChildExit>() {
+        return ExitCode::from(*code);
+    }
+    if OutputFormat::resolve(false) == OutputFormat::Json {
+        eprintln!("{}", error_json(err));
+    } else {
+        eprintln!("Error: {:?}", err);
+    }
+    ExitCode::from(classify(err).exit_code())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    fn auth_failure() -> anyhow::Result<()> {
+        bail_kind!(Auth, "Token for {} expired", "jira");
+    }
+
+    #[test]
+    fn bail_kind_sets_kind_and_message() {
+        let err = auth_failure().unwrap_err();
+        assert_eq!(classify(&err), ErrorKind::Auth);
+        assert_eq!(err.to_string(), "Token for jira expired");
+    }
+
+    #[test]
+    fn kind_survives_context() {
+        let err = auth_failure()
+            .context("Failed to load issue")
+            .context("hu jira show")
+            .unwrap_err();
+        assert_eq!(classify(&err), ErrorKind::Auth);
+    }
+
+    #[test]
+    fn toml_errors_are_config() {
+        let err = toml::from_str::<toml::Table>("[eks")
+            .map_err(anyhow::Error::from)
+            .context("Failed to parse settings")
+            .unwrap_err();
+        assert_eq!(classify(&err), ErrorKind::Config);
+    }
+
+    #[test]
+    fn child_exit_keeps_the_code() {
+        assert_eq!(ChildExit::from_code(Some(130)), ChildExit(130));
+        assert_eq!(ChildExit::from_code(None), ChildExit(1));
+        assert_eq!(ChildExit::from_code(Some(0)), ChildExit(1));
+        assert_eq!(ChildExit::from_code(Some(300)), ChildExit(1));
+        let err = anyhow::Error::from(ChildExit(2));
+        assert_eq!(err.downcast_ref::<ChildExit>(), Some(&ChildExit(2)));
+    }
+
+    #[test]
+    fn unclassified_errors_are_general() {
+        let err = anyhow::anyhow!("something broke");
+        assert_eq!(classify(&err), ErrorKind::General);
+        assert_eq!(ErrorKind::General.exit_code(), 1);
+    }
+
+    #[test]
+    fn status_codes_map_to_kinds() {
+        assert_eq!(ErrorKind::from_status(401), ErrorKind::Auth);
+        assert_eq!(ErrorKind::from_status(403), ErrorKind::Auth);
+        assert_eq!(ErrorKind::from_status(404), ErrorKind::NotFound);
+        assert_eq!(ErrorKind::from_status(502), ErrorKind::External);
+        assert_eq!(ErrorKind::from_status(422), ErrorKind::General);
+    }
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let kinds = [
+            ErrorKind::General,
+            ErrorKind::Auth,
+            ErrorKind::NotFound,
+            ErrorKind::Network,
+            ErrorKind::Config,
+            ErrorKind::External,
+        ];
+        let mut codes: Vec<u8> = kinds.iter().map(|k| k.exit_code()).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), kinds.len());
+        assert!(!codes.contains(&0) && !codes.contains(&2));
+    }
+
+    #[test]
+    fn error_json_shape() {
+        let err = HuError::new(ErrorKind::NotFound, "Issue ABC-1 not found");
+        let json: serde_json::Value = serde_json::from_str(&error_json(&err.into())).unwrap();
+        assert_eq!(json["error"]["kind"], "not_found");
+        assert_eq!(json["error"]["code"], 4);
+        assert_eq!(json["error"]["message"], "Issue ABC-1 not found");
+    }
+}
@@ -0,0 +1,61 @@
+//! Formatting helpers shared by the display modules
+
+/// `s` cut to at most `max` characters, ending in `...` when cut
+///
+/// Counts chars, not bytes, so multibyte text never splits mid-character.
+/// With `max` of 3 or less there is no room for the ellipsis and `s` is
+/// simply cut.
+pub fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else if max <= 3 {
+        s.chars().take(max).collect()
+    } else {
+        let kept: String = s.chars().take(max - 3).collect();
+        format!("{}...", kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_short_unchanged() {
+        assert_eq!(truncate("hello", 10), "hello");
+        assert_eq!(truncate("", 5), "");
+    }
+
+    #[test]
+    fn truncate_exact_length_unchanged() {
+        assert_eq!(truncate("hello", 5), "hello");
+    }
+
+    #[test]
+    fn truncate_long_adds_ellipsis() {
+        assert_eq!(truncate("hello world", 8), "hello...");
+    }
+
+    #[test]
+    fn truncate_tiny_max_cuts_without_ellipsis() {
+        assert_eq!(truncate("hello", 3), "hel");
+        assert_eq!(truncate("hello", 1), "h");
+        assert_eq!(truncate("hello", 0), "");
+    }
+
+    #[test]
+    fn truncate_multibyte_on_char_boundaries() {
+        assert_eq!(truncate("héllo wörld", 8), "héllo...");
+        assert_eq!(truncate("日本語のテキスト", 6), "日本語...");
+        assert_eq!(truncate("🚀🚀🚀🚀🚀", 4), "🚀...");
+        assert_eq!(truncate("ééé", 3), "ééé");
+    }
+
+    #[test]
+    fn truncate_never_exceeds_max() {
+        let text = "ünïcödé text that is long";
+        for max in 0..30 {
+            assert!(truncate(text, max).chars().count() <= max);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Shared HTTP client setup
+//!
+//! Every outbound client gets the same user-agent and timeouts so a hung
+//! endpoint fails with an error instead of blocking forever.
+//!
+//! reqwest already routes through `HTTPS_PROXY`/`HTTP_PROXY`/`ALL_PROXY`
+//! and skips hosts in `NO_PROXY`. On networks that intercept TLS, point
+//! `HU_CA_BUNDLE` at a PEM file and its certificates are trusted on top of
+//! the built-in roots.
+
+use anyhow::{Context, Result};
+use reqwest::Certificate;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use super::{ErrorKind, HuError};
+
+/// User-agent sent on every request
+pub const USER_AGENT: &str = concat!("hu-cli/", env!("CARGO_PKG_VERSION"));
+
+/// Whole-request timeout when `--http-timeout` isn't given
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Longest we'll wait to establish a connection
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Env var naming a PEM file of extra root certificates
+pub const CA_BUNDLE_ENV: &str = "HU_CA_BUNDLE";
+
+/// Timeout chosen with `--http-timeout` / `HU_HTTP_TIMEOUT`
+static TIMEOUT_OVERRIDE: OnceLock<Duration> = OnceLock::new();
+
+/// Certificates from `HU_CA_BUNDLE`, loaded once at startup
+static EXTRA_ROOTS: OnceLock<Vec<Certificate>> = OnceLock::new();
+
+/// Use `secs` as the request timeout for every client (first call wins)
+pub fn set_timeout(secs: u64) {
+    let _ = TIMEOUT_OVERRIDE.set(Duration::from_secs(secs));
+}
+
+/// Request timeout: the override, else the default
+pub fn timeout() -> Duration {
+    TIMEOUT_OVERRIDE
+        .get()
+        .copied()
+        .unwrap_or(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+}
+
+/// Connect timeout: never longer than the request timeout
+pub fn connect_timeout() -> Duration {
+    timeout().min(Duration::from_secs(CONNECT_TIMEOUT_SECS))
+}
+
+/// Trust the certificates in `HU_CA_BUNDLE`, if set (first call wins)
+///
+/// Called from `main` so a bad path fails up front rather than as a TLS
+/// error from whichever request happens first.
+pub fn load_ca_bundle() -> Result<()> {
+    let Some(path) = std::env::var_os(CA_BUNDLE_ENV).filter(|p| !p.is_empty()) else {
+        return Ok(());
+    };
+    let certs = read_ca_bundle(Path::new(&path))?;
+    log::debug!(
+        "Trusting {} certificate(s) from {}",
+        certs.len(),
+        Path::new(&path).display()
+    );
+    let _ = EXTRA_ROOTS.set(certs);
+    Ok(())
+}
+
+/// Parse every certificate in a PEM file
+fn read_ca_bundle(path: &Path) -> Result<Vec<Certificate>> {
+    let config_error = |why: String| {
+        HuError::new(
+            ErrorKind::Config,
+            format!("{} ({}) {}", path.display(), CA_BUNDLE_ENV, why),
+        )
+    };
+    let pem = fs::read(path).map_err(|e| config_error(format!("can't be read: {}", e)))?;
+    let certs = Certificate::from_pem_bundle(&pem)
+        .map_err(|e| config_error(format!("isn't a PEM bundle: {}", e)))?;
+    if certs.is_empty() {
+        return Err(config_error("has no PEM certificates".to_string()).into());
+    }
+    Ok(certs)
+}
+
+/// Client builder with the shared user-agent, timeouts and extra roots
+///
+/// For clients that need extra settings (redirect policy).
+pub fn builder() -> reqwest::ClientBuilder {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout())
+        .connect_timeout(connect_timeout());
+    for cert in EXTRA_ROOTS.get().into_iter().flatten() {
+        builder = builder.add_root_certificate(cert.clone());
+    }
+    builder
+}
+
+/// Client with the shared user-agent, timeouts and extra roots
+pub fn client() -> Result<reqwest::Client> {
+    builder().build().context("Failed to create HTTP client")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_agent_has_version() {
+        assert!(USER_AGENT.starts_with("hu-cli/"));
+        assert!(USER_AGENT.len() > "hu-cli/".len());
+    }
+
+    #[test]
+    fn connect_timeout_never_exceeds_timeout() {
+        assert!(connect_timeout() <= timeout());
+    }
+
+    #[test]
+    fn client_builds() {
+        assert!(client().is_ok());
+    }
+
+    const TEST_CA: &str = "-----BEGIN CERTIFICATE-----
+MIIBgDCCASegAwIBAgIUawlwZjov+VpVCOrHVHFAwYMORQ0wCgYIKoZIzj0EAwIw
+FTETMBEGA1UEAwwKaHUgdGVzdCBDQTAgFw0yNjEwMTQxOTIwMjRaGA8yMTI2MDky
+MDE5MjAyNFowFTETMBEGA1UEAwwKaHUgdGVzdCBDQTBZMBMGByqGSM49AgEGCCqG
+SM49AwEHA0IABA0SdsTinFzODnih5X2agXwsTFOHCUPXkUc69jDenTKZlfn2CmQk
+XRqn/3Z/noe6EXhnEnZXAosc5G8hwt/OI1ijUzBRMB0GA1UdDgQWBBRxaxBYkkIm
+tqWAZbVAWPN43POfhzAfBgNVHSMEGDAWgBRxaxBYkkImtqWAZbVAWPN43POfhzAP
+BgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0cAMEQCICqbOewZo/2EkNImszuZ
+HaSsqhD6C/5RM3uJ/sWu6qtsAiAcNBzDOM0NGrdQzSkifgfhJR61MCenwKb9n+cc
+ij/yYA==
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn ca_bundle_certificates_are_trusted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("corp.pem");
+        fs::write(&path, format!("{TEST_CA}{TEST_CA}")).unwrap();
+
+        let certs = read_ca_bundle(&path).unwrap();
+        assert_eq!(certs.len(), 2);
+        let mut builder = builder();
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn bad_ca_bundle_is_a_config_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("empty.pem");
+        fs::write(&empty, "not a certificate\n").unwrap();
+
+        for path in [empty, dir.path().join("missing.pem")] {
+            let err = read_ca_bundle(&path).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<HuError>().unwrap().kind,
+                ErrorKind::Config
+            );
+            assert!(err.to_string().contains(CA_BUNDLE_ENV));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Diagnostics on stderr, controlled by `-v`/`-q`
+//!
+//! Friendly progress notes ("Fetching logs…", rate-limit waits) are
+//! `info!` and shown by default. `-v` adds `debug!` (HTTP requests,
+//! config and cache decisions), `-vv` adds `trace!`, and `-q` drops
+//! everything below warnings. Command errors are printed by `main`, not
+//! through here, so `-q` never hides them.
+
+use anstream::eprintln;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+struct StderrLogger;
+
+static LOGGER: StderrLogger = StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level() && metadata.target().starts_with("hu")
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", format_record(record.level(), record.args()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the logger; call once at startup
+pub fn init(verbose: u8, quiet: bool) {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level_for(verbose, quiet));
+    }
+}
+
+/// Whether `-q` was given, for output that doesn't go through `log` (spinners)
+pub fn is_quiet() -> bool {
+    log::max_level() < LevelFilter::Info
+}
+
+/// Whether `-v` was given, for commands that show extra detail with it
+pub fn is_verbose() -> bool {
+    log::max_level() >= LevelFilter::Debug
+}
+
+fn level_for(verbose: u8, quiet: bool) -> LevelFilter {
+    match (quiet, verbose) {
+        (true, _) => LevelFilter::Warn,
+        (false, 0) => LevelFilter::Info,
+        (false, 1) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    }
+}
+
+fn format_record(level: Level, message: &std::fmt::Arguments) -> String {
+    match level {
+        Level::Error => format!("{RED}Error:{RESET} {}", message),
+        Level::Warn => format!("{YELLOW}Warning:{RESET} {}", message),
+        Level::Info => message.to_string(),
+        Level::Debug => format!("{GRAY}[debug] {}{RESET}", message),
+        Level::Trace => format!("{GRAY}[trace] {}{RESET}", message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_for_flags() {
+        assert_eq!(level_for(0, false), LevelFilter::Info);
+        assert_eq!(level_for(1, false), LevelFilter::Debug);
+        assert_eq!(level_for(3, false), LevelFilter::Trace);
+        assert_eq!(level_for(0, true), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn format_record_prefixes_by_level() {
+        let line = format_record(Level::Warn, &format_args!("cache is stale"));
+        assert_eq!(line, "\x1b[33mWarning:\x1b[0m cache is stale");
+        assert_eq!(
+            format_record(Level::Info, &format_args!("Fetching logs")),
+            "Fetching logs"
+        );
+        assert!(format_record(Level::Debug, &format_args!("GET /users")).contains("[debug]"));
+    }
+}
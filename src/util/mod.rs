@@ -1,16 +1,26 @@
+pub mod color;
 mod config;
+pub mod dry_run;
+mod error;
+pub mod fmt;
+pub mod http;
+pub mod logging;
 mod output;
+pub mod secrets;
 pub mod shell;
 
 pub use config::{
-    load_credentials, save_credentials, BraveCredentials, GithubCredentials, JiraCredentials,
+    load_credentials, save_credentials, set_settings_path, settings_path, BraveCredentials,
+    GithubCredentials, JiraCredentials,
 };
 
 #[allow(unused_imports)]
-pub use config::{config_dir, Credentials};
+pub use config::{config_dir, credentials_path, Credentials};
 
 // These are used in tests
 #[allow(unused_imports)]
-pub use config::{load_credentials_from, save_credentials_to};
+pub use config::{load_credentials_from, resolve_settings_path, save_credentials_to};
 
-pub use output::OutputFormat;
+pub(crate) use error::bail_kind;
+pub use error::{report_error, ChildExit, ErrorKind, HuError};
+pub use output::{print_structured, set_fields, OutputFormat};
@@ -1,21 +1,199 @@
 //! Shared output format type for CLI commands.
 
+use anstream::{print, println};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::OnceLock;
+
+/// Format chosen with the top-level `-o`/`--output` flag
+static DEFAULT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Keys chosen with the top-level `--fields` flag
+static FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Keep only these keys in JSON and CSV output (first call wins)
+pub fn set_fields(fields: Vec<String>) {
+    let _ = FIELDS.set(fields);
+}
+
 /// Output format for CLI commands.
 ///
-/// Most commands support both human-readable table output and
-/// machine-readable JSON output (via `-j`/`--json` flags).
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Chosen globally with `hu -o <format>`; most commands still accept a
+/// hidden `-j`/`--json` for compatibility.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
 pub enum OutputFormat {
     /// Human-readable table format
     #[default]
     Table,
     /// JSON format for scripting
     Json,
+    /// CSV, one row per record
+    Csv,
+}
+
+impl OutputFormat {
+    /// Set the process-wide format from `--output` (first call wins)
+    pub fn set_default(format: Self) {
+        let _ = DEFAULT_FORMAT.set(format);
+    }
+
+    /// Format for a command: its own `--json` wins, then `--output`
+    pub fn resolve(json: bool) -> Self {
+        Self::pick(json, DEFAULT_FORMAT.get().copied())
+    }
+
+    fn pick(json: bool, global: Option<Self>) -> Self {
+        if json {
+            Self::Json
+        } else {
+            global.unwrap_or_default()
+        }
+    }
+}
+
+/// Print a serializable value as pretty JSON or CSV
+///
+/// Table output is command-specific, so it prints JSON for `Table`.
+/// With `--fields`, objects are cut down to those keys first.
+pub fn print_structured<T: Serialize + ?Sized>(value: &T, format: OutputFormat) -> Result<()> {
+    let mut value = serde_json::to_value(value).context("Failed to serialize")?;
+    if let Some(fields) = FIELDS.get() {
+        let unknown = select_fields(&mut value, fields);
+        if !unknown.is_empty() {
+            log::warn!("Unknown field(s) for --fields: {}", unknown.join(", "));
+        }
+    }
+
+    match format {
+        OutputFormat::Csv => print!("{}", to_csv(&value)?),
+        OutputFormat::Json | OutputFormat::Table => {
+            let json = serde_json::to_string_pretty(&value).context("Failed to serialize")?;
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
+
+/// Keep only `fields` in an object, or in each object of a list
+///
+/// Returns the fields no object had. Anything other than objects is left
+/// alone.
+pub fn select_fields(value: &mut Value, fields: &[String]) -> Vec<String> {
+    let objects: Vec<&mut Map<String, Value>> = match value {
+        Value::Object(obj) => vec![obj],
+        Value::Array(items) => items.iter_mut().filter_map(Value::as_object_mut).collect(),
+        _ => return Vec::new(),
+    };
+    if objects.is_empty() {
+        return Vec::new();
+    }
+
+    let mut seen = vec![false; fields.len()];
+    for obj in objects {
+        for (i, field) in fields.iter().enumerate() {
+            seen[i] |= obj.contains_key(field);
+        }
+        let mut kept = Map::new();
+        for field in fields {
+            if let Some(v) = obj.remove(field) {
+                kept.insert(field.clone(), v);
+            }
+        }
+        *obj = kept;
+    }
+    fields
+        .iter()
+        .zip(seen)
+        .filter(|(_, seen)| !seen)
+        .map(|(field, _)| field.clone())
+        .collect()
+}
+
+/// Render a value as CSV
+///
+/// A list becomes one row per item and anything else a single row. Nested
+/// objects are flattened to dotted column names; columns are the union of
+/// keys across rows.
+pub fn to_csv<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let value = serde_json::to_value(value).context("Failed to serialize")?;
+    let items = match value {
+        Value::Array(items) => items,
+        other => vec![other],
+    };
+
+    let rows: Vec<Vec<(String, Value)>> = items
+        .into_iter()
+        .map(|item| {
+            let mut row = Vec::new();
+            match item {
+                Value::Object(obj) => flatten_into(&obj, "", &mut row),
+                other => row.push(("value".to_string(), other)),
+            }
+            row
+        })
+        .collect();
+
+    let mut columns: Vec<&str> = Vec::new();
+    for (key, _) in rows.iter().flatten() {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+
+    let mut out = csv_line(columns.iter().map(|c| c.to_string()));
+    for row in &rows {
+        out.push_str(&csv_line(columns.iter().map(|col| {
+            row.iter()
+                .find(|(key, _)| key == col)
+                .map(|(_, v)| csv_value(v))
+                .unwrap_or_default()
+        })));
+    }
+    Ok(out)
+}
+
+fn flatten_into(obj: &Map<String, Value>, prefix: &str, out: &mut Vec<(String, Value)>) {
+    for (key, value) in obj {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if !nested.is_empty() => flatten_into(nested, &key, out),
+            _ => out.push((key, value.clone())),
+        }
+    }
+}
+
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Join fields into a CSV record, quoting per RFC 4180
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let fields: Vec<String> = fields
+        .map(|f| {
+            if f.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f
+            }
+        })
+        .collect();
+    format!("{}\n", fields.join(","))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::ValueEnum;
+    use serde_json::json;
 
     #[test]
     fn default_is_table() {
@@ -43,6 +221,7 @@ mod tests {
     fn debug_format() {
         assert_eq!(format!("{:?}", OutputFormat::Table), "Table");
         assert_eq!(format!("{:?}", OutputFormat::Json), "Json");
+        assert_eq!(format!("{:?}", OutputFormat::Csv), "Csv");
     }
 
     #[test]
@@ -51,4 +230,82 @@ mod tests {
         assert_eq!(OutputFormat::Json, OutputFormat::Json);
         assert_ne!(OutputFormat::Table, OutputFormat::Json);
     }
+
+    #[test]
+    fn parses_flag_values() {
+        assert_eq!(
+            OutputFormat::from_str("csv", true).unwrap(),
+            OutputFormat::Csv
+        );
+        assert_eq!(
+            OutputFormat::from_str("json", true).unwrap(),
+            OutputFormat::Json
+        );
+        assert!(OutputFormat::from_str("yaml", true).is_err());
+    }
+
+    #[test]
+    fn json_flag_overrides_global() {
+        assert_eq!(
+            OutputFormat::pick(true, Some(OutputFormat::Csv)),
+            OutputFormat::Json
+        );
+        assert_eq!(
+            OutputFormat::pick(false, Some(OutputFormat::Csv)),
+            OutputFormat::Csv
+        );
+        assert_eq!(OutputFormat::pick(false, None), OutputFormat::Table);
+    }
+
+    #[test]
+    fn csv_from_list_unions_columns() {
+        let rows = json!([
+            {"id": 1, "name": "a", "user": {"email": "a@x"}},
+            {"id": 2, "extra": true},
+        ]);
+        assert_eq!(
+            to_csv(&rows).unwrap(),
+            "id,name,user.email,extra\n1,a,a@x,\n2,,,true\n"
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields() {
+        let row = json!({"text": "hello, \"world\"", "tags": ["a", "b"]});
+        assert_eq!(
+            to_csv(&row).unwrap(),
+            "tags,text\n\"[\"\"a\"\",\"\"b\"\"]\",\"hello, \"\"world\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn select_fields_projects_each_item() {
+        let mut value = json!([
+            {"id": "a", "project": "hu", "total_cost": 1.5, "extra": true},
+            {"id": "b", "project": "web"}
+        ]);
+        let unknown = select_fields(&mut value, &["total_cost".into(), "id".into()]);
+        assert!(unknown.is_empty());
+        assert_eq!(value, json!([{"total_cost": 1.5, "id": "a"}, {"id": "b"}]));
+    }
+
+    #[test]
+    fn select_fields_reports_unknown() {
+        let mut value = json!({"id": 1, "name": "x"});
+        let unknown = select_fields(&mut value, &["id".into(), "nmae".into()]);
+        assert_eq!(unknown, vec!["nmae"]);
+        assert_eq!(value, json!({"id": 1}));
+    }
+
+    #[test]
+    fn select_fields_ignores_scalars() {
+        let mut value = json!(["a", "b"]);
+        assert!(select_fields(&mut value, &["id".into()]).is_empty());
+        assert_eq!(value, json!(["a", "b"]));
+    }
+
+    #[test]
+    fn csv_from_scalars() {
+        assert_eq!(to_csv(&["x", "y"]).unwrap(), "value\nx\ny\n");
+    }
 }
@@ -0,0 +1,179 @@
+//! OS keychain storage for tokens and API keys
+//!
+//! Off by default. With `secure_credentials = true` at the top of
+//! `settings.toml`, secrets are written to the platform keychain (macOS
+//! Keychain, Windows Credential Manager, Secret Service on Linux) and left
+//! out of the plaintext files. When no keychain is reachable, saves fall back
+//! to the files as before.
+
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Keychain service name all entries are stored under
+const SERVICE: &str = "hu";
+
+/// Keychain entry holding the serialized `credentials.toml`
+pub const CREDENTIALS_KEY: &str = "credentials";
+
+/// Secret fields in `settings.toml`, as dotted paths
+///
+/// Each doubles as the keychain entry name.
+pub const SETTINGS_SECRETS: &[&str] = &[
+    "pagerduty.api_token",
+    "newrelic.api_key",
+    "sentry.auth_token",
+    "slack.oauth.client_secret",
+    "slack.oauth.bot_token",
+    "slack.oauth.user_token",
+];
+
+/// Whether `settings.toml` turns on keychain storage
+pub fn enabled() -> bool {
+    crate::util::settings_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .is_some_and(|contents| enabled_in(&contents))
+}
+
+/// Whether settings contents set `secure_credentials = true`
+pub fn enabled_in(contents: &str) -> bool {
+    toml::from_str::<toml::Value>(contents)
+        .ok()
+        .and_then(|doc| doc.get("secure_credentials")?.as_bool())
+        .unwrap_or(false)
+}
+
+/// Read a secret; `None` if it isn't stored or the keychain is unavailable
+pub fn get(key: &str) -> Option<String> {
+    keyring::Entry::new(SERVICE, key).ok()?.get_password().ok()
+}
+
+/// Write a secret to the keychain
+pub fn set(key: &str, value: &str) -> Result<()> {
+    keyring::Entry::new(SERVICE, key)
+        .and_then(|entry| entry.set_password(value))
+        .with_context(|| format!("Failed to store {} in the keychain", key))
+}
+
+/// Fill a secret missing from the file with the keychain copy
+pub fn fill(slot: &mut Option<String>, key: &str, secure: bool) {
+    if secure && slot.is_none() {
+        *slot = get(key);
+    }
+}
+
+/// Store `value` under `field` in a settings table, or in the keychain
+///
+/// With `secure` on, the keychain gets the value and any plaintext copy is
+/// removed from the table. If the keychain write fails, the value goes in
+/// the table as usual.
+pub fn store(
+    table: &mut toml::map::Map<String, toml::Value>,
+    field: &str,
+    key: &str,
+    value: &str,
+    secure: bool,
+) {
+    if secure {
+        match set(key, value) {
+            Ok(()) => {
+                table.remove(field);
+                return;
+            }
+            Err(e) => log::warn!("{:#}; saving to the settings file instead", e),
+        }
+    }
+    table.insert(field.to_string(), toml::Value::String(value.to_string()));
+}
+
+/// Remove every secret from a settings document, returning `(key, value)` pairs
+pub fn take_settings_secrets(doc: &mut toml::Value) -> Vec<(String, String)> {
+    let mut taken = Vec::new();
+    for key in SETTINGS_SECRETS {
+        let (parents, field) = match key.rsplit_once('.') {
+            Some(split) => split,
+            None => continue,
+        };
+        let mut table = doc.as_table_mut();
+        for part in parents.split('.') {
+            table = table.and_then(|t| t.get_mut(part)?.as_table_mut());
+        }
+        let Some(table) = table else { continue };
+        if let Some(toml::Value::String(value)) = table.get(field) {
+            taken.push((key.to_string(), value.clone()));
+            table.remove(field);
+        }
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!enabled_in(""));
+        assert!(!enabled_in("[pagerduty]\napi_token = \"x\"\n"));
+        assert!(!enabled_in("secure_credentials = false\n"));
+        assert!(!enabled_in("not toml ["));
+    }
+
+    #[test]
+    fn enabled_by_top_level_flag() {
+        assert!(enabled_in("secure_credentials = true\n[slack]\n"));
+        // Only the top-level key counts
+        assert!(!enabled_in("[slack]\nsecure_credentials = true\n"));
+    }
+
+    #[test]
+    fn store_writes_plaintext_when_not_secure() {
+        let mut table = toml::map::Map::new();
+        store(&mut table, "api_token", "pagerduty.api_token", "tok", false);
+        assert_eq!(table["api_token"].as_str(), Some("tok"));
+    }
+
+    #[test]
+    fn fill_ignores_keychain_when_not_secure() {
+        let mut slot = None;
+        fill(&mut slot, "pagerduty.api_token", false);
+        assert!(slot.is_none());
+    }
+
+    #[test]
+    fn take_settings_secrets_strips_known_fields() {
+        let mut doc: toml::Value = toml::from_str(
+            r##"
+[pagerduty]
+api_token = "pd"
+schedule_ids = ["S1"]
+
+[slack]
+default_channel = "#general"
+
+[slack.oauth]
+bot_token = "xoxb-1"
+team_id = "T1"
+"##,
+        )
+        .unwrap();
+
+        let taken = take_settings_secrets(&mut doc);
+        assert_eq!(
+            taken,
+            vec![
+                ("pagerduty.api_token".to_string(), "pd".to_string()),
+                ("slack.oauth.bot_token".to_string(), "xoxb-1".to_string()),
+            ]
+        );
+        assert!(doc["pagerduty"].get("api_token").is_none());
+        assert!(doc["pagerduty"].get("schedule_ids").is_some());
+        assert!(doc["slack"]["oauth"].get("bot_token").is_none());
+        assert_eq!(doc["slack"]["oauth"]["team_id"].as_str(), Some("T1"));
+    }
+
+    #[test]
+    fn take_settings_secrets_empty_doc() {
+        let mut doc = toml::Value::Table(toml::map::Map::new());
+        assert!(take_settings_secrets(&mut doc).is_empty());
+    }
+}
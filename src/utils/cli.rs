@@ -37,6 +37,18 @@ pub struct FetchHtmlArgs {
     #[arg(long, short = 'H')]
     pub headings: bool,
 
+    /// Extract metadata (title, description, Open Graph, Twitter, JSON-LD) as JSON
+    #[arg(long, short = 'm')]
+    pub meta: bool,
+
+    /// Extract tables as GitHub-flavored Markdown
+    #[arg(long, short = 't')]
+    pub tables: bool,
+
+    /// With --tables, emit CSV instead of Markdown
+    #[arg(long, requires = "tables")]
+    pub csv: bool,
+
     /// CSS selector to target (e.g., "article", "main", ".content")
     #[arg(long)]
     pub selector: Option<String>,
@@ -45,6 +57,30 @@ pub struct FetchHtmlArgs {
     #[arg(long, short = 'o')]
     pub output: Option<String>,
 
+    /// Print the final URL (after redirects) to stderr
+    #[arg(long)]
+    pub show_url: bool,
+
+    /// Print the page's canonical URL to stderr instead of the final URL
+    #[arg(long)]
+    pub canonical: bool,
+
+    /// Don't follow redirects (inspect a single hop)
+    #[arg(long)]
+    pub no_redirect: bool,
+
+    /// Reuse a cached copy younger than this many seconds (default: no cache)
+    #[arg(long, value_name = "SECS")]
+    pub cache_ttl: Option<u64>,
+
+    /// Ignore the cache even if --cache-ttl is set
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Re-fetch and overwrite the cached copy
+    #[arg(long, requires = "cache_ttl")]
+    pub refresh: bool,
+
     /// Raw output (no filtering)
     #[arg(long, short = 'r')]
     pub raw: bool,
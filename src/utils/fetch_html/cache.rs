@@ -0,0 +1,151 @@
+//! On-disk cache of fetched pages (`~/.config/hu/cache/fetch-html/`)
+//!
+//! Entries are keyed by a hash of the requested URL and hold the raw HTML,
+//! the final URL and the fetch time. Freshness is decided by the caller's TTL,
+//! so the same entry can be reused or ignored depending on `--cache-ttl`.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A cached page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// URL that was requested
+    pub url: String,
+    /// URL of the response after redirects
+    pub final_url: String,
+    pub fetched_at: DateTime<Utc>,
+    pub html: String,
+}
+
+impl CacheEntry {
+    /// Whether the entry is younger than `ttl` at `now`
+    pub fn is_fresh(&self, ttl: Duration, now: DateTime<Utc>) -> bool {
+        now.signed_duration_since(self.fetched_at) < ttl
+    }
+}
+
+/// Directory-backed page cache
+#[derive(Debug, Clone)]
+pub struct FetchCache {
+    dir: PathBuf,
+}
+
+impl FetchCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Cache under the hu config directory
+    pub fn open_default() -> Result<Self> {
+        let dir = dirs::home_dir()
+            .map(|p| {
+                p.join(".config")
+                    .join("hu")
+                    .join("cache")
+                    .join("fetch-html")
+            })
+            .context("Cannot determine config directory")?;
+        Ok(Self::new(dir))
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", cache_key(url)))
+    }
+
+    /// Cached entry for `url` if one exists and is still fresh
+    ///
+    /// Unreadable or corrupt entries are treated as missing.
+    pub fn load_fresh(&self, url: &str, ttl: Duration, now: DateTime<Utc>) -> Option<CacheEntry> {
+        let contents = fs::read_to_string(self.path(url)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+        (entry.url == url && entry.is_fresh(ttl, now)).then_some(entry)
+    }
+
+    /// Write (or overwrite) the entry for its URL
+    pub fn store(&self, entry: &CacheEntry) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let path = self.path(&entry.url);
+        fs::write(&path, serde_json::to_string(entry)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Stable file name for a URL (64-bit FNV-1a, hex)
+pub fn cache_key(url: &str) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let hash = url.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(url: &str, fetched_at: DateTime<Utc>) -> CacheEntry {
+        CacheEntry {
+            url: url.to_string(),
+            final_url: format!("{}/landed", url),
+            fetched_at,
+            html: "<p>hi</p>".to_string(),
+        }
+    }
+
+    #[test]
+    fn cache_key_is_stable_and_distinct() {
+        assert_eq!(cache_key(""), "cbf29ce484222325");
+        assert_eq!(cache_key("https://a.com"), cache_key("https://a.com"));
+        assert_ne!(cache_key("https://a.com"), cache_key("https://b.com"));
+    }
+
+    #[test]
+    fn store_and_load_fresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FetchCache::new(dir.path().join("fetch-html"));
+        let now = Utc::now();
+        let stored = entry("https://example.com", now - Duration::seconds(30));
+        cache.store(&stored).unwrap();
+
+        let loaded = cache.load_fresh("https://example.com", Duration::seconds(60), now);
+        assert_eq!(loaded, Some(stored));
+        assert!(cache
+            .load_fresh("https://example.com", Duration::seconds(10), now)
+            .is_none());
+        assert!(cache
+            .load_fresh("https://other.com", Duration::seconds(60), now)
+            .is_none());
+    }
+
+    #[test]
+    fn store_overwrites() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FetchCache::new(dir.path().to_path_buf());
+        let now = Utc::now();
+        cache.store(&entry("https://example.com", now)).unwrap();
+        let mut newer = entry("https://example.com", now);
+        newer.html = "<p>new</p>".to_string();
+        cache.store(&newer).unwrap();
+
+        let loaded = cache
+            .load_fresh("https://example.com", Duration::seconds(60), now)
+            .unwrap();
+        assert_eq!(loaded.html, "<p>new</p>");
+    }
+
+    #[test]
+    fn corrupt_entry_is_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FetchCache::new(dir.path().to_path_buf());
+        fs::write(cache.path("https://example.com"), "not json").unwrap();
+        assert!(cache
+            .load_fresh("https://example.com", Duration::seconds(60), Utc::now())
+            .is_none());
+    }
+}
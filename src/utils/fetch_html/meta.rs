@@ -0,0 +1,155 @@
+//! Page metadata: `<title>`, description, Open Graph, Twitter cards, JSON-LD
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use super::{decode_entities, strip_tags};
+
+/// Metadata as a single JSON object, plus warnings for skipped JSON-LD blocks
+pub fn extract_meta(html: &str) -> (Value, Vec<String>) {
+    let mut meta = Map::new();
+    let mut og = Map::new();
+    let mut twitter = Map::new();
+
+    if let Some(title) = title(html) {
+        meta.insert("title".to_string(), Value::String(title));
+    }
+
+    for (key, content) in meta_tags(html) {
+        let key = key.to_lowercase();
+        if let Some(name) = key.strip_prefix("og:") {
+            insert_multi(&mut og, name, content);
+        } else if let Some(name) = key.strip_prefix("twitter:") {
+            insert_multi(&mut twitter, name, content);
+        } else if key == "description" && !meta.contains_key("description") {
+            meta.insert("description".to_string(), Value::String(content));
+        }
+    }
+
+    let (json_ld, warnings) = json_ld(html);
+    if !og.is_empty() {
+        meta.insert("og".to_string(), Value::Object(og));
+    }
+    if !twitter.is_empty() {
+        meta.insert("twitter".to_string(), Value::Object(twitter));
+    }
+    if !json_ld.is_empty() {
+        meta.insert("json_ld".to_string(), Value::Array(json_ld));
+    }
+    (Value::Object(meta), warnings)
+}
+
+fn title(html: &str) -> Option<String> {
+    let title_re = Regex::new(r"(?is)<title\b[^>]*>(.*?)</title>").unwrap();
+    let caps = title_re.captures(html)?;
+    let text = decode_entities(&strip_tags(&caps[1]));
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// `(name or property, content)` for every `<meta>` tag that has both
+fn meta_tags(html: &str) -> Vec<(String, String)> {
+    let tag_re = Regex::new(r"(?is)<meta\b[^>]*>").unwrap();
+    let key_re = Regex::new(r#"(?is)\b(?:property|name)\s*=\s*["']([^"']+)["']"#).unwrap();
+    let content_re = Regex::new(r#"(?is)\bcontent\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+
+    tag_re
+        .find_iter(html)
+        .filter_map(|tag| {
+            let tag = tag.as_str();
+            let key = key_re.captures(tag)?[1].trim().to_string();
+            let caps = content_re.captures(tag)?;
+            let content = caps.get(1).or_else(|| caps.get(2))?.as_str();
+            Some((key, decode_entities(content.trim())))
+        })
+        .collect()
+}
+
+/// Repeated keys (e.g. several `og:image`) become an array
+fn insert_multi(map: &mut Map<String, Value>, key: &str, value: String) {
+    match map.get_mut(key) {
+        None => {
+            map.insert(key.to_string(), Value::String(value));
+        }
+        Some(Value::Array(items)) => items.push(Value::String(value)),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, Value::String(value)]);
+        }
+    }
+}
+
+/// Parsed `<script type="application/ld+json">` blocks
+fn json_ld(html: &str) -> (Vec<Value>, Vec<String>) {
+    let script_re = Regex::new(
+        r#"(?is)<script\b[^>]*type\s*=\s*["']application/ld\+json["'][^>]*>(.*?)</script>"#,
+    )
+    .unwrap();
+
+    let mut blocks = Vec::new();
+    let mut warnings = Vec::new();
+    for (idx, caps) in script_re.captures_iter(html).enumerate() {
+        let raw = caps[1]
+            .trim()
+            .trim_start_matches("<!--")
+            .trim_end_matches("-->")
+            .trim();
+        match serde_json::from_str::<Value>(raw) {
+            Ok(value) => blocks.push(value),
+            Err(e) => warnings.push(format!(
+                "Skipping malformed JSON-LD block {}: {}",
+                idx + 1,
+                e
+            )),
+        }
+    }
+    (blocks, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PAGE: &str = r#"<html><head>
+        <title> Hello &amp; welcome </title>
+        <meta name="description" content="An article">
+        <meta property="og:title" content="Hello">
+        <meta content="https://x.com/a.png" property="og:image">
+        <meta property="og:image" content='https://x.com/b.png'>
+        <meta name="twitter:card" content="summary">
+        <meta charset="utf-8">
+        <script type="application/ld+json">
+          {"@type": "Article", "datePublished": "2024-06-10"}
+        </script>
+        <script type="application/ld+json">{ not json</script>
+        <script>var x = 1;</script>
+    </head></html>"#;
+
+    #[test]
+    fn extracts_all_sources() {
+        let (meta, warnings) = extract_meta(PAGE);
+        assert_eq!(
+            meta,
+            json!({
+                "title": "Hello & welcome",
+                "description": "An article",
+                "og": {
+                    "title": "Hello",
+                    "image": ["https://x.com/a.png", "https://x.com/b.png"],
+                },
+                "twitter": { "card": "summary" },
+                "json_ld": [{"@type": "Article", "datePublished": "2024-06-10"}],
+            })
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("block 2"));
+    }
+
+    #[test]
+    fn empty_page() {
+        let (meta, warnings) = extract_meta("<p>nothing</p>");
+        assert_eq!(meta, json!({}));
+        assert!(warnings.is_empty());
+    }
+}
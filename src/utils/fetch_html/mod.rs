@@ -1,15 +1,24 @@
+use anstream::println;
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::fs;
 
 use super::cli::FetchHtmlArgs;
 
+mod cache;
+mod meta;
+mod tables;
+
 #[cfg(test)]
 mod tests;
 
+use tables::TableFormat;
+
 /// Handle the `hu utils fetch-html` command
 pub async fn run(args: FetchHtmlArgs) -> Result<()> {
-    let html = fetch_url(&args.url).await?;
+    let page = load_page(&args).await?;
+    report_url(&page, &args);
+    let html = page.html;
 
     let output = if args.raw {
         html_to_markdown(&html)
@@ -19,6 +28,19 @@ pub async fn run(args: FetchHtmlArgs) -> Result<()> {
         extract_headings(&html)
     } else if args.summary {
         extract_summary(&html)
+    } else if args.meta {
+        let (meta, warnings) = meta::extract_meta(&html);
+        for warning in warnings {
+            log::warn!("{}", warning);
+        }
+        serde_json::to_string_pretty(&meta)?
+    } else if args.tables {
+        let format = if args.csv {
+            TableFormat::Csv
+        } else {
+            TableFormat::Markdown
+        };
+        tables::extract_tables(&html, format)
     } else if args.content || args.selector.is_some() {
         let selector = args.selector.as_deref();
         extract_content(&html, selector)
@@ -29,7 +51,7 @@ pub async fn run(args: FetchHtmlArgs) -> Result<()> {
 
     if let Some(path) = args.output {
         fs::write(&path, &output).with_context(|| format!("Failed to write to {}", path))?;
-        eprintln!("Written to {}", path);
+        log::info!("Written to {}", path);
     } else {
         println!("{}", output);
     }
@@ -37,11 +59,62 @@ pub async fn run(args: FetchHtmlArgs) -> Result<()> {
     Ok(())
 }
 
-/// Fetch URL content
-async fn fetch_url(url: &str) -> Result<String> {
-    let client = reqwest::Client::builder()
-        .user_agent("hu-cli/0.1")
-        .build()?;
+/// A fetched page and where it came from
+#[derive(Debug)]
+struct FetchedPage {
+    html: String,
+    /// URL of the final response (after any redirects that were followed)
+    final_url: String,
+    status: reqwest::StatusCode,
+    /// `Location` header of an unfollowed redirect
+    location: Option<String>,
+}
+
+/// Fetch the page, going through the disk cache when `--cache-ttl` is set
+///
+/// Single-hop (`--no-redirect`) fetches always go to the network.
+async fn load_page(args: &FetchHtmlArgs) -> Result<FetchedPage> {
+    let ttl = match args.cache_ttl {
+        Some(secs) if !args.no_cache && !args.no_redirect => i64::try_from(secs)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .unwrap_or(chrono::Duration::MAX),
+        _ => return fetch_url(&args.url, !args.no_redirect).await,
+    };
+
+    let cache = cache::FetchCache::open_default()?;
+    let now = chrono::Utc::now();
+    if !args.refresh {
+        if let Some(entry) = cache.load_fresh(&args.url, ttl, now) {
+            return Ok(FetchedPage {
+                html: entry.html,
+                final_url: entry.final_url,
+                status: reqwest::StatusCode::OK,
+                location: None,
+            });
+        }
+    }
+
+    let page = fetch_url(&args.url, true).await?;
+    if page.status.is_success() {
+        cache.store(&cache::CacheEntry {
+            url: args.url.clone(),
+            final_url: page.final_url.clone(),
+            fetched_at: now,
+            html: page.html.clone(),
+        })?;
+    }
+    Ok(page)
+}
+
+/// Fetch URL content, optionally without following redirects
+async fn fetch_url(url: &str, follow_redirects: bool) -> Result<FetchedPage> {
+    let policy = if follow_redirects {
+        reqwest::redirect::Policy::default()
+    } else {
+        reqwest::redirect::Policy::none()
+    };
+    let client = crate::util::http::builder().redirect(policy).build()?;
 
     let response = client
         .get(url)
@@ -49,10 +122,59 @@ async fn fetch_url(url: &str) -> Result<String> {
         .await
         .with_context(|| format!("Failed to fetch {}", url))?;
 
-    response
+    let final_url = response.url().to_string();
+    let status = response.status();
+    let location = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let html = response
         .text()
         .await
-        .with_context(|| format!("Failed to read response from {}", url))
+        .with_context(|| format!("Failed to read response from {}", url))?;
+
+    Ok(FetchedPage {
+        html,
+        final_url,
+        status,
+        location,
+    })
+}
+
+/// Print redirect and URL information to stderr, as requested
+fn report_url(page: &FetchedPage, args: &FetchHtmlArgs) {
+    if page.status.is_redirection() {
+        let target = page.location.as_deref().unwrap_or("(no Location header)");
+        eprintln!("Redirect {} -> {}", page.status.as_u16(), target);
+    }
+    if args.canonical {
+        match canonical_url(&page.html, &page.final_url) {
+            Some(url) => eprintln!("{}", url),
+            None => eprintln!("{} (no canonical link)", page.final_url),
+        }
+    } else if args.show_url {
+        eprintln!("{}", page.final_url);
+    }
+}
+
+/// URL from `<link rel="canonical">`, resolved against `base`
+pub fn canonical_url(html: &str, base: &str) -> Option<String> {
+    let link_re = Regex::new(r"(?is)<link\b[^>]*>").unwrap();
+    let rel_re = Regex::new(r#"(?i)\brel\s*=\s*["']?canonical\b"#).unwrap();
+    let href_re = Regex::new(r#"(?i)\bhref\s*=\s*["']([^"']+)["']"#).unwrap();
+
+    let href = link_re
+        .find_iter(html)
+        .map(|m| m.as_str())
+        .filter(|tag| rel_re.is_match(tag))
+        .find_map(|tag| href_re.captures(tag))
+        .map(|caps| decode_entities(caps[1].trim()))?;
+
+    match reqwest::Url::parse(base).and_then(|base| base.join(&href)) {
+        Ok(url) => Some(url.to_string()),
+        Err(_) => Some(href),
+    }
 }
 
 /// Convert HTML to markdown (basic conversion)
@@ -124,19 +246,7 @@ pub fn html_to_markdown(html: &str) -> String {
         .to_string();
 
     // Remove remaining HTML tags
-    result = Regex::new(r"<[^>]+>")
-        .unwrap()
-        .replace_all(&result, "")
-        .to_string();
-
-    // Decode common HTML entities
-    result = result
-        .replace("&amp;", "&")
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-        .replace("&quot;", "\"")
-        .replace("&#39;", "'")
-        .replace("&nbsp;", " ");
+    result = decode_entities(&strip_tags(&result));
 
     // Clean up whitespace
     result = Regex::new(r"\n{3,}")
@@ -151,6 +261,24 @@ pub fn html_to_markdown(html: &str) -> String {
     result.trim().to_string()
 }
 
+/// Remove all HTML tags, keeping their text
+fn strip_tags(html: &str) -> String {
+    Regex::new(r"<[^>]+>")
+        .unwrap()
+        .replace_all(html, "")
+        .to_string()
+}
+
+/// Decode common HTML entities
+fn decode_entities(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+}
+
 /// Remove HTML tag and its content
 fn remove_tag_with_content(html: &str, tag: &str) -> String {
     let re = Regex::new(&format!(r"(?is)<{}\b[^>]*>.*?</{}>", tag, tag)).unwrap();
@@ -257,7 +385,6 @@ pub fn extract_links(html: &str) -> String {
 /// Extract headings only (document outline)
 pub fn extract_headings(html: &str) -> String {
     let mut headings = Vec::new();
-    let strip_tags_re = Regex::new(r"<[^>]+>").unwrap();
 
     for level in 1..=6 {
         let re = Regex::new(&format!(r"(?is)<h{}\b[^>]*>(.*?)</h{}>", level, level)).unwrap();
@@ -265,7 +392,7 @@ pub fn extract_headings(html: &str) -> String {
         for cap in re.captures_iter(html) {
             let text = cap.get(1).map_or("", |m| m.as_str());
             // Strip any nested tags
-            let clean = strip_tags_re.replace_all(text, "").trim().to_string();
+            let clean = strip_tags(text).trim().to_string();
 
             if !clean.is_empty() {
                 let indent = "  ".repeat(level - 1);
@@ -0,0 +1,265 @@
+//! `<table>` extraction to Markdown or CSV
+//!
+//! Cells spanning several rows or columns are expanded into empty padding
+//! cells so every row of the output has the same number of columns.
+
+use regex::Regex;
+
+use super::{decode_entities, strip_tags};
+
+/// Output format for extracted tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Markdown,
+    Csv,
+}
+
+/// A table flattened to a rectangular grid of cell text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlTable {
+    /// Whether the first row is made of `<th>` cells
+    pub has_header: bool,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A cell still covering rows below the one it was declared in
+struct PendingSpan {
+    rows_left: usize,
+    cols: usize,
+}
+
+/// Extract every table, rendered in `format` and separated by blank lines
+pub fn extract_tables(html: &str, format: TableFormat) -> String {
+    parse_tables(html)
+        .iter()
+        .map(|table| match format {
+            TableFormat::Markdown => to_markdown(table),
+            TableFormat::Csv => to_csv(table),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Parse all `<table>` elements (nested tables are not supported)
+pub fn parse_tables(html: &str) -> Vec<HtmlTable> {
+    let table_re = Regex::new(r"(?is)<table\b[^>]*>(.*?)</table>").unwrap();
+    table_re
+        .captures_iter(html)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| parse_table(m.as_str()))
+        .filter(|table| !table.rows.is_empty())
+        .collect()
+}
+
+fn parse_table(inner: &str) -> HtmlTable {
+    let row_re = Regex::new(r"(?is)<tr\b[^>]*>(.*?)</tr>").unwrap();
+    let cell_re = Regex::new(r"(?is)<(th|td)\b([^>]*)>(.*?)</t[hd]>").unwrap();
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut pending: Vec<Option<PendingSpan>> = Vec::new();
+    let mut has_header = false;
+
+    for (row_idx, row_caps) in row_re.captures_iter(inner).enumerate() {
+        let mut row = Vec::new();
+        let mut all_th = true;
+
+        for cell in cell_re.captures_iter(&row_caps[1]) {
+            pad_spans(&mut row, &mut pending);
+            all_th &= cell[1].eq_ignore_ascii_case("th");
+
+            let attrs = &cell[2];
+            let colspan = span_attr(attrs, "colspan");
+            let rowspan = span_attr(attrs, "rowspan");
+            let col = row.len();
+
+            row.push(cell_text(&cell[3]));
+            row.extend(std::iter::repeat(String::new()).take(colspan - 1));
+            if rowspan > 1 {
+                if pending.len() <= col {
+                    pending.resize_with(col + 1, || None);
+                }
+                pending[col] = Some(PendingSpan {
+                    rows_left: rowspan - 1,
+                    cols: colspan,
+                });
+            }
+        }
+        // Spans covering columns after the last declared cell
+        while pending.iter().skip(row.len()).any(Option::is_some) {
+            if pending[row.len()].is_some() {
+                pad_spans(&mut row, &mut pending);
+            } else {
+                row.push(String::new());
+            }
+        }
+
+        if row_idx == 0 {
+            has_header = all_th && !row.is_empty();
+        }
+        if !row.is_empty() {
+            rows.push(row);
+        }
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, String::new());
+    }
+    HtmlTable { has_header, rows }
+}
+
+/// Fill empty cells for rowspans from earlier rows that cover the next column
+fn pad_spans(row: &mut Vec<String>, pending: &mut [Option<PendingSpan>]) {
+    while let Some(slot) = pending.get_mut(row.len()) {
+        let Some(span) = slot else {
+            break;
+        };
+        row.extend(std::iter::repeat(String::new()).take(span.cols));
+        span.rows_left -= 1;
+        if span.rows_left == 0 {
+            *slot = None;
+        }
+    }
+}
+
+/// Read a `colspan`/`rowspan` attribute, defaulting to 1
+fn span_attr(attrs: &str, name: &str) -> usize {
+    let re = Regex::new(&format!(r#"(?i)\b{}\s*=\s*["']?(\d+)"#, name)).unwrap();
+    re.captures(attrs)
+        .and_then(|caps| caps[1].parse().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(1)
+}
+
+/// Text content of a cell on one line
+fn cell_text(html: &str) -> String {
+    let text = decode_entities(&strip_tags(html));
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render as a GitHub-flavored Markdown table
+///
+/// Tables without a `<th>` row get an empty header so no data row is promoted.
+pub fn to_markdown(table: &HtmlTable) -> String {
+    let width = table.rows.first().map_or(0, Vec::len);
+    let md_row = |cells: &[String]| {
+        let escaped: Vec<String> = cells.iter().map(|c| c.replace('|', "\\|")).collect();
+        format!("| {} |", escaped.join(" | "))
+    };
+
+    let (header, body) = if table.has_header {
+        (table.rows[0].clone(), &table.rows[1..])
+    } else {
+        (vec![String::new(); width], &table.rows[..])
+    };
+
+    let mut lines = vec![md_row(&header), format!("|{}", " --- |".repeat(width))];
+    lines.extend(body.iter().map(|row| md_row(row)));
+    lines.join("\n")
+}
+
+/// Render as CSV (RFC 4180 quoting)
+pub fn to_csv(table: &HtmlTable) -> String {
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| csv_field(cell))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|c| c.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn simple_two_by_two() {
+        let html = "<table><tr><td>a</td><td>b</td></tr><tr><td>c</td><td>d</td></tr></table>";
+        let tables = parse_tables(html);
+        assert_eq!(tables.len(), 1);
+        assert!(!tables[0].has_header);
+        assert_eq!(tables[0].rows, cells(&[&["a", "b"], &["c", "d"]]));
+        assert_eq!(
+            extract_tables(html, TableFormat::Markdown),
+            "|  |  |\n| --- | --- |\n| a | b |\n| c | d |"
+        );
+    }
+
+    #[test]
+    fn header_row() {
+        let html = r#"
+            <table>
+              <thead><tr><th>Name</th><th>Price</th></tr></thead>
+              <tbody>
+                <tr><td><b>Widget</b></td><td>&lt;$5 &amp; up</td></tr>
+              </tbody>
+            </table>"#;
+        assert_eq!(
+            extract_tables(html, TableFormat::Markdown),
+            "| Name | Price |\n| --- | --- |\n| Widget | <$5 & up |"
+        );
+    }
+
+    #[test]
+    fn csv_output_quotes_fields() {
+        let html = r#"<table><tr><th>k</th><th>v</th></tr><tr><td>a, b</td><td>say "hi"</td></tr></table>"#;
+        assert_eq!(
+            extract_tables(html, TableFormat::Csv),
+            "k,v\n\"a, b\",\"say \"\"hi\"\"\""
+        );
+    }
+
+    #[test]
+    fn colspan_pads_cells() {
+        let html = r#"<table><tr><td colspan="2">wide</td><td>x</td></tr><tr><td>1</td><td>2</td><td>3</td></tr></table>"#;
+        let table = &parse_tables(html)[0];
+        assert_eq!(table.rows, cells(&[&["wide", "", "x"], &["1", "2", "3"]]));
+    }
+
+    #[test]
+    fn rowspan_pads_following_rows() {
+        let html = r#"<table>
+            <tr><td rowspan="2">group</td><td>a</td><td rowspan=3>side</td></tr>
+            <tr><td>b</td></tr>
+            <tr><td>next</td><td>c</td></tr>
+        </table>"#;
+        let table = &parse_tables(html)[0];
+        assert_eq!(
+            table.rows,
+            cells(&[&["group", "a", "side"], &["", "b", ""], &["next", "c", ""],])
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_multiple_tables() {
+        let html = "<table><tr><td>a|b</td></tr></table><p>x</p><table><tr><td>c</td></tr></table>";
+        assert_eq!(
+            extract_tables(html, TableFormat::Markdown),
+            "|  |\n| --- |\n| a\\|b |\n\n|  |\n| --- |\n| c |"
+        );
+    }
+
+    #[test]
+    fn no_tables() {
+        assert_eq!(extract_tables("<p>none</p>", TableFormat::Csv), "");
+    }
+}
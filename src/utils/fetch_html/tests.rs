@@ -293,3 +293,27 @@ fn html_to_markdown_apos_entity() {
     let md = html_to_markdown(html);
     assert!(md.contains("it's"));
 }
+
+#[test]
+fn canonical_url_absolute() {
+    let html = r#"<head><link rel="stylesheet" href="/a.css"><link href="https://example.com/post?id=1&amp;x=2" rel="canonical"></head>"#;
+    assert_eq!(
+        canonical_url(html, "https://t.co/abc").as_deref(),
+        Some("https://example.com/post?id=1&x=2")
+    );
+}
+
+#[test]
+fn canonical_url_relative_resolves_against_base() {
+    let html = r#"<link rel='canonical' href='/blog/post'>"#;
+    assert_eq!(
+        canonical_url(html, "https://example.com/landing?utm=1").as_deref(),
+        Some("https://example.com/blog/post")
+    );
+}
+
+#[test]
+fn canonical_url_missing() {
+    let html = r#"<link rel="alternate" href="/feed.xml">"#;
+    assert!(canonical_url(html, "https://example.com").is_none());
+}
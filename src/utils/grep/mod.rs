@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::collections::HashMap;
@@ -9,6 +9,7 @@ mod web_search;
 
 pub use cli::UtilsCommand;
 
+use anstream::println;
 use anyhow::Result;
 
 /// Run a utils subcommand
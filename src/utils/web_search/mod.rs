@@ -1,3 +1,4 @@
+use anstream::println;
 use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 use std::fs;
@@ -63,8 +64,7 @@ pub struct BraveSearchClient {
 
 impl BraveSearchClient {
     pub fn new(api_key: String) -> Self {
-        let http = reqwest::Client::builder()
-            .user_agent("hu-cli/0.1")
+        let http = crate::util::http::builder()
             .build()
             .expect("Failed to build HTTP client");
         Self { api_key, http }
@@ -131,8 +131,7 @@ impl Default for DefaultHttpFetcher {
 
 impl DefaultHttpFetcher {
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
-            .user_agent("hu-cli/0.1")
+        let http = crate::util::http::builder()
             .timeout(std::time::Duration::from_secs(10))
             .build()
             .expect("Failed to build HTTP client");
@@ -243,7 +242,7 @@ pub async fn run(args: WebSearchArgs) -> Result<()> {
 
     if let Some(path) = args.output {
         fs::write(&path, &output).with_context(|| format!("Failed to write to {}", path))?;
-        eprintln!("Written to {}", path);
+        log::info!("Written to {}", path);
     } else {
         println!("{}", output);
     }
@@ -10,7 +10,7 @@ fn no_args_shows_help_and_exits_zero() {
 
     assert!(output.status.success(), "expected exit code 0");
     let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(stdout.contains("Usage: hu [COMMAND]"));
+    assert!(stdout.contains("Usage: hu [OPTIONS] [COMMAND]"));
     assert!(stdout.contains("Commands:"));
 }
 